@@ -65,6 +65,8 @@ class FileType(Enum):
     FILE_TYPE_UNSPECIFIED = 0
     FILE_TYPE_PARQUET = 1
     FILE_TYPE_CSV = 2
+    FILE_TYPE_JSONL = 3
+    FILE_TYPE_ARROW_IPC = 4
 
 
 class ObjectStoreDestination(Destination):
@@ -166,6 +166,12 @@ impl SourceData {
                 source_data::Source::ParquetPath(format!("file://{}", path.display()))
             }
             Some("csv") => source_data::Source::CsvPath(format!("file://{}", path.display())),
+            Some("jsonl" | "ndjson") => {
+                source_data::Source::JsonlPath(format!("file://{}", path.display()))
+            }
+            Some("arrow" | "feather" | "ipc") => {
+                source_data::Source::ArrowIpcPath(format!("file://{}", path.display()))
+            }
             unsupported => anyhow::bail!("Unsupported extension {:?}", unsupported),
         };
         Ok(path)
@@ -198,6 +204,8 @@ impl std::fmt::Display for TickBehavior {
             TickBehavior::Daily => write!(f, "daily"),
             TickBehavior::Monthly => write!(f, "monthly"),
             TickBehavior::Yearly => write!(f, "yearly"),
+            TickBehavior::Every => write!(f, "every"),
+            TickBehavior::Cron => write!(f, "cron"),
             TickBehavior::Finished => write!(f, "final"),
             TickBehavior::Unspecified => panic!("Unspecified tick behavior"),
         }
@@ -47,6 +47,13 @@ impl Literal {
             Some(literal::Literal::Uint64(v)) => ScalarValue::UInt64(Some(*v)),
             Some(literal::Literal::Float32(v)) => ScalarValue::Float32(Some((*v).into())),
             Some(literal::Literal::Float64(v)) => ScalarValue::Float64(Some((*v).into())),
+            Some(literal::Literal::Decimal(v)) => {
+                let value = v
+                    .value
+                    .parse::<i128>()
+                    .with_context(|| format!("invalid decimal literal '{}'", v.value))?;
+                ScalarValue::Decimal128(Some(value), v.precision.try_into()?, v.scale.try_into()?)
+            }
             Some(literal::Literal::Timestamp(v)) => {
                 let tu = timeunit_from_suffix(&v.unit)?;
                 let tz = v.tz.as_ref().map(|tz| Arc::from(tz.clone()));
@@ -73,18 +80,20 @@ impl Literal {
             Some(literal::Literal::Utf8(v)) => ScalarValue::Utf8(Some(v.clone())),
             Some(literal::Literal::LargeUtf8(v)) => ScalarValue::LargeUtf8(Some(v.clone())),
             Some(literal::Literal::Record(v)) => {
-                let values = v
-                    .values
-                    .iter()
-                    .map(|v| v.clone().try_into_scalar_value(data_type))
-                    .collect::<anyhow::Result<Vec<ScalarValue>>>()?;
-
                 let fields = if let DataType::Struct(fields) = data_type {
                     fields
                 } else {
                     unreachable!("Record value has non-struct type {:?}", data_type)
                 };
 
+                // Each value has the type of the corresponding field.
+                let values = v
+                    .values
+                    .iter()
+                    .zip(fields.iter())
+                    .map(|(v, field)| v.clone().try_into_scalar_value(field.data_type()))
+                    .collect::<anyhow::Result<Vec<ScalarValue>>>()?;
+
                 ScalarValue::Record(Box::new(ScalarRecord::new(Some(values), fields.clone())))
             }
             None => ScalarValue::try_new_null(data_type)?,
@@ -108,6 +117,13 @@ impl From<&ScalarValue> for Literal {
             ScalarValue::UInt64(Some(v)) => Some(literal::Literal::Uint64(*v)),
             ScalarValue::Float32(Some(v)) => Some(literal::Literal::Float32(v.into_inner())),
             ScalarValue::Float64(Some(v)) => Some(literal::Literal::Float64(v.into_inner())),
+            ScalarValue::Decimal128(Some(v), precision, scale) => {
+                Some(literal::Literal::Decimal(literal::DecimalValue {
+                    value: v.to_string(),
+                    precision: *precision as u32,
+                    scale: *scale as i32,
+                }))
+            }
             ScalarValue::Timestamp(v) => {
                 let tz = v.tz().map(|tz| tz.as_ref().to_owned());
                 Some(literal::Literal::Timestamp(literal::TimestampValue {
@@ -44,11 +44,40 @@ impl DataType {
         }
     }
 
+    /// Creates a new list with the given item type.
+    pub fn new_list(item_type: DataType) -> Self {
+        Self {
+            kind: Some(data_type::Kind::List(Box::new(item_type))),
+        }
+    }
+
     pub fn new_primitive(primitive: data_type::PrimitiveType) -> Self {
         Self {
             kind: Some(data_type::Kind::Primitive(primitive as i32)),
         }
     }
+
+    /// Creates a new decimal type with the given precision and scale.
+    pub fn new_decimal(precision: u8, scale: i8) -> Self {
+        Self {
+            kind: Some(data_type::Kind::Decimal(data_type::Decimal {
+                precision: precision as u32,
+                scale: scale as i32,
+            })),
+        }
+    }
+
+    /// Creates a new timestamp type with the given unit and time zone.
+    ///
+    /// The `unit` should be one of the `Timestamp*` primitive types.
+    pub fn new_timestamp(unit: data_type::PrimitiveType, time_zone: &str) -> Self {
+        Self {
+            kind: Some(data_type::Kind::Timestamp(data_type::Timestamp {
+                unit: unit as i32,
+                time_zone: time_zone.to_owned(),
+            })),
+        }
+    }
 }
 
 fn fields_to_arrow(
@@ -149,6 +178,15 @@ impl TryFrom<&arrow::datatypes::DataType> for DataType {
             arrow::datatypes::DataType::Timestamp(arrow::datatypes::TimeUnit::Nanosecond, None) => {
                 Ok(DataType::new_primitive(PrimitiveType::TimestampNanosecond))
             }
+            arrow::datatypes::DataType::Timestamp(unit, Some(time_zone)) => {
+                let unit = match unit {
+                    arrow::datatypes::TimeUnit::Second => PrimitiveType::TimestampSecond,
+                    arrow::datatypes::TimeUnit::Millisecond => PrimitiveType::TimestampMillisecond,
+                    arrow::datatypes::TimeUnit::Microsecond => PrimitiveType::TimestampMicrosecond,
+                    arrow::datatypes::TimeUnit::Nanosecond => PrimitiveType::TimestampNanosecond,
+                };
+                Ok(DataType::new_timestamp(unit, time_zone))
+            }
             arrow::datatypes::DataType::Duration(arrow::datatypes::TimeUnit::Second) => {
                 Ok(DataType::new_primitive(PrimitiveType::DurationSecond))
             }
@@ -171,6 +209,9 @@ impl TryFrom<&arrow::datatypes::DataType> for DataType {
             arrow::datatypes::DataType::LargeUtf8 => {
                 Ok(DataType::new_primitive(PrimitiveType::LargeString))
             }
+            arrow::datatypes::DataType::Decimal128(precision, scale) => {
+                Ok(DataType::new_decimal(*precision, *scale))
+            }
             arrow::datatypes::DataType::Struct(fields) => {
                 let fields = fields
                     .iter()
@@ -188,13 +229,21 @@ impl TryFrom<&arrow::datatypes::DataType> for DataType {
                     .try_collect()?;
                 Ok(DataType::new_struct(fields))
             }
+            arrow::datatypes::DataType::List(item) => {
+                let item_type = item.data_type().try_into().map_err(
+                    |err: ConversionError<arrow::datatypes::DataType>| {
+                        err.with_prepend_field("list item".to_owned())
+                    },
+                )?;
+                Ok(DataType::new_list(item_type))
+            }
             // Note: the `ordered` field may let us specialize the implementation
             // to use binary search in the future.
             arrow::datatypes::DataType::Map(s, is_ordered) => {
                 // [DataType::Map] is represented as a list of structs with two fields: `key` and `value`
                 let arrow::datatypes::DataType::Struct(fields) = s.data_type() else {
                     // unexpected - maps should always contain a struct
-                    return Err(ConversionError::new_unsupported(s.data_type().clone()))
+                    return Err(ConversionError::new_unsupported(s.data_type().clone()));
                 };
 
                 debug_assert!(fields.len() == 2, "expect two fields in map");
@@ -334,6 +383,43 @@ impl TryFrom<&DataType> for arrow::datatypes::DataType {
                     }
                 }
             }
+            Some(data_type::Kind::Decimal(decimal)) => {
+                let precision = u8::try_from(decimal.precision)
+                    .ok()
+                    .filter(|p| (1..=arrow::datatypes::DECIMAL128_MAX_PRECISION).contains(p));
+                let scale = i8::try_from(decimal.scale)
+                    .ok()
+                    .filter(|s| s.unsigned_abs() <= arrow::datatypes::DECIMAL128_MAX_SCALE as u8);
+                match (precision, scale) {
+                    (Some(precision), Some(scale)) => {
+                        Ok(arrow::datatypes::DataType::Decimal128(precision, scale))
+                    }
+                    _ => Err(ConversionError::new_unsupported(value.clone())),
+                }
+            }
+            Some(data_type::Kind::Timestamp(timestamp)) => {
+                use data_type::PrimitiveType;
+                let unit = match PrimitiveType::from_i32(timestamp.unit) {
+                    Some(PrimitiveType::TimestampSecond) => arrow::datatypes::TimeUnit::Second,
+                    Some(PrimitiveType::TimestampMillisecond) => {
+                        arrow::datatypes::TimeUnit::Millisecond
+                    }
+                    Some(PrimitiveType::TimestampMicrosecond) => {
+                        arrow::datatypes::TimeUnit::Microsecond
+                    }
+                    Some(PrimitiveType::TimestampNanosecond) => {
+                        arrow::datatypes::TimeUnit::Nanosecond
+                    }
+                    _ => return Err(ConversionError::new_unsupported(value.clone())),
+                };
+                if timestamp.time_zone.is_empty() {
+                    return Err(ConversionError::new_unsupported(value.clone()));
+                }
+                Ok(arrow::datatypes::DataType::Timestamp(
+                    unit,
+                    Some(timestamp.time_zone.as_str().into()),
+                ))
+            }
             Some(data_type::Kind::Struct(schema)) => Ok(arrow::datatypes::DataType::Struct(
                 fields_to_arrow(&schema.fields)?.into(),
             )),
@@ -485,6 +571,47 @@ mod tests {
         assert_data_type_round_trip(&outer_struct_type);
     }
 
+    #[test]
+    fn test_list_round_trip() {
+        let list_type = arrow::datatypes::DataType::List(Arc::new(arrow::datatypes::Field::new(
+            "item",
+            arrow::datatypes::DataType::Int64,
+            true,
+        )));
+        assert_data_type_round_trip(&list_type);
+
+        let nested_list_type = arrow::datatypes::DataType::List(Arc::new(
+            arrow::datatypes::Field::new("item", list_type, true),
+        ));
+        assert_data_type_round_trip(&nested_list_type);
+    }
+
+    #[test]
+    fn test_decimal_round_trip() {
+        assert_data_type_round_trip(&arrow::datatypes::DataType::Decimal128(10, 2));
+        assert_data_type_round_trip(&arrow::datatypes::DataType::Decimal128(38, 0));
+        assert_data_type_round_trip(&arrow::datatypes::DataType::Decimal128(5, -2));
+    }
+
+    #[test]
+    fn test_timestamp_with_time_zone_round_trip() {
+        assert_data_type_round_trip(&arrow::datatypes::DataType::Timestamp(
+            arrow::datatypes::TimeUnit::Nanosecond,
+            Some("America/New_York".into()),
+        ));
+        assert_data_type_round_trip(&arrow::datatypes::DataType::Timestamp(
+            arrow::datatypes::TimeUnit::Millisecond,
+            Some("+07:00".into()),
+        ));
+    }
+
+    #[test]
+    fn test_invalid_decimal_precision() {
+        let data_type = DataType::new_decimal(39, 2);
+        let err = arrow::datatypes::DataType::try_from(&data_type).unwrap_err();
+        assert_eq!(err.data_type, data_type);
+    }
+
     #[test]
     fn test_schema_round_trip() {
         // Schema with primitive fields.
@@ -33,6 +33,10 @@ pub enum ScalarValue {
     UInt64(Option<u64>),
     Float32(Option<Total<f32>>),
     Float64(Option<Total<f64>>),
+    /// A 128-bit fixed-point decimal.
+    ///
+    /// The value is stored unscaled, along with the precision and scale.
+    Decimal128(Option<i128>, u8, i8),
     Timestamp(Box<ScalarTimestamp>),
     /// A 32-bit date representing the days since the epoch.
     Date32(Option<i32>),
@@ -118,6 +122,11 @@ impl std::fmt::Display for ScalarValue {
             ScalarValue::UInt64(Some(n)) => write!(f, "{n}u64"),
             ScalarValue::Float32(Some(n)) => write!(f, "{n}f32"),
             ScalarValue::Float64(Some(n)) => write!(f, "{n}f64"),
+            ScalarValue::Decimal128(Some(n), precision, scale) => write!(
+                f,
+                "decimal({precision},{scale}):{}",
+                Decimal128Type::format_decimal(*n, *precision, *scale)
+            ),
             ScalarValue::Timestamp(timestamp) => {
                 assert!(
                     timestamp.tz.is_none(),
@@ -280,6 +289,9 @@ impl ScalarValue {
             DataType::UInt64 => Ok(Self::UInt64(None)),
             DataType::Float32 => Ok(Self::Float32(None)),
             DataType::Float64 => Ok(Self::Float64(None)),
+            DataType::Decimal128(precision, scale) => {
+                Ok(Self::Decimal128(None, *precision, *scale))
+            }
             DataType::Timestamp(unit, tz) => Ok(Self::Timestamp(Box::new(ScalarTimestamp {
                 value: None,
                 unit: unit.clone(),
@@ -320,6 +332,9 @@ impl ScalarValue {
             ScalarValue::UInt64(_) => DataType::UInt64,
             ScalarValue::Float32(_) => DataType::Float32,
             ScalarValue::Float64(_) => DataType::Float64,
+            ScalarValue::Decimal128(_, precision, scale) => {
+                DataType::Decimal128(*precision, *scale)
+            }
             ScalarValue::Timestamp(timestamp) => {
                 DataType::Timestamp(timestamp.unit.clone(), timestamp.tz.clone())
             }
@@ -358,6 +373,15 @@ impl ScalarValue {
             ScalarValue::Float64(n) => {
                 fill_primitive::<Float64Type>(len, &n.map(|f| f.into_inner()))
             }
+            ScalarValue::Decimal128(n, precision, scale) => {
+                let iter = std::iter::repeat(n).take(len);
+                // Safety: The iterator is of a fixed size.
+                let array =
+                    unsafe { PrimitiveArray::<Decimal128Type>::from_trusted_len_iter(iter) }
+                        .with_precision_and_scale(*precision, *scale)
+                        .expect("valid decimal precision and scale");
+                Arc::new(array)
+            }
             ScalarValue::Timestamp(ts) => match ts.unit {
                 TimeUnit::Second => fill_primitive::<TimestampSecondType>(len, &ts.value),
                 TimeUnit::Millisecond => fill_primitive::<TimestampMillisecondType>(len, &ts.value),
@@ -482,6 +506,11 @@ impl ScalarValue {
             DataType::Float64 => Ok(Self::Float64(
                 from_primitive::<Float64Type>(row, array)?.map(Total::from_inner),
             )),
+            DataType::Decimal128(precision, scale) => Ok(Self::Decimal128(
+                from_primitive::<Decimal128Type>(row, array)?,
+                *precision,
+                *scale,
+            )),
             DataType::Timestamp(unit, tz) => {
                 let value = match unit {
                     TimeUnit::Second => from_primitive::<TimestampSecondType>(row, array)?,
@@ -599,6 +628,7 @@ impl ScalarValue {
             ScalarValue::UInt64(n) => n.is_none(),
             ScalarValue::Float32(n) => n.is_none(),
             ScalarValue::Float64(n) => n.is_none(),
+            ScalarValue::Decimal128(n, _, _) => n.is_none(),
             ScalarValue::Timestamp(n) => n.value.is_none(),
             ScalarValue::Date32(n) => n.is_none(),
             ScalarValue::Date64(n) => n.is_none(),
@@ -628,6 +658,9 @@ impl ScalarValue {
             ScalarValue::UInt64(_) => ScalarValue::UInt64(None),
             ScalarValue::Float32(_) => ScalarValue::Float32(None),
             ScalarValue::Float64(_) => ScalarValue::Float64(None),
+            ScalarValue::Decimal128(_, precision, scale) => {
+                ScalarValue::Decimal128(None, *precision, *scale)
+            }
             ScalarValue::Timestamp(timestamp) => {
                 ScalarValue::Timestamp(Box::new(ScalarTimestamp {
                     value: None,
@@ -660,7 +693,8 @@ impl ScalarValue {
             | ScalarValue::UInt8(Some(0))
             | ScalarValue::UInt16(Some(0))
             | ScalarValue::UInt32(Some(0))
-            | ScalarValue::UInt64(Some(0)) => true,
+            | ScalarValue::UInt64(Some(0))
+            | ScalarValue::Decimal128(Some(0), _, _) => true,
             ScalarValue::Float32(Some(f)) => f.is_zero(),
             ScalarValue::Float64(Some(f)) => f.is_zero(),
             _ => false,
@@ -793,6 +827,19 @@ native_from_scalar!(Date64Type, Date64);
 
 native_from_scalar!(IntervalYearMonthType, IntervalMonths);
 
+impl NativeFromScalar for Decimal128Type {
+    fn native_from_scalar(scalar: &ScalarValue) -> anyhow::Result<Option<Self::Native>> {
+        match scalar {
+            ScalarValue::Decimal128(n, _, _) => Ok(*n),
+            _ => Err(anyhow!(
+                "Unable to convert {:?} to {:?}",
+                scalar,
+                Self::DATA_TYPE
+            )),
+        }
+    }
+}
+
 macro_rules! native_timestamp {
     ($arrow_type:ty, $time_case:ident, $timeunit:expr) => {
         impl NativeFromScalar for $arrow_type {
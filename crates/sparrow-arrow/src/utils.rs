@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
-use arrow::array::{ArrayData, ArrayRef, StructArray};
+use arrow::array::{make_array, ArrayData, ArrayRef, StructArray};
 use arrow::buffer::Buffer;
 use arrow::datatypes::{DataType, FieldRef, Fields};
+use arrow::error::ArrowError;
 
 /// Create a StructArray with the given length and fields.
 ///
@@ -99,3 +100,24 @@ pub fn make_struct_array_null(
         StructArray::from((fields, null_buffer))
     }
 }
+
+/// Cast a timestamp array to the given timestamp type, preserving instants.
+///
+/// Sparrow interprets timestamps without a time zone as UTC. When adding a
+/// time zone, Arrow instead interprets them as wall-clock times in that zone,
+/// which would shift the instants. This converts the unit and then attaches
+/// (or removes) the time zone without changing the underlying values.
+pub fn cast_timestamp(array: &ArrayRef, to: &DataType) -> Result<ArrayRef, ArrowError> {
+    let DataType::Timestamp(to_unit, _) = to else {
+        return Err(ArrowError::CastError(format!(
+            "Expected timestamp to cast to, but was {to:?}"
+        )));
+    };
+    let converted = arrow::compute::cast(array, &DataType::Timestamp(to_unit.clone(), None))?;
+    let converted = converted
+        .to_data()
+        .into_builder()
+        .data_type(to.clone())
+        .build()?;
+    Ok(make_array(converted))
+}
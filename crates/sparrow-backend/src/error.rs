@@ -0,0 +1,29 @@
+use std::borrow::Cow;
+
+#[derive(derive_more::Display, Debug)]
+pub enum Error {
+    #[display(fmt = "invalid operation {operation}: {reason}")]
+    InvalidOperation {
+        operation: usize,
+        reason: Cow<'static, str>,
+    },
+    #[display(fmt = "unsupported operation {operation}: {reason}")]
+    UnsupportedOperation {
+        operation: usize,
+        reason: Cow<'static, str>,
+    },
+    #[display(fmt = "invalid expression {expression} in operation {operation}: {reason}")]
+    InvalidExpression {
+        operation: usize,
+        expression: usize,
+        reason: Cow<'static, str>,
+    },
+    #[display(fmt = "unsupported expression {expression} in operation {operation}: {reason}")]
+    UnsupportedExpression {
+        operation: usize,
+        expression: usize,
+        reason: Cow<'static, str>,
+    },
+}
+
+impl error_stack::Context for Error {}
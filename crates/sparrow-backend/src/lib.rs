@@ -14,6 +14,10 @@
 //! It also performs optimizations on both the logical plans and the physical
 //! plans.
 
+mod error;
+mod lower_compute_plan;
 mod pipeline_schedule;
 
+pub use error::*;
+pub use lower_compute_plan::*;
 pub use pipeline_schedule::*;
@@ -0,0 +1,1276 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use error_stack::{IntoReport, ResultExt};
+use index_vec::IndexVec;
+use sparrow_api::kaskada::v1alpha::operation_input_ref::{Column, Interpolation, KeyColumn};
+use sparrow_api::kaskada::v1alpha::operation_plan::tick_operation::TickBehavior;
+use sparrow_api::kaskada::v1alpha::operation_plan::{shift_to_operation, Operator, TickOperation};
+use sparrow_api::kaskada::v1alpha::{
+    expression_plan, literal, ComputePlan, ExpressionPlan, LateBoundValue, Literal,
+    OperationInputRef, OperationPlan,
+};
+use sparrow_arrow::scalar_value::{ScalarTimestamp, ScalarValue};
+use sparrow_physical::{Aggregation, Expr, ExprId, Exprs, Plan, Step, StepId, StepKind, Window};
+use sparrow_plan::InstOp;
+
+use crate::{pipeline_schedule, Error};
+
+/// Lower a [ComputePlan] produced by the compiler to a physical [Plan].
+///
+/// Each operation is lowered to a step producing the rows of the operation --
+/// a scan, merge, filter or repartition -- followed by a projection computing
+/// the output expressions of the operation. The column computed by output
+/// expression `e` of operation `o` is named `o_e`, which is how steps lowered
+/// from later operations reference it.
+///
+/// Repartition steps lowered from `with_key` operations use `num_partitions`.
+///
+/// Ticks, shifts and lookups are lowered to the corresponding steps. The
+/// aggregations of an operation are computed by an aggregate step between the
+/// step producing the rows and the projection, which reads the aggregated
+/// values from columns named like the output expressions. Expressions which
+/// can't be lowered yet, such as nested aggregations and as-of interpolation
+/// within a merge, are reported as unsupported.
+pub fn lower_compute_plan(
+    plan: &ComputePlan,
+    num_partitions: usize,
+) -> error_stack::Result<Plan, Error> {
+    let mut lowering = Lowering {
+        steps: IndexVec::new(),
+        outputs: Vec::with_capacity(plan.operations.len()),
+        num_partitions,
+    };
+    for (index, operation) in plan.operations.iter().enumerate() {
+        let output = lowering.lower_operation(index, operation)?;
+        lowering.outputs.push(output);
+    }
+
+    let pipelines = pipeline_schedule(&lowering.steps);
+    Ok(Plan {
+        steps: lowering.steps,
+        pipelines,
+    })
+}
+
+/// Return the name of the column containing an output expression.
+fn column_name(operation: usize, expression: usize) -> String {
+    format!("{operation}_{expression}")
+}
+
+/// Return the physical tick behavior of a tick operation.
+fn tick_behavior(tick: &TickOperation) -> Option<sparrow_physical::TickBehavior> {
+    use sparrow_physical::TickBehavior as Physical;
+
+    let behavior = match tick.behavior() {
+        TickBehavior::Unspecified => return None,
+        TickBehavior::Finished => Physical::Finished,
+        TickBehavior::Minutely => Physical::Minutely,
+        TickBehavior::Hourly => Physical::Hourly,
+        TickBehavior::Daily => Physical::Daily,
+        TickBehavior::Monthly => Physical::Monthly,
+        TickBehavior::Yearly => Physical::Yearly,
+        TickBehavior::Every => {
+            let every = tick.every.as_ref()?;
+            Physical::Every {
+                period_ns: every.period_ns,
+                offset_ns: every.offset_ns,
+            }
+        }
+        TickBehavior::Cron => Physical::Cron {
+            schedule: tick.cron.as_ref()?.schedule.clone(),
+        },
+    };
+    Some(behavior)
+}
+
+/// Return true if the expression applies an aggregation.
+fn is_aggregation(expression: &ExpressionPlan) -> bool {
+    match &expression.operator {
+        Some(expression_plan::Operator::Instruction(name)) => {
+            InstOp::from_str(name).is_ok_and(|op| op.is_aggregation())
+        }
+        _ => false,
+    }
+}
+
+/// Create an expression reading the named input column.
+fn column(name: String, result_type: DataType) -> Expr {
+    Expr {
+        name: "column".into(),
+        literal_args: vec![ScalarValue::Utf8(Some(name))],
+        args: vec![],
+        result_type,
+    }
+}
+
+struct Lowering {
+    steps: IndexVec<StepId, Step>,
+    /// The step producing the output columns of each lowered operation.
+    outputs: Vec<StepId>,
+    num_partitions: usize,
+}
+
+impl Lowering {
+    fn add_step(&mut self, kind: StepKind, inputs: Vec<StepId>, schema: SchemaRef) -> StepId {
+        self.steps.push(Step {
+            kind,
+            inputs,
+            schema,
+        })
+    }
+
+    /// Return the step producing the outputs of an earlier operation.
+    fn output(&self, operation: usize, input: u32) -> error_stack::Result<StepId, Error> {
+        self.outputs.get(input as usize).copied().ok_or_else(|| {
+            error_stack::report!(Error::InvalidOperation {
+                operation,
+                reason: format!("input operation {input} has not been lowered").into()
+            })
+        })
+    }
+
+    fn lower_operation(
+        &mut self,
+        index: usize,
+        operation: &OperationPlan,
+    ) -> error_stack::Result<StepId, Error> {
+        let invalid = |reason: &'static str| Error::InvalidOperation {
+            operation: index,
+            reason: reason.into(),
+        };
+
+        let operator = operation
+            .operator
+            .as_ref()
+            .ok_or_else(|| error_stack::report!(invalid("missing operator")))?;
+
+        // Determine the step producing the rows of the operation, and the
+        // operations which the expressions may reference.
+        let (input, inputs) = match operator {
+            Operator::Scan(scan) => {
+                let table_name = scan
+                    .slice_plan
+                    .as_ref()
+                    .ok_or_else(|| error_stack::report!(invalid("missing table name")))?
+                    .table_name
+                    .clone();
+                let schema = scan
+                    .schema
+                    .as_ref()
+                    .ok_or_else(|| error_stack::report!(invalid("missing table schema")))?
+                    .as_arrow_schema()
+                    .map_err(|e| {
+                        error_stack::report!(invalid("unsupported table schema"))
+                            .attach_printable(e.to_string())
+                    })?;
+                let scan = self.add_step(StepKind::Scan { table_name }, vec![], Arc::new(schema));
+                // The scan record is read from the scan operation itself.
+                (scan, vec![index as u32])
+            }
+            Operator::Merge(merge) => {
+                let left = self.output(index, merge.left)?;
+                let right = self.output(index, merge.right)?;
+                let fields: Vec<_> = self.steps[left]
+                    .schema
+                    .fields()
+                    .iter()
+                    .chain(self.steps[right].schema.fields().iter())
+                    .cloned()
+                    .collect();
+                let merge_step = self.add_step(
+                    StepKind::Merge,
+                    vec![left, right],
+                    Arc::new(Schema::new(fields)),
+                );
+                (merge_step, vec![merge.left, merge.right])
+            }
+            Operator::Select(select) => {
+                let input = self.output(index, select.input)?;
+                let condition = self.input_column(index, input, select.condition.as_ref())?;
+                let schema = self.steps[input].schema.clone();
+                let filter = self.add_step(
+                    StepKind::Filter {
+                        exprs: Exprs::singleton(vec![condition]),
+                    },
+                    vec![input],
+                    schema,
+                );
+                (filter, vec![select.input])
+            }
+            Operator::WithKey(with_key) => {
+                let input = self.output(index, with_key.input)?;
+                let key = self.input_column(index, input, with_key.new_key.as_ref())?;
+                let schema = self.steps[input].schema.clone();
+                let repartition = self.add_step(
+                    StepKind::Repartition {
+                        num_partitions: self.num_partitions,
+                        keys: Exprs::singleton(vec![key]),
+                    },
+                    vec![input],
+                    schema,
+                );
+                (repartition, vec![with_key.input])
+            }
+            Operator::Tick(tick) => {
+                // The tick rows are produced for the keys of the input, but
+                // contain none of its columns.
+                let input = self.output(index, tick.input)?;
+                let behavior = tick_behavior(tick)
+                    .ok_or_else(|| error_stack::report!(invalid("invalid tick behavior")))?;
+                let time_zone = match tick.behavior() {
+                    TickBehavior::Cron => tick.cron.as_ref().map(|cron| cron.time_zone.clone()),
+                    _ => Some(tick.time_zone.clone()),
+                }
+                .filter(|time_zone| !time_zone.is_empty());
+                let tick_step = self.add_step(
+                    StepKind::Tick {
+                        behavior,
+                        time_zone,
+                    },
+                    vec![input],
+                    Arc::new(Schema::empty()),
+                );
+                // The tick column is read from the tick operation itself.
+                (tick_step, vec![index as u32])
+            }
+            Operator::ShiftTo(shift_to) => {
+                let input = self.output(index, shift_to.input)?;
+                let time = match &shift_to.time {
+                    Some(shift_to_operation::Time::Computed(computed)) => {
+                        self.input_column(index, input, Some(computed))?
+                    }
+                    Some(shift_to_operation::Time::Literal(timestamp)) => {
+                        let time = timestamp.seconds * 1_000_000_000 + timestamp.nanos as i64;
+                        let time = ScalarTimestamp::new(Some(time), TimeUnit::Nanosecond, None);
+                        Expr {
+                            name: "literal".into(),
+                            literal_args: vec![ScalarValue::Timestamp(Box::new(time))],
+                            args: vec![],
+                            result_type: DataType::Timestamp(TimeUnit::Nanosecond, None),
+                        }
+                    }
+                    None => error_stack::bail!(invalid("missing shift time")),
+                };
+                let schema = self.steps[input].schema.clone();
+                let shift_step = self.add_step(
+                    StepKind::ShiftTo {
+                        time: Exprs::singleton(vec![time]),
+                    },
+                    vec![input],
+                    schema,
+                );
+                (shift_step, vec![shift_to.input])
+            }
+            Operator::ShiftUntil(shift_until) => {
+                let input = self.output(index, shift_until.input)?;
+                let condition = self.input_column(index, input, shift_until.condition.as_ref())?;
+                let schema = self.steps[input].schema.clone();
+                let shift_step = self.add_step(
+                    StepKind::ShiftUntil {
+                        condition: Exprs::singleton(vec![condition]),
+                    },
+                    vec![input],
+                    schema,
+                );
+                (shift_step, vec![shift_until.input])
+            }
+            Operator::LookupRequest(request) => {
+                // The request is sent to the partition of the foreign key.
+                let primary = self.output(index, request.primary_operation)?;
+                let foreign_key_hash =
+                    self.input_column(index, primary, request.foreign_key_hash.as_ref())?;
+                let schema = self.steps[primary].schema.clone();
+                let request_step = self.add_step(
+                    StepKind::LookupRequest {
+                        foreign_key_hash: Exprs::singleton(vec![foreign_key_hash]),
+                    },
+                    vec![primary],
+                    schema,
+                );
+                (request_step, vec![request.primary_operation])
+            }
+            Operator::LookupResponse(response) => {
+                // The response is sent back to the partition of the requesting key.
+                let foreign = self.output(index, response.foreign_operation)?;
+                let requesting_key_hash =
+                    self.input_column(index, foreign, response.requesting_key_hash.as_ref())?;
+                let schema = self.steps[foreign].schema.clone();
+                let response_step = self.add_step(
+                    StepKind::LookupResponse {
+                        requesting_key_hash: Exprs::singleton(vec![requesting_key_hash]),
+                    },
+                    vec![foreign],
+                    schema,
+                );
+                (response_step, vec![response.foreign_operation])
+            }
+        };
+
+        // Compute the aggregations of the operation, if any, before the
+        // projection.
+        let aggregations: Vec<_> = operation
+            .expressions
+            .iter()
+            .enumerate()
+            .filter(|(_, expression)| is_aggregation(expression))
+            .map(|(expression, _)| expression)
+            .collect();
+        let input = if aggregations.is_empty() {
+            input
+        } else {
+            let expressions = ExpressionLowering::new(
+                index,
+                operation,
+                operator,
+                &inputs,
+                self.steps[input].schema.clone(),
+            );
+            let (exprs, aggregations, aggregated_fields) =
+                expressions.lower_aggregations(&aggregations)?;
+            let fields: Vec<_> = self.steps[input]
+                .schema
+                .fields()
+                .iter()
+                .cloned()
+                .chain(aggregated_fields.into_iter().map(Arc::new))
+                .collect();
+            self.add_step(
+                StepKind::Aggregate {
+                    exprs,
+                    aggregations,
+                },
+                vec![input],
+                Arc::new(Schema::new(fields)),
+            )
+        };
+
+        let expressions = ExpressionLowering::new(
+            index,
+            operation,
+            operator,
+            &inputs,
+            self.steps[input].schema.clone(),
+        );
+        let (exprs, fields) = expressions.lower_outputs()?;
+        Ok(self.add_step(
+            StepKind::Project { exprs },
+            vec![input],
+            Arc::new(Schema::new(fields)),
+        ))
+    }
+
+    /// Create an expression reading the output column referenced by an
+    /// operation from the given input step.
+    fn input_column(
+        &self,
+        operation: usize,
+        input: StepId,
+        input_ref: Option<&OperationInputRef>,
+    ) -> error_stack::Result<Expr, Error> {
+        let input_ref = input_ref.ok_or_else(|| {
+            error_stack::report!(Error::InvalidOperation {
+                operation,
+                reason: "missing input reference".into()
+            })
+        })?;
+        let Some(Column::ProducerExpression(expression)) = &input_ref.column else {
+            error_stack::bail!(Error::UnsupportedOperation {
+                operation,
+                reason: "input reference must be to an expression".into()
+            })
+        };
+
+        let name = column_name(input_ref.producing_operation as usize, *expression as usize);
+        let field = self.steps[input]
+            .schema
+            .field_with_name(&name)
+            .into_report()
+            .change_context(Error::InvalidOperation {
+                operation,
+                reason: format!("missing input column '{name}'").into(),
+            })?;
+        let result_type = field.data_type().clone();
+        Ok(column(name, result_type))
+    }
+}
+
+/// Lowers the expressions of a single operation.
+///
+/// Expressions are lowered on demand starting from the outputs. This omits
+/// expressions which are only needed by the compiler's plan, such as the field
+/// names passed to `field_ref` and `record`.
+struct ExpressionLowering<'a> {
+    operation: usize,
+    expressions: &'a [ExpressionPlan],
+    /// The operations which expressions may reference.
+    inputs: &'a [u32],
+    /// The schema of the rows the expressions are computed over.
+    input_schema: SchemaRef,
+    /// The operator producing the rows.
+    operator: &'a Operator,
+    exprs: IndexVec<ExprId, Expr>,
+    /// The physical expression each expression has been lowered to.
+    lowered: Vec<Option<ExprId>>,
+}
+
+impl<'a> ExpressionLowering<'a> {
+    fn new(
+        operation: usize,
+        plan: &'a OperationPlan,
+        operator: &'a Operator,
+        inputs: &'a [u32],
+        input_schema: SchemaRef,
+    ) -> Self {
+        Self {
+            operation,
+            expressions: &plan.expressions,
+            inputs,
+            input_schema,
+            operator,
+            exprs: IndexVec::new(),
+            lowered: vec![None; plan.expressions.len()],
+        }
+    }
+
+    fn invalid(&self, expression: usize, reason: impl Into<Cow<'static, str>>) -> Error {
+        Error::InvalidExpression {
+            operation: self.operation,
+            expression,
+            reason: reason.into(),
+        }
+    }
+
+    fn unsupported(&self, expression: usize, reason: impl Into<Cow<'static, str>>) -> Error {
+        Error::UnsupportedExpression {
+            operation: self.operation,
+            expression,
+            reason: reason.into(),
+        }
+    }
+
+    /// Lower the output expressions, returning the physical expressions and
+    /// the fields they produce.
+    fn lower_outputs(mut self) -> error_stack::Result<(Exprs, Vec<Field>), Error> {
+        let mut outputs = Vec::new();
+        let mut fields = Vec::new();
+        for (index, expression) in self.expressions.iter().enumerate() {
+            if expression.output {
+                let output = self.lower(index)?;
+                fields.push(Field::new(
+                    column_name(self.operation, index),
+                    self.exprs[output].result_type.clone(),
+                    true,
+                ));
+                outputs.push(output);
+            }
+        }
+
+        let exprs = Exprs {
+            exprs: self.exprs,
+            outputs,
+        };
+        Ok((exprs, fields))
+    }
+
+    /// Lower the aggregations computed by an aggregate step, returning the
+    /// expressions computing their arguments, the aggregations and the fields
+    /// they produce.
+    fn lower_aggregations(
+        mut self,
+        aggregations: &[usize],
+    ) -> error_stack::Result<(Exprs, Vec<Aggregation>, Vec<Field>), Error> {
+        let mut fields = Vec::with_capacity(aggregations.len());
+        let aggregations = aggregations
+            .iter()
+            .map(|index| {
+                let aggregation = self.lower_aggregation(*index)?;
+                fields.push(Field::new(
+                    column_name(self.operation, *index),
+                    aggregation.result_type.clone(),
+                    true,
+                ));
+                Ok(aggregation)
+            })
+            .collect::<error_stack::Result<_, Error>>()?;
+
+        let exprs = Exprs {
+            exprs: self.exprs,
+            outputs: vec![],
+        };
+        Ok((exprs, aggregations, fields))
+    }
+
+    /// Lower an aggregation.
+    ///
+    /// The compiler passes the input first and the window last -- the
+    /// condition followed by the duration. Any arguments in between are
+    /// literal arguments if they are literals, such as the percentile to
+    /// compute.
+    fn lower_aggregation(&mut self, index: usize) -> error_stack::Result<Aggregation, Error> {
+        let expressions = self.expressions;
+        let expression = &expressions[index];
+        let result_type = self.result_type(index)?;
+        let Some(expression_plan::Operator::Instruction(name)) = &expression.operator else {
+            error_stack::bail!(self.invalid(index, "expected aggregation instruction"))
+        };
+        let [input, rest @ .., condition, duration] = expression.arguments.as_slice() else {
+            error_stack::bail!(self.invalid(
+                index,
+                format!("expected input and window arguments to '{name}'")
+            ))
+        };
+
+        let mut args = vec![self.lower(*input as usize)?];
+        let mut literal_args = Vec::new();
+        for arg in rest {
+            match self.literal_value(*arg as usize)? {
+                Some(literal) => literal_args.push(literal),
+                None => args.push(self.lower(*arg as usize)?),
+            }
+        }
+
+        let duration = match self.literal_value(*duration as usize)? {
+            Some(ScalarValue::Int64(Some(duration))) => Some(duration),
+            Some(literal) if literal.is_null() => None,
+            _ => error_stack::bail!(self.invalid(index, "expected literal window duration")),
+        };
+        let window = match (self.literal_value(*condition as usize)?, duration) {
+            (Some(literal), None) if literal.is_null() => Window::Unbounded,
+            (Some(literal), Some(duration_ns)) if literal.is_null() => {
+                Window::Trailing { duration_ns }
+            }
+            // A single sliding window is reset by each condition, as in `since`.
+            (_, None | Some(1)) => Window::Since {
+                condition: self.lower(*condition as usize)?,
+            },
+            (_, Some(windows)) => Window::Sliding {
+                condition: self.lower(*condition as usize)?,
+                windows,
+            },
+        };
+
+        Ok(Aggregation {
+            name: name.clone().into(),
+            literal_args,
+            args,
+            window,
+            result_type,
+        })
+    }
+
+    /// Return the result type of an expression.
+    fn result_type(&self, index: usize) -> error_stack::Result<DataType, Error> {
+        let expression = self.expressions.get(index).ok_or_else(|| {
+            error_stack::report!(self.invalid(index, "no such expression in operation"))
+        })?;
+        let result_type = expression
+            .result_type
+            .as_ref()
+            .ok_or_else(|| error_stack::report!(self.invalid(index, "missing result type")))?;
+        DataType::try_from(result_type).map_err(|e| {
+            error_stack::report!(self.invalid(index, "unsupported result type"))
+                .attach_printable(e.to_string())
+        })
+    }
+
+    fn lower(&mut self, index: usize) -> error_stack::Result<ExprId, Error> {
+        let expressions = self.expressions;
+        let expression = expressions.get(index).ok_or_else(|| {
+            error_stack::report!(self.invalid(index, "no such expression in operation"))
+        })?;
+        if let Some(lowered) = self.lowered[index] {
+            return Ok(lowered);
+        }
+
+        let result_type = self.result_type(index)?;
+
+        let expr = match &expression.operator {
+            Some(expression_plan::Operator::Instruction(name)) => {
+                self.lower_instruction(index, name, &expression.arguments, result_type)?
+            }
+            Some(expression_plan::Operator::Input(input)) => {
+                self.lower_input(index, input, result_type)?
+            }
+            Some(expression_plan::Operator::Literal(literal)) => {
+                let literal = literal.try_into_scalar_value(&result_type).map_err(|e| {
+                    error_stack::report!(self.invalid(index, "invalid literal"))
+                        .attach_printable(e.to_string())
+                })?;
+                Expr {
+                    name: "literal".into(),
+                    literal_args: vec![literal],
+                    args: vec![],
+                    result_type,
+                }
+            }
+            Some(expression_plan::Operator::LateBound(late_bound)) => {
+                // Late bound values are provided when the plan is executed, so
+                // they are identified by name.
+                let late_bound = LateBoundValue::from_i32(*late_bound).ok_or_else(|| {
+                    error_stack::report!(self.invalid(index, "invalid late bound value"))
+                })?;
+                Expr {
+                    name: "late_bound".into(),
+                    literal_args: vec![ScalarValue::Utf8(Some(late_bound.label().to_owned()))],
+                    args: vec![],
+                    result_type,
+                }
+            }
+            None => error_stack::bail!(self.invalid(index, "missing operator")),
+        };
+
+        let lowered = self.exprs.push(expr);
+        self.lowered[index] = Some(lowered);
+        Ok(lowered)
+    }
+
+    fn lower_args(&mut self, args: &[u32]) -> error_stack::Result<Vec<ExprId>, Error> {
+        args.iter().map(|arg| self.lower(*arg as usize)).collect()
+    }
+
+    fn lower_instruction(
+        &mut self,
+        index: usize,
+        name: &str,
+        arguments: &[u32],
+        result_type: DataType,
+    ) -> error_stack::Result<Expr, Error> {
+        let expr = match name {
+            "field_ref" => {
+                let [base, field] = arguments else {
+                    error_stack::bail!(self.invalid(index, "expected 2 arguments to 'field_ref'"))
+                };
+                let field = self.literal_string(*field as usize)?;
+                if self.is_scan_record(*base as usize) {
+                    // Fields of the scanned record are columns of the input.
+                    return Ok(column(field, result_type));
+                }
+
+                Expr {
+                    name: "field_ref".into(),
+                    literal_args: vec![ScalarValue::Utf8(Some(field))],
+                    args: self.lower_args(&[*base])?,
+                    result_type,
+                }
+            }
+            "record" => {
+                // The compiler interleaves the field names with the field values,
+                // while the physical record takes the names from the result type.
+                error_stack::ensure!(
+                    arguments.len() % 2 == 0,
+                    self.invalid(index, "expected field names and values for 'record'")
+                );
+                let values: Vec<_> = arguments.iter().skip(1).step_by(2).copied().collect();
+                Expr {
+                    name: "record".into(),
+                    literal_args: vec![],
+                    args: self.lower_args(&values)?,
+                    result_type,
+                }
+            }
+            _ if InstOp::from_str(name).is_ok_and(|op| op.is_aggregation()) => {
+                // Aggregations are computed by the preceding aggregate step.
+                let name = column_name(self.operation, index);
+                error_stack::ensure!(
+                    self.input_schema.field_with_name(&name).is_ok(),
+                    self.unsupported(index, "nested aggregation")
+                );
+                column(name, result_type)
+            }
+            "gt" | "gte" | "lt" | "lte" => Expr {
+                name: format!("{name}_primitive").into(),
+                literal_args: vec![],
+                args: self.lower_args(arguments)?,
+                result_type,
+            },
+            _ => Expr {
+                name: name.to_owned().into(),
+                literal_args: vec![],
+                args: self.lower_args(arguments)?,
+                result_type,
+            },
+        };
+        Ok(expr)
+    }
+
+    fn lower_input(
+        &mut self,
+        index: usize,
+        input: &OperationInputRef,
+        result_type: DataType,
+    ) -> error_stack::Result<Expr, Error> {
+        match &input.column {
+            Some(Column::ScanRecord(())) => {
+                error_stack::ensure!(
+                    input.producing_operation as usize == self.operation,
+                    self.invalid(index, "scan record must be read by the scan")
+                );
+
+                // The scanned record is the struct containing every input column.
+                let fields = self.input_schema.fields().clone();
+                let args = fields
+                    .iter()
+                    .map(|field| {
+                        self.exprs
+                            .push(column(field.name().clone(), field.data_type().clone()))
+                    })
+                    .collect();
+                Ok(Expr {
+                    name: "record".into(),
+                    literal_args: vec![],
+                    args,
+                    result_type,
+                })
+            }
+            Some(Column::ProducerExpression(expression)) => {
+                error_stack::ensure!(
+                    self.inputs.contains(&input.producing_operation),
+                    self.invalid(
+                        index,
+                        format!("operation {} is not an input", input.producing_operation)
+                    )
+                );
+                if matches!(self.operator, Operator::Merge(_))
+                    && input.interpolation() == Interpolation::AsOf
+                {
+                    error_stack::bail!(self.unsupported(index, "as-of interpolation"));
+                }
+
+                let name = column_name(input.producing_operation as usize, *expression as usize);
+                error_stack::ensure!(
+                    self.input_schema.field_with_name(&name).is_ok(),
+                    self.invalid(index, format!("missing input column '{name}'"))
+                );
+                Ok(column(name, result_type))
+            }
+            Some(Column::KeyColumn(key_column)) => {
+                error_stack::ensure!(
+                    input.producing_operation as usize == self.operation
+                        || self.inputs.contains(&input.producing_operation),
+                    self.invalid(
+                        index,
+                        format!("operation {} is not an input", input.producing_operation)
+                    )
+                );
+
+                // The key columns are part of every batch, rather than the schema.
+                let name = match KeyColumn::from_i32(*key_column) {
+                    Some(KeyColumn::Time) => "time",
+                    Some(KeyColumn::Subsort) => "subsort",
+                    Some(KeyColumn::KeyHash) => "key_hash",
+                    Some(KeyColumn::Unspecified) | None => {
+                        error_stack::bail!(self.invalid(index, "unspecified key column"))
+                    }
+                };
+                Ok(Expr {
+                    name: name.into(),
+                    literal_args: vec![],
+                    args: vec![],
+                    result_type,
+                })
+            }
+            Some(Column::Tick(())) => {
+                error_stack::ensure!(
+                    matches!(self.operator, Operator::Tick(_))
+                        && input.producing_operation as usize == self.operation,
+                    self.unsupported(index, "tick input outside of the tick")
+                );
+
+                // Every row produced by the tick step is a tick.
+                Ok(Expr {
+                    name: "literal".into(),
+                    literal_args: vec![ScalarValue::Boolean(Some(true))],
+                    args: vec![],
+                    result_type,
+                })
+            }
+            None => error_stack::bail!(self.invalid(index, "missing input column")),
+        }
+    }
+
+    /// Return true if the expression reads the scanned record.
+    fn is_scan_record(&self, index: usize) -> bool {
+        matches!(
+            self.expressions
+                .get(index)
+                .and_then(|e| e.operator.as_ref()),
+            Some(expression_plan::Operator::Input(OperationInputRef {
+                column: Some(Column::ScanRecord(())),
+                ..
+            }))
+        )
+    }
+
+    /// Return the value of the expression if it is a literal.
+    fn literal_value(&self, index: usize) -> error_stack::Result<Option<ScalarValue>, Error> {
+        match self
+            .expressions
+            .get(index)
+            .and_then(|e| e.operator.as_ref())
+        {
+            Some(expression_plan::Operator::Literal(literal)) => {
+                let result_type = self.result_type(index)?;
+                let literal = literal.try_into_scalar_value(&result_type).map_err(|e| {
+                    error_stack::report!(self.invalid(index, "invalid literal"))
+                        .attach_printable(e.to_string())
+                })?;
+                Ok(Some(literal))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Return the value of a string literal expression.
+    fn literal_string(&self, index: usize) -> error_stack::Result<String, Error> {
+        match self
+            .expressions
+            .get(index)
+            .and_then(|e| e.operator.as_ref())
+        {
+            Some(expression_plan::Operator::Literal(Literal {
+                literal: Some(literal::Literal::Utf8(value)),
+            })) => Ok(value.clone()),
+            _ => error_stack::bail!(self.invalid(index, "expected string literal")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_schema::{DataType, Field, Fields, Schema};
+    use sparrow_api::kaskada::v1alpha::operation_input_ref::{Column, Interpolation};
+    use sparrow_api::kaskada::v1alpha::operation_plan::tick_operation::TickBehavior;
+    use sparrow_api::kaskada::v1alpha::operation_plan::{
+        LookupRequestOperation, LookupResponseOperation, Operator, ScanOperation, SelectOperation,
+        TickOperation, WithKeyOperation,
+    };
+    use sparrow_api::kaskada::v1alpha::{
+        expression_plan, ComputePlan, ExpressionPlan, OperationInputRef, OperationPlan, SlicePlan,
+    };
+    use sparrow_arrow::scalar_value::ScalarValue;
+    use sparrow_physical::StepKind;
+
+    use crate::{lower_compute_plan, Error};
+
+    fn expression(
+        operator: expression_plan::Operator,
+        arguments: Vec<u32>,
+        result_type: &DataType,
+        output: bool,
+    ) -> ExpressionPlan {
+        ExpressionPlan {
+            arguments,
+            result_type: Some(result_type.try_into().unwrap()),
+            output,
+            operator: Some(operator),
+        }
+    }
+
+    fn input_ref(producing_operation: u32, column: Column) -> OperationInputRef {
+        OperationInputRef {
+            producing_operation,
+            column: Some(column),
+            input_column: u32::MAX,
+            interpolation: Interpolation::Null as i32,
+        }
+    }
+
+    fn input(producing_operation: u32, column: Column) -> expression_plan::Operator {
+        expression_plan::Operator::Input(input_ref(producing_operation, column))
+    }
+
+    fn literal(value: ScalarValue) -> expression_plan::Operator {
+        expression_plan::Operator::Literal((&value).into())
+    }
+
+    fn instruction(name: &str) -> expression_plan::Operator {
+        expression_plan::Operator::Instruction(name.to_owned())
+    }
+
+    fn scan_operation(expressions: Vec<ExpressionPlan>) -> OperationPlan {
+        let schema = Schema::new(vec![
+            Field::new("key", DataType::UInt64, true),
+            Field::new("value", DataType::Int64, true),
+        ]);
+        OperationPlan {
+            expressions,
+            operator: Some(Operator::Scan(ScanOperation {
+                table_id: None,
+                schema: Some((&schema).try_into().unwrap()),
+                slice_plan: Some(SlicePlan {
+                    table_name: "Table".to_owned(),
+                    slice: None,
+                }),
+            })),
+        }
+    }
+
+    /// Return the type of the record read by `scan_operation`.
+    fn scan_record_type() -> DataType {
+        DataType::Struct(Fields::from(vec![
+            Field::new("key", DataType::UInt64, true),
+            Field::new("value", DataType::Int64, true),
+        ]))
+    }
+
+    #[test]
+    fn test_lower_scan_select_with_key() {
+        let plan = ComputePlan {
+            operations: vec![
+                // 0: Compute `value > 10` and `value + 1` from the table.
+                scan_operation(vec![
+                    expression(
+                        input(0, Column::ScanRecord(())),
+                        vec![],
+                        &scan_record_type(),
+                        false,
+                    ),
+                    expression(
+                        literal(ScalarValue::Utf8(Some("value".to_owned()))),
+                        vec![],
+                        &DataType::Utf8,
+                        false,
+                    ),
+                    expression(
+                        instruction("field_ref"),
+                        vec![0, 1],
+                        &DataType::Int64,
+                        false,
+                    ),
+                    expression(
+                        literal(ScalarValue::Int64(Some(10))),
+                        vec![],
+                        &DataType::Int64,
+                        false,
+                    ),
+                    expression(instruction("gt"), vec![2, 3], &DataType::Boolean, true),
+                    expression(
+                        literal(ScalarValue::Int64(Some(1))),
+                        vec![],
+                        &DataType::Int64,
+                        false,
+                    ),
+                    expression(instruction("add"), vec![2, 5], &DataType::Int64, true),
+                ]),
+                // 1: Select the rows where `value > 10`.
+                OperationPlan {
+                    expressions: vec![expression(
+                        input(0, Column::ProducerExpression(6)),
+                        vec![],
+                        &DataType::Int64,
+                        true,
+                    )],
+                    operator: Some(Operator::Select(SelectOperation {
+                        input: 0,
+                        condition: Some(input_ref(0, Column::ProducerExpression(4))),
+                    })),
+                },
+                // 2: Key the selected rows by `value + 1`.
+                OperationPlan {
+                    expressions: vec![
+                        expression(
+                            input(1, Column::ProducerExpression(0)),
+                            vec![],
+                            &DataType::Int64,
+                            false,
+                        ),
+                        expression(
+                            literal(ScalarValue::Int64(Some(2))),
+                            vec![],
+                            &DataType::Int64,
+                            false,
+                        ),
+                        expression(instruction("mul"), vec![0, 1], &DataType::Int64, true),
+                    ],
+                    operator: Some(Operator::WithKey(WithKeyOperation {
+                        input: 1,
+                        new_key: Some(input_ref(1, Column::ProducerExpression(0))),
+                        grouping: "value".to_owned(),
+                    })),
+                },
+            ],
+            ..ComputePlan::default()
+        };
+
+        let plan = lower_compute_plan(&plan, 4).unwrap();
+        insta::assert_snapshot!(plan.to_string(), @r###"
+        0: scan Table inputs=[] schema=[key: UInt64, value: Int64]
+        1: project inputs=[0] schema=[0_4: Boolean, 0_6: Int64]
+          0: column["value"]() -> Int64
+          1: literal[10i64]() -> Int64
+          2: gt_primitive[](0, 1) -> Boolean
+          3: literal[1i64]() -> Int64
+          4: add[](0, 3) -> Int64
+          outputs: [2, 4]
+        2: filter inputs=[1] schema=[0_4: Boolean, 0_6: Int64]
+          0: column["0_4"]() -> Boolean
+          outputs: [0]
+        3: project inputs=[2] schema=[1_0: Int64]
+          0: column["0_6"]() -> Int64
+          outputs: [0]
+        4: repartition 4 inputs=[3] schema=[1_0: Int64]
+          0: column["1_0"]() -> Int64
+          outputs: [0]
+        5: project inputs=[4] schema=[2_2: Int64]
+          0: column["1_0"]() -> Int64
+          1: literal[2i64]() -> Int64
+          2: mul[](0, 1) -> Int64
+          outputs: [2]
+        pipelines: [[0, 1, 2, 3], [4, 5]]
+        "###);
+    }
+
+    #[test]
+    fn test_lower_tick() {
+        let plan = ComputePlan {
+            operations: vec![
+                scan_operation(vec![]),
+                OperationPlan {
+                    expressions: vec![expression(
+                        input(1, Column::Tick(())),
+                        vec![],
+                        &DataType::Boolean,
+                        true,
+                    )],
+                    operator: Some(Operator::Tick(TickOperation {
+                        behavior: TickBehavior::Daily as i32,
+                        input: 0,
+                        time_zone: "America/New_York".to_owned(),
+                        ..TickOperation::default()
+                    })),
+                },
+            ],
+            ..ComputePlan::default()
+        };
+
+        let plan = lower_compute_plan(&plan, 4).unwrap();
+        let StepKind::Tick { time_zone, .. } = &plan.steps[2].kind else {
+            panic!("expected tick step")
+        };
+        assert_eq!(time_zone.as_deref(), Some("America/New_York"));
+        insta::assert_snapshot!(plan.to_string(), @r###"
+        0: scan Table inputs=[] schema=[key: UInt64, value: Int64]
+        1: project inputs=[0] schema=[]
+          outputs: []
+        2: tick Daily in America/New_York inputs=[1] schema=[]
+        3: project inputs=[2] schema=[1_0: Boolean]
+          0: literal[true]() -> Boolean
+          outputs: [0]
+        pipelines: [[0, 1, 2, 3]]
+        "###);
+    }
+
+    #[test]
+    fn test_lower_lookup() {
+        let plan = ComputePlan {
+            operations: vec![
+                // 0: Compute the key to look up.
+                scan_operation(vec![
+                    expression(
+                        input(0, Column::ScanRecord(())),
+                        vec![],
+                        &scan_record_type(),
+                        false,
+                    ),
+                    expression(
+                        literal(ScalarValue::Utf8(Some("value".to_owned()))),
+                        vec![],
+                        &DataType::Utf8,
+                        false,
+                    ),
+                    expression(
+                        instruction("field_ref"),
+                        vec![0, 1],
+                        &DataType::Int64,
+                        false,
+                    ),
+                    expression(instruction("hash"), vec![2], &DataType::UInt64, true),
+                ]),
+                // 1: Send the request to the foreign key.
+                OperationPlan {
+                    expressions: vec![expression(
+                        input(0, Column::ProducerExpression(3)),
+                        vec![],
+                        &DataType::UInt64,
+                        true,
+                    )],
+                    operator: Some(Operator::LookupRequest(LookupRequestOperation {
+                        primary_operation: 0,
+                        foreign_key_hash: Some(input_ref(0, Column::ProducerExpression(3))),
+                    })),
+                },
+                // 2: Send the response back to the requesting key.
+                OperationPlan {
+                    expressions: vec![expression(
+                        input(1, Column::ProducerExpression(0)),
+                        vec![],
+                        &DataType::UInt64,
+                        true,
+                    )],
+                    operator: Some(Operator::LookupResponse(LookupResponseOperation {
+                        foreign_operation: 1,
+                        requesting_key_hash: Some(input_ref(1, Column::ProducerExpression(0))),
+                    })),
+                },
+            ],
+            ..ComputePlan::default()
+        };
+
+        let plan = lower_compute_plan(&plan, 4).unwrap();
+        insta::assert_snapshot!(plan.to_string(), @r###"
+        0: scan Table inputs=[] schema=[key: UInt64, value: Int64]
+        1: project inputs=[0] schema=[0_3: UInt64]
+          0: column["value"]() -> Int64
+          1: hash[](0) -> UInt64
+          outputs: [1]
+        2: lookup_request inputs=[1] schema=[0_3: UInt64]
+          0: column["0_3"]() -> UInt64
+          outputs: [0]
+        3: project inputs=[2] schema=[1_0: UInt64]
+          0: column["0_3"]() -> UInt64
+          outputs: [0]
+        4: lookup_response inputs=[3] schema=[1_0: UInt64]
+          0: column["1_0"]() -> UInt64
+          outputs: [0]
+        5: project inputs=[4] schema=[2_0: UInt64]
+          0: column["1_0"]() -> UInt64
+          outputs: [0]
+        pipelines: [[0, 1], [2, 3], [4, 5]]
+        "###);
+    }
+
+    /// Return the expressions computing `sum(value, window)` from the table.
+    ///
+    /// The window is described by the `condition` and `duration` literals.
+    fn sum_expressions(condition: ScalarValue, duration: ScalarValue) -> Vec<ExpressionPlan> {
+        vec![
+            expression(
+                input(0, Column::ScanRecord(())),
+                vec![],
+                &scan_record_type(),
+                false,
+            ),
+            expression(
+                literal(ScalarValue::Utf8(Some("value".to_owned()))),
+                vec![],
+                &DataType::Utf8,
+                false,
+            ),
+            expression(
+                instruction("field_ref"),
+                vec![0, 1],
+                &DataType::Int64,
+                false,
+            ),
+            expression(
+                literal(condition.clone()),
+                vec![],
+                &condition.data_type(),
+                false,
+            ),
+            expression(
+                literal(duration.clone()),
+                vec![],
+                &duration.data_type(),
+                false,
+            ),
+            expression(instruction("sum"), vec![2, 3, 4], &DataType::Int64, true),
+            expression(
+                literal(ScalarValue::Int64(Some(1))),
+                vec![],
+                &DataType::Int64,
+                false,
+            ),
+            expression(instruction("add"), vec![5, 6], &DataType::Int64, true),
+        ]
+    }
+
+    #[test]
+    fn test_lower_aggregation() {
+        let plan = ComputePlan {
+            operations: vec![scan_operation(sum_expressions(
+                ScalarValue::Null,
+                ScalarValue::Null,
+            ))],
+            ..ComputePlan::default()
+        };
+
+        let plan = lower_compute_plan(&plan, 4).unwrap();
+        insta::assert_snapshot!(plan.to_string(), @r###"
+        0: scan Table inputs=[] schema=[key: UInt64, value: Int64]
+        1: aggregate inputs=[0] schema=[key: UInt64, value: Int64, 0_5: Int64]
+          0: column["value"]() -> Int64
+          outputs: []
+          aggregate: sum[](0) Unbounded -> Int64
+        2: project inputs=[1] schema=[0_5: Int64, 0_7: Int64]
+          0: column["0_5"]() -> Int64
+          1: literal[1i64]() -> Int64
+          2: add[](0, 1) -> Int64
+          outputs: [0, 2]
+        pipelines: [[0, 1, 2]]
+        "###);
+    }
+
+    #[test]
+    fn test_lower_aggregation_windows() {
+        let windows = [
+            (ScalarValue::Boolean(Some(true)), ScalarValue::Null),
+            (
+                ScalarValue::Boolean(Some(true)),
+                ScalarValue::Int64(Some(3)),
+            ),
+            (ScalarValue::Null, ScalarValue::Int64(Some(1_000))),
+        ];
+        let windows = windows.map(|(condition, duration)| {
+            let plan = ComputePlan {
+                operations: vec![scan_operation(sum_expressions(condition, duration))],
+                ..ComputePlan::default()
+            };
+            let plan = lower_compute_plan(&plan, 4).unwrap();
+            let StepKind::Aggregate { aggregations, .. } = &plan.steps[1].kind else {
+                panic!("expected aggregate step")
+            };
+            format!("{:?}", aggregations[0].window)
+        });
+        assert_eq!(
+            windows,
+            [
+                "Since { condition: 1 }",
+                "Sliding { condition: 1, windows: 3 }",
+                "Trailing { duration_ns: 1000 }"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lower_nested_aggregation_unsupported() {
+        let mut expressions = sum_expressions(ScalarValue::Null, ScalarValue::Null);
+        expressions.push(expression(
+            instruction("sum"),
+            vec![5, 3, 4],
+            &DataType::Int64,
+            true,
+        ));
+        let plan = ComputePlan {
+            operations: vec![scan_operation(expressions)],
+            ..ComputePlan::default()
+        };
+
+        let error = lower_compute_plan(&plan, 4).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            Error::UnsupportedExpression {
+                operation: 0,
+                expression: 5,
+                ..
+            }
+        ));
+        assert_eq!(
+            error.current_context().to_string(),
+            "unsupported expression 5 in operation 0: nested aggregation"
+        );
+    }
+}
@@ -10,7 +10,7 @@ const DEBUG_SCHEDULING: bool = false;
 ///
 /// 1. Each leaf (input) is the start of a separate pipeline.
 /// 2. Certain step kinds are "pipeline breaking", which means
-///    they start a new pipeline. For instance, `with_key`.
+///    they start a new pipeline. For instance, `with_key` and lookups.
 /// 3. Any other operation with a single input is part of the
 ///    the same pipeline as the input.
 /// 4. Any other operation is a separate pipeline.
@@ -67,7 +67,11 @@ fn is_pipeline_breaker(index: StepId, step: &Step, references: &IndexVec<StepId,
             );
             true
         }
-        StepKind::Scan { .. } | StepKind::Merge | StepKind::Repartition { .. } => {
+        StepKind::Scan { .. }
+        | StepKind::Merge
+        | StepKind::Repartition { .. }
+        | StepKind::LookupRequest { .. }
+        | StepKind::LookupResponse { .. } => {
             debug_println!(
                 DEBUG_SCHEDULING,
                 "Step {index} is new pipeline based on kind {:?}",
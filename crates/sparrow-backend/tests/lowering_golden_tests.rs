@@ -0,0 +1,472 @@
+#![warn(
+    rust_2018_idioms,
+    nonstandard_style,
+    future_incompatible,
+    clippy::mod_module_files,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::undocumented_unsafe_blocks
+)]
+
+//! Golden tests lowering the plans produced by `sparrow-compiler`.
+//!
+//! Each test compiles a query and snapshots the rendered physical plan,
+//! including the expressions computed by each step.
+
+use arrow::datatypes::DataType;
+use itertools::Itertools;
+use sparrow_api::kaskada::v1alpha::compile_request::ExpressionKind;
+use sparrow_api::kaskada::v1alpha::schema::Field;
+use sparrow_api::kaskada::v1alpha::{
+    CompileRequest, ComputePlan, ComputeTable, FeatureSet, Formula, PerEntityBehavior, Schema,
+    TableConfig, TableMetadata,
+};
+use sparrow_backend::lower_compute_plan;
+use sparrow_compiler::InternalCompileOptions;
+use uuid::Uuid;
+
+struct TestTable {
+    name: &'static str,
+    id: &'static str,
+    group_key: &'static str,
+    grouping: &'static str,
+    fields: Vec<(&'static str, DataType)>,
+}
+
+fn sent_table() -> TestTable {
+    TestTable {
+        name: "Sent",
+        id: "111DA01F9ABD4d9d80C702AF85C822A8",
+        group_key: "sender",
+        grouping: "account",
+        fields: vec![
+            ("sender", DataType::UInt64),
+            ("amount", DataType::Float64),
+            ("receiver", DataType::UInt64),
+            ("store", DataType::UInt64),
+        ],
+    }
+}
+
+fn received_table() -> TestTable {
+    TestTable {
+        name: "Received",
+        id: "333DA01F9ABD4d9d80C702AF85C822A8",
+        group_key: "receiver",
+        grouping: "account",
+        fields: vec![
+            ("amount", DataType::Float64),
+            ("receiver", DataType::UInt64),
+        ],
+    }
+}
+
+fn store_received_table() -> TestTable {
+    TestTable {
+        name: "StoreReceived",
+        id: "444DA01F9ABD4d9d80C702AF85C822A8",
+        group_key: "store_id",
+        grouping: "store",
+        fields: vec![
+            ("amount", DataType::Float64),
+            ("store_id", DataType::UInt64),
+            ("sender_id", DataType::UInt64),
+        ],
+    }
+}
+
+fn formula(name: &str, formula: &str) -> Formula {
+    Formula {
+        name: name.to_owned(),
+        formula: formula.to_owned(),
+        source_location: name.to_owned(),
+    }
+}
+
+/// Compile the query against the given tables, returning the plan.
+async fn compile(tables: Vec<TestTable>, formulas: Vec<Formula>, query: &str) -> ComputePlan {
+    let tables = tables
+        .into_iter()
+        .map(|table| {
+            let fields = table
+                .fields
+                .iter()
+                .map(|(name, data_type)| Field {
+                    name: (*name).to_owned(),
+                    data_type: Some(data_type.try_into().unwrap()),
+                    nullable: false,
+                })
+                .collect();
+            ComputeTable {
+                config: Some(TableConfig::new_with_table_source(
+                    table.name,
+                    &Uuid::parse_str(table.id).unwrap(),
+                    "",
+                    Some(""),
+                    table.group_key,
+                    table.grouping,
+                )),
+                metadata: Some(TableMetadata {
+                    schema: Some(Schema { fields }),
+                    file_count: 1,
+                }),
+                file_sets: vec![],
+            }
+        })
+        .collect();
+
+    let result = sparrow_compiler::compile_proto(
+        CompileRequest {
+            tables,
+            feature_set: Some(FeatureSet {
+                formulas,
+                query: query.to_owned(),
+            }),
+            slice_request: None,
+            expression_kind: ExpressionKind::Complete as i32,
+            experimental: false,
+            per_entity_behavior: PerEntityBehavior::All as i32,
+        },
+        InternalCompileOptions::default(),
+    )
+    .await
+    .unwrap();
+
+    result.plan.unwrap_or_else(|| {
+        panic!(
+            "Compilation failed with diagnostics:\n{}",
+            result
+                .fenl_diagnostics
+                .unwrap_or_default()
+                .fenl_diagnostics
+                .into_iter()
+                .map(|elt| elt.formatted)
+                .format("\n")
+        )
+    })
+}
+
+#[tokio::test]
+async fn test_lower_projection() {
+    let compute_plan = compile(
+        vec![sent_table()],
+        vec![],
+        "{ amount: Sent.amount + 1.0, large: Sent.amount > 10.0 }",
+    )
+    .await;
+
+    let plan = lower_compute_plan(&compute_plan, 4).unwrap();
+    insta::assert_snapshot!(plan.to_string(), @r###"
+    0: scan Sent inputs=[] schema=[sender: UInt64, amount: Float64, receiver: UInt64, store: UInt64]
+    1: project inputs=[0] schema=[0_9: {amount: Float64, large: Boolean}, 0_11: Boolean]
+      0: column["amount"]() -> Float64
+      1: literal[1f64]() -> Float64
+      2: add[](0, 1) -> Float64
+      3: literal[10f64]() -> Float64
+      4: gt_primitive[](0, 3) -> Boolean
+      5: record[](2, 4) -> {amount: Float64, large: Boolean}
+      6: time_of[](5) -> Timestamp(Nanosecond, None)
+      7: late_bound["changed_since_time"]() -> Timestamp(Nanosecond, None)
+      8: gte_primitive[](6, 7) -> Boolean
+      outputs: [5, 8]
+    2: filter inputs=[1] schema=[0_9: {amount: Float64, large: Boolean}, 0_11: Boolean]
+      0: column["0_11"]() -> Boolean
+      outputs: [0]
+    3: project inputs=[2] schema=[1_0: {amount: Float64, large: Boolean}]
+      0: column["0_9"]() -> {amount: Float64, large: Boolean}
+      outputs: [0]
+    pipelines: [[0, 1, 2, 3]]
+    "###);
+}
+
+#[tokio::test]
+async fn test_lower_when() {
+    let compute_plan = compile(
+        vec![sent_table()],
+        vec![],
+        "{ amount: Sent.amount } | when(Sent.amount > 10.0)",
+    )
+    .await;
+
+    let plan = lower_compute_plan(&compute_plan, 4).unwrap();
+    insta::assert_snapshot!(plan.to_string(), @r###"
+    0: scan Sent inputs=[] schema=[sender: UInt64, amount: Float64, receiver: UInt64, store: UInt64]
+    1: project inputs=[0] schema=[0_4: Boolean, 0_5: {amount: Float64}]
+      0: column["amount"]() -> Float64
+      1: literal[10f64]() -> Float64
+      2: gt_primitive[](0, 1) -> Boolean
+      3: record[](0) -> {amount: Float64}
+      outputs: [2, 3]
+    2: filter inputs=[1] schema=[0_4: Boolean, 0_5: {amount: Float64}]
+      0: column["0_4"]() -> Boolean
+      outputs: [0]
+    3: project inputs=[2] schema=[1_1: {amount: Float64}, 1_3: Boolean]
+      0: column["0_5"]() -> {amount: Float64}
+      1: time_of[](0) -> Timestamp(Nanosecond, None)
+      2: late_bound["changed_since_time"]() -> Timestamp(Nanosecond, None)
+      3: gte_primitive[](1, 2) -> Boolean
+      outputs: [0, 3]
+    4: filter inputs=[3] schema=[1_1: {amount: Float64}, 1_3: Boolean]
+      0: column["1_3"]() -> Boolean
+      outputs: [0]
+    5: project inputs=[4] schema=[2_0: {amount: Float64}]
+      0: column["1_1"]() -> {amount: Float64}
+      outputs: [0]
+    pipelines: [[0, 1, 2, 3, 4, 5]]
+    "###);
+}
+
+#[tokio::test]
+async fn test_lower_merge() {
+    let compute_plan = compile(
+        vec![sent_table(), received_table()],
+        vec![],
+        "{ sent: Sent.amount, received: Received.amount }",
+    )
+    .await;
+
+    let plan = lower_compute_plan(&compute_plan, 4).unwrap();
+    insta::assert_snapshot!(plan.to_string(), @r###"
+    0: scan Sent inputs=[] schema=[sender: UInt64, amount: Float64, receiver: UInt64, store: UInt64]
+    1: project inputs=[0] schema=[0_2: Float64]
+      0: column["amount"]() -> Float64
+      outputs: [0]
+    2: scan Received inputs=[] schema=[amount: Float64, receiver: UInt64]
+    3: project inputs=[2] schema=[1_2: Float64]
+      0: column["amount"]() -> Float64
+      outputs: [0]
+    4: merge inputs=[3, 1] schema=[1_2: Float64, 0_2: Float64]
+    5: project inputs=[4] schema=[2_5: {sent: Float64, received: Float64}, 2_7: Boolean]
+      0: column["0_2"]() -> Float64
+      1: column["1_2"]() -> Float64
+      2: record[](0, 1) -> {sent: Float64, received: Float64}
+      3: time_of[](2) -> Timestamp(Nanosecond, None)
+      4: late_bound["changed_since_time"]() -> Timestamp(Nanosecond, None)
+      5: gte_primitive[](3, 4) -> Boolean
+      outputs: [2, 5]
+    6: filter inputs=[5] schema=[2_5: {sent: Float64, received: Float64}, 2_7: Boolean]
+      0: column["2_7"]() -> Boolean
+      outputs: [0]
+    7: project inputs=[6] schema=[3_0: {sent: Float64, received: Float64}]
+      0: column["2_5"]() -> {sent: Float64, received: Float64}
+      outputs: [0]
+    pipelines: [[0, 1], [2, 3], [4, 5, 6, 7]]
+    "###);
+}
+
+#[tokio::test]
+async fn test_lower_with_key() {
+    let compute_plan = compile(
+        vec![store_received_table()],
+        vec![formula(
+            "StoreReceivedBySender",
+            "StoreReceived | with_key($input.sender_id)",
+        )],
+        "{ amount: StoreReceivedBySender.amount }",
+    )
+    .await;
+
+    let plan = lower_compute_plan(&compute_plan, 4).unwrap();
+    insta::assert_snapshot!(plan.to_string(), @r###"
+    0: scan StoreReceived inputs=[] schema=[amount: Float64, store_id: UInt64, sender_id: UInt64]
+    1: project inputs=[0] schema=[0_0: {amount: Float64, store_id: UInt64, sender_id: UInt64}, 0_2: UInt64]
+      0: column["amount"]() -> Float64
+      1: column["store_id"]() -> UInt64
+      2: column["sender_id"]() -> UInt64
+      3: record[](0, 1, 2) -> {amount: Float64, store_id: UInt64, sender_id: UInt64}
+      4: column["sender_id"]() -> UInt64
+      outputs: [3, 4]
+    2: repartition 4 inputs=[1] schema=[0_0: {amount: Float64, store_id: UInt64, sender_id: UInt64}, 0_2: UInt64]
+      0: column["0_2"]() -> UInt64
+      outputs: [0]
+    3: project inputs=[2] schema=[1_4: {amount: Float64}, 1_6: Boolean]
+      0: column["0_0"]() -> {amount: Float64, store_id: UInt64, sender_id: UInt64}
+      1: field_ref["amount"](0) -> Float64
+      2: record[](1) -> {amount: Float64}
+      3: time_of[](2) -> Timestamp(Nanosecond, None)
+      4: late_bound["changed_since_time"]() -> Timestamp(Nanosecond, None)
+      5: gte_primitive[](3, 4) -> Boolean
+      outputs: [2, 5]
+    4: filter inputs=[3] schema=[1_4: {amount: Float64}, 1_6: Boolean]
+      0: column["1_6"]() -> Boolean
+      outputs: [0]
+    5: project inputs=[4] schema=[2_0: {amount: Float64}]
+      0: column["1_4"]() -> {amount: Float64}
+      outputs: [0]
+    pipelines: [[0, 1], [2, 3, 4, 5]]
+    "###);
+}
+
+#[tokio::test]
+async fn test_lower_shift_to() {
+    let compute_plan = compile(
+        vec![sent_table()],
+        vec![],
+        "{ amount: Sent.amount | shift_to(add_time(seconds(10), time_of($input))) }",
+    )
+    .await;
+
+    let plan = lower_compute_plan(&compute_plan, 4).unwrap();
+    insta::assert_snapshot!(plan.to_string(), @r###"
+    0: scan Sent inputs=[] schema=[sender: UInt64, amount: Float64, receiver: UInt64, store: UInt64]
+    1: project inputs=[0] schema=[0_2: Float64, 0_5: Timestamp(Nanosecond, None)]
+      0: column["amount"]() -> Float64
+      1: literal[duration_s:10]() -> Duration(Second)
+      2: time_of[](0) -> Timestamp(Nanosecond, None)
+      3: add_time[](1, 2) -> Timestamp(Nanosecond, None)
+      outputs: [0, 3]
+    2: shift_to inputs=[1] schema=[0_2: Float64, 0_5: Timestamp(Nanosecond, None)]
+      0: column["0_5"]() -> Timestamp(Nanosecond, None)
+      outputs: [0]
+    3: project inputs=[2] schema=[1_3: {amount: Float64}, 1_5: Boolean]
+      0: column["0_2"]() -> Float64
+      1: record[](0) -> {amount: Float64}
+      2: time_of[](1) -> Timestamp(Nanosecond, None)
+      3: late_bound["changed_since_time"]() -> Timestamp(Nanosecond, None)
+      4: gte_primitive[](2, 3) -> Boolean
+      outputs: [1, 4]
+    4: filter inputs=[3] schema=[1_3: {amount: Float64}, 1_5: Boolean]
+      0: column["1_5"]() -> Boolean
+      outputs: [0]
+    5: project inputs=[4] schema=[2_0: {amount: Float64}]
+      0: column["1_3"]() -> {amount: Float64}
+      outputs: [0]
+    pipelines: [[0, 1, 2, 3, 4, 5]]
+    "###);
+}
+
+#[tokio::test]
+async fn test_lower_aggregation() {
+    let compute_plan = compile(
+        vec![sent_table()],
+        vec![],
+        "{ sum: sum(Sent.amount), count: count(Sent.amount), mean: mean(Sent.amount) + 1.0 }",
+    )
+    .await;
+
+    let plan = lower_compute_plan(&compute_plan, 4).unwrap();
+    insta::assert_snapshot!(plan.to_string(), @r###"
+    0: scan Sent inputs=[] schema=[sender: UInt64, amount: Float64, receiver: UInt64, store: UInt64]
+    1: aggregate inputs=[0] schema=[sender: UInt64, amount: Float64, receiver: UInt64, store: UInt64, 0_6: Float64, 0_10: UInt32, 0_12: Float64]
+      0: column["amount"]() -> Float64
+      1: is_valid[](0) -> Boolean
+      outputs: []
+      aggregate: mean[](0) Unbounded -> Float64
+      aggregate: count_if[](1) Unbounded -> UInt32
+      aggregate: sum[](0) Unbounded -> Float64
+    2: project inputs=[1] schema=[0_14: {sum: Float64, count: UInt32, mean: Float64}, 0_16: Boolean]
+      0: column["0_12"]() -> Float64
+      1: column["0_10"]() -> UInt32
+      2: column["0_6"]() -> Float64
+      3: literal[1f64]() -> Float64
+      4: add[](2, 3) -> Float64
+      5: record[](0, 1, 4) -> {sum: Float64, count: UInt32, mean: Float64}
+      6: time_of[](5) -> Timestamp(Nanosecond, None)
+      7: late_bound["changed_since_time"]() -> Timestamp(Nanosecond, None)
+      8: gte_primitive[](6, 7) -> Boolean
+      outputs: [5, 8]
+    3: filter inputs=[2] schema=[0_14: {sum: Float64, count: UInt32, mean: Float64}, 0_16: Boolean]
+      0: column["0_16"]() -> Boolean
+      outputs: [0]
+    4: project inputs=[3] schema=[1_0: {sum: Float64, count: UInt32, mean: Float64}]
+      0: column["0_14"]() -> {sum: Float64, count: UInt32, mean: Float64}
+      outputs: [0]
+    pipelines: [[0, 1, 2, 3, 4]]
+    "###);
+}
+
+#[tokio::test]
+async fn test_lower_tick() {
+    let compute_plan = compile(
+        vec![sent_table()],
+        vec![],
+        "{ sum: sum(Sent.amount, window = since(daily())) }",
+    )
+    .await;
+
+    let plan = lower_compute_plan(&compute_plan, 4).unwrap();
+    insta::assert_snapshot!(plan.to_string(), @r###"
+    0: scan Sent inputs=[] schema=[sender: UInt64, amount: Float64, receiver: UInt64, store: UInt64]
+    1: project inputs=[0] schema=[0_2: Float64]
+      0: column["amount"]() -> Float64
+      outputs: [0]
+    2: tick Daily inputs=[1] schema=[]
+    3: project inputs=[2] schema=[1_0: Boolean]
+      0: literal[true]() -> Boolean
+      outputs: [0]
+    4: merge inputs=[1, 3] schema=[0_2: Float64, 1_0: Boolean]
+    5: aggregate inputs=[4] schema=[0_2: Float64, 1_0: Boolean, 2_4: Float64]
+      0: column["0_2"]() -> Float64
+      1: column["1_0"]() -> Boolean
+      outputs: []
+      aggregate: sum[](0) Since { condition: 1 } -> Float64
+    6: project inputs=[5] schema=[2_6: {sum: Float64}, 2_8: Boolean]
+      0: column["2_4"]() -> Float64
+      1: record[](0) -> {sum: Float64}
+      2: time_of[](1) -> Timestamp(Nanosecond, None)
+      3: late_bound["changed_since_time"]() -> Timestamp(Nanosecond, None)
+      4: gte_primitive[](2, 3) -> Boolean
+      outputs: [1, 4]
+    7: filter inputs=[6] schema=[2_6: {sum: Float64}, 2_8: Boolean]
+      0: column["2_8"]() -> Boolean
+      outputs: [0]
+    8: project inputs=[7] schema=[3_0: {sum: Float64}]
+      0: column["2_6"]() -> {sum: Float64}
+      outputs: [0]
+    pipelines: [[0, 1], [2, 3], [4, 5, 6, 7, 8]]
+    "###);
+}
+
+#[tokio::test]
+async fn test_lower_lookup() {
+    let compute_plan = compile(
+        vec![sent_table(), received_table()],
+        vec![],
+        "{ received: lookup(Sent.receiver, Received.amount) }",
+    )
+    .await;
+
+    let plan = lower_compute_plan(&compute_plan, 4).unwrap();
+    insta::assert_snapshot!(plan.to_string(), @r###"
+    0: scan Received inputs=[] schema=[amount: Float64, receiver: UInt64]
+    1: project inputs=[0] schema=[0_2: Float64]
+      0: column["amount"]() -> Float64
+      outputs: [0]
+    2: scan Sent inputs=[] schema=[sender: UInt64, amount: Float64, receiver: UInt64, store: UInt64]
+    3: project inputs=[2] schema=[1_2: UInt64]
+      0: column["receiver"]() -> UInt64
+      outputs: [0]
+    4: lookup_request inputs=[3] schema=[1_2: UInt64]
+      0: column["1_2"]() -> UInt64
+      outputs: [0]
+    5: project inputs=[4] schema=[2_0: List<UInt64>]
+      0: key_hash[]() -> List<UInt64>
+      outputs: [0]
+    6: merge inputs=[5, 1] schema=[2_0: List<UInt64>, 0_2: Float64]
+    7: project inputs=[6] schema=[3_0: List<UInt64>, 3_1: Float64]
+      0: column["2_0"]() -> List<UInt64>
+      1: column["0_2"]() -> Float64
+      outputs: [0, 1]
+    8: lookup_response inputs=[7] schema=[3_0: List<UInt64>, 3_1: Float64]
+      0: column["3_0"]() -> List<UInt64>
+      outputs: [0]
+    9: project inputs=[8] schema=[4_0: Float64]
+      0: column["3_1"]() -> Float64
+      outputs: [0]
+    10: merge inputs=[3, 9] schema=[1_2: UInt64, 4_0: Float64]
+    11: project inputs=[10] schema=[5_3: {received: Float64}, 5_5: Boolean]
+      0: column["4_0"]() -> Float64
+      1: record[](0) -> {received: Float64}
+      2: time_of[](1) -> Timestamp(Nanosecond, None)
+      3: late_bound["changed_since_time"]() -> Timestamp(Nanosecond, None)
+      4: gte_primitive[](2, 3) -> Boolean
+      outputs: [1, 4]
+    12: filter inputs=[11] schema=[5_3: {received: Float64}, 5_5: Boolean]
+      0: column["5_5"]() -> Boolean
+      outputs: [0]
+    13: project inputs=[12] schema=[6_0: {received: Float64}]
+      0: column["5_3"]() -> {received: Float64}
+      outputs: [0]
+    pipelines: [[0, 1], [2, 3], [4, 5], [6, 7], [8, 9], [10, 11, 12, 13]]
+    "###);
+}
@@ -100,10 +100,12 @@ pub(super) async fn execute_example(
             plan: result.plan,
             tables,
             destination: Some(output_to),
+            additional_destinations: vec![],
             limits: None,
             compute_snapshot_config: None,
             changed_since: None,
             final_result_time: None,
+            preserve_time_zones: false,
         },
         None,
         None,
@@ -2,6 +2,7 @@
 
 mod ast_dfg;
 mod record_ops_to_dfg;
+mod user_function;
 mod window_args;
 
 #[cfg(test)]
@@ -22,9 +23,12 @@ use sparrow_syntax::{
     ExprOp, FenlType, FormatDataType, LiteralValue, Located, Location, Resolved, ResolvedExpr,
 };
 
+use self::user_function::call_user_function;
+pub(crate) use self::user_function::UserFunction;
 use self::window_args::flatten_window_args;
 use crate::dfg::{Dfg, Expression, Operation};
 use crate::diagnostics::DiagnosticCode;
+use crate::functions::{literal_timedelta_ns, ArgumentCheck};
 use crate::time_domain::TimeDomain;
 use crate::types::inference::instantiate;
 use crate::{DataContext, DiagnosticBuilder, DiagnosticCollector};
@@ -51,9 +55,11 @@ pub(super) fn ast_to_dfg(
     let mut arguments = match expr.op() {
         ExprOp::Pipe(_) => {
             let lhs = ast_to_dfg(data_context, dfg, diagnostics, &arguments[0])?;
+            dfg.add_expression_type(arguments[0].location(), &lhs);
             dfg.enter_env();
             dfg.bind("$input", lhs);
             let rhs = ast_to_dfg(data_context, dfg, diagnostics, &arguments[1])?;
+            dfg.add_expression_type(arguments[1].location(), &rhs);
             dfg.exit_env();
             return Ok(rhs);
         }
@@ -63,15 +69,29 @@ pub(super) fn ast_to_dfg(
             let bindings = names.len() - 1;
             // Skip the last argument (which will correspond to the let body).
             for (name, value) in izip!(names, arguments.values()).take(bindings) {
-                let value = ast_to_dfg(data_context, dfg, diagnostics, value)?;
-                dfg.bind(name.inner(), value);
+                let value_dfg = ast_to_dfg(data_context, dfg, diagnostics, value)?;
+                dfg.add_expression_type(name.location(), &value_dfg);
+                dfg.add_expression_type(value.location(), &value_dfg);
+                dfg.bind(name.inner(), value_dfg);
             }
-            let result = ast_to_dfg(
-                data_context,
+            let body = &arguments.values()[names.len() - 1];
+            let result = ast_to_dfg(data_context, dfg, diagnostics, body)?;
+            dfg.add_expression_type(body.location(), &result);
+            dfg.exit_env();
+            return Ok(result);
+        }
+        ExprOp::Def(signature, _) => {
+            let function = UserFunction::new(
                 dfg,
                 diagnostics,
-                &arguments.values()[names.len() - 1],
-            )?;
+                signature.clone(),
+                arguments.values()[0].clone(),
+            );
+            dfg.enter_env();
+            dfg.define(Rc::new(function));
+            let body = &arguments.values()[1];
+            let result = ast_to_dfg(data_context, dfg, diagnostics, body)?;
+            dfg.add_expression_type(body.location(), &result);
             dfg.exit_env();
             return Ok(result);
         }
@@ -79,6 +99,7 @@ pub(super) fn ast_to_dfg(
         // need to be wrapped in `Located`.
         _ => arguments.try_transform(|e| -> anyhow::Result<Located<AstDfgRef>> {
             let ast_dfg = ast_to_dfg(data_context, dfg, diagnostics, e.inner())?;
+            dfg.add_expression_type(e.location(), &ast_dfg);
             Ok(e.with_value(ast_dfg))
         })?,
     };
@@ -144,35 +165,30 @@ pub(super) fn ast_to_dfg(
                 .emit(diagnostics);
             Ok(dfg.error_node())
         }
-        ExprOp::Reference(reference) => match dfg.get_binding(reference) {
-            Ok(value) => Ok(value),
-            Err(nearest) => {
-                DiagnosticCode::UnboundReference
-                    .builder()
-                    .with_label(
-                        reference
-                            .location()
-                            .primary_label()
-                            .with_message(format!("No reference named '{reference}'")),
-                    )
-                    .with_note(if nearest.is_empty() {
-                        "No formulas, tables, or let-bound names available".to_owned()
-                    } else {
-                        format!(
-                            "Nearest matches: {}",
-                            nearest
-                                .iter()
-                                .format_with(", ", |e, f| f(&format_args!("'{e}'")))
-                        )
-                    })
-                    .emit(diagnostics);
-                Ok(dfg.error_node())
-            }
-        },
+        ExprOp::Reference(reference) => Ok(reference_to_dfg(dfg, diagnostics, reference)),
         ExprOp::FieldRef(field, _) => {
             let base = &arguments[0];
             let base_type = &argument_types[0];
 
+            if let Some(json) = dfg.json_string(base.value()) {
+                // This is a pseudo-hack that allows us to support json datatypes without
+                // a specific arrow-representable json type. Field references on `json`
+                // values (including nested fields) are converted to `json_field`
+                // instructions that take a `string` and output a `string`, hence the
+                // `utf8` type here.
+                let field_name = dfg.add_string_literal(field.inner())?;
+                let value = dfg.add_instruction(InstOp::JsonField, smallvec![json, field_name])?;
+                return Ok(Rc::new(AstDfg::new(
+                    value,
+                    base.is_new(),
+                    FenlType::Concrete(DataType::Utf8),
+                    base.grouping(),
+                    base.time_domain().clone(),
+                    base.location().clone(),
+                    None,
+                )));
+            }
+
             let field_type = match base_type.inner() {
                 FenlType::Concrete(DataType::Struct(fields)) => {
                     if let Some(field) = fields.iter().find(|f| f.name() == field.inner()) {
@@ -183,13 +199,6 @@ pub(super) fn ast_to_dfg(
                         return Ok(dfg.error_node());
                     }
                 }
-                FenlType::Json => {
-                    // This is a pseudo-hack that allows us to support json datatypes without
-                    // a specific arrow-representable json type. All `json` functions are converted
-                    // to `json_field` instructions that take a `string` and output a `string`,
-                    // hence the `utf8` return type here.
-                    &DataType::Utf8
-                }
                 FenlType::Error => {
                     // The original error is already reported.
                     return Ok(dfg.error_node());
@@ -230,6 +239,18 @@ pub(super) fn ast_to_dfg(
             )))
         }
         ExprOp::Call(function_name) => {
+            // User-defined functions shadow built-in functions.
+            if let Some(function) = dfg.user_function(function_name) {
+                return call_user_function(
+                    &function,
+                    function_name,
+                    data_context,
+                    dfg,
+                    diagnostics,
+                    &arguments,
+                );
+            }
+
             // Assumption: All instructions are exposed as Fenl functions. It seems
             // reasonable / desirable to keep this consistency, until such
             // time as it is clear they should diverge.
@@ -251,12 +272,16 @@ pub(super) fn ast_to_dfg(
                 //
                 // TODO: Can we move this before we create the args, so we don't have to
                 // recreate them?
-                let behavior = function.tick_behavior().context("tick behavior")?;
                 if let Ok(agg_input) = dfg.get_binding("$condition_input") {
+                    let Some(kind) = function.tick_kind(dfg, diagnostics, arguments.values())?
+                    else {
+                        return Ok(dfg.error_node());
+                    };
+
                     // The argument is a tick, so we can directly create the necessary node.
                     let agg_input_op = dfg.operation(agg_input.value());
                     let tick_input = smallvec![agg_input_op];
-                    let tick_node = dfg.add_operation(Operation::Tick(behavior), tick_input)?;
+                    let tick_node = dfg.add_operation(Operation::Tick(kind), tick_input)?;
                     let tick_node = Rc::new(AstDfg::new(
                         tick_node,
                         tick_node,
@@ -270,6 +295,40 @@ pub(super) fn ast_to_dfg(
                 }
             }
 
+            if function.name() == "index" {
+                if let Some(json) = dfg.json_string(arguments[1].value()) {
+                    // Elements of `json` arrays are accessed from the underlying string,
+                    // as described for field references. This requires a literal index,
+                    // since the `json_index` instruction is applied to each row.
+                    let index = &arguments[0];
+                    if !matches!(
+                        dfg.literal(index.value()),
+                        Some(ScalarValue::Int64(Some(_)))
+                    ) {
+                        DiagnosticCode::InvalidNonConstArgument
+                            .builder()
+                            .with_label(index.location().primary_label().with_message(
+                                "Index into 'json' must be a constant integer, but was not",
+                            ))
+                            .emit(diagnostics);
+                        return Ok(dfg.error_node());
+                    }
+
+                    let base = &arguments[1];
+                    let value =
+                        dfg.add_instruction(InstOp::JsonIndex, smallvec![json, index.value()])?;
+                    return Ok(Rc::new(AstDfg::new(
+                        value,
+                        base.is_new(),
+                        FenlType::Concrete(DataType::Utf8),
+                        base.grouping(),
+                        base.time_domain().clone(),
+                        function_name.location().clone(),
+                        None,
+                    )));
+                }
+            }
+
             let mut invalid = false;
             for constant_index in function.signature().parameters().constant_indices() {
                 let argument = &arguments.values()[constant_index];
@@ -286,10 +345,42 @@ pub(super) fn ast_to_dfg(
                 }
             }
 
+            if !function.check_arguments(dfg, diagnostics, arguments.values()) {
+                invalid = true;
+            }
+
             if invalid {
                 return Ok(dfg.error_node());
             }
 
+            // Calendar functions use the time zone of the input timestamp, unless a
+            // time zone is given explicitly.
+            if let Some(time_zone_index) = function
+                .signature()
+                .arg_names()
+                .iter()
+                .position(|name| name.inner() == "time_zone")
+            {
+                let time_zone_arg = &arguments.values()[time_zone_index];
+                let input_time_zone = match (
+                    dfg.literal(time_zone_arg.value()),
+                    arguments.get("time").map(|time| time.value_type()),
+                ) {
+                    (
+                        Some(ScalarValue::Null | ScalarValue::Utf8(None)),
+                        Some(FenlType::Concrete(DataType::Timestamp(_, Some(time_zone)))),
+                    ) => Some(time_zone.clone()),
+                    _ => None,
+                };
+
+                if let Some(time_zone) = input_time_zone {
+                    let location = time_zone_arg.location().clone();
+                    let time_zone = dfg.add_string_literal(&time_zone)?;
+                    let time_zone = add_literal(dfg, time_zone, DataType::Utf8.into(), location)?;
+                    arguments.values_mut()[time_zone_index].update_value(time_zone);
+                }
+            }
+
             let (instantiated_types, instantiated_result_type) =
                 match instantiate(function_name, &argument_types, function.signature()) {
                     Ok(result) => result,
@@ -504,11 +595,40 @@ pub(super) fn ast_to_dfg(
                 .try_collect()?;
 
             let args: Vec<_> = if function.is_aggregation() {
+                // The window follows the input and any other arguments of the
+                // aggregation, and is followed by the (internal) duration.
+                let window_index = function
+                    .arg_names()
+                    .position(|name| name.inner() == "window")
+                    .context("aggregation missing window")?;
+                let window = &expr.args()[window_index];
+
+                // Some aggregations only support specific windows.
+                if let (
+                    ExprOp::Call(window_name),
+                    Some(ArgumentCheck::SupportedWindows(supported)),
+                ) = (window.op(), function.argument_check("window"))
+                {
+                    if !supported.contains(&window_name.inner().as_str()) {
+                        let supported = supported.iter().map(|name| format!("'{name}'")).join(", ");
+                        DiagnosticCode::InvalidArguments
+                            .builder()
+                            .with_label(window_name.location().primary_label().with_message(
+                                format!(
+                                    "Window '{}' is not supported by '{function_name}'",
+                                    window_name.inner()
+                                ),
+                            ))
+                            .with_note(format!("Supported windows: {supported}"))
+                            .emit(diagnostics);
+                        return Ok(dfg.error_node());
+                    }
+                }
+
                 // If the function is an aggregation, we may need to flatten the window.
                 dfg.enter_env();
                 dfg.bind("$condition_input", args[0].inner().clone());
 
-                let window = &expr.args()[1];
                 let (condition, duration) = match window.op() {
                     ExprOp::Call(window_name) => {
                         flatten_window_args(window_name, window, dfg, data_context, diagnostics)?
@@ -532,8 +652,26 @@ pub(super) fn ast_to_dfg(
                 };
 
                 dfg.exit_env();
-                // [agg_input, condition, duration]
-                vec![args[0].clone(), condition, duration]
+                // [agg_input, ...agg_args, condition, duration]
+                let mut args = args[..window_index].to_vec();
+                for (arg, arg_name) in args.iter_mut().zip(function.arg_names()) {
+                    // Durations are passed to the instruction in nanoseconds.
+                    if let Some(ArgumentCheck::PositiveDuration) =
+                        function.argument_check(arg_name.inner())
+                    {
+                        let nanos = literal_timedelta_ns(dfg, arg)
+                            .context("duration validated as literal")?;
+                        let nanos_id = dfg.add_literal(ScalarValue::Int64(Some(nanos)))?;
+                        *arg = arg.with_value(add_literal(
+                            dfg,
+                            nanos_id,
+                            FenlType::Concrete(DataType::Int64),
+                            arg.location().clone(),
+                        )?);
+                    }
+                }
+                args.extend([condition, duration]);
+                args
             } else if function.name() == "when" || function.name() == "if" {
                 dfg.enter_env();
                 dfg.bind("$condition_input", args[1].inner().clone());
@@ -558,6 +696,7 @@ pub(super) fn ast_to_dfg(
         }
         ExprOp::Pipe(_) => Err(anyhow!("Unreachable: Pipe expression handled above")),
         ExprOp::Let(_, _) => Err(anyhow!("Unreachable: Let expression handled above")),
+        ExprOp::Def(_, _) => Err(anyhow!("Unreachable: Def expression handled above")),
         ExprOp::Error => Err(anyhow!("Unreachable: Error expression handled above")),
         ExprOp::Record(fields, location) => {
             record_to_dfg(data_context, location, dfg, diagnostics, fields, arguments)
@@ -589,6 +728,41 @@ pub(super) fn ast_to_dfg(
     }
 }
 
+/// Return the value bound to the `reference`.
+///
+/// Reports a diagnostic and returns the error node if nothing is bound.
+fn reference_to_dfg(
+    dfg: &mut Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    reference: &Located<String>,
+) -> AstDfgRef {
+    match dfg.get_binding(reference) {
+        Ok(value) => value,
+        Err(nearest) => {
+            DiagnosticCode::UnboundReference
+                .builder()
+                .with_label(
+                    reference
+                        .location()
+                        .primary_label()
+                        .with_message(format!("No reference named '{reference}'")),
+                )
+                .with_note(if nearest.is_empty() {
+                    "No formulas, tables, or let-bound names available".to_owned()
+                } else {
+                    format!(
+                        "Nearest matches: {}",
+                        nearest
+                            .iter()
+                            .format_with(", ", |e, f| f(&format_args!("'{e}'")))
+                    )
+                })
+                .emit(diagnostics);
+            dfg.error_node()
+        }
+    }
+}
+
 // Verify that the arguments are compatibly partitioned.
 fn verify_same_partitioning(
     data_context: &DataContext,
@@ -653,6 +827,12 @@ fn cast_if_needed(
         {
             Ok(value)
         }
+        // Lists with the same item type are compatible, regardless of the item field name.
+        (FenlType::Concrete(DataType::List(a)), FenlType::Concrete(DataType::List(b)))
+            if a.data_type() == b.data_type() =>
+        {
+            Ok(value)
+        }
         (FenlType::Concrete(DataType::Null), FenlType::Window) => Ok(value),
         (
             FenlType::Concrete(DataType::Struct(actual_fields)),
@@ -59,3 +59,44 @@ fn test_typecheck_pipe_when() {
         FenlType::Concrete(DataType::Float64),
     );
 }
+
+#[test]
+fn test_typecheck_user_defined_function() {
+    assert_type(
+        "def scale(x, factor) = x * factor in scale(Table1.x_i64, 2)",
+        FenlType::Concrete(DataType::Int64),
+    );
+    assert_type(
+        "def scale(x, factor) = x * factor in scale(Table1.x_i64, 2.5)",
+        FenlType::Concrete(DataType::Float64),
+    );
+
+    // Arguments are converted to the declared parameter types.
+    assert_type(
+        "def half(x: f64) = x / 2 in half(Table1.x_i64)",
+        FenlType::Concrete(DataType::Float64),
+    );
+    assert_type(
+        "def negate(x: bool) = !x in negate(Table1.x_i64)",
+        FenlType::Error,
+    );
+
+    // References in the definition use the bindings where it was defined.
+    assert_type(
+        "let y = 1 def add_y(x) = x + y in let y = 2.5 in add_y(Table1.x_i64)",
+        FenlType::Concrete(DataType::Int64),
+    );
+
+    // Calls in the definition use the functions visible where it was defined.
+    assert_type(
+        "def f(x) = x + 1 in def f(x) = f(x) * 2 in f(1)",
+        FenlType::Concrete(DataType::Int64),
+    );
+    assert_type(
+        "def total(x) = sum(x) in def sum(x: bool) = x in total(Table1.x_i64)",
+        FenlType::Concrete(DataType::Int64),
+    );
+
+    // Functions may not call themselves.
+    assert_type("def f(x) = f(x) in f(1)", FenlType::Error);
+}
@@ -0,0 +1,201 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use itertools::izip;
+use sparrow_syntax::{ExprOp, Located, Resolved, ResolvedExpr, Signature};
+
+use crate::ast_to_dfg::{ast_to_dfg, cast_if_needed, reference_to_dfg, AstDfg};
+use crate::dfg::Dfg;
+use crate::types::inference::instantiate;
+use crate::{AstDfgRef, DataContext, DiagnosticCollector};
+
+/// A function defined by a `def` binding.
+#[derive(Debug)]
+pub(crate) struct UserFunction {
+    signature: Arc<Signature>,
+    /// The definition of the function.
+    definition: Located<Box<ResolvedExpr>>,
+    /// The values of the free references in the definition.
+    ///
+    /// These are captured where the function is defined, so that references
+    /// in the definition aren't affected by bindings at the call site.
+    captured: Vec<(String, AstDfgRef)>,
+    /// The user-defined functions called in the definition.
+    ///
+    /// Like references, these are captured where the function is defined,
+    /// so calls aren't affected by functions defined at the call site.
+    functions: Vec<Rc<UserFunction>>,
+}
+
+impl UserFunction {
+    /// Create a user-defined function, capturing the values of references
+    /// within the definition.
+    ///
+    /// References that aren't bound where the function is defined are
+    /// reported here, rather than at each call.
+    pub(super) fn new(
+        dfg: &mut Dfg,
+        diagnostics: &mut DiagnosticCollector<'_>,
+        signature: Arc<Signature>,
+        definition: Located<Box<ResolvedExpr>>,
+    ) -> Self {
+        let mut bound: Vec<&str> = signature
+            .arg_names()
+            .iter()
+            .map(|name| name.inner().as_str())
+            .collect();
+        let mut free = BTreeMap::new();
+        free_references(definition.inner(), &mut bound, &mut free);
+
+        let captured = free
+            .into_iter()
+            .map(|(name, reference)| {
+                let value = reference_to_dfg(dfg, diagnostics, reference);
+                (name.to_owned(), value)
+            })
+            .collect();
+
+        let mut calls = BTreeSet::new();
+        called_functions(definition.inner(), &mut calls);
+        let functions = calls
+            .into_iter()
+            .filter_map(|name| dfg.user_function(name))
+            .collect();
+
+        Self {
+            signature,
+            definition,
+            captured,
+            functions,
+        }
+    }
+
+    pub(crate) fn signature(&self) -> &Signature {
+        &self.signature
+    }
+}
+
+/// Call a user-defined function.
+///
+/// The argument types are checked against the signature of the function,
+/// and then the definition is converted with each parameter bound to the
+/// corresponding argument.
+pub(super) fn call_user_function(
+    function: &UserFunction,
+    call: &Located<String>,
+    data_context: &mut DataContext,
+    dfg: &mut Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    arguments: &Resolved<Located<AstDfgRef>>,
+) -> anyhow::Result<AstDfgRef> {
+    let argument_types = arguments.transform(|i| i.with_value(i.value_type().clone()));
+    let instantiated_types = match instantiate(call, &argument_types, function.signature()) {
+        Ok((instantiated_types, _)) => instantiated_types,
+        Err(diagnostic) => {
+            diagnostic.emit(diagnostics);
+            return Ok(dfg.error_node());
+        }
+    };
+
+    if argument_types.iter().any(|arg| arg.is_error()) {
+        return Ok(dfg.error_node());
+    }
+
+    // Cast the arguments before entering the environment of the definition.
+    let mut parameters = Vec::with_capacity(arguments.len());
+    for (name, arg, expected_type) in izip!(
+        function.signature().arg_names(),
+        arguments.iter(),
+        instantiated_types
+    ) {
+        let value = if arg.value_type() == &expected_type {
+            arg.inner().clone()
+        } else {
+            Rc::new(AstDfg::new(
+                cast_if_needed(dfg, arg.value(), arg.value_type(), &expected_type)?,
+                arg.is_new(),
+                expected_type,
+                arg.grouping(),
+                arg.time_domain().clone(),
+                arg.location().clone(),
+                None,
+            ))
+        };
+        parameters.push((name.inner(), value));
+    }
+
+    let call_site_functions = dfg.replace_functions(function.functions.iter().cloned());
+    dfg.enter_env();
+    for (name, value) in function.captured.iter() {
+        dfg.bind(name, value.clone());
+    }
+    for (name, value) in parameters {
+        dfg.bind(name, value);
+    }
+    let result = ast_to_dfg(data_context, dfg, diagnostics, &function.definition);
+    dfg.exit_env();
+    dfg.replace_functions_env(call_site_functions);
+    result
+}
+
+/// Collect the names of all functions called in `expr`.
+///
+/// This may include functions defined within `expr`, which are bound again
+/// when the definition is converted.
+fn called_functions<'a>(expr: &'a ResolvedExpr, calls: &mut BTreeSet<&'a str>) {
+    if let ExprOp::Call(name) = expr.op() {
+        calls.insert(name.inner().as_str());
+    }
+    for arg in expr.args().values() {
+        called_functions(arg.inner(), calls);
+    }
+}
+
+/// Collect the first occurrence of each reference in `expr` that isn't bound
+/// within `expr` or by `bound`.
+fn free_references<'a>(
+    expr: &'a ResolvedExpr,
+    bound: &mut Vec<&'a str>,
+    free: &mut BTreeMap<&'a str, &'a Located<String>>,
+) {
+    let args = expr.args().values();
+    match expr.op() {
+        ExprOp::Reference(reference) => {
+            if !bound.contains(&reference.inner().as_str()) {
+                free.entry(reference.inner().as_str()).or_insert(reference);
+            }
+        }
+        ExprOp::Pipe(_) => {
+            free_references(args[0].inner(), bound, free);
+            bound.push("$input");
+            free_references(args[1].inner(), bound, free);
+            bound.pop();
+        }
+        ExprOp::Let(names, _) => {
+            let depth = bound.len();
+            for (name, value) in izip!(names, args) {
+                free_references(value.inner(), bound, free);
+                bound.push(name.inner().as_str());
+            }
+            bound.truncate(depth);
+        }
+        ExprOp::Def(signature, _) => {
+            let depth = bound.len();
+            bound.extend(
+                signature
+                    .arg_names()
+                    .iter()
+                    .map(|name| name.inner().as_str()),
+            );
+            free_references(args[0].inner(), bound, free);
+            bound.truncate(depth);
+            free_references(args[1].inner(), bound, free);
+        }
+        _ => {
+            for arg in args {
+                free_references(arg.inner(), bound, free);
+            }
+        }
+    }
+}
@@ -1,8 +1,10 @@
 use arrow::datatypes::DataType;
+use sparrow_arrow::scalar_value::ScalarValue;
 use sparrow_syntax::{FenlType, LiteralValue, Located, ResolvedExpr};
 
 use crate::ast_to_dfg::add_literal;
 use crate::dfg::Dfg;
+use crate::functions::literal_timedelta_ns;
 use crate::{AstDfgRef, DataContext, DiagnosticCode, DiagnosticCollector};
 
 /// Flattens window arguments into condition and duration nodes.
@@ -51,6 +53,66 @@ pub(crate) fn flatten_window_args(
 
         let condition = crate::ast_to_dfg(data_context, dfg, diagnostics, &window.args()[1])?;
         Ok((window.with_value(condition), duration))
+    } else if name.inner() == "trailing" {
+        debug_assert!(
+            window.args().len() == 1,
+            "expected one arg for trailing window, saw {}",
+            window.args().len()
+        );
+
+        // Trailing windows aren't based on a condition, and evict inputs once
+        // they are older than the duration (in nanoseconds).
+        let duration = &window.args()[0];
+        let duration_node = crate::ast_to_dfg(data_context, dfg, diagnostics, duration)?;
+        let duration = duration.with_value(duration_node);
+        let duration_ns = match literal_timedelta_ns(dfg, &duration) {
+            Some(duration_ns) if duration_ns > 0 => duration_ns,
+            Some(_) => {
+                DiagnosticCode::InvalidArguments
+                    .builder()
+                    .with_label(
+                        duration
+                            .location()
+                            .primary_label()
+                            .with_message("Argument 'duration' to 'trailing' must be positive"),
+                    )
+                    .emit(diagnostics);
+                return Ok((
+                    window.with_value(dfg.error_node()),
+                    window.with_value(dfg.error_node()),
+                ));
+            }
+            // Non-constant durations are reported when checking the signature.
+            None => {
+                return Ok((
+                    window.with_value(dfg.error_node()),
+                    window.with_value(dfg.error_node()),
+                ))
+            }
+        };
+
+        let condition_id = dfg.add_literal(ScalarValue::Null)?;
+        let condition = Located::new(
+            add_literal(
+                dfg,
+                condition_id,
+                FenlType::Concrete(DataType::Null),
+                name.location().clone(),
+            )?,
+            name.location().clone(),
+        );
+
+        let duration_id = dfg.add_literal(ScalarValue::Int64(Some(duration_ns)))?;
+        let duration = Located::new(
+            add_literal(
+                dfg,
+                duration_id,
+                FenlType::Concrete(DataType::Int64),
+                duration.location().clone(),
+            )?,
+            duration.location().clone(),
+        );
+        Ok((condition, duration))
     } else {
         DiagnosticCode::InvalidArgumentType
             .builder()
@@ -59,7 +121,7 @@ pub(crate) fn flatten_window_args(
                     .primary_label()
                     .with_message(format!("Invalid window function: '{}'", name.inner())),
             )
-            .with_note("Supported windows: 'since', 'sliding'".to_string())
+            .with_note("Supported windows: 'since', 'sliding', 'trailing'".to_string())
             .emit(diagnostics);
         Ok((
             window.with_value(dfg.error_node()),
@@ -47,7 +47,7 @@ use smallvec::smallvec;
 use tracing::{info, info_span};
 pub(crate) use useless_transforms::*;
 
-use crate::ast_to_dfg::AstDfg;
+use crate::ast_to_dfg::{AstDfg, UserFunction};
 use crate::dfg::language::DfgLang;
 use crate::env::Env;
 use crate::time_domain::TimeDomain;
@@ -60,6 +60,8 @@ pub(super) struct Dfg {
     graph: DfgGraph,
     /// A mapping from identifiers to corresponding DFG nodes.
     env: Env<String, AstDfgRef>,
+    /// A mapping from names to user-defined functions.
+    functions: Env<String, Rc<UserFunction>>,
     /// String literal IDs (used for interning, and to avoid copying).
     string_literals: HashMap<String, Id>,
     /// Reference to a shared error node.
@@ -69,6 +71,11 @@ pub(super) struct Dfg {
     error_node: AstDfgRef,
     /// Id of the empty operation.
     empty_operation: Id,
+    /// The type of each expression converted to the DFG, if being recorded.
+    ///
+    /// This is only recorded when requested, since it is only needed for
+    /// editor tooling.
+    expression_types: Option<Vec<(Location, FenlType)>>,
 }
 
 impl Default for Dfg {
@@ -98,9 +105,11 @@ impl Default for Dfg {
         Self {
             graph,
             env,
+            functions: Env::default(),
             string_literals,
             error_node,
             empty_operation,
+            expression_types: None,
         }
     }
 }
@@ -329,10 +338,12 @@ impl Dfg {
 
     pub(super) fn enter_env(&mut self) {
         self.env.enter();
+        self.functions.enter();
     }
 
     pub(super) fn exit_env(&mut self) {
-        self.env.exit()
+        self.env.exit();
+        self.functions.exit();
     }
 
     /// Add a binding for the given name to the environment.
@@ -343,6 +354,59 @@ impl Dfg {
         self.env.insert(name.to_owned(), value)
     }
 
+    /// Add a user-defined function to the environment.
+    pub(super) fn define(&mut self, function: Rc<UserFunction>) {
+        self.functions
+            .insert(function.signature().name().to_owned(), function);
+    }
+
+    /// Replace the user-defined functions in scope with `functions`.
+    ///
+    /// Returns the previous functions, which should be restored with
+    /// [Self::replace_functions_env].
+    pub(super) fn replace_functions(
+        &mut self,
+        functions: impl IntoIterator<Item = Rc<UserFunction>>,
+    ) -> Env<String, Rc<UserFunction>> {
+        let mut env = Env::default();
+        for function in functions {
+            env.insert(function.signature().name().to_owned(), function);
+        }
+        self.replace_functions_env(env)
+    }
+
+    /// Replace the environment of user-defined functions, returning the
+    /// previous one.
+    pub(super) fn replace_functions_env(
+        &mut self,
+        functions: Env<String, Rc<UserFunction>>,
+    ) -> Env<String, Rc<UserFunction>> {
+        std::mem::replace(&mut self.functions, functions)
+    }
+
+    /// Return the user-defined function with the given name, if any.
+    pub(super) fn user_function(&self, name: &str) -> Option<Rc<UserFunction>> {
+        self.functions.get(name).cloned()
+    }
+
+    /// Start recording the type of each expression converted to the DFG.
+    pub(super) fn record_expression_types(&mut self) {
+        self.expression_types = Some(Vec::new());
+    }
+
+    /// Record the type of the expression at `location`, if types are being
+    /// recorded.
+    pub(super) fn add_expression_type(&mut self, location: &Location, value: &AstDfgRef) {
+        if let Some(expression_types) = &mut self.expression_types {
+            expression_types.push((location.clone(), value.value_type().clone()));
+        }
+    }
+
+    /// Return the recorded expression types.
+    pub(super) fn take_expression_types(&mut self) -> Vec<(Location, FenlType)> {
+        self.expression_types.take().unwrap_or_default()
+    }
+
     pub(super) fn is_bound(&self, name: &str) -> bool {
         self.env.contains(name)
     }
@@ -509,6 +573,27 @@ impl Dfg {
         self.graph[id].data.operation(id)
     }
 
+    /// Returns the ID of the `json` string underlying `id`, if any.
+    ///
+    /// This is the string argument of a `json` instruction, or the string
+    /// result of accessing a field or element of a `json` value. Since `json`
+    /// values are represented as strings, fields and elements of these may be
+    /// accessed using `json_field` and `json_index` on the returned ID.
+    pub(super) fn json_string(&self, id: Id) -> Option<Id> {
+        self.graph[id]
+            .nodes
+            .iter()
+            .find_map(|node| match node.kind() {
+                StepKind::Expression(Expression::Inst(InstKind::Simple(InstOp::Json))) => {
+                    Some(node.children()[0])
+                }
+                StepKind::Expression(Expression::Inst(InstKind::Simple(
+                    InstOp::JsonField | InstOp::JsonIndex,
+                ))) => Some(id),
+                _ => None,
+            })
+    }
+
     /// Returns the stepkind associated with this `id`.
     fn step_kind(&self, id: Id) -> StepKind {
         self.graph[id].data.kind()
@@ -27,19 +27,34 @@ pub(super) fn evaluate_constant(
             //
             // These definitions may at first seem a little weird. The idea is
             // a literal is never new, so an aggregation applied to a literal behaves
-            // as if there was never anything in the aggregation. For `count_if`, the
-            // empty behavior is `0`. For other aggregations, it is `null`.
+            // as if there was never anything in the aggregation. For `count_if` and
+            // the distinct counts, the empty behavior is `0`. For other aggregations,
+            // it is `null`.
             //
             // It may turn out to need more thinking, but we're sticking with it for
             // now to fix various panics caused by not having *some* behavior defined.
+            //
+            // Lists can't be represented as literals, so `collect` and `top_k` are
+            // `null` rather than an empty list.
+            InstOp::ApproxCountDistinct => return Ok(ScalarValue::UInt32(Some(0))),
+            InstOp::Collect => return Ok(ScalarValue::Null),
+            InstOp::CountDistinct => return Ok(ScalarValue::UInt32(Some(0))),
             InstOp::CountIf => return Ok(ScalarValue::UInt32(Some(0))),
+            InstOp::EwmVariance => return Ok(ScalarValue::Float64(None)),
+            InstOp::Ewma => return Ok(ScalarValue::Float64(None)),
             InstOp::First => return Ok(inputs[0].null()),
             InstOp::Lag => return Ok(inputs[0].null()),
             InstOp::Last => return Ok(inputs[0].null()),
             InstOp::Max => return Ok(inputs[0].null()),
-            InstOp::Mean => return Ok(ScalarValue::Float64(None)),
+            InstOp::Mean => match &inputs[0] {
+                decimal @ ScalarValue::Decimal128(..) => return Ok(decimal.null()),
+                _ => return Ok(ScalarValue::Float64(None)),
+            },
             InstOp::Min => return Ok(inputs[0].null()),
+            InstOp::Mode => return Ok(inputs[0].null()),
+            InstOp::Percentile => return Ok(ScalarValue::Float64(None)),
             InstOp::Sum => return Ok(inputs[0].null()),
+            InstOp::TopK => return Ok(ScalarValue::Null),
             InstOp::Variance => return Ok(ScalarValue::Float64(None)),
 
             // Handle instructions for which the default `null` behavior of
@@ -106,9 +106,6 @@ static RULES: Vec<Rewrite<DfgLang, DfgAnalysis>> = vec![
     //
     // rewrite!("is_valid-null_if"; "(is_valid (null_if ?cond ?value ?op) ?op)" => "(logical_and
     // (not ?cond ?op) (is_valid ?value ?op) ?op)"),
-    // HACK: This simplifies away the `json(str) -> json` instruction into the internal
-    // `json_field(str, field) -> str` instruction.
-    rewrite!("json-to-json_field"; "(field_ref (json ?value ?op) ?field ?op)" => "(json_field ?value ?field ?op)"),
     //
     //--------------------------------------
     // Rewrite rules for operations (merge join, transform, etc.)
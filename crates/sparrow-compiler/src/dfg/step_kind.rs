@@ -74,7 +74,53 @@ pub(crate) enum Operation {
     /// Takes one operation argument indicating the operation to tick over.
     ///
     /// Used for creating signals at periodic points in time.
-    Tick(TickBehavior),
+    Tick(TickKind),
+}
+
+/// Describes the times a tick operation produces ticks at.
+#[derive(Debug, Clone, PartialEq, Hash, Eq, PartialOrd, Ord)]
+pub(crate) enum TickKind {
+    /// One of the fixed tick behaviors, such as `hourly()` or `daily()`.
+    Behavior(TickBehavior),
+    /// One of the calendar tick behaviors, such as `daily()`, with
+    /// boundaries in the given time zone rather than UTC.
+    Zoned {
+        behavior: TickBehavior,
+        time_zone: String,
+    },
+    /// Ticks every `period_ns` nanoseconds, offset from the epoch by
+    /// `offset_ns` nanoseconds.
+    Every { period_ns: i64, offset_ns: i64 },
+    /// Ticks at times matching the cron `schedule`, evaluated in the given
+    /// time zone (UTC if `None`).
+    Cron {
+        schedule: String,
+        time_zone: Option<String>,
+    },
+}
+
+impl std::fmt::Display for TickKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Behavior(behavior) => write!(f, "{behavior:?}"),
+            Self::Zoned {
+                behavior,
+                time_zone,
+            } => write!(f, "{behavior:?}({time_zone})"),
+            Self::Every {
+                period_ns,
+                offset_ns,
+            } => write!(f, "Every({period_ns}ns, {offset_ns}ns)"),
+            Self::Cron {
+                schedule,
+                time_zone: Some(time_zone),
+            } => write!(f, "Cron({schedule:?}, {time_zone})"),
+            Self::Cron {
+                schedule,
+                time_zone: None,
+            } => write!(f, "Cron({schedule:?})"),
+        }
+    }
 }
 
 /// The expression nodes in the DFG.
@@ -140,7 +186,7 @@ impl std::fmt::Display for Operation {
             Self::Select => write!(f, "select"),
             Self::ShiftTo => write!(f, "shift_to"),
             Self::ShiftUntil => write!(f, "shift_until"),
-            Self::Tick(behavior) => write!(f, "tick:{behavior:?}"),
+            Self::Tick(kind) => write!(f, "tick:{kind}"),
         }
     }
 }
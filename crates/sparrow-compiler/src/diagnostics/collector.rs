@@ -1,4 +1,4 @@
-use codespan_reporting::diagnostic::{Diagnostic, Severity};
+use codespan_reporting::diagnostic::{Diagnostic, Label, Severity};
 use codespan_reporting::term::{self, Chars, Config, DisplayStyle, Styles};
 use sparrow_api::kaskada::v1alpha::FeatureSet;
 use sparrow_api::kaskada::v1alpha::FenlDiagnostic;
@@ -30,9 +30,42 @@ impl<'a> std::fmt::Debug for DiagnosticCollector<'a> {
 pub struct CollectedDiagnostic {
     code: DiagnosticCode,
     formatted: String,
+    /// The labels from the diagnostic, identifying the relevant code.
+    labels: Vec<Label<FeatureSetPart>>,
+    /// The notes from the diagnostic.
+    notes: Vec<String>,
 }
 
 impl CollectedDiagnostic {
+    fn failed_to_report() -> Self {
+        Self {
+            code: DiagnosticCode::FailedToReport,
+            formatted: "Failed to report diagnostic".to_owned(),
+            labels: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+
+    pub fn code_str(&self) -> &'static str {
+        self.code.code_str()
+    }
+
+    pub fn message(&self) -> &'static str {
+        self.code.message()
+    }
+
+    /// The labels associated with the diagnostic.
+    ///
+    /// Unlike the formatted diagnostic, these retain the location of each
+    /// label, allowing tools such as editors to position the diagnostic.
+    pub fn labels(&self) -> &[Label<FeatureSetPart>] {
+        &self.labels
+    }
+
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
     pub fn severity(&self) -> Severity {
         self.code.severity()
     }
@@ -116,10 +149,7 @@ impl<'a> DiagnosticCollector<'a> {
                 "Unable to report diagnostic: {:?} due to {}",
                 diagnostic, err
             );
-            self.collected.push(CollectedDiagnostic {
-                code: DiagnosticCode::FailedToReport,
-                formatted: "Failed to report diagnostic".to_owned(),
-            });
+            self.collected.push(CollectedDiagnostic::failed_to_report());
             return;
         };
         let formatted = match String::from_utf8(buffer.into_inner()) {
@@ -129,15 +159,17 @@ impl<'a> DiagnosticCollector<'a> {
                     "Unable to report diagnostic: {:?} due to {}",
                     diagnostic, err
                 );
-                self.collected.push(CollectedDiagnostic {
-                    code: DiagnosticCode::FailedToReport,
-                    formatted: "Failed to report diagnostic".to_owned(),
-                });
+                self.collected.push(CollectedDiagnostic::failed_to_report());
                 return;
             }
         };
 
-        let diagnostic = CollectedDiagnostic { code, formatted };
+        let diagnostic = CollectedDiagnostic {
+            code,
+            formatted,
+            labels: diagnostic.labels,
+            notes: diagnostic.notes,
+        };
 
         match code.severity() {
             Severity::Bug | Severity::Error => {
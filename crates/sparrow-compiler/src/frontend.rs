@@ -1,5 +1,6 @@
 //! Functionality for analyzing the AST before conversion to the DFG.
 
+mod editor;
 mod first_reference;
 mod free_variable;
 mod incremental_enabled;
@@ -21,6 +22,7 @@ use sparrow_plan::GroupId;
 use sparrow_syntax::{FeatureSetPart, FenlType, Location};
 use tracing::error;
 
+pub use self::editor::{analyze_for_editor, EditorAnalysis};
 use self::resolve_arguments::resolve_recursive;
 use crate::dfg::{Dfg, DfgExpr, Expression};
 use crate::frontend::parse_feature_set::ParsedFeatureSet;
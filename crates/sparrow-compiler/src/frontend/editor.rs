@@ -0,0 +1,112 @@
+use sparrow_api::kaskada::v1alpha::FeatureSet;
+use sparrow_syntax::{FeatureSetPart, FenlType, Location};
+
+use crate::frontend::parse_feature_set::ParsedFeatureSet;
+use crate::{ast_to_dfg, CollectedDiagnostic, DataContext, DiagnosticCollector};
+
+/// The results of analyzing a feature set for editor tooling.
+///
+/// Unlike [FrontendAnalysis](crate::FrontendAnalysis) this stops after
+/// type-checking, since editors need the diagnostics and types for what the
+/// user is typing rather than a plan.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct EditorAnalysis {
+    pub diagnostics: Vec<CollectedDiagnostic>,
+    /// The type of each expression in the feature set, with its location.
+    ///
+    /// An expression may appear more than once, for instance if it is part of
+    /// a user-defined function called multiple times.
+    pub expression_types: Vec<(Location, FenlType)>,
+}
+
+impl EditorAnalysis {
+    /// Return the innermost expression containing the given byte `offset` in
+    /// `part`, along with its type.
+    pub fn type_at(&self, part: FeatureSetPart, offset: usize) -> Option<&(Location, FenlType)> {
+        self.expression_types
+            .iter()
+            .filter(|(location, _)| {
+                location.part() == part && location.start() <= offset && offset <= location.end()
+            })
+            .min_by_key(|(location, _)| location.end() - location.start())
+    }
+
+    /// Return the type of the expression ending at the given byte `offset`
+    /// in `part`.
+    ///
+    /// If multiple expressions end at the offset, the type of the outermost
+    /// is returned. This is useful for determining the type of the base of a
+    /// field reference.
+    pub fn type_ending_at(&self, part: FeatureSetPart, offset: usize) -> Option<&FenlType> {
+        self.expression_types
+            .iter()
+            .filter(|(location, _)| location.part() == part && location.end() == offset)
+            .max_by_key(|(location, _)| location.end() - location.start())
+            .map(|(_, fenl_type)| fenl_type)
+    }
+}
+
+/// Analyze the given feature set for editor tooling.
+///
+/// This parses and type-checks the formulas and query, collecting the
+/// diagnostics and the type of each expression. The query need not produce
+/// a record, since it may be a partially written expression.
+pub fn analyze_for_editor(
+    data_context: &mut DataContext,
+    feature_set: &FeatureSet,
+) -> anyhow::Result<EditorAnalysis> {
+    let mut dfg = data_context.create_dfg()?;
+    dfg.record_expression_types();
+    let mut diagnostics = DiagnosticCollector::new(feature_set);
+
+    let parsed = ParsedFeatureSet::try_new(feature_set, &mut diagnostics)?;
+    for formula in parsed.formulas.into_iter() {
+        let expr_dfg = ast_to_dfg(data_context, &mut dfg, &mut diagnostics, &formula.expr)?;
+        dfg.bind(formula.name, expr_dfg);
+    }
+    ast_to_dfg(data_context, &mut dfg, &mut diagnostics, &parsed.query_expr)?;
+
+    Ok(EditorAnalysis {
+        diagnostics: diagnostics.finish(),
+        expression_types: dfg.take_expression_types(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::datatypes::DataType;
+    use sparrow_api::kaskada::v1alpha::Formula;
+
+    use super::*;
+
+    #[test]
+    fn test_expression_types() {
+        let feature_set = FeatureSet {
+            formulas: vec![Formula {
+                name: "five".to_owned(),
+                formula: "5".to_owned(),
+                source_location: "".to_owned(),
+            }],
+            query: "let x = five + 1.5\nin x > 3".to_owned(),
+        };
+        let mut data_context = DataContext::default();
+        let analysis = analyze_for_editor(&mut data_context, &feature_set).unwrap();
+        assert!(analysis.diagnostics.is_empty());
+
+        // The binding `x`.
+        let (location, x_type) = analysis.type_at(FeatureSetPart::Query, 4).unwrap();
+        assert_eq!((location.start(), location.end()), (4, 5));
+        assert_eq!(x_type, &FenlType::Concrete(DataType::Float64));
+
+        // The reference to the formula `five`.
+        let (_, five_type) = analysis.type_at(FeatureSetPart::Query, 9).unwrap();
+        assert_eq!(five_type, &FenlType::Concrete(DataType::Int64));
+
+        // The body of the `let`.
+        assert_eq!(
+            analysis.type_ending_at(FeatureSetPart::Query, 27),
+            Some(&FenlType::Concrete(DataType::Boolean))
+        );
+    }
+}
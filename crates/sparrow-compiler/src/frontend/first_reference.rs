@@ -54,6 +54,19 @@ pub(super) fn first_reference<'a>(
 
             first_reference(expr.args()[bindings].inner(), needle)
         }
+        ExprOp::Def(signature, _) => {
+            // The parameters shadow the needle within the definition.
+            let shadowed = signature
+                .arg_names()
+                .iter()
+                .any(|name| name.inner() == needle);
+            let found = if shadowed {
+                None
+            } else {
+                first_reference(expr.args()[0].inner(), needle)
+            };
+            found.or_else(|| first_reference(expr.args()[1].inner(), needle))
+        }
         ExprOp::Record(_, _) => recurse(expr.args(), needle),
         ExprOp::ExtendRecord(_) | ExprOp::RemoveFields(_) | ExprOp::SelectFields(_) => {
             recurse(expr.args(), needle)
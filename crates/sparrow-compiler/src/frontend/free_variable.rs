@@ -98,6 +98,32 @@ fn analysis<'a>(
                     free
                 })
         }
+        ExprOp::Def(signature, _) => {
+            let mut free = analysis(expr.args()[1].inner(), diagnostics);
+
+            // Parameters are bound within the definition. Report an unused error
+            // for any parameter that isn't referenced. If the definition failed
+            // to resolve, the error has already been reported.
+            let definition_expr = expr.args()[0].inner();
+            let mut definition = analysis(definition_expr, diagnostics);
+            if !matches!(definition_expr.op(), ExprOp::Error) {
+                for name in signature.arg_names() {
+                    if !definition.remove(name.inner().as_str()) {
+                        DiagnosticCode::UnusedBinding
+                            .builder()
+                            .with_label(
+                                name.location()
+                                    .primary_label()
+                                    .with_message(format!("Unused parameter '{}'", name.inner())),
+                            )
+                            .emit(diagnostics);
+                    }
+                }
+            }
+
+            free.append(&mut definition);
+            free
+        }
 
         ExprOp::SelectFields(_)
         | ExprOp::RemoveFields(_)
@@ -263,4 +289,17 @@ mod tests {
              used\n\n"
         );
     }
+
+    #[test]
+    fn test_free_def_unresolved_definition() {
+        // The recursive call fails to resolve, so the parameter shouldn't also
+        // be reported as unused.
+        let input = "def f(x) = f(x) in f(Foo.x)";
+        let feature_set = feature_set_fixture(input);
+        let mut diagnostics = DiagnosticCollector::new(&feature_set);
+
+        let free = test_free_variables_with_diagnostics(input, &mut diagnostics);
+        assert_eq!(free, vec!["Foo".to_string()]);
+        assert_eq!(diagnostics.finish().len(), 0, "expected no warnings");
+    }
 }
@@ -1,11 +1,14 @@
 use std::borrow::Cow;
+use std::sync::Arc;
 
 use itertools::Itertools;
+use smallvec::smallvec;
 use sparrow_syntax::{
-    Arguments, Expr, ExprOp, ExprRef, Located, ResolveError, Resolved, ResolvedExpr,
+    Arguments, Expr, ExprOp, ExprRef, Located, ResolveError, Resolved, ResolvedExpr, Signature,
 };
 use static_init::dynamic;
 
+use crate::env::Env;
 use crate::{DiagnosticBuilder, DiagnosticCode};
 
 #[dynamic]
@@ -32,6 +35,15 @@ static SELECT_REMOVE_FIELDS_ARGUMENTS: [Located<String>; 2] = [
 #[dynamic]
 static CAST_ARGUMENTS: [Located<String>; 1] = [Located::internal_string("input")];
 
+#[dynamic]
+static DEF_ARGUMENTS: [Located<String>; 2] = [
+    Located::internal_string("definition"),
+    Located::internal_string("def_body"),
+];
+
+/// The user-defined functions in scope, by name.
+type FunctionEnv = Env<String, Arc<Signature>>;
+
 /// Recursively resolves the arguments to the given operator and
 /// all sub-expressions.
 ///
@@ -46,15 +58,46 @@ static CAST_ARGUMENTS: [Located<String>; 1] = [Located::internal_string("input")
 pub(crate) fn resolve_recursive(
     expr: &ExprRef,
     diagnostics: &mut Vec<DiagnosticBuilder>,
+) -> anyhow::Result<ResolvedExpr> {
+    resolve_in_env(expr, &mut FunctionEnv::default(), diagnostics)
+}
+
+fn resolve_in_env(
+    expr: &ExprRef,
+    functions: &mut FunctionEnv,
+    diagnostics: &mut Vec<DiagnosticBuilder>,
 ) -> anyhow::Result<ResolvedExpr> {
     // Resolve first -- this takes the reference to arguments we have and gives
     // us back an owned `Resolved<ExprRef>`.
-    match resolve_arguments(expr.op(), expr.args()) {
+    match resolve_arguments(expr.op(), expr.args(), functions) {
         Ok(args) => {
-            let args = args.try_transform(|arg| -> anyhow::Result<_> {
-                let resolved_arg = resolve_recursive(arg.inner(), diagnostics)?;
-                Ok(arg.with_value(Box::new(resolved_arg)))
-            })?;
+            let args = if let ExprOp::Def(signature, _) = expr.op() {
+                // The definition is resolved before the function is in scope,
+                // so functions may not call themselves.
+                let definition = &args[0];
+                let definition = definition.with_value(Box::new(resolve_in_env(
+                    definition.inner(),
+                    functions,
+                    diagnostics,
+                )?));
+
+                functions.enter();
+                functions.insert(signature.name().to_owned(), signature.clone());
+                let body = &args[1];
+                let body = body.with_value(Box::new(resolve_in_env(
+                    body.inner(),
+                    functions,
+                    diagnostics,
+                )?));
+                functions.exit();
+
+                args.with_values(smallvec![definition, body])
+            } else {
+                args.try_transform(|arg| -> anyhow::Result<_> {
+                    let resolved_arg = resolve_in_env(arg.inner(), functions, diagnostics)?;
+                    Ok(arg.with_value(Box::new(resolved_arg)))
+                })?
+            };
             Ok(ResolvedExpr {
                 op: expr.op().clone(),
                 args,
@@ -75,6 +118,7 @@ pub(crate) fn resolve_recursive(
 fn resolve_arguments(
     op: &ExprOp,
     arguments: &Arguments<ExprRef>,
+    functions: &FunctionEnv,
 ) -> Result<Resolved<Located<ExprRef>>, Option<DiagnosticBuilder>> {
     let (operator_location, names, defaults, vararg): (
         _,
@@ -87,36 +131,46 @@ fn resolve_arguments(
         ExprOp::FieldRef(_, location) => {
             (location, Cow::Borrowed(&*FIELD_REF_ARGUMENTS), None, false)
         }
-        ExprOp::Call(function_name) => match crate::functions::get_function(function_name) {
-            Ok(function) => {
-                let parameters = function.signature().parameters();
-                (
-                    function_name.location(),
-                    Cow::Borrowed(parameters.names()),
-                    Some(parameters.defaults()),
-                    parameters.has_vararg,
-                )
-            }
-            Err(candidates) => {
-                let diagnostic = DiagnosticCode::UndefinedFunction
-                    .builder()
-                    .with_label(
-                        function_name
-                            .location()
-                            .primary_label()
-                            .with_message(format!("No function named '{function_name}'")),
+        ExprOp::Call(function_name) => match functions.get(function_name.inner()) {
+            // User-defined functions shadow built-in functions.
+            Some(signature) => (
+                function_name.location(),
+                Cow::Owned(signature.arg_names().to_vec()),
+                None,
+                false,
+            ),
+            None => match crate::functions::get_function(function_name) {
+                Ok(function) => {
+                    let parameters = function.signature().parameters();
+                    (
+                        function_name.location(),
+                        Cow::Borrowed(parameters.names()),
+                        Some(parameters.defaults()),
+                        parameters.has_vararg,
                     )
-                    .with_note(format!(
-                        "Nearest matches: {}",
-                        candidates
-                            .iter()
-                            .format_with(", ", |e, f| f(&format_args!("'{e}'")))
-                    ));
-                return Err(Some(diagnostic));
-            }
+                }
+                Err(candidates) => {
+                    let diagnostic = DiagnosticCode::UndefinedFunction
+                        .builder()
+                        .with_label(
+                            function_name
+                                .location()
+                                .primary_label()
+                                .with_message(format!("No function named '{function_name}'")),
+                        )
+                        .with_note(format!(
+                            "Nearest matches: {}",
+                            candidates
+                                .iter()
+                                .format_with(", ", |e, f| f(&format_args!("'{e}'")))
+                        ));
+                    return Err(Some(diagnostic));
+                }
+            },
         },
         ExprOp::Pipe(location) => (location, Cow::Borrowed(&*PIPE_ARGUMENTS), None, false),
         ExprOp::Let(names, location) => (location, Cow::Owned(names.to_vec()), None, false),
+        ExprOp::Def(_, location) => (location, Cow::Borrowed(&*DEF_ARGUMENTS), None, false),
         ExprOp::Record(names, location) => (location, Cow::Owned(names.to_vec()), None, false),
         ExprOp::ExtendRecord(location) => (
             location,
@@ -1,6 +1,7 @@
 //! Information about the built-in functions for compilation.
 
 mod aggregation;
+mod argument_check;
 mod collection;
 mod comparison;
 mod function;
@@ -16,7 +17,9 @@ mod time;
 mod time_domain_check;
 mod window;
 
+pub(crate) use argument_check::ArgumentCheck;
 pub use function::*;
+pub(crate) use implementation::literal_timedelta_ns;
 use implementation::*;
 pub(crate) use pushdown::*;
 pub use registry::*;
@@ -1,5 +1,6 @@
 use anyhow::Context;
 
+use crate::functions::argument_check::ArgumentCheck;
 use crate::functions::time_domain_check::TimeDomainCheck;
 use crate::functions::{Implementation, Pushdown, Registry};
 
@@ -36,6 +37,35 @@ pub(super) fn register(registry: &mut Registry) {
         .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
         .with_time_domain_check(TimeDomainCheck::Aggregation);
 
+    registry
+        .register("count_distinct<K: key>(input: K, window: window = null) -> u32")
+        .with_dfg_signature(
+            "count_distinct<K: key>(input: K, window: window = null, duration: i64 = null) -> u32",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(count_distinct ({}) ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value",
+        )))
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
+    registry
+        .register("approx_count_distinct<K: key>(input: K, window: window = null) -> u32")
+        .with_dfg_signature(
+            "approx_count_distinct<K: key>(input: K, window: window = null, duration: i64 = \
+             null) -> u32",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(approx_count_distinct ({}) ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value",
+        )))
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
     registry
         .register("sum<N: number>(input: N, window: window = null) -> N ")
         .with_dfg_signature(
@@ -79,9 +109,9 @@ pub(super) fn register(registry: &mut Registry) {
         .with_time_domain_check(TimeDomainCheck::Aggregation);
 
     registry
-        .register("mean<N: number>(input: N, window: window = null) -> f64")
+        .register("mean<F: fractional>(input: F, window: window = null) -> F")
         .with_dfg_signature(
-            "mean<N: number>(input: N, window: window = null, duration: i64 = null) -> f64",
+            "mean<F: fractional>(input: F, window: window = null, duration: i64 = null) -> F",
         )
         .with_implementation(Implementation::new_pattern(&format!(
             "(mean ({}) ({}) ({}))",
@@ -119,6 +149,125 @@ pub(super) fn register(registry: &mut Registry) {
         )))
         .with_time_domain_check(TimeDomainCheck::Aggregation);
 
+    registry
+        .register(
+            "ewma<D: timedelta>(input: f64, const half_life: D, window: window = null) -> f64",
+        )
+        .with_dfg_signature(
+            "ewma<D: timedelta>(input: f64, const half_life: D, window: window = null, \
+             duration: i64 = null) -> f64",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(ewma ({}) ?half_life_value ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value"
+        )))
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_argument_check("half_life", ArgumentCheck::PositiveDuration)
+        // Exponentially-weighted aggregations already discount older inputs, so
+        // they only support resetting with `since` windows.
+        .with_argument_check("window", ArgumentCheck::SupportedWindows(&["since"]))
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
+    registry
+        .register(
+            "ewm_variance<D: timedelta>(input: f64, const half_life: D, window: window = null) -> f64",
+        )
+        .with_dfg_signature(
+            "ewm_variance<D: timedelta>(input: f64, const half_life: D, window: window = null, \
+             duration: i64 = null) -> f64",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(ewm_variance ({}) ?half_life_value ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value"
+        )))
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_argument_check("half_life", ArgumentCheck::PositiveDuration)
+        // Exponentially-weighted aggregations already discount older inputs, so
+        // they only support resetting with `since` windows.
+        .with_argument_check("window", ArgumentCheck::SupportedWindows(&["since"]))
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
+    registry
+        .register("percentile(input: f64, const p: f64, window: window = null) -> f64")
+        .with_dfg_signature(
+            "percentile(input: f64, p: f64, window: window = null, duration: i64 = null) -> f64",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(percentile ({}) ?p_value ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value"
+        )))
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_argument_check("p", ArgumentCheck::Percentile)
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
+    registry
+        .register("median(input: f64, window: window = null) -> f64")
+        .with_dfg_signature(
+            "median(input: f64, window: window = null, duration: i64 = null) -> f64",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(percentile ({}) 50.0f64 ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value"
+        )))
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
+    registry
+        .register(
+            "collect<T: any>(input: T, const max: i64 = null, window: window = null) -> list<T>",
+        )
+        .with_dfg_signature(
+            "collect<T: any>(input: T, max: i64 = null, window: window = null, duration: i64 = \
+             null) -> list<T>",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(collect ({}) ?max_value ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value"
+        )))
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_argument_check("max", ArgumentCheck::Positive)
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
+    registry
+        .register("top_k<K: key>(input: K, const k: i64, window: window = null) -> list<K>")
+        .with_dfg_signature(
+            "top_k<K: key>(input: K, k: i64, window: window = null, duration: i64 = null) -> \
+             list<K>",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(top_k ({}) ?k_value ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value"
+        )))
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_argument_check("k", ArgumentCheck::Positive)
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
+    registry
+        .register("mode<K: key>(input: K, window: window = null) -> K")
+        .with_dfg_signature(
+            "mode<K: key>(input: K, window: window = null, duration: i64 = null) -> K",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(mode ({}) ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value"
+        )))
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
     registry
         .register("last<T: any>(input: T, window: window = null) -> T")
         .with_dfg_signature(
@@ -0,0 +1,114 @@
+use sparrow_arrow::scalar_value::ScalarValue;
+use sparrow_syntax::Located;
+
+use crate::dfg::Dfg;
+use crate::functions::literal_timedelta_ns;
+use crate::AstDfgRef;
+
+/// Enumerate the ways that literal arguments may be validated for a specific
+/// function.
+///
+/// These checks are applied during compilation so that invalid arguments are
+/// reported as diagnostics, rather than causing failures during execution.
+/// Arguments which are not literals are not checked.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum ArgumentCheck {
+    /// The argument must be a valid time zone.
+    TimeZone,
+    /// The argument must be a percentile between 0 and 100.
+    Percentile,
+    /// The argument must be a valid regular expression.
+    Regex,
+    /// The argument must be a positive integer.
+    Positive,
+    /// The argument must be a positive duration.
+    ///
+    /// The duration is passed to the instruction in nanoseconds.
+    PositiveDuration,
+    /// The window argument of an aggregation must be one of the named windows.
+    ///
+    /// Windows aren't literals, so this is checked when the window is
+    /// flattened rather than by [ArgumentCheck::check].
+    SupportedWindows(&'static [&'static str]),
+}
+
+impl ArgumentCheck {
+    /// Check the argument named `argument_name` to `function_name`.
+    ///
+    /// Returns a message describing the problem if the argument is invalid.
+    pub(super) fn check(
+        &self,
+        function_name: &str,
+        argument_name: &str,
+        dfg: &Dfg,
+        argument: &Located<AstDfgRef>,
+    ) -> Option<String> {
+        let literal = dfg.literal(argument.value())?;
+        match self {
+            ArgumentCheck::TimeZone => match literal {
+                ScalarValue::Utf8(Some(time_zone)) => {
+                    sparrow_kernels::time::parse_time_zone(time_zone)
+                        .err()
+                        .map(|e| format!("{e}"))
+                }
+                _ => None,
+            },
+            ArgumentCheck::Percentile => {
+                // Report numbers as written, rather than with the type suffix
+                // of the literal.
+                let value = match percentile_value(literal) {
+                    Some(p) if (0.0..=100.0).contains(&p) => return None,
+                    Some(p) => p.to_string(),
+                    None => literal.to_string(),
+                };
+                Some(format!(
+                    "Argument '{argument_name}' to '{function_name}' must be between 0 and 100, \
+                     but was {value}"
+                ))
+            }
+            ArgumentCheck::Regex => match literal {
+                ScalarValue::Utf8(Some(pattern)) => regex::Regex::new(pattern).err().map(|e| {
+                    // Syntax errors span multiple lines pointing into the pattern,
+                    // with the problem described on the last line.
+                    let e = e.to_string();
+                    let problem = e.lines().last().unwrap_or_default();
+                    let problem = problem.strip_prefix("error: ").unwrap_or(problem);
+                    format!("Invalid regex pattern: {problem}")
+                }),
+                _ => None,
+            },
+            ArgumentCheck::Positive => match literal {
+                ScalarValue::Int64(Some(value)) if *value <= 0 => Some(format!(
+                    "Argument '{argument_name}' to '{function_name}' must be positive, but was \
+                     {value}"
+                )),
+                _ => None,
+            },
+            ArgumentCheck::PositiveDuration => match literal_timedelta_ns(dfg, argument) {
+                Some(nanos) if nanos > 0 => None,
+                _ => Some(format!(
+                    "Argument '{argument_name}' to '{function_name}' must be a positive duration"
+                )),
+            },
+            ArgumentCheck::SupportedWindows(_) => None,
+        }
+    }
+}
+
+/// Returns the numeric value of a literal percentile, before it is cast to
+/// `f64`.
+fn percentile_value(value: &ScalarValue) -> Option<f64> {
+    match value {
+        ScalarValue::Int8(Some(p)) => Some(*p as f64),
+        ScalarValue::Int16(Some(p)) => Some(*p as f64),
+        ScalarValue::Int32(Some(p)) => Some(*p as f64),
+        ScalarValue::Int64(Some(p)) => Some(*p as f64),
+        ScalarValue::UInt8(Some(p)) => Some(*p as f64),
+        ScalarValue::UInt16(Some(p)) => Some(*p as f64),
+        ScalarValue::UInt32(Some(p)) => Some(*p as f64),
+        ScalarValue::UInt64(Some(p)) => Some(*p as f64),
+        ScalarValue::Float32(Some(p)) => Some(p.into_inner() as f64),
+        ScalarValue::Float64(Some(p)) => Some(p.into_inner()),
+        _ => None,
+    }
+}
@@ -5,6 +5,37 @@ use crate::functions::{Implementation, Registry};
 pub(super) fn register(registry: &mut Registry) {
     registry
         .register("get<K: key, V: any>(key: K, map: map<K, V>) -> V")
-        .with_implementation(Implementation::Instruction(InstOp::Get))
-        .set_internal();
+        .with_implementation(Implementation::Instruction(InstOp::Get));
+
+    registry
+        .register("contains_key<K: key, V: any>(key: K, map: map<K, V>) -> bool")
+        .with_implementation(Implementation::Instruction(InstOp::ContainsKey));
+
+    registry
+        .register("keys<K: key, V: any>(map: map<K, V>) -> list<K>")
+        .with_implementation(Implementation::Instruction(InstOp::Keys));
+
+    registry
+        .register("values<K: key, V: any>(map: map<K, V>) -> list<V>")
+        .with_implementation(Implementation::Instruction(InstOp::Values));
+
+    registry
+        .register("index<T: any>(i: i64, list: list<T>) -> T")
+        .with_implementation(Implementation::Instruction(InstOp::Index));
+
+    registry
+        .register("list_len<T: any>(input: list<T>) -> i32")
+        .with_implementation(Implementation::Instruction(InstOp::ListLen));
+
+    registry
+        .register("flatten<L: list>(input: list<L>) -> L")
+        .with_implementation(Implementation::Instruction(InstOp::Flatten));
+
+    registry
+        .register("list_contains<T: key>(value: T, list: list<T>) -> bool")
+        .with_implementation(Implementation::Instruction(InstOp::ListContains));
+
+    registry
+        .register("list_sum<N: number>(input: list<N>) -> N")
+        .with_implementation(Implementation::Instruction(InstOp::ListSum));
 }
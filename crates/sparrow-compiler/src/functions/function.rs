@@ -3,13 +3,14 @@ use std::str::FromStr;
 
 use egg::{Subst, Var};
 use itertools::{izip, Itertools};
-use sparrow_api::kaskada::v1alpha::operation_plan::tick_operation::TickBehavior;
 use sparrow_plan::GroupId;
 use sparrow_syntax::{FeatureSetPart, FenlType, Located, Location, Signature};
 
 use crate::ast_to_dfg::AstDfg;
-use crate::dfg::Dfg;
-use crate::functions::implementation::Implementation;
+use crate::dfg::{Dfg, TickKind};
+use crate::diagnostics::DiagnosticCode;
+use crate::functions::argument_check::ArgumentCheck;
+use crate::functions::implementation::{tick_kind, Implementation};
 use crate::functions::time_domain_check::TimeDomainCheck;
 use crate::{AstDfgRef, DataContext, DiagnosticCollector};
 
@@ -27,6 +28,8 @@ pub struct Function {
     is_new: Implementation,
     /// Configure how time domains are checked for the function.
     time_domain_check: TimeDomainCheck,
+    /// Checks to apply to named literal arguments.
+    argument_checks: Vec<(&'static str, ArgumentCheck)>,
     /// Whether the function is internal only.
     internal: bool,
 }
@@ -58,6 +61,11 @@ impl<'building> FunctionBuilder<'building> {
         self
     }
 
+    pub(crate) fn with_argument_check(self, argument_name: &'static str, check: ArgumentCheck) -> Self {
+        self.0.argument_checks.push((argument_name, check));
+        self
+    }
+
     pub fn with_dfg_signature(self, signature_str: &'static str) -> Self {
         let signature =
             Signature::try_from_str(FeatureSetPart::Function(signature_str), signature_str)
@@ -77,6 +85,7 @@ impl Function {
             implementation: Implementation::Special,
             is_new: Implementation::AnyInputIsNew,
             time_domain_check: TimeDomainCheck::default(),
+            argument_checks: Vec::new(),
             internal: false,
         }
     }
@@ -90,11 +99,20 @@ impl Function {
                 | "max"
                 | "first"
                 | "last"
+                | "collect"
                 | "count"
                 | "count_if"
+                | "count_distinct"
+                | "approx_count_distinct"
                 | "mean"
+                | "percentile"
+                | "median"
+                | "mode"
+                | "top_k"
                 | "variance"
                 | "stddev"
+                | "ewma"
+                | "ewm_variance"
         )
     }
 
@@ -102,11 +120,22 @@ impl Function {
         matches!(self.implementation, Implementation::Tick(_))
     }
 
-    /// Returns the tick behavior if this function is a tick.
-    pub fn tick_behavior(&self) -> Option<TickBehavior> {
+    /// Returns the tick kind if this function is a tick.
+    ///
+    /// Parameterized ticks (such as `every`) are determined from the literal
+    /// values of the `args`. If the arguments are invalid, a diagnostic is
+    /// reported and this returns `Ok(None)`.
+    pub(crate) fn tick_kind(
+        &self,
+        dfg: &Dfg,
+        diagnostics: &mut DiagnosticCollector<'_>,
+        args: &[Located<AstDfgRef>],
+    ) -> anyhow::Result<Option<TickKind>> {
         match self.implementation {
-            Implementation::Tick(b) => Some(b),
-            _ => None,
+            Implementation::Tick(behavior) => {
+                tick_kind(self.name(), behavior, dfg, diagnostics, args)
+            }
+            _ => Err(anyhow::anyhow!("Function '{}' is not a tick", self.name())),
         }
     }
 
@@ -144,6 +173,46 @@ impl Function {
         }
     }
 
+    /// Returns the check for the argument named `argument_name`, if any.
+    pub(crate) fn argument_check(&self, argument_name: &str) -> Option<ArgumentCheck> {
+        self.argument_checks
+            .iter()
+            .find(|(name, _)| *name == argument_name)
+            .map(|(_, check)| *check)
+    }
+
+    /// Check the literal arguments to this function.
+    ///
+    /// The `args` correspond to the parameters of the (internal) signature.
+    /// Reports a diagnostic for each invalid argument, and returns `false` if
+    /// any argument was invalid.
+    pub(crate) fn check_arguments(
+        &self,
+        dfg: &Dfg,
+        diagnostics: &mut DiagnosticCollector<'_>,
+        args: &[Located<AstDfgRef>],
+    ) -> bool {
+        let mut valid = true;
+        for (argument_name, check) in &self.argument_checks {
+            let Some(index) = self
+                .arg_names()
+                .position(|name| name.inner() == argument_name)
+            else {
+                continue;
+            };
+
+            if let Some(error) = check.check(self.name(), argument_name, dfg, &args[index]) {
+                valid = false;
+
+                DiagnosticCode::InvalidArguments
+                    .builder()
+                    .with_label(args[index].location().primary_label().with_message(error))
+                    .emit(diagnostics)
+            }
+        }
+        valid
+    }
+
     /// Create a substitution from the arguments to this function.
     ///
     /// Each parameter name `name` creates two entries -- `?name_is_new` and
@@ -1,16 +1,20 @@
 use std::str::FromStr;
 
 use anyhow::{anyhow, Context};
+use arrow::datatypes::TimeUnit;
 use egg::{Id, Var};
 use itertools::izip;
 use once_cell::sync::OnceCell;
 use smallvec::smallvec;
 use sparrow_api::kaskada::v1alpha::operation_plan::tick_operation::TickBehavior;
+use sparrow_arrow::scalar_value::ScalarValue;
+use sparrow_kernels::time::{parse_time_zone, CronSchedule};
 use sparrow_plan::InstOp;
 use sparrow_syntax::{Expr, FeatureSetPart, FenlType, Located, ResolvedExpr, WindowBehavior};
 
 use crate::ast_to_dfg::ast_to_dfg;
-use crate::dfg::{Dfg, DfgPattern, Operation, StepKind};
+use crate::dfg::{Dfg, DfgPattern, Operation, StepKind, TickKind};
+use crate::diagnostics::DiagnosticCode;
 use crate::frontend::resolve_arguments::resolve_recursive;
 use crate::functions::{Function, Pushdown};
 use crate::{is_any_new, AstDfgRef, DataContext, DiagnosticCollector};
@@ -87,8 +91,11 @@ impl Implementation {
             Implementation::Instruction(inst) => {
                 Ok(dfg.add_instruction(*inst, args.iter().map(|i| i.value()).collect())?)
             }
-            Implementation::Tick(tick) => {
-                Ok(dfg.add_operation(Operation::Tick(*tick), smallvec![])?)
+            Implementation::Tick(behavior) => {
+                match tick_kind(function.name(), *behavior, dfg, diagnostics, args)? {
+                    Some(kind) => Ok(dfg.add_operation(Operation::Tick(kind), smallvec![])?),
+                    None => Ok(dfg.error_node().value()),
+                }
             }
             Implementation::Window(window) => Ok(dfg.add_node(
                 StepKind::Window(*window),
@@ -175,3 +182,228 @@ impl Implementation {
         }
     }
 }
+
+/// Nanoseconds in a day, used for converting `interval_days`.
+const NANOS_PER_DAY: i64 = 86_400 * 1_000_000_000;
+
+/// Determine the tick kind for a tick function with the given behavior.
+///
+/// For `TickBehavior::Every`, the `period` and `offset` arguments must be
+/// literal time deltas. If they are not, a diagnostic is reported and this
+/// returns `Ok(None)`.
+pub(super) fn tick_kind(
+    function_name: &str,
+    behavior: TickBehavior,
+    dfg: &Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    args: &[Located<AstDfgRef>],
+) -> anyhow::Result<Option<TickKind>> {
+    match behavior {
+        TickBehavior::Every => every_tick_kind(function_name, dfg, diagnostics, args),
+        TickBehavior::Cron => cron_tick_kind(function_name, dfg, diagnostics, args),
+        TickBehavior::Daily | TickBehavior::Monthly | TickBehavior::Yearly => {
+            calendar_tick_kind(function_name, behavior, dfg, diagnostics, args)
+        }
+        behavior => Ok(Some(TickKind::Behavior(behavior))),
+    }
+}
+
+fn calendar_tick_kind(
+    function_name: &str,
+    behavior: TickBehavior,
+    dfg: &Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    args: &[Located<AstDfgRef>],
+) -> anyhow::Result<Option<TickKind>> {
+    anyhow::ensure!(
+        args.len() == 1,
+        "Expected 1 argument to '{function_name}', but got {}",
+        args.len()
+    );
+    let time_zone_arg = &args[0];
+
+    match dfg.literal(time_zone_arg.value()) {
+        Some(ScalarValue::Null | ScalarValue::Utf8(None)) => Ok(Some(TickKind::Behavior(behavior))),
+        Some(ScalarValue::Utf8(Some(time_zone))) => {
+            if let Err(e) = parse_time_zone(time_zone) {
+                DiagnosticCode::InvalidArguments
+                    .builder()
+                    .with_label(
+                        time_zone_arg
+                            .location()
+                            .primary_label()
+                            .with_message(format!("{e}")),
+                    )
+                    .emit(diagnostics);
+                return Ok(None);
+            }
+
+            Ok(Some(TickKind::Zoned {
+                behavior,
+                time_zone: time_zone.clone(),
+            }))
+        }
+        _ => {
+            DiagnosticCode::InvalidNonConstArgument
+                .builder()
+                .with_label(
+                    time_zone_arg
+                        .location()
+                        .primary_label()
+                        .with_message(format!(
+                            "Argument 'time_zone' to '{function_name}' must be a constant string"
+                        )),
+                )
+                .emit(diagnostics);
+            Ok(None)
+        }
+    }
+}
+
+fn every_tick_kind(
+    function_name: &str,
+    dfg: &Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    args: &[Located<AstDfgRef>],
+) -> anyhow::Result<Option<TickKind>> {
+    anyhow::ensure!(
+        args.len() == 2,
+        "Expected 2 arguments to '{function_name}', but got {}",
+        args.len()
+    );
+    let period = &args[0];
+    let offset = &args[1];
+
+    let Some(period_ns) = literal_timedelta_ns(dfg, period) else {
+        DiagnosticCode::InvalidNonConstArgument
+            .builder()
+            .with_label(period.location().primary_label().with_message(format!(
+                "Argument 'period' to '{function_name}' must be a constant duration in seconds or days"
+            )))
+            .emit(diagnostics);
+        return Ok(None);
+    };
+    let offset_ns = if dfg.literal(offset.value()).map_or(false, |v| v.is_null()) {
+        0
+    } else if let Some(offset_ns) = literal_timedelta_ns(dfg, offset) {
+        offset_ns
+    } else {
+        DiagnosticCode::InvalidNonConstArgument
+            .builder()
+            .with_label(offset.location().primary_label().with_message(format!(
+                "Argument 'offset' to '{function_name}' must be a constant duration in seconds or days"
+            )))
+            .emit(diagnostics);
+        return Ok(None);
+    };
+
+    if period_ns <= 0 {
+        DiagnosticCode::InvalidArguments
+            .builder()
+            .with_label(
+                period
+                    .location()
+                    .primary_label()
+                    .with_message("Tick period must be positive"),
+            )
+            .emit(diagnostics);
+        return Ok(None);
+    }
+
+    Ok(Some(TickKind::Every {
+        period_ns,
+        // Normalize the offset so that equivalent ticks compare equal.
+        offset_ns: offset_ns.rem_euclid(period_ns),
+    }))
+}
+
+/// Determine the tick kind for a `cron` tick.
+///
+/// The `schedule` and `time_zone` arguments must be literal strings. The
+/// schedule is validated so errors are reported during compilation.
+fn cron_tick_kind(
+    function_name: &str,
+    dfg: &Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    args: &[Located<AstDfgRef>],
+) -> anyhow::Result<Option<TickKind>> {
+    anyhow::ensure!(
+        args.len() == 2,
+        "Expected 2 arguments to '{function_name}', but got {}",
+        args.len()
+    );
+    let schedule_arg = &args[0];
+    let time_zone_arg = &args[1];
+
+    let Some(ScalarValue::Utf8(Some(schedule))) = dfg.literal(schedule_arg.value()) else {
+        DiagnosticCode::InvalidNonConstArgument
+            .builder()
+            .with_label(
+                schedule_arg
+                    .location()
+                    .primary_label()
+                    .with_message(format!(
+                        "Argument 'schedule' to '{function_name}' must be a constant string"
+                    )),
+            )
+            .emit(diagnostics);
+        return Ok(None);
+    };
+    let time_zone = match dfg.literal(time_zone_arg.value()) {
+        Some(ScalarValue::Null | ScalarValue::Utf8(None)) => None,
+        Some(ScalarValue::Utf8(Some(time_zone))) => Some(time_zone.clone()),
+        _ => {
+            DiagnosticCode::InvalidNonConstArgument
+                .builder()
+                .with_label(
+                    time_zone_arg
+                        .location()
+                        .primary_label()
+                        .with_message(format!(
+                            "Argument 'time_zone' to '{function_name}' must be a constant string"
+                        )),
+                )
+                .emit(diagnostics);
+            return Ok(None);
+        }
+    };
+
+    // Validate the schedule and time zone, so that errors are reported
+    // during compilation rather than execution.
+    if let Err(e) = CronSchedule::try_new(schedule, time_zone.as_deref()) {
+        DiagnosticCode::InvalidArguments
+            .builder()
+            .with_label(
+                schedule_arg
+                    .location()
+                    .primary_label()
+                    .with_message(format!("{e}")),
+            )
+            .emit(diagnostics);
+        return Ok(None);
+    }
+
+    Ok(Some(TickKind::Cron {
+        schedule: schedule.clone(),
+        time_zone,
+    }))
+}
+
+/// Return the nanoseconds of a literal `duration` or `interval_days`.
+pub(crate) fn literal_timedelta_ns(dfg: &Dfg, arg: &Located<AstDfgRef>) -> Option<i64> {
+    match dfg.literal(arg.value())? {
+        ScalarValue::Duration(Some(value), unit) => {
+            let nanos_per_unit = match unit {
+                TimeUnit::Second => 1_000_000_000,
+                TimeUnit::Millisecond => 1_000_000,
+                TimeUnit::Microsecond => 1_000,
+                TimeUnit::Nanosecond => 1,
+            };
+            value.checked_mul(nanos_per_unit)
+        }
+        ScalarValue::IntervalDayTime(Some((days, millis))) => (*days as i64)
+            .checked_mul(NANOS_PER_DAY)?
+            .checked_add((*millis as i64).checked_mul(1_000_000)?),
+        _ => None,
+    }
+}
@@ -6,6 +6,9 @@ use crate::functions::Registry;
 pub(super) fn register(registry: &mut Registry) {
     registry
         .register("json(s: string) -> json")
-        .with_implementation(Implementation::Instruction(InstOp::Json))
-        .set_internal();
+        .with_implementation(Implementation::Instruction(InstOp::Json));
+
+    registry
+        .register("parse_json<T: any>(s: string, schema: T) -> T")
+        .with_implementation(Implementation::Instruction(InstOp::ParseJson));
 }
@@ -1,5 +1,6 @@
 use sparrow_plan::InstOp;
 
+use crate::functions::argument_check::ArgumentCheck;
 use crate::functions::{Implementation, Registry};
 
 pub(super) fn register(registry: &mut Registry) {
@@ -18,4 +19,47 @@ pub(super) fn register(registry: &mut Registry) {
     registry
         .register("substring(s: string, start: i64 = null, end: i64 = null) -> string")
         .with_implementation(Implementation::Instruction(InstOp::Substring));
+
+    registry
+        .register("contains(substring: string, s: string) -> bool")
+        .with_implementation(Implementation::Instruction(InstOp::Contains));
+
+    registry
+        .register("starts_with(prefix: string, s: string) -> bool")
+        .with_implementation(Implementation::Instruction(InstOp::StartsWith));
+
+    registry
+        .register("ends_with(suffix: string, s: string) -> bool")
+        .with_implementation(Implementation::Instruction(InstOp::EndsWith));
+
+    registry
+        .register("regex_match(const pattern: string, s: string) -> bool")
+        .with_implementation(Implementation::Instruction(InstOp::RegexMatch))
+        .with_argument_check("pattern", ArgumentCheck::Regex);
+
+    registry
+        .register("regex_extract(const pattern: string, s: string, group: i64 = 0) -> string")
+        .with_implementation(Implementation::Instruction(InstOp::RegexExtract))
+        .with_argument_check("pattern", ArgumentCheck::Regex);
+
+    registry
+        .register("regex_replace(const pattern: string, replacement: string, s: string) -> string")
+        .with_implementation(Implementation::Instruction(InstOp::RegexReplace))
+        .with_argument_check("pattern", ArgumentCheck::Regex);
+
+    registry
+        .register("split(separator: string, s: string) -> list<string>")
+        .with_implementation(Implementation::Instruction(InstOp::Split));
+
+    registry
+        .register("concat(strings+: string) -> string")
+        .with_implementation(Implementation::Instruction(InstOp::Concat));
+
+    registry
+        .register("trim(s: string) -> string")
+        .with_implementation(Implementation::Instruction(InstOp::Trim));
+
+    registry
+        .register("pad(length: i64, s: string, fill: string = \" \") -> string")
+        .with_implementation(Implementation::Instruction(InstOp::Pad));
 }
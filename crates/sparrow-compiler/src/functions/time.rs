@@ -1,5 +1,6 @@
 use sparrow_plan::InstOp;
 
+use crate::functions::argument_check::ArgumentCheck;
 use crate::functions::time_domain_check::TimeDomainCheck;
 use crate::functions::{Implementation, Registry};
 
@@ -67,36 +68,43 @@ pub(super) fn register(registry: &mut Registry) {
         .with_implementation(Implementation::Instruction(InstOp::Days));
 
     registry
-        .register("day_of_month(time: timestamp_ns) -> u32")
-        .with_implementation(Implementation::Instruction(InstOp::DayOfMonth));
+        .register("day_of_month(time: timestamp_ns, const time_zone: string = null) -> u32")
+        .with_implementation(Implementation::Instruction(InstOp::DayOfMonth))
+        .with_argument_check("time_zone", ArgumentCheck::TimeZone);
 
     registry
-        .register("day_of_month0(time: timestamp_ns) -> u32")
-        .with_implementation(Implementation::Instruction(InstOp::DayOfMonth0));
+        .register("day_of_month0(time: timestamp_ns, const time_zone: string = null) -> u32")
+        .with_implementation(Implementation::Instruction(InstOp::DayOfMonth0))
+        .with_argument_check("time_zone", ArgumentCheck::TimeZone);
 
     registry
-        .register("day_of_year(time: timestamp_ns) -> u32")
-        .with_implementation(Implementation::Instruction(InstOp::DayOfYear));
+        .register("day_of_year(time: timestamp_ns, const time_zone: string = null) -> u32")
+        .with_implementation(Implementation::Instruction(InstOp::DayOfYear))
+        .with_argument_check("time_zone", ArgumentCheck::TimeZone);
 
     registry
-        .register("day_of_year0(time: timestamp_ns) -> u32")
-        .with_implementation(Implementation::Instruction(InstOp::DayOfYear0));
+        .register("day_of_year0(time: timestamp_ns, const time_zone: string = null) -> u32")
+        .with_implementation(Implementation::Instruction(InstOp::DayOfYear0))
+        .with_argument_check("time_zone", ArgumentCheck::TimeZone);
 
     registry
         .register("months(months: i64) -> interval_months")
         .with_implementation(Implementation::Instruction(InstOp::Months));
 
     registry
-        .register("month_of_year(time: timestamp_ns) -> u32")
-        .with_implementation(Implementation::Instruction(InstOp::MonthOfYear));
+        .register("month_of_year(time: timestamp_ns, const time_zone: string = null) -> u32")
+        .with_implementation(Implementation::Instruction(InstOp::MonthOfYear))
+        .with_argument_check("time_zone", ArgumentCheck::TimeZone);
 
     registry
-        .register("month_of_year0(time: timestamp_ns) -> u32")
-        .with_implementation(Implementation::Instruction(InstOp::MonthOfYear0));
+        .register("month_of_year0(time: timestamp_ns, const time_zone: string = null) -> u32")
+        .with_implementation(Implementation::Instruction(InstOp::MonthOfYear0))
+        .with_argument_check("time_zone", ArgumentCheck::TimeZone);
 
     registry
-        .register("year(time: timestamp_ns) -> i32")
-        .with_implementation(Implementation::Instruction(InstOp::Year));
+        .register("year(time: timestamp_ns, const time_zone: string = null) -> i32")
+        .with_implementation(Implementation::Instruction(InstOp::Year))
+        .with_argument_check("time_zone", ArgumentCheck::TimeZone);
 
     registry
         .register("seconds(seconds: i64) -> duration_s")
@@ -15,6 +15,10 @@ pub(super) fn register(registry: &mut Registry) {
         .with_implementation(Implementation::Window(WindowBehavior::Sliding))
         .with_is_new(Implementation::new_pattern("?condition_value"));
 
+    registry
+        .register("trailing<D: timedelta>(const duration: D) -> window")
+        .with_implementation(Implementation::Window(WindowBehavior::Trailing));
+
     registry
         .register("minutely() -> bool")
         .with_implementation(Implementation::Tick(TickBehavior::Minutely))
@@ -28,23 +32,35 @@ pub(super) fn register(registry: &mut Registry) {
         .with_time_domain_check(TimeDomainCheck::Compatible);
 
     registry
-        .register("daily() -> bool")
+        .register("daily(const time_zone: string = null) -> bool")
         .with_implementation(Implementation::Tick(TickBehavior::Daily))
         .with_is_new(Implementation::Tick(TickBehavior::Daily))
         .with_time_domain_check(TimeDomainCheck::Compatible);
 
     registry
-        .register("monthly() -> bool")
+        .register("monthly(const time_zone: string = null) -> bool")
         .with_implementation(Implementation::Tick(TickBehavior::Monthly))
         .with_is_new(Implementation::Tick(TickBehavior::Monthly))
         .with_time_domain_check(TimeDomainCheck::Compatible);
 
     registry
-        .register("yearly() -> bool")
+        .register("yearly(const time_zone: string = null) -> bool")
         .with_implementation(Implementation::Tick(TickBehavior::Yearly))
         .with_is_new(Implementation::Tick(TickBehavior::Yearly))
         .with_time_domain_check(TimeDomainCheck::Compatible);
 
+    registry
+        .register("every<D: timedelta>(const period: D, const offset: D = null) -> bool")
+        .with_implementation(Implementation::Tick(TickBehavior::Every))
+        .with_is_new(Implementation::Tick(TickBehavior::Every))
+        .with_time_domain_check(TimeDomainCheck::Compatible);
+
+    registry
+        .register("cron(const schedule: string, const time_zone: string = null) -> bool")
+        .with_implementation(Implementation::Tick(TickBehavior::Cron))
+        .with_is_new(Implementation::Tick(TickBehavior::Cron))
+        .with_time_domain_check(TimeDomainCheck::Compatible);
+
     registry
         .register("finished() -> bool")
         .with_implementation(Implementation::Tick(TickBehavior::Finished))
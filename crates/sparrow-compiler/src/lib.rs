@@ -53,4 +53,5 @@ pub use diagnostics::*;
 pub use error::*;
 pub use frontend::*;
 pub use functions::*;
+pub use nearest_matches::nearest_matches;
 pub use options::*;
@@ -3,6 +3,7 @@ use operation_input_ref::Interpolation;
 use operation_plan::LookupResponseOperation;
 use sparrow_api::kaskada::v1alpha::data_type::{self, PrimitiveType};
 use sparrow_api::kaskada::v1alpha::operation_input_ref::{self, Column};
+use sparrow_api::kaskada::v1alpha::operation_plan::tick_operation::{self, TickBehavior};
 use sparrow_api::kaskada::v1alpha::operation_plan::{
     shift_to_operation, LookupRequestOperation, ScanOperation, SelectOperation, ShiftToOperation,
     ShiftUntilOperation, TickOperation, WithKeyOperation,
@@ -14,7 +15,7 @@ use sparrow_api::kaskada::v1alpha::{DataType, Schema};
 use sparrow_arrow::scalar_value::ScalarValue;
 
 use super::transform_to_plan::TransformToPlan;
-use crate::dfg::{DfgExpr, Expression, Operation, StepKind};
+use crate::dfg::{DfgExpr, Expression, Operation, StepKind, TickKind};
 use crate::plan::plan_builder::PlanBuilder;
 use crate::DataContext;
 
@@ -286,12 +287,50 @@ pub(super) fn dfg_to_plan<'plan>(
                 }),
             )
         }
-        Operation::Tick(behavior) => {
+        Operation::Tick(kind) => {
             let result_type = DataType::new_primitive(PrimitiveType::Bool);
 
-            let behavior = *behavior as i32;
+            let mut time_zone = String::new();
+            let (behavior, every, cron) = match kind {
+                TickKind::Behavior(behavior) => (*behavior, None, None),
+                TickKind::Zoned {
+                    behavior,
+                    time_zone: zone,
+                } => {
+                    time_zone = zone.clone();
+                    (*behavior, None, None)
+                }
+                TickKind::Every {
+                    period_ns,
+                    offset_ns,
+                } => (
+                    TickBehavior::Every,
+                    Some(tick_operation::Every {
+                        period_ns: *period_ns,
+                        offset_ns: *offset_ns,
+                    }),
+                    None,
+                ),
+                TickKind::Cron {
+                    schedule,
+                    time_zone,
+                } => (
+                    TickBehavior::Cron,
+                    None,
+                    Some(tick_operation::Cron {
+                        schedule: schedule.clone(),
+                        time_zone: time_zone.clone().unwrap_or_default(),
+                    }),
+                ),
+            };
             let input = plan_builder.schedule.operation(children[0])?;
-            let operator = operation_plan::Operator::Tick(TickOperation { behavior, input });
+            let operator = operation_plan::Operator::Tick(TickOperation {
+                behavior: behavior as i32,
+                input,
+                every,
+                cron,
+                time_zone,
+            });
 
             let operation = plan_builder.add_operation(id, operation_index, operator)?;
 
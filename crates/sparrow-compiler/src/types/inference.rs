@@ -2,7 +2,7 @@
 
 use std::{cmp::Ordering, sync::Arc};
 
-use arrow::datatypes::{DataType, Field, Fields, TimeUnit};
+use arrow::datatypes::{DataType, Field, Fields, TimeUnit, DECIMAL128_MAX_PRECISION};
 use hashbrown::hash_map::Entry;
 use hashbrown::HashMap;
 use itertools::{izip, Itertools};
@@ -108,7 +108,7 @@ pub(crate) fn instantiate(
     let instantiated_return = if instantiated_arguments.iter().any(|t| t.is_error()) {
         FenlType::Error
     } else {
-        instantiate_type(signature.result(), &solutions)
+        widen_decimal_result(signature, instantiate_type(signature.result(), &solutions))
     };
 
     Ok((instantiated_arguments, instantiated_return))
@@ -210,8 +210,30 @@ pub fn validate_instantiation(
                     }
                 }
             }
-            FenlType::Collection(Collection::List, _) => {
-                todo!("list unsupported")
+            FenlType::Collection(Collection::List, type_vars) => {
+                debug_assert!(type_vars.len() == 1);
+                let item_type = match argument_type {
+                    FenlType::Concrete(DataType::List(f)) => {
+                        FenlType::Concrete(f.data_type().clone())
+                    }
+                    other => anyhow::bail!("expected list, saw {:?}", other),
+                };
+
+                match types_for_variable.entry(type_vars[0].clone()) {
+                    Entry::Occupied(occupied) => {
+                        anyhow::ensure!(
+                            occupied.get() == &item_type
+                                || matches!(occupied.get(), FenlType::Error)
+                                || matches!(item_type, FenlType::Error),
+                            "Failed type validation: expected {} but was {}",
+                            occupied.get(),
+                            item_type
+                        );
+                    }
+                    Entry::Vacant(vacant) => {
+                        vacant.insert(item_type);
+                    }
+                }
             }
             FenlType::Error => {
                 // Assume the argument matches (since we already reported what
@@ -244,7 +266,53 @@ pub fn validate_instantiation(
     }
 
     let instantiated_return = instantiate_type(signature.result(), &types_for_variable);
-    Ok(instantiated_return)
+    Ok(widen_decimal_result(signature, instantiated_return))
+}
+
+/// Widen the decimal result of arithmetic and `sum` to hold every result.
+///
+/// The arguments of these functions have the same decimal type, which is also
+/// the result type in the signature. The result instead gets enough digits
+/// to represent the result of any arguments of that type:
+///
+/// * `add` and `sub` need one more integer digit.
+/// * `mul` needs the digits of both arguments (plus one), and the scales of
+///   both arguments.
+/// * `div` needs as many more integer digits as the scale.
+/// * `sum` uses the maximum precision.
+///
+/// If the precision would exceed the maximum, see [bounded_decimal].
+fn widen_decimal_result(signature: &Signature, result: FenlType) -> FenlType {
+    let FenlType::Concrete(DataType::Decimal128(precision, scale)) = result else {
+        return result;
+    };
+    let (precision, scale) = (precision as i16, scale as i16);
+    match signature.name() {
+        "add" | "sub" => bounded_decimal(precision + 1, scale),
+        "mul" => bounded_decimal(2 * precision + 1, 2 * scale),
+        "div" => bounded_decimal(precision + scale.max(0), scale),
+        "sum" => bounded_decimal(DECIMAL128_MAX_PRECISION as i16, scale),
+        _ => result,
+    }
+}
+
+/// Create a decimal type, limiting the precision to the maximum.
+///
+/// If the precision exceeds the maximum, integer digits are kept in favor of
+/// fractional digits, keeping a scale of at least 6 (or the requested scale,
+/// if smaller).
+fn bounded_decimal(precision: i16, scale: i16) -> FenlType {
+    let max_precision = DECIMAL128_MAX_PRECISION as i16;
+    let scale = if precision > max_precision {
+        let integer_digits = precision - scale;
+        (max_precision - integer_digits).max(scale.min(6))
+    } else {
+        scale
+    };
+    FenlType::Concrete(DataType::Decimal128(
+        precision.min(max_precision) as u8,
+        scale as i8,
+    ))
 }
 
 /// Determine the type for a type class based on the associated argument types.
@@ -359,7 +427,22 @@ fn instantiate_type(fenl_type: &FenlType, solutions: &HashMap<TypeVariable, Fenl
             let s = Arc::new(Field::new("entries", DataType::Struct(fields), false));
             FenlType::Concrete(DataType::Map(s, false))
         }
-        FenlType::Collection(Collection::List, _) => todo!("unsupported"),
+        FenlType::Collection(Collection::List, type_vars) => {
+            debug_assert!(type_vars.len() == 1);
+
+            let concrete_item_type = solutions
+                .get(&type_vars[0])
+                .cloned()
+                .unwrap_or(FenlType::Concrete(DataType::Null));
+
+            // `solutions` map should contain concrete types for all type variables.
+            let item_field = match concrete_item_type {
+                FenlType::Concrete(t) => Field::new("item", t, true),
+                FenlType::Error => return FenlType::Error,
+                other => panic!("expected concrete type, got {:?}", other),
+            };
+            FenlType::Concrete(DataType::List(Arc::new(item_field)))
+        }
         FenlType::Concrete(_) => fenl_type.clone(),
         FenlType::Window => fenl_type.clone(),
         FenlType::Json => fenl_type.clone(),
@@ -542,6 +625,13 @@ fn least_upper_bound_data_type(a: DataType, b: &DataType) -> Option<DataType> {
         ///////////////////////////////////////////////////////////////////
         // Other rules
 
+        // Decimals may be joined with other decimals and integers. They are never
+        // implicitly joined with floating point types, since that would silently
+        // lose precision.
+        (a, b) if matches!(a, Decimal128(_, _)) || matches!(b, Decimal128(_, _)) => {
+            least_upper_bound_decimal(&a, b)
+        }
+
         // Null is promotable to a null version of any other type.
         (Null, other) => Some(other.clone()),
         (other, Null) => Some(other),
@@ -549,6 +639,13 @@ fn least_upper_bound_data_type(a: DataType, b: &DataType) -> Option<DataType> {
         // Catch all
         (lhs, rhs) if &lhs == rhs => Some(lhs),
 
+        // Timestamps with different units or time zones are joined as
+        // nanoseconds. The time zone is kept only if both have the same one.
+        (Timestamp(_, a_tz), Timestamp(_, b_tz)) => {
+            let tz = if a_tz == *b_tz { a_tz } else { None };
+            Some(Timestamp(TimeUnit::Nanosecond, tz))
+        }
+
         // Least Upper bound on records = least upper bound on each field.
         // Currently, this requires the fields to be present (in any order) in both
         // structs. We could relax this and allow absent fields to be treated as null.
@@ -592,6 +689,42 @@ fn least_upper_bound_data_type(a: DataType, b: &DataType) -> Option<DataType> {
     }
 }
 
+/// Defines the least-upper-bound of a decimal with another type.
+///
+/// The result has enough integer digits and enough fractional digits to
+/// represent the values of either type, limited to the maximum precision
+/// of a 128-bit decimal.
+fn least_upper_bound_decimal(a: &DataType, b: &DataType) -> Option<DataType> {
+    match (a, b) {
+        (DataType::Null, other) | (other, DataType::Null) => Some(other.clone()),
+        _ => {
+            let (integer_a, scale_a) = decimal_digits(a)?;
+            let (integer_b, scale_b) = decimal_digits(b)?;
+            let scale = scale_a.max(scale_b);
+            let precision =
+                (integer_a.max(integer_b) + scale).clamp(1, DECIMAL128_MAX_PRECISION as i16);
+            Some(DataType::Decimal128(precision as u8, scale as i8))
+        }
+    }
+}
+
+/// Returns the number of integer and fractional digits needed to exactly
+/// represent values of the given type as a decimal.
+///
+/// Returns `None` if the type can't be exactly represented as a decimal.
+fn decimal_digits(data_type: &DataType) -> Option<(i16, i16)> {
+    use DataType::*;
+    match data_type {
+        Decimal128(precision, scale) => Some((*precision as i16 - *scale as i16, *scale as i16)),
+        Int8 | UInt8 => Some((3, 0)),
+        Int16 | UInt16 => Some((5, 0)),
+        Int32 | UInt32 => Some((10, 0)),
+        Int64 => Some((19, 0)),
+        UInt64 => Some((20, 0)),
+        _ => None,
+    }
+}
+
 /// Promote a concrete type to satisfy this type class.
 ///
 /// If the `concrete` type already satisfies this type class, it is
@@ -616,8 +749,18 @@ fn promote_concrete(concrete: FenlType, type_class: &TypeClass) -> Option<FenlTy
         (
             TypeClass::Number,
             FenlType::Concrete(
-                Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float16 | Float32
-                | Float64,
+                Int8
+                | Int16
+                | Int32
+                | Int64
+                | UInt8
+                | UInt16
+                | UInt32
+                | UInt64
+                | Float16
+                | Float32
+                | Float64
+                | Decimal128(_, _),
             ),
         ) => Some(concrete),
         (TypeClass::Number, FenlType::Concrete(_)) => None,
@@ -628,7 +771,9 @@ fn promote_concrete(concrete: FenlType, type_class: &TypeClass) -> Option<FenlTy
         (TypeClass::Signed, FenlType::Concrete(UInt16)) => Some(FenlType::Concrete(Int32)),
         (TypeClass::Signed, FenlType::Concrete(UInt32)) => Some(FenlType::Concrete(Int64)),
         (TypeClass::Signed, FenlType::Concrete(UInt64)) => Some(FenlType::Concrete(Float64)),
-        (TypeClass::Signed, FenlType::Concrete(Float16 | Float32 | Float64)) => Some(concrete),
+        (TypeClass::Signed, FenlType::Concrete(Float16 | Float32 | Float64 | Decimal128(_, _))) => {
+            Some(concrete)
+        }
         (TypeClass::Signed, FenlType::Concrete(_)) => None,
 
         // Int and UInt needs to be widened to Float64 to be `float`
@@ -639,6 +784,16 @@ fn promote_concrete(concrete: FenlType, type_class: &TypeClass) -> Option<FenlTy
         (TypeClass::Float, FenlType::Concrete(Float16 | Float32 | Float64)) => Some(concrete),
         (TypeClass::Float, FenlType::Concrete(_)) => None,
 
+        // Decimals are already fractional. Other numbers are widened to Float64.
+        (TypeClass::Fractional, FenlType::Concrete(Decimal128(_, _) | Float64)) => Some(concrete),
+        (
+            TypeClass::Fractional,
+            FenlType::Concrete(
+                Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float16 | Float32,
+            ),
+        ) => Some(FenlType::Concrete(Float64)),
+        (TypeClass::Fractional, FenlType::Concrete(_)) => None,
+
         // Duration and Interval types are time deltas.
         (TypeClass::TimeDelta, FenlType::Concrete(Duration(_) | Interval(_))) => Some(concrete),
         (TypeClass::TimeDelta, FenlType::Concrete(_)) => None,
@@ -647,13 +802,27 @@ fn promote_concrete(concrete: FenlType, type_class: &TypeClass) -> Option<FenlTy
         (
             TypeClass::Ordered,
             FenlType::Concrete(
-                Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float16 | Float32
-                | Float64,
+                Int8
+                | Int16
+                | Int32
+                | Int64
+                | UInt8
+                | UInt16
+                | UInt32
+                | UInt64
+                | Float16
+                | Float32
+                | Float64
+                | Decimal128(_, _),
             ),
         ) => Some(concrete),
         (TypeClass::Ordered, FenlType::Concrete(Timestamp(_, _))) => Some(concrete),
         (TypeClass::Ordered, _) => None,
 
+        // Lists include lists of any item type.
+        (TypeClass::List, FenlType::Concrete(List(_))) => Some(concrete),
+        (TypeClass::List, _) => None,
+
         // Keys include anything we can currently hash.
         (TypeClass::Key, FenlType::Concrete(actual_type)) => {
             if sparrow_arrow::hash::can_hash(actual_type) {
@@ -706,10 +875,17 @@ fn can_implicitly_cast(from: &DataType, to: &DataType) -> bool {
         (Float16, Float16 | Float32 | Float64) => true,
         (Float32, Float32 | Float64) => true,
         (Float64, Float64) => true,
+        // Integers and decimals can be promoted to decimals with enough digits.
+        (_, Decimal128(precision, scale)) => {
+            decimal_digits(from).is_some_and(|(from_integer, from_scale)| {
+                from_integer <= *precision as i16 - *scale as i16 && from_scale <= *scale as i16
+            })
+        }
         // Other promotions that we allow implicitly.
         (Utf8, Timestamp(TimeUnit::Nanosecond, None)) => true,
         (Utf8, LargeUtf8) => true,
         (Timestamp(_, _), Timestamp(TimeUnit::Nanosecond, None)) => true,
+        (Timestamp(_, from_tz), Timestamp(TimeUnit::Nanosecond, to_tz)) => from_tz == to_tz,
         // Other promotions must be explicitly requested.
         (_, _) => false,
     }
@@ -927,6 +1103,111 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_instantiate_add_decimal() {
+        const ADD_SIGNATURE: &str = "add<N: number>(lhs: N, rhs: N) -> N";
+
+        // the arguments should have enough integer and fractional digits for both
+        assert_eq!(
+            instantiate_types(ADD_SIGNATURE, &["decimal(10, 2)", "decimal(12, 4)"]),
+            Ok("(lhs: decimal(12, 4), rhs: decimal(12, 4)) -> decimal(13, 4)".to_owned())
+        );
+        // i32 should be widened to a decimal with 10 integer digits
+        assert_eq!(
+            instantiate_types(ADD_SIGNATURE, &["decimal(10, 2)", "i32"]),
+            Ok("(lhs: decimal(12, 2), rhs: decimal(12, 2)) -> decimal(13, 2)".to_owned())
+        );
+        // decimals are never implicitly converted to floating point
+        assert!(instantiate_types(ADD_SIGNATURE, &["decimal(10, 2)", "f64"]).is_err());
+    }
+
+    #[test]
+    fn test_instantiate_widens_decimal_results() {
+        // the result of add has an extra integer digit
+        assert_eq!(
+            instantiate_types(
+                "add<N: number>(a: N, b: N) -> N",
+                &["decimal(3, 1)", "decimal(4, 2)"]
+            ),
+            Ok("(a: decimal(4, 2), b: decimal(4, 2)) -> decimal(5, 2)".to_owned())
+        );
+        // the result of mul has the digits and scales of both arguments
+        assert_eq!(
+            instantiate_types(
+                "mul<N: number>(a: N, b: N) -> N",
+                &["decimal(3, 1)", "decimal(4, 2)"]
+            ),
+            Ok("(a: decimal(4, 2), b: decimal(4, 2)) -> decimal(9, 4)".to_owned())
+        );
+        // when limited to the maximum precision, integer digits are kept
+        assert_eq!(
+            instantiate_types(
+                "mul<N: number>(a: N, b: N) -> N",
+                &["decimal(30, 10)", "decimal(30, 10)"]
+            ),
+            Ok("(a: decimal(30, 10), b: decimal(30, 10)) -> decimal(38, 6)".to_owned())
+        );
+        // the result of div has as many more integer digits as the scale
+        assert_eq!(
+            instantiate_types(
+                "div<N: number>(a: N, b: N) -> N",
+                &["decimal(4, 2)", "decimal(4, 2)"]
+            ),
+            Ok("(a: decimal(4, 2), b: decimal(4, 2)) -> decimal(6, 2)".to_owned())
+        );
+        // the result of sum has the maximum precision
+        assert_eq!(
+            instantiate_types("sum<N: number>(input: N) -> N", &["decimal(3, 1)"]),
+            Ok("(input: decimal(3, 1)) -> decimal(38, 1)".to_owned())
+        );
+        // other numbers are unchanged
+        assert_eq!(
+            instantiate_types("mul<N: number>(a: N, b: N) -> N", &["i64", "i32"]),
+            Ok("(a: i64, b: i64) -> i64".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_instantiate_mean() {
+        const MEAN_SIGNATURE: &str = "mean<F: fractional>(input: F) -> F";
+
+        // decimals are fractional
+        assert_eq!(
+            instantiate_types(MEAN_SIGNATURE, &["decimal(10, 2)"]),
+            Ok("(input: decimal(10, 2)) -> decimal(10, 2)".to_owned())
+        );
+        // integers should be widened to f64
+        assert_eq!(
+            instantiate_types(MEAN_SIGNATURE, &["i32"]),
+            Ok("(input: f64) -> f64".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_instantiate_zoned_timestamps() {
+        const SELECT_SIGNATURE: &str = "select<T: any>(a: T, b: T) -> T";
+
+        // timestamps in the same time zone keep that time zone
+        assert_eq!(
+            instantiate_types(
+                SELECT_SIGNATURE,
+                &["timestamp_ms(\"UTC\")", "timestamp_ns(\"UTC\")"]
+            ),
+            Ok(
+                "(a: timestamp_ns(\"UTC\"), b: timestamp_ns(\"UTC\")) -> timestamp_ns(\"UTC\")"
+                    .to_owned()
+            )
+        );
+        // timestamps in different time zones drop the time zone
+        assert_eq!(
+            instantiate_types(
+                SELECT_SIGNATURE,
+                &["timestamp_ns(\"UTC\")", "timestamp_ns(\"+07:00\")"]
+            ),
+            Ok("(a: timestamp_ns, b: timestamp_ns) -> timestamp_ns".to_owned())
+        );
+    }
+
     #[test]
     fn test_instantiate_neg() {
         const NEG_SIGNATURE: &str = "neg<S: signed>(n: S) -> S";
@@ -967,4 +1248,26 @@ mod tests {
             Ok("(key: i32, map: map<i32, i32>) -> i32".to_owned())
         );
     }
+
+    #[test]
+    fn test_instantiate_list() {
+        const INDEX_SIGNATURE: &str = "index<T: any>(i: i64, list: list<T>) -> T";
+        assert_eq!(
+            instantiate_types(INDEX_SIGNATURE, &["i32", "list<f32>"]),
+            Ok("(i: i64, list: list<f32>) -> f32".to_owned())
+        );
+
+        const FLATTEN_SIGNATURE: &str = "flatten<L: list>(input: list<L>) -> L";
+        assert_eq!(
+            instantiate_types(FLATTEN_SIGNATURE, &["list<list<i64>>"]),
+            Ok("(input: list<list<i64>>) -> list<i64>".to_owned())
+        );
+        assert!(instantiate_types(FLATTEN_SIGNATURE, &["list<i64>"]).is_err());
+
+        const KEYS_SIGNATURE: &str = "keys<K: key, V: any>(map: map<K, V>) -> list<K>";
+        assert_eq!(
+            instantiate_types(KEYS_SIGNATURE, &["map<string, f32>"]),
+            Ok("(map: map<string, f32>) -> list<string>".to_owned())
+        );
+    }
 }
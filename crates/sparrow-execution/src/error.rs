@@ -0,0 +1,38 @@
+use std::borrow::Cow;
+
+use sparrow_physical::StepId;
+
+#[derive(derive_more::Display, Debug)]
+pub enum Error {
+    #[display(fmt = "no input provided for table '{_0}'")]
+    MissingInput(String),
+    #[display(fmt = "step {_0} is not part of any pipeline")]
+    UnscheduledStep(StepId),
+    #[display(fmt = "invalid pipeline starting at step {step}: {reason}")]
+    InvalidPipeline {
+        step: StepId,
+        reason: Cow<'static, str>,
+    },
+    #[display(fmt = "invalid step {step}: {reason}")]
+    InvalidStep {
+        step: StepId,
+        reason: Cow<'static, str>,
+    },
+    #[display(fmt = "unsupported step {step}: {reason}")]
+    UnsupportedStep {
+        step: StepId,
+        reason: Cow<'static, str>,
+    },
+    #[display(fmt = "error creating expressions for step {_0}")]
+    CreateExpressions(StepId),
+    #[display(fmt = "error executing step {_0}")]
+    ExecuteStep(StepId),
+    #[display(fmt = "input to step {_0} disconnected before completing")]
+    InputDisconnected(StepId),
+    #[display(fmt = "output of step {_0} disconnected before completing")]
+    OutputDisconnected(StepId),
+    #[display(fmt = "internal error")]
+    Internal,
+}
+
+impl error_stack::Context for Error {}
@@ -0,0 +1,735 @@
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use error_stack::ResultExt;
+use index_vec::IndexVec;
+use itertools::Itertools;
+use sparrow_arrow::Batch;
+use sparrow_physical::{Plan, StepId, StepKind};
+
+use crate::gather::Gather;
+use crate::repartition::Repartition;
+use crate::transform::Transform;
+use crate::Error;
+
+/// An iterator over the batches in a single partition of an input.
+///
+/// Batches should be ordered by time, subsort and key hash.
+pub type BatchIter = Box<dyn Iterator<Item = Batch> + Send>;
+
+/// Executes a physical plan over partitioned streams of ordered batches.
+///
+/// Each [pipeline](sparrow_physical::Pipeline) of the plan is run for each of
+/// its partitions on a separate thread. The number of partitions of each step
+/// is determined by the number of partitions of the scanned inputs, and
+/// changed only by repartitioning.
+///
+/// The output of the plan is the output of the last step, with the batches
+/// produced by each partition in order.
+pub struct PlanExecutor<'a> {
+    plan: &'a Plan,
+    /// The partitions of each scanned table.
+    inputs: HashMap<String, Vec<BatchIter>>,
+    /// The number of partitions produced by each step.
+    partitions: IndexVec<StepId, usize>,
+    /// The steps which consume the output of each step.
+    consumers: IndexVec<StepId, Vec<StepId>>,
+}
+
+/// A message sent from a partition of one pipeline to a partition of another.
+struct Message {
+    /// The index of the sending source within the receiving partition.
+    source: usize,
+    /// The next batch from the source, or `None` if the source has finished.
+    batch: Option<Batch>,
+}
+
+impl<'a> PlanExecutor<'a> {
+    /// Create an executor for the plan reading from the given inputs.
+    ///
+    /// The inputs contain the batches for each partition of each table.
+    pub fn try_new(
+        plan: &'a Plan,
+        inputs: HashMap<String, Vec<BatchIter>>,
+    ) -> error_stack::Result<Self, Error> {
+        let mut consumers: IndexVec<StepId, Vec<StepId>> =
+            plan.steps.iter().map(|_| Vec::new()).collect();
+        let mut partitions = IndexVec::with_capacity(plan.steps.len());
+        for (step_id, step) in plan.steps.iter_enumerated() {
+            for input in step.inputs.iter() {
+                error_stack::ensure!(
+                    *input < step_id,
+                    Error::InvalidStep {
+                        step: step_id,
+                        reason: format!("input {input} does not precede the step").into()
+                    }
+                );
+                if !consumers[*input].contains(&step_id) {
+                    consumers[*input].push(step_id);
+                }
+            }
+
+            let num_partitions = match &step.kind {
+                StepKind::Scan { table_name } => inputs
+                    .get(table_name)
+                    .ok_or_else(|| error_stack::report!(Error::MissingInput(table_name.clone())))?
+                    .len(),
+                StepKind::Merge => {
+                    let Some((first, rest)) = step.inputs.split_first() else {
+                        error_stack::bail!(Error::InvalidStep {
+                            step: step_id,
+                            reason: "merge should have at least one input".into()
+                        })
+                    };
+                    let num_partitions = partitions[*first];
+                    error_stack::ensure!(
+                        rest.iter()
+                            .all(|input| partitions[*input] == num_partitions),
+                        Error::UnsupportedStep {
+                            step: step_id,
+                            reason: "merged inputs should have the same number of partitions"
+                                .into()
+                        }
+                    );
+                    num_partitions
+                }
+                StepKind::Repartition { num_partitions, .. } => {
+                    error_stack::ensure!(
+                        *num_partitions > 0 && step.inputs.len() == 1,
+                        Error::InvalidStep {
+                            step: step_id,
+                            reason: "repartition should have 1 input and at least 1 partition"
+                                .into()
+                        }
+                    );
+                    *num_partitions
+                }
+                StepKind::Project { .. } | StepKind::Filter { .. } => {
+                    error_stack::ensure!(
+                        step.inputs.len() == 1,
+                        Error::InvalidStep {
+                            step: step_id,
+                            reason: format!("expected 1 input but got {}", step.inputs.len())
+                                .into()
+                        }
+                    );
+                    partitions[step.inputs[0]]
+                }
+                StepKind::Aggregate { .. }
+                | StepKind::Tick { .. }
+                | StepKind::ShiftTo { .. }
+                | StepKind::ShiftUntil { .. }
+                | StepKind::LookupRequest { .. }
+                | StepKind::LookupResponse { .. } => {
+                    error_stack::bail!(Error::UnsupportedStep {
+                        step: step_id,
+                        reason: "stateful steps are not yet executable".into()
+                    })
+                }
+                StepKind::Error => error_stack::bail!(Error::UnsupportedStep {
+                    step: step_id,
+                    reason: "error steps cannot be executed".into()
+                }),
+            };
+            partitions.push(num_partitions);
+        }
+
+        // Each step must be part of exactly one pipeline. Within a pipeline,
+        // every step after the first should be a transform consuming only the
+        // output of the previous step, which should have no other consumers.
+        let mut scheduled: IndexVec<StepId, bool> = plan.steps.iter().map(|_| false).collect();
+        for pipeline in plan.pipelines.iter() {
+            let Some(first) = pipeline.steps.first() else {
+                continue;
+            };
+            for (previous, step) in pipeline.steps.iter().tuple_windows() {
+                error_stack::ensure!(
+                    plan.steps[*step].inputs == [*previous]
+                        && consumers[*previous] == [*step]
+                        && matches!(
+                            plan.steps[*step].kind,
+                            StepKind::Project { .. } | StepKind::Filter { .. }
+                        ),
+                    Error::InvalidPipeline {
+                        step: *first,
+                        reason: format!("step {step} cannot follow step {previous}").into()
+                    }
+                );
+            }
+            for step in pipeline.steps.iter() {
+                error_stack::ensure!(
+                    !scheduled[*step],
+                    Error::InvalidPipeline {
+                        step: *first,
+                        reason: format!("step {step} is part of multiple pipelines").into()
+                    }
+                );
+                scheduled[*step] = true;
+            }
+        }
+        if let Some((step, _)) = scheduled
+            .iter_enumerated()
+            .find(|(_, scheduled)| !**scheduled)
+        {
+            error_stack::bail!(Error::UnscheduledStep(step))
+        }
+
+        Ok(Self {
+            plan,
+            inputs,
+            partitions,
+            consumers,
+        })
+    }
+
+    /// Execute the plan, returning the batches in each partition of the output.
+    pub fn execute(mut self) -> error_stack::Result<Vec<Vec<Batch>>, Error> {
+        let plan = self.plan;
+        let Some(output) = plan.steps.indices().next_back() else {
+            return Ok(vec![]);
+        };
+
+        // Create a channel for each partition of each pipeline which receives
+        // batches from other pipelines.
+        let mut senders: IndexVec<StepId, Vec<Sender<Message>>> =
+            plan.steps.iter().map(|_| Vec::new()).collect();
+        let mut receivers: IndexVec<StepId, Vec<Receiver<Message>>> =
+            plan.steps.iter().map(|_| Vec::new()).collect();
+        for pipeline in plan.pipelines.iter() {
+            let Some(head) = pipeline.steps.first() else {
+                continue;
+            };
+            if !matches!(plan.steps[*head].kind, StepKind::Scan { .. }) {
+                for _ in 0..self.partitions[*head] {
+                    let (sender, receiver) = mpsc::channel();
+                    senders[*head].push(sender);
+                    receivers[*head].push(receiver);
+                }
+            }
+        }
+
+        let mut tasks = Vec::new();
+        for pipeline in plan.pipelines.iter() {
+            let (Some(head), Some(tail)) = (pipeline.steps.first(), pipeline.steps.last()) else {
+                continue;
+            };
+            let head_step = &plan.steps[*head];
+            let inputs: Vec<_> = match &head_step.kind {
+                StepKind::Scan { table_name } => self
+                    .inputs
+                    .remove(table_name)
+                    .ok_or_else(|| error_stack::report!(Error::MissingInput(table_name.clone())))
+                    .attach_printable("each table may only be scanned once")?
+                    .into_iter()
+                    .map(PartitionInput::Scan)
+                    .collect(),
+                kind => {
+                    // Merges and repartitions gather and align the batches to
+                    // their schema. Other steps receive the batches of their input.
+                    let (schema, num_sources) = match kind {
+                        StepKind::Merge => (head_step.schema.clone(), head_step.inputs.len()),
+                        StepKind::Repartition { .. } => (
+                            head_step.schema.clone(),
+                            self.partitions[head_step.inputs[0]],
+                        ),
+                        _ => (plan.steps[head_step.inputs[0]].schema.clone(), 1),
+                    };
+                    std::mem::take(&mut receivers[*head])
+                        .into_iter()
+                        .map(|receiver| PartitionInput::Gather {
+                            receiver,
+                            gather: Gather::new(schema.clone(), num_sources),
+                        })
+                        .collect()
+                }
+            };
+
+            for (partition, input) in inputs.into_iter().enumerate() {
+                let mut transforms = Vec::new();
+                for step in pipeline.steps.iter() {
+                    if let Some(transform) = Transform::try_new(plan, *step)? {
+                        transforms.push(transform);
+                    }
+                }
+
+                let mut routes = Vec::new();
+                for consumer in self.consumers[*tail].iter() {
+                    let consumer_step = &plan.steps[*consumer];
+                    for (position, _) in consumer_step
+                        .inputs
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, input)| *input == tail)
+                    {
+                        let route = match &consumer_step.kind {
+                            StepKind::Repartition {
+                                num_partitions,
+                                keys,
+                            } => Route::Repartition {
+                                repartition: Repartition::try_new(
+                                    *consumer,
+                                    &plan.steps[*tail].schema,
+                                    keys,
+                                    *num_partitions,
+                                )?,
+                                senders: senders[*consumer].clone(),
+                                source: partition,
+                            },
+                            _ => Route::Direct {
+                                sender: senders[*consumer][partition].clone(),
+                                source: position,
+                            },
+                        };
+                        routes.push(route);
+                    }
+                }
+
+                tasks.push(PartitionTask {
+                    head: *head,
+                    input,
+                    output: PartitionOutput {
+                        tail: *tail,
+                        transforms,
+                        routes,
+                        collected: (*tail == output).then(Vec::new),
+                    },
+                    partition,
+                });
+            }
+        }
+
+        // Drop the original senders so that each receiver disconnects once
+        // the tasks sending to it have completed.
+        std::mem::drop(senders);
+
+        let results: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = tasks
+                .into_iter()
+                .map(|task| {
+                    let partition = task.partition;
+                    (partition, scope.spawn(move || task.run()))
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|(partition, handle)| {
+                    let result = handle.join().unwrap_or_else(|_| {
+                        Err(error_stack::report!(Error::Internal)
+                            .attach_printable("partition task panicked"))
+                    });
+                    (partition, result)
+                })
+                .collect()
+        });
+
+        let mut outputs = vec![Vec::new(); self.partitions[output]];
+        let mut errors = Vec::new();
+        for (partition, result) in results {
+            match result {
+                Ok(Some(batches)) => outputs[partition] = batches,
+                Ok(None) => {}
+                Err(e) => errors.push(e),
+            }
+        }
+
+        // Report the first error which didn't result from another task failing.
+        let error = errors
+            .iter()
+            .position(|e| {
+                !matches!(
+                    e.current_context(),
+                    Error::InputDisconnected(_) | Error::OutputDisconnected(_)
+                )
+            })
+            .map(|index| errors.swap_remove(index))
+            .or_else(|| errors.pop());
+        match error {
+            Some(error) => Err(error),
+            None => Ok(outputs),
+        }
+    }
+}
+
+/// The input to a partition of a pipeline.
+enum PartitionInput {
+    /// Read batches from a partition of a scanned table.
+    Scan(BatchIter),
+    /// Gather batches sent from other pipelines.
+    Gather {
+        receiver: Receiver<Message>,
+        gather: Gather,
+    },
+}
+
+/// Where the output of a partition of a pipeline is sent.
+enum Route {
+    /// Send batches to the same partition of the consuming pipeline.
+    Direct {
+        sender: Sender<Message>,
+        source: usize,
+    },
+    /// Split batches between the partitions of the consuming pipeline.
+    Repartition {
+        repartition: Repartition,
+        senders: Vec<Sender<Message>>,
+        source: usize,
+    },
+}
+
+impl Route {
+    fn send(&mut self, step: StepId, batch: &Batch) -> error_stack::Result<(), Error> {
+        match self {
+            Route::Direct { sender, source } => send(sender, *source, Some(batch.clone()), step),
+            Route::Repartition {
+                repartition,
+                senders,
+                source,
+            } => {
+                let batches = repartition.split(batch)?;
+                for (sender, batch) in senders.iter().zip(batches) {
+                    send(sender, *source, Some(batch), step)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn finish(&self, step: StepId) -> error_stack::Result<(), Error> {
+        match self {
+            Route::Direct { sender, source } => send(sender, *source, None, step),
+            Route::Repartition {
+                senders, source, ..
+            } => senders
+                .iter()
+                .try_for_each(|sender| send(sender, *source, None, step)),
+        }
+    }
+}
+
+fn send(
+    sender: &Sender<Message>,
+    source: usize,
+    batch: Option<Batch>,
+    step: StepId,
+) -> error_stack::Result<(), Error> {
+    sender
+        .send(Message { source, batch })
+        .map_err(|_| error_stack::report!(Error::OutputDisconnected(step)))
+}
+
+/// The work of running a single partition of a pipeline.
+struct PartitionTask {
+    /// The first step of the pipeline.
+    head: StepId,
+    input: PartitionInput,
+    output: PartitionOutput,
+    partition: usize,
+}
+
+impl PartitionTask {
+    /// Run the partition to completion.
+    ///
+    /// Returns the collected batches if this partition produces the output
+    /// of the plan.
+    fn run(self) -> error_stack::Result<Option<Vec<Batch>>, Error> {
+        let Self {
+            head,
+            input,
+            mut output,
+            ..
+        } = self;
+
+        match input {
+            PartitionInput::Scan(batches) => {
+                for batch in batches {
+                    output.add_batch(batch)?;
+                }
+            }
+            PartitionInput::Gather {
+                receiver,
+                mut gather,
+            } => {
+                while !gather.is_finished() {
+                    let Message { source, batch } = receiver
+                        .recv()
+                        .map_err(|_| error_stack::report!(Error::InputDisconnected(head)))?;
+                    let batch = match batch {
+                        Some(batch) => gather.add_batch(source, batch)?,
+                        None => gather.finish_source(source)?,
+                    };
+                    if let Some(batch) = batch {
+                        output.add_batch(batch)?;
+                    }
+                }
+            }
+        }
+
+        output.finish()
+    }
+}
+
+/// Applies the steps of a pipeline and sends the results to its consumers.
+struct PartitionOutput {
+    /// The last step of the pipeline.
+    tail: StepId,
+    transforms: Vec<Transform>,
+    routes: Vec<Route>,
+    /// The batches produced, if this is the output of the plan.
+    collected: Option<Vec<Batch>>,
+}
+
+impl PartitionOutput {
+    fn add_batch(&mut self, batch: Batch) -> error_stack::Result<(), Error> {
+        let batch = self
+            .transforms
+            .iter()
+            .try_fold(batch, |batch, transform| transform.apply(batch))?;
+        for route in self.routes.iter_mut() {
+            route.send(self.tail, &batch)?;
+        }
+        if let Some(collected) = &mut self.collected {
+            if !batch.is_empty() {
+                collected.push(batch);
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> error_stack::Result<Option<Vec<Batch>>, Error> {
+        for route in self.routes.iter() {
+            route.finish(self.tail)?;
+        }
+        Ok(self.collected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::cast::as_primitive_array;
+    use arrow_array::types::{Int64Type, TimestampNanosecondType, UInt64Type};
+    use arrow_array::{
+        ArrayRef, ArrowPrimitiveType, Int64Array, RecordBatch, TimestampNanosecondArray,
+        UInt64Array,
+    };
+    use arrow_schema::{DataType, Field, Schema, SchemaRef};
+    use itertools::izip;
+    use sparrow_arrow::scalar_value::ScalarValue;
+    use sparrow_arrow::{Batch, RowTime};
+    use sparrow_physical::{Expr, Exprs, Plan, Step, StepKind};
+
+    use crate::{BatchIter, PlanExecutor};
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("time", TimestampNanosecondType::DATA_TYPE, false),
+            Field::new("key", DataType::UInt64, false),
+            Field::new("value", DataType::Int64, true),
+        ]))
+    }
+
+    /// Create a batch containing the given `(time, key, value)` rows.
+    fn batch(rows: &[(i64, u64, i64)], up_to_time: i64) -> Batch {
+        let time: ArrayRef = Arc::new(TimestampNanosecondArray::from_iter_values(
+            rows.iter().map(|row| row.0),
+        ));
+        let key: ArrayRef = Arc::new(UInt64Array::from_iter_values(rows.iter().map(|row| row.1)));
+        let value: ArrayRef = Arc::new(Int64Array::from_iter_values(rows.iter().map(|row| row.2)));
+        let subsort: ArrayRef = Arc::new(UInt64Array::from_iter_values(0..rows.len() as u64));
+        let record_batch =
+            RecordBatch::try_new(schema(), vec![time.clone(), key.clone(), value]).unwrap();
+        Batch::new_with_data(
+            record_batch,
+            time,
+            subsort,
+            key,
+            RowTime::from_timestamp_ns(up_to_time),
+        )
+    }
+
+    /// Return the `(time, key, value)` rows of the batches.
+    fn rows(batches: &[Batch]) -> Vec<(i64, u64, i64)> {
+        batches
+            .iter()
+            .flat_map(|batch| {
+                let record_batch = batch.record_batch().unwrap();
+                let time = as_primitive_array::<TimestampNanosecondType>(record_batch.column(0));
+                let key = as_primitive_array::<UInt64Type>(record_batch.column(1));
+                let value = as_primitive_array::<Int64Type>(record_batch.column(2));
+                izip!(
+                    time.values().iter(),
+                    key.values().iter(),
+                    value.values().iter()
+                )
+                .map(|(time, key, value)| (*time, *key, *value))
+                .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn column(name: &str, result_type: DataType) -> Expr {
+        Expr {
+            name: "column".into(),
+            literal_args: vec![ScalarValue::Utf8(Some(name.to_owned()))],
+            args: vec![],
+            result_type,
+        }
+    }
+
+    fn literal(value: i64) -> Expr {
+        Expr {
+            name: "literal".into(),
+            literal_args: vec![ScalarValue::Int64(Some(value))],
+            args: vec![],
+            result_type: DataType::Int64,
+        }
+    }
+
+    fn scan(table_name: &str) -> Step {
+        Step {
+            kind: StepKind::Scan {
+                table_name: table_name.to_owned(),
+            },
+            inputs: vec![],
+            schema: schema(),
+        }
+    }
+
+    fn execute(plan: &Plan, inputs: Vec<(&str, Vec<Batch>)>) -> Vec<Vec<Batch>> {
+        let inputs = inputs
+            .into_iter()
+            .map(|(table_name, partitions)| {
+                let partitions: Vec<BatchIter> = partitions
+                    .into_iter()
+                    .map(|batch| -> BatchIter { Box::new(std::iter::once(batch)) })
+                    .collect();
+                (table_name.to_owned(), partitions)
+            })
+            .collect::<HashMap<_, _>>();
+        PlanExecutor::try_new(plan, inputs)
+            .unwrap()
+            .execute()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_project_filter_repartition() {
+        let steps = index_vec::index_vec![
+            // 0: scan table
+            scan("table"),
+            // 1: add 10 to each value
+            Step {
+                kind: StepKind::Project {
+                    exprs: Exprs {
+                        exprs: index_vec::index_vec![
+                            column("time", TimestampNanosecondType::DATA_TYPE),
+                            column("key", DataType::UInt64),
+                            column("value", DataType::Int64),
+                            literal(10),
+                            Expr {
+                                name: "add".into(),
+                                literal_args: vec![],
+                                args: vec![2.into(), 3.into()],
+                                result_type: DataType::Int64,
+                            },
+                        ],
+                        outputs: vec![0.into(), 1.into(), 4.into()],
+                    },
+                },
+                inputs: vec![0.into()],
+                schema: schema(),
+            },
+            // 2: filter to values greater than 15
+            Step {
+                kind: StepKind::Filter {
+                    exprs: Exprs::singleton(vec![
+                        column("value", DataType::Int64),
+                        literal(15),
+                        Expr {
+                            name: "gt_primitive".into(),
+                            literal_args: vec![],
+                            args: vec![0.into(), 1.into()],
+                            result_type: DataType::Boolean,
+                        },
+                    ]),
+                },
+                inputs: vec![1.into()],
+                schema: schema(),
+            },
+            // 3: repartition by key
+            Step {
+                kind: StepKind::Repartition {
+                    num_partitions: 2,
+                    keys: Exprs::singleton(vec![column("key", DataType::UInt64)]),
+                },
+                inputs: vec![2.into()],
+                schema: schema(),
+            },
+        ];
+        let pipelines = sparrow_backend::pipeline_schedule(&steps);
+        let plan = Plan { steps, pipelines };
+
+        let outputs = execute(
+            &plan,
+            vec![(
+                "table",
+                vec![
+                    batch(&[(1, 1, 1), (2, 2, 10), (3, 1, 20)], 3),
+                    batch(&[(1, 2, 5), (4, 1, 30)], 4),
+                ],
+            )],
+        );
+        assert_eq!(outputs.len(), 2);
+        let partitions: Vec<_> = outputs.iter().map(|batches| rows(batches)).collect();
+
+        let mut all_rows: Vec<_> = partitions.iter().flatten().copied().collect();
+        all_rows.sort();
+        assert_eq!(all_rows, vec![(2, 2, 20), (3, 1, 30), (4, 1, 40)]);
+
+        for rows in partitions.iter() {
+            // Rows within each partition are ordered by time.
+            assert!(rows.windows(2).all(|rows| rows[0].0 <= rows[1].0));
+        }
+        for key in [1, 2] {
+            // Rows with each key are sent to a single partition.
+            let partitions_with_key = partitions
+                .iter()
+                .filter(|rows| rows.iter().any(|row| row.1 == key))
+                .count();
+            assert_eq!(partitions_with_key, 1);
+        }
+    }
+
+    #[test]
+    fn test_merge_orders_rows() {
+        let steps = index_vec::index_vec![
+            // 0: scan table a
+            scan("a"),
+            // 1: scan table b
+            scan("b"),
+            // 2: merge 0 and 1
+            Step {
+                kind: StepKind::Merge,
+                inputs: vec![0.into(), 1.into()],
+                schema: schema(),
+            },
+        ];
+        let pipelines = sparrow_backend::pipeline_schedule(&steps);
+        let plan = Plan { steps, pipelines };
+
+        let outputs = execute(
+            &plan,
+            vec![
+                ("a", vec![batch(&[(1, 1, 1), (3, 1, 3)], 3)]),
+                ("b", vec![batch(&[(2, 2, 2), (5, 2, 5)], 5)]),
+            ],
+        );
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(
+            rows(&outputs[0]),
+            vec![(1, 1, 1), (2, 2, 2), (3, 1, 3), (5, 2, 5)]
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use std::sync::Arc;
+
+use arrow_array::{Array, ArrayRef, RecordBatch, UInt32Array};
+use arrow_ord::sort::{lexsort_to_indices, SortColumn};
+use arrow_schema::SchemaRef;
+use error_stack::{IntoReport, ResultExt};
+use itertools::Itertools;
+use sparrow_arrow::{Batch, RowTime};
+
+use crate::Error;
+
+/// The time each source is complete up to before receiving any batches.
+const MIN_TIME: RowTime = RowTime::from_timestamp_ns(i64::MIN);
+
+/// Gathers ordered batches from one or more sources into an ordered stream.
+///
+/// Each source produces rows in order, with the `up_to_time` of each batch
+/// indicating the source will not produce any more rows at or before that
+/// time. Rows are buffered until every source has progressed past them, at
+/// which point they are emitted ordered by time, subsort and key hash.
+///
+/// Columns from each source are aligned to the output schema by name, with
+/// columns missing from a source filled with `null`.
+pub(crate) struct Gather {
+    /// The schema of the gathered batches.
+    schema: SchemaRef,
+    /// The time each source is complete up to.
+    ///
+    /// Finished sources are complete up to [RowTime::MAX].
+    up_to_times: Vec<RowTime>,
+    /// Batches which have been received but not yet emitted.
+    pending: Vec<Batch>,
+    /// The time the gathered output is complete up to.
+    emitted_up_to: RowTime,
+}
+
+impl Gather {
+    pub fn new(schema: SchemaRef, num_sources: usize) -> Self {
+        Self {
+            schema,
+            up_to_times: vec![MIN_TIME; num_sources],
+            pending: Vec::new(),
+            emitted_up_to: MIN_TIME,
+        }
+    }
+
+    /// Return true if all sources have finished.
+    pub fn is_finished(&self) -> bool {
+        self.up_to_times.iter().all(|time| *time == RowTime::MAX)
+    }
+
+    /// Add a batch from the given source.
+    ///
+    /// Returns the rows which are complete, if the output has progressed.
+    pub fn add_batch(
+        &mut self,
+        source: usize,
+        batch: Batch,
+    ) -> error_stack::Result<Option<Batch>, Error> {
+        self.up_to_times[source] = self.up_to_times[source].max(batch.up_to_time);
+        if !batch.is_empty() {
+            self.pending.push(align(&self.schema, batch)?);
+        }
+        self.emit()
+    }
+
+    /// Mark the given source as finished.
+    ///
+    /// Returns the rows which are complete, if the output has progressed.
+    pub fn finish_source(&mut self, source: usize) -> error_stack::Result<Option<Batch>, Error> {
+        self.up_to_times[source] = RowTime::MAX;
+        self.emit()
+    }
+
+    fn emit(&mut self) -> error_stack::Result<Option<Batch>, Error> {
+        let up_to_time = self
+            .up_to_times
+            .iter()
+            .min()
+            .copied()
+            .unwrap_or(RowTime::MAX);
+        if up_to_time <= self.emitted_up_to {
+            return Ok(None);
+        }
+        self.emitted_up_to = up_to_time;
+
+        let pending = std::mem::take(&mut self.pending);
+        let pending = concat_sorted(&self.schema, pending, up_to_time)?;
+
+        // Split off the rows at or before the time all sources are complete to.
+        let complete_len = pending.time().map_or(0, |time| {
+            time.values()
+                .partition_point(|time| RowTime::from_timestamp_ns(*time) <= up_to_time)
+        });
+        let remaining_len = pending.num_rows() - complete_len;
+        if remaining_len > 0 {
+            self.pending
+                .push(slice(&pending, complete_len, remaining_len, up_to_time));
+        }
+        Ok(Some(slice(&pending, 0, complete_len, up_to_time)))
+    }
+}
+
+/// Align the columns of the batch to the given schema by name.
+fn align(schema: &SchemaRef, batch: Batch) -> error_stack::Result<Batch, Error> {
+    let Some(record_batch) = batch.record_batch() else {
+        return Ok(batch);
+    };
+    if record_batch.schema().fields() == schema.fields() {
+        return Ok(batch);
+    }
+
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| match record_batch.column_by_name(field.name()) {
+            Some(column) => column.clone(),
+            None => arrow_array::new_null_array(field.data_type(), record_batch.num_rows()),
+        })
+        .collect();
+    let record_batch = RecordBatch::try_new(schema.clone(), columns)
+        .into_report()
+        .change_context(Error::Internal)?;
+    Ok(batch.with_projection(record_batch))
+}
+
+/// Concatenate the batches and sort the rows by time, subsort and key hash.
+fn concat_sorted(
+    schema: &SchemaRef,
+    batches: Vec<Batch>,
+    up_to_time: RowTime,
+) -> error_stack::Result<Batch, Error> {
+    if batches.len() <= 1 {
+        // A single batch from a source is already sorted.
+        return Ok(batches
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| Batch::new_empty(up_to_time)));
+    }
+
+    let record_batches: Vec<_> = batches
+        .iter()
+        .flat_map(|batch| batch.record_batch())
+        .cloned()
+        .collect();
+    let record_batch = arrow_select::concat::concat_batches(schema, &record_batches)
+        .into_report()
+        .change_context(Error::Internal)?;
+    let time = concat(
+        batches
+            .iter()
+            .flat_map(|batch| batch.time())
+            .map(|time| -> &dyn Array { time }),
+    )?;
+    let subsort = concat(
+        batches
+            .iter()
+            .flat_map(|batch| batch.subsort())
+            .map(|subsort| -> &dyn Array { subsort }),
+    )?;
+    let key_hash = concat(
+        batches
+            .iter()
+            .flat_map(|batch| batch.key_hash())
+            .map(|key_hash| -> &dyn Array { key_hash }),
+    )?;
+
+    let sort_columns = [&time, &subsort, &key_hash].map(|values| SortColumn {
+        values: values.clone(),
+        options: None,
+    });
+    let indices = lexsort_to_indices(&sort_columns, None)
+        .into_report()
+        .change_context(Error::Internal)?;
+
+    let columns = record_batch
+        .columns()
+        .iter()
+        .map(|column| take(column.as_ref(), &indices))
+        .try_collect()?;
+    let record_batch = RecordBatch::try_new(schema.clone(), columns)
+        .into_report()
+        .change_context(Error::Internal)?;
+    Ok(Batch::new_with_data(
+        record_batch,
+        take(time.as_ref(), &indices)?,
+        take(subsort.as_ref(), &indices)?,
+        take(key_hash.as_ref(), &indices)?,
+        up_to_time,
+    ))
+}
+
+fn concat<'a>(arrays: impl Iterator<Item = &'a dyn Array>) -> error_stack::Result<ArrayRef, Error> {
+    let arrays: Vec<_> = arrays.collect();
+    arrow_select::concat::concat(&arrays)
+        .into_report()
+        .change_context(Error::Internal)
+}
+
+fn take(array: &dyn Array, indices: &UInt32Array) -> error_stack::Result<ArrayRef, Error> {
+    arrow_select::take::take(array, indices, None)
+        .into_report()
+        .change_context(Error::Internal)
+}
+
+/// Return the given rows of the batch, complete up to the given time.
+fn slice(batch: &Batch, offset: usize, length: usize, up_to_time: RowTime) -> Batch {
+    match (
+        batch.record_batch(),
+        batch.time(),
+        batch.subsort(),
+        batch.key_hash(),
+    ) {
+        (Some(record_batch), Some(time), Some(subsort), Some(key_hash)) if length > 0 => {
+            Batch::new_with_data(
+                record_batch.slice(offset, length),
+                Arc::new(time.slice(offset, length)),
+                Arc::new(subsort.slice(offset, length)),
+                Arc::new(key_hash.slice(offset, length)),
+                up_to_time,
+            )
+        }
+        _ => Batch::new_empty(up_to_time),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sparrow_arrow::{Batch, RowTime};
+
+    use super::Gather;
+
+    #[test]
+    fn test_gather_orders_rows_across_sources() {
+        let a = Batch::minimal_from(vec![1, 3, 5], vec![0, 0, 0], 5);
+        let b = Batch::minimal_from(vec![2, 4], vec![1, 1], 4);
+        let schema = a.record_batch().unwrap().schema();
+        let mut gather = Gather::new(schema, 2);
+
+        // Nothing is complete until the second source has progressed.
+        assert_eq!(gather.add_batch(0, a).unwrap(), None);
+
+        // Both sources are complete up to 4.
+        let complete = gather.add_batch(1, b).unwrap().unwrap();
+        assert_eq!(complete.up_to_time, RowTime::from_timestamp_ns(4));
+        assert_eq!(complete.time().unwrap().values().to_vec(), vec![1, 2, 3, 4]);
+
+        // Once the second source finishes, the first determines progress.
+        let complete = gather.finish_source(1).unwrap().unwrap();
+        assert_eq!(complete.up_to_time, RowTime::from_timestamp_ns(5));
+        assert_eq!(complete.time().unwrap().values().to_vec(), vec![5]);
+        assert!(!gather.is_finished());
+
+        let complete = gather.finish_source(0).unwrap().unwrap();
+        assert!(complete.is_empty());
+        assert_eq!(complete.up_to_time, RowTime::MAX);
+        assert!(gather.is_finished());
+    }
+}
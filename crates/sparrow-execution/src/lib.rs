@@ -0,0 +1,32 @@
+#![warn(
+    rust_2018_idioms,
+    nonstandard_style,
+    future_incompatible,
+    clippy::mod_module_files,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::undocumented_unsafe_blocks
+)]
+
+//! Execution of physical plans for Kaskada queries.
+//!
+//! A [PlanExecutor] runs the [steps](sparrow_physical::Step) of a
+//! [physical plan](sparrow_physical::Plan) over partitioned streams of ordered
+//! batches. Each [pipeline](sparrow_physical::Pipeline) is run for each of its
+//! partitions on a separate thread, so a single query may use multiple cores.
+//! Batches are passed between pipelines over channels.
+//!
+//! Projections and filters are computed using an
+//! [ExpressionExecutor](sparrow_expressions::ExpressionExecutor). Steps which
+//! combine multiple streams -- merges and repartitions -- gather the batches
+//! from each input stream and emit rows in order once every input stream has
+//! progressed past them.
+
+mod error;
+mod executor;
+mod gather;
+mod repartition;
+mod transform;
+
+pub use error::*;
+pub use executor::*;
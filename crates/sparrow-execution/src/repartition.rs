@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, UInt64Array};
+use error_stack::{IntoReport, ResultExt};
+use sparrow_arrow::hasher::Hasher;
+use sparrow_arrow::{take_record_batch, Batch};
+use sparrow_expressions::ExpressionExecutor;
+use sparrow_physical::{Exprs, StepId};
+
+use crate::Error;
+
+/// Splits batches between partitions based on newly computed keys.
+///
+/// The key hash of each row is replaced with the hash of the computed keys,
+/// and the row is sent to the partition determined by that hash. Rows sent
+/// to each partition remain in order.
+pub(crate) struct Repartition {
+    step: StepId,
+    keys: ExpressionExecutor,
+    /// The index of each part of the key within the computed expressions.
+    outputs: Vec<usize>,
+    num_partitions: usize,
+    hasher: Hasher,
+}
+
+impl Repartition {
+    pub fn try_new(
+        step: StepId,
+        input_schema: &arrow_schema::Schema,
+        keys: &Exprs,
+        num_partitions: usize,
+    ) -> error_stack::Result<Self, Error> {
+        error_stack::ensure!(
+            !keys.is_empty(),
+            Error::InvalidStep {
+                step,
+                reason: "repartition requires at least one key".into()
+            }
+        );
+
+        let outputs = keys.outputs.iter().map(|output| output.index()).collect();
+        let keys = ExpressionExecutor::try_new(input_schema, &keys.exprs.raw)
+            .change_context(Error::CreateExpressions(step))?;
+        Ok(Self {
+            step,
+            keys,
+            outputs,
+            num_partitions,
+            hasher: Hasher::default(),
+        })
+    }
+
+    /// Split the batch into a batch for each partition.
+    ///
+    /// Every partition receives a (possibly empty) batch so that all of them
+    /// observe the progress of the input.
+    pub fn split(&mut self, batch: &Batch) -> error_stack::Result<Vec<Batch>, Error> {
+        let (Some(record_batch), Some(time), Some(subsort)) =
+            (batch.record_batch(), batch.time(), batch.subsort())
+        else {
+            return Ok(vec![
+                Batch::new_empty(batch.up_to_time);
+                self.num_partitions
+            ]);
+        };
+
+        let columns = self
+            .keys
+            .execute(batch)
+            .change_context(Error::ExecuteStep(self.step))?;
+        let keys: Vec<ArrayRef> = self
+            .outputs
+            .iter()
+            .map(|output| columns[*output].clone())
+            .collect();
+        let key_hash = self
+            .hasher
+            .hash_arrays(&keys)
+            .change_context(Error::ExecuteStep(self.step))?;
+
+        let mut partition_indices = vec![Vec::new(); self.num_partitions];
+        for (index, hash) in key_hash.iter().enumerate() {
+            let partition = (hash % self.num_partitions as u64) as usize;
+            partition_indices[partition].push(index as u64);
+        }
+        let key_hash: ArrayRef = Arc::new(UInt64Array::from(key_hash.to_vec()));
+
+        partition_indices
+            .into_iter()
+            .map(|indices| {
+                if indices.is_empty() {
+                    return Ok(Batch::new_empty(batch.up_to_time));
+                }
+
+                let indices = UInt64Array::from(indices);
+                let take = |array: &dyn arrow_array::Array| {
+                    arrow_select::take::take(array, &indices, None)
+                        .into_report()
+                        .change_context(Error::ExecuteStep(self.step))
+                };
+                Ok(Batch::new_with_data(
+                    take_record_batch(record_batch, &indices)
+                        .change_context(Error::ExecuteStep(self.step))?,
+                    take(time)?,
+                    take(subsort)?,
+                    take(key_hash.as_ref())?,
+                    batch.up_to_time,
+                ))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,144 @@
+use arrow_array::{Array, BooleanArray, RecordBatch};
+use arrow_schema::SchemaRef;
+use error_stack::{IntoReport, ResultExt};
+use sparrow_arrow::Batch;
+use sparrow_expressions::ExpressionExecutor;
+use sparrow_physical::{Exprs, Plan, StepId, StepKind};
+
+use crate::Error;
+
+/// A stateless transformation applied to each batch within a pipeline.
+pub(crate) enum Transform {
+    /// Replace the columns of each batch with the computed columns.
+    Project {
+        step: StepId,
+        executor: ExpressionExecutor,
+        /// The index of each output column within the computed expressions.
+        outputs: Vec<usize>,
+        schema: SchemaRef,
+    },
+    /// Retain the rows of each batch for which the predicate is true.
+    Filter {
+        step: StepId,
+        executor: ExpressionExecutor,
+        /// The index of the predicate within the computed expressions.
+        predicate: usize,
+    },
+}
+
+impl Transform {
+    /// Create the transform for the given step.
+    ///
+    /// Returns `None` for steps which pass batches through unchanged, such
+    /// as scans and the steps which gather batches from other pipelines.
+    pub fn try_new(plan: &Plan, step_id: StepId) -> error_stack::Result<Option<Self>, Error> {
+        let step = &plan.steps[step_id];
+        let transform = match &step.kind {
+            StepKind::Project { exprs } => {
+                error_stack::ensure!(
+                    exprs.output_len() == step.schema.fields().len(),
+                    Error::InvalidStep {
+                        step: step_id,
+                        reason: format!(
+                            "projection has {} outputs but schema has {} fields",
+                            exprs.output_len(),
+                            step.schema.fields().len()
+                        )
+                        .into()
+                    }
+                );
+                Transform::Project {
+                    step: step_id,
+                    executor: create_executor(plan, step_id, exprs)?,
+                    outputs: exprs.outputs.iter().map(|output| output.index()).collect(),
+                    schema: step.schema.clone(),
+                }
+            }
+            StepKind::Filter { exprs } => {
+                error_stack::ensure!(
+                    exprs.is_singleton(),
+                    Error::InvalidStep {
+                        step: step_id,
+                        reason: "filter should have a single output".into()
+                    }
+                );
+                Transform::Filter {
+                    step: step_id,
+                    executor: create_executor(plan, step_id, exprs)?,
+                    predicate: exprs.outputs[0].index(),
+                }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(transform))
+    }
+
+    pub fn apply(&self, batch: Batch) -> error_stack::Result<Batch, Error> {
+        if batch.is_empty() {
+            // There are no rows to transform, but the progress is preserved.
+            return Ok(batch);
+        }
+
+        match self {
+            Transform::Project {
+                step,
+                executor,
+                outputs,
+                schema,
+            } => {
+                let columns = executor
+                    .execute(&batch)
+                    .change_context(Error::ExecuteStep(*step))?;
+                let columns = outputs
+                    .iter()
+                    .map(|output| columns[*output].clone())
+                    .collect();
+                let record_batch = RecordBatch::try_new(schema.clone(), columns)
+                    .into_report()
+                    .change_context(Error::ExecuteStep(*step))?;
+                Ok(batch.with_projection(record_batch))
+            }
+            Transform::Filter {
+                step,
+                executor,
+                predicate,
+            } => {
+                let columns = executor
+                    .execute(&batch)
+                    .change_context(Error::ExecuteStep(*step))?;
+                let predicate = columns[*predicate]
+                    .as_any()
+                    .downcast_ref::<BooleanArray>()
+                    .ok_or_else(|| error_stack::report!(Error::ExecuteStep(*step)))
+                    .attach_printable("filter predicate should be boolean")?;
+                let result = batch
+                    .filter(predicate)
+                    .change_context(Error::ExecuteStep(*step))?;
+                if result.num_rows() == 0 {
+                    Ok(Batch::new_empty(batch.up_to_time))
+                } else {
+                    Ok(result)
+                }
+            }
+        }
+    }
+}
+
+/// Create an executor for expressions applied to the single input of a step.
+fn create_executor(
+    plan: &Plan,
+    step_id: StepId,
+    exprs: &Exprs,
+) -> error_stack::Result<ExpressionExecutor, Error> {
+    let step = &plan.steps[step_id];
+    error_stack::ensure!(
+        step.inputs.len() == 1,
+        Error::InvalidStep {
+            step: step_id,
+            reason: format!("expected 1 input but got {}", step.inputs.len()).into()
+        }
+    );
+    let input_schema = &plan.steps[step.inputs[0]].schema;
+    ExpressionExecutor::try_new(input_schema, &exprs.exprs.raw)
+        .change_context(Error::CreateExpressions(step_id))
+}
@@ -1,24 +1,36 @@
 use std::sync::Arc;
 
 use arrow_array::StringArray;
+use sparrow_arrow::scalar_value::ScalarValue;
 
 use crate::evaluator::Evaluator;
 use crate::values::StringValue;
 
 inventory::submit!(crate::evaluators::EvaluatorFactory {
     name: "json_field",
-    create: &create
+    create: &create_field
 });
 
-/// Evaluator for `json_field` expressions.
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "json_index",
+    create: &create_index
+});
+
+/// Evaluator for `json_field` and `json_index` expressions.
 ///
-/// This evaluator expects a `string` input and `field` name.
-/// It parses the `string` into a `json` object, then outputs the
-/// value of the `field` name as a `string`, or `null` if the `field`
+/// This evaluator expects a `string` input and a `field` name or array
+/// `index`. It parses the `string` into a `json` value, then outputs the
+/// value of the `field` or array element as a `string`, or `null` if it
 /// does not exist.
 struct JsonFieldEvaluator {
     input: StringValue,
-    field_name: String,
+    element: JsonElement,
+}
+
+/// The element of a `json` value to access.
+enum JsonElement {
+    Field(String),
+    Index(usize),
 }
 
 impl Evaluator for JsonFieldEvaluator {
@@ -27,22 +39,56 @@ impl Evaluator for JsonFieldEvaluator {
         work_area: &crate::work_area::WorkArea<'_>,
     ) -> error_stack::Result<arrow_array::ArrayRef, crate::Error> {
         let input = work_area.expression(self.input);
-        let result = json_field(input, &self.field_name);
+        let result = match &self.element {
+            JsonElement::Field(field_name) => json_field(input, field_name),
+            JsonElement::Index(index) => json_index(input, *index),
+        };
         Ok(Arc::new(result))
     }
 }
 
-fn create(info: super::StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, crate::Error> {
+fn create_field(
+    info: super::StaticInfo<'_>,
+) -> error_stack::Result<Box<dyn Evaluator>, crate::Error> {
     let field_name = info.literal_string()?.to_owned();
     let input = info.unpack_argument()?;
 
     Ok(Box::new(JsonFieldEvaluator {
         input: input.string()?,
-        field_name,
+        element: JsonElement::Field(field_name),
+    }))
+}
+
+fn create_index(
+    info: super::StaticInfo<'_>,
+) -> error_stack::Result<Box<dyn Evaluator>, crate::Error> {
+    let index = match info.literal()? {
+        ScalarValue::Int64(Some(index)) if *index >= 0 => *index as usize,
+        other => error_stack::bail!(crate::Error::InvalidLiteral {
+            expected: "non-negative i64",
+            actual: other.clone()
+        }),
+    };
+    let input = info.unpack_argument()?;
+
+    Ok(Box::new(JsonFieldEvaluator {
+        input: input.string()?,
+        element: JsonElement::Index(index),
     }))
 }
 
 fn json_field(input: &StringArray, field_name: &str) -> StringArray {
+    json_element(input, |json| &json[field_name])
+}
+
+fn json_index(input: &StringArray, index: usize) -> StringArray {
+    json_element(input, |json| &json[index])
+}
+
+fn json_element(
+    input: &StringArray,
+    element: impl Fn(&serde_json::Value) -> &serde_json::Value,
+) -> StringArray {
     input
         .iter()
         .map(|s| {
@@ -57,9 +103,9 @@ fn json_field(input: &StringArray, field_name: &str) -> StringArray {
         })
         .map(|json| {
             json.and_then(|json: serde_json::Value| {
-                let value = &json[field_name];
+                let value = element(&json);
                 if value.is_null() {
-                    // No value - field does not exist in this json object.
+                    // No value - element does not exist in this json value.
                     None
                 } else if let Some(s) = value.as_str() {
                     // If the value is a string, use this representation.
@@ -151,4 +197,22 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn test_json_index() {
+        let input = StringArray::from(vec![
+            Some("[1, \"two\", [3]]"),
+            Some("[1]"),
+            Some("{ \"1\": 2 }"),
+            None,
+        ]);
+        assert_eq!(
+            json_index(&input, 1),
+            StringArray::from(vec![Some("two"), None, None, None])
+        );
+        assert_eq!(
+            json_index(&input, 2),
+            StringArray::from(vec![Some("[3]"), None, None, None])
+        );
+    }
 }
@@ -1,4 +1,10 @@
+mod concat;
+mod contains;
 mod len;
 mod lower;
+mod pad;
+mod regex;
+mod split;
 mod substring;
+mod trim;
 mod upper;
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, StringArray};
+use error_stack::{IntoReport, ResultExt};
+use itertools::Itertools;
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::StringValue;
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "concat",
+    create: &create
+});
+
+/// Evaluator for concatenating strings (`concat`).
+struct ConcatEvaluator {
+    inputs: Vec<StringValue>,
+}
+
+impl Evaluator for ConcatEvaluator {
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let inputs: Vec<&StringArray> = self
+            .inputs
+            .iter()
+            .map(|input| info.expression(*input))
+            .collect();
+        let result = sparrow_kernels::string::concat(&inputs)
+            .into_report()
+            .change_context(Error::ExprEvaluation)?;
+        Ok(Arc::new(result))
+    }
+}
+
+fn create(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error> {
+    error_stack::ensure!(
+        !info.args.is_empty(),
+        Error::NoArguments {
+            name: info.name.clone(),
+            actual: 0
+        }
+    );
+    let inputs = info.args.iter().map(|input| input.string()).try_collect()?;
+    Ok(Box::new(ConcatEvaluator { inputs }))
+}
@@ -0,0 +1,70 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, BooleanArray, StringArray};
+use error_stack::{IntoReport, ResultExt};
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::StringValue;
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "contains",
+    create: &create_contains
+});
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "starts_with",
+    create: &create_starts_with
+});
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "ends_with",
+    create: &create_ends_with
+});
+
+type Compare = fn(&StringArray, &StringArray) -> Result<BooleanArray, Infallible>;
+
+/// Evaluator for `contains`, `starts_with` and `ends_with`.
+struct StringComparisonEvaluator {
+    input: StringValue,
+    other: StringValue,
+    compare: Compare,
+}
+
+impl Evaluator for StringComparisonEvaluator {
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let input = info.expression(self.input);
+        let other = info.expression(self.other);
+        let result = (self.compare)(input, other)
+            .into_report()
+            .change_context(Error::ExprEvaluation)?;
+        Ok(Arc::new(result))
+    }
+}
+
+fn create_contains(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error> {
+    create(info, sparrow_kernels::string::contains)
+}
+
+fn create_starts_with(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error> {
+    create(info, sparrow_kernels::string::starts_with)
+}
+
+fn create_ends_with(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error> {
+    create(info, sparrow_kernels::string::ends_with)
+}
+
+fn create(
+    info: StaticInfo<'_>,
+    compare: Compare,
+) -> error_stack::Result<Box<dyn Evaluator>, Error> {
+    let (other, input) = info.unpack_arguments()?;
+    Ok(Box::new(StringComparisonEvaluator {
+        input: input.string()?,
+        other: other.string()?,
+        compare,
+    }))
+}
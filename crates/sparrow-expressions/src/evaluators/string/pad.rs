@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use arrow_array::types::Int64Type;
+use arrow_array::ArrayRef;
+use error_stack::{IntoReport, ResultExt};
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::{PrimitiveValue, StringValue};
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "pad",
+    create: &create
+});
+
+/// Evaluator for padding the start of strings (`pad`).
+struct PadEvaluator {
+    input: StringValue,
+    length: PrimitiveValue<Int64Type>,
+    fill: StringValue,
+}
+
+impl Evaluator for PadEvaluator {
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let input = info.expression(self.input);
+        let length = info.expression(self.length);
+        let fill = info.expression(self.fill);
+        let result = sparrow_kernels::string::pad(input, length, fill)
+            .into_report()
+            .change_context(Error::ExprEvaluation)?;
+        Ok(Arc::new(result))
+    }
+}
+
+fn create(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error> {
+    let (length, input, fill) = info.unpack_arguments()?;
+    Ok(Box::new(PadEvaluator {
+        input: input.string()?,
+        length: length.primitive()?,
+        fill: fill.string()?,
+    }))
+}
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use arrow_array::types::Int64Type;
+use arrow_array::ArrayRef;
+use error_stack::{IntoReport, ResultExt};
+use regex::Regex;
+use sparrow_arrow::scalar_value::ScalarValue;
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::{PrimitiveValue, StringValue};
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "regex_match",
+    create: &create_match
+});
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "regex_extract",
+    create: &create_extract
+});
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "regex_replace",
+    create: &create_replace
+});
+
+/// Evaluator for `regex_match`.
+///
+/// The pattern is the literal argument, so it is only compiled once.
+struct RegexMatchEvaluator {
+    input: StringValue,
+    regex: Regex,
+}
+
+impl Evaluator for RegexMatchEvaluator {
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let input = info.expression(self.input);
+        let result = sparrow_kernels::string::regex_match(input, &self.regex)
+            .into_report()
+            .change_context(Error::ExprEvaluation)?;
+        Ok(Arc::new(result))
+    }
+}
+
+fn create_match(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error> {
+    let regex = literal_regex(&info)?;
+    let input = info.unpack_argument()?;
+    Ok(Box::new(RegexMatchEvaluator {
+        input: input.string()?,
+        regex,
+    }))
+}
+
+/// Evaluator for `regex_extract`.
+///
+/// The pattern is the literal argument, so it is only compiled once.
+struct RegexExtractEvaluator {
+    input: StringValue,
+    regex: Regex,
+    group: PrimitiveValue<Int64Type>,
+}
+
+impl Evaluator for RegexExtractEvaluator {
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let input = info.expression(self.input);
+        let group = info.expression(self.group);
+        let result = sparrow_kernels::string::regex_extract(input, &self.regex, group)
+            .into_report()
+            .change_context(Error::ExprEvaluation)?;
+        Ok(Arc::new(result))
+    }
+}
+
+fn create_extract(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error> {
+    let regex = literal_regex(&info)?;
+    let (input, group) = info.unpack_arguments()?;
+    Ok(Box::new(RegexExtractEvaluator {
+        input: input.string()?,
+        regex,
+        group: group.primitive()?,
+    }))
+}
+
+/// Evaluator for `regex_replace`.
+///
+/// The pattern is the literal argument, so it is only compiled once.
+struct RegexReplaceEvaluator {
+    input: StringValue,
+    regex: Regex,
+    replacement: StringValue,
+}
+
+impl Evaluator for RegexReplaceEvaluator {
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let input = info.expression(self.input);
+        let replacement = info.expression(self.replacement);
+        let result = sparrow_kernels::string::regex_replace(input, &self.regex, replacement)
+            .into_report()
+            .change_context(Error::ExprEvaluation)?;
+        Ok(Arc::new(result))
+    }
+}
+
+fn create_replace(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error> {
+    let regex = literal_regex(&info)?;
+    let (replacement, input) = info.unpack_arguments()?;
+    Ok(Box::new(RegexReplaceEvaluator {
+        input: input.string()?,
+        regex,
+        replacement: replacement.string()?,
+    }))
+}
+
+fn literal_regex(info: &StaticInfo<'_>) -> error_stack::Result<Regex, Error> {
+    let pattern = info.literal_string()?;
+    Regex::new(pattern).map_err(|e| {
+        error_stack::report!(Error::InvalidLiteral {
+            expected: "valid regex pattern",
+            actual: ScalarValue::Utf8(Some(pattern.to_owned()))
+        })
+        .attach_printable(e)
+    })
+}
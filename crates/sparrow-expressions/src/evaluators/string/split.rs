@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use arrow_array::ArrayRef;
+use error_stack::{IntoReport, ResultExt};
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::StringValue;
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "split",
+    create: &create
+});
+
+/// Evaluator for splitting strings into lists (`split`).
+struct SplitEvaluator {
+    input: StringValue,
+    separator: StringValue,
+}
+
+impl Evaluator for SplitEvaluator {
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let input = info.expression(self.input);
+        let separator = info.expression(self.separator);
+        let result = sparrow_kernels::string::split(input, separator)
+            .into_report()
+            .change_context(Error::ExprEvaluation)?;
+        Ok(Arc::new(result))
+    }
+}
+
+fn create(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error> {
+    let (separator, input) = info.unpack_arguments()?;
+    Ok(Box::new(SplitEvaluator {
+        input: input.string()?,
+        separator: separator.string()?,
+    }))
+}
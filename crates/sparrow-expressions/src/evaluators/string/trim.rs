@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use arrow_array::ArrayRef;
+use error_stack::{IntoReport, ResultExt};
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::StringValue;
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "trim",
+    create: &create
+});
+
+/// Evaluator for removing leading and trailing whitespace (`trim`).
+struct TrimEvaluator {
+    input: StringValue,
+}
+
+impl Evaluator for TrimEvaluator {
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let input = info.expression(self.input);
+        let result = sparrow_kernels::string::trim(input)
+            .into_report()
+            .change_context(Error::ExprEvaluation)?;
+        Ok(Arc::new(result))
+    }
+}
+
+fn create(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error> {
+    let input = info.unpack_argument()?;
+    Ok(Box::new(TrimEvaluator {
+        input: input.string()?,
+    }))
+}
@@ -1,4 +1,3 @@
-use anyhow::anyhow;
 use sparrow_plan::ValueRef;
 
 use crate::StaticArg;
@@ -15,6 +14,10 @@ pub enum AggregationArgs<T> {
     Since { input: T, ticks: T },
     /// Sliding windowed aggregations have both non-null ticks and duration.
     Sliding { input: T, ticks: T, duration: T },
+    /// Trailing windowed aggregations have null ticks and a non-null duration.
+    ///
+    /// The duration is the length of the window in nanoseconds.
+    Trailing { input: T, duration: T },
 }
 
 impl AggregationArgs<ValueRef> {
@@ -42,11 +45,10 @@ impl AggregationArgs<ValueRef> {
                 ticks: input[1].value_ref.clone(),
                 duration: input[2].value_ref.clone(),
             }),
-
-            invalid => Err(anyhow!(
-                "Saw invalid combination of windowed aggregation parameters: {:?}",
-                invalid
-            )),
+            (true, false) => Ok(AggregationArgs::Trailing {
+                input: input[0].value_ref.clone(),
+                duration: input[2].value_ref.clone(),
+            }),
         }
     }
 }
@@ -61,6 +63,9 @@ impl<T> AggregationArgs<T> {
                 ticks,
                 duration,
             } => vec![Some(input), Some(ticks), Some(duration)],
+            AggregationArgs::Trailing { input, duration } => {
+                vec![Some(input), None, Some(duration)]
+            }
         }
     }
 }
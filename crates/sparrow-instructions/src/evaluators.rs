@@ -4,22 +4,25 @@ use itertools::Itertools;
 use sparrow_plan::{InstKind, InstOp};
 
 use crate::evaluators::macros::{
-    create_float_evaluator, create_number_evaluator, create_ordered_evaluator,
-    create_signed_evaluator, create_typed_evaluator,
+    create_float_evaluator, create_key_evaluator, create_number_evaluator,
+    create_ordered_evaluator, create_signed_evaluator, create_typed_evaluator,
 };
 use crate::{ColumnarValue, ComputeStore, GroupingIndices};
 
 pub mod aggregation;
 mod cast;
 mod comparison;
+mod decimal;
 mod equality;
 mod field_ref;
 mod general;
 mod json_field;
+mod list;
 mod logical;
 mod macros;
 mod map;
 mod math;
+mod parse_json;
 mod record;
 mod string;
 mod time;
@@ -31,9 +34,11 @@ use equality::*;
 use field_ref::*;
 use general::*;
 use json_field::*;
+use list::*;
 use logical::*;
 use map::*;
 use math::*;
+use parse_json::*;
 use record::*;
 use sparrow_plan::ValueRef;
 use string::*;
@@ -152,7 +157,17 @@ pub trait EvaluatorFactory {
 
 pub fn create_evaluator(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
     match info.inst_kind {
-        InstKind::Simple(op) => create_simple_evaluator(*op, info),
+        InstKind::Simple(op) => match info.result_type {
+            DataType::Timestamp(_, Some(_)) => {
+                let result_type = info.result_type.clone();
+                let evaluator = create_simple_evaluator(*op, info)?;
+                Ok(Box::new(ZonedTimestampEvaluator::new(
+                    evaluator,
+                    result_type,
+                )))
+            }
+            _ => create_simple_evaluator(*op, info),
+        },
         InstKind::FieldRef => Ok(FieldRefEvaluator::try_new(info)?),
         InstKind::Cast(cast_type) => {
             assert_eq!(info.result_type, cast_type);
@@ -167,16 +182,36 @@ fn create_simple_evaluator(
     op: InstOp,
     mut info: StaticInfo<'_>,
 ) -> anyhow::Result<Box<dyn Evaluator>> {
+    if decimal::is_decimal_instruction(op, &info) {
+        return decimal::create_decimal_evaluator(op, info);
+    }
+
     match op {
         InstOp::Add => {
             create_number_evaluator!(&info.args[0].data_type, AddEvaluator, info)
         }
         InstOp::AddTime => AddTimeEvaluator::try_new(info),
+        InstOp::ApproxCountDistinct => CountDistinctEvaluator::<ApproxCountDistinct>::try_new(info),
         InstOp::Ceil => CeilEvaluator::try_new(info),
         InstOp::Clamp => {
             create_number_evaluator!(&info.args[0].data_type, ClampEvaluator, info)
         }
         InstOp::Coalesce => CoalesceEvaluator::try_new(info),
+        InstOp::Collect => {
+            create_typed_evaluator!(
+                &info.args[0].data_type,
+                CollectPrimitiveEvaluator,
+                CollectBooleanEvaluator,
+                CollectStringEvaluator,
+                info
+            )
+        }
+        InstOp::Concat => ConcatEvaluator::try_new(info),
+        InstOp::Contains | InstOp::EndsWith | InstOp::StartsWith => {
+            StringComparisonEvaluator::try_new(info)
+        }
+        InstOp::ContainsKey => ContainsKeyEvaluator::try_new(info),
+        InstOp::CountDistinct => CountDistinctEvaluator::<CountDistinct>::try_new(info),
         InstOp::CountIf => CountIfEvaluator::try_new(info),
         InstOp::DayOfMonth => DayOfMonthEvaluator::try_new(info),
         InstOp::DayOfMonth0 => DayOfMonth0Evaluator::try_new(info),
@@ -188,6 +223,7 @@ fn create_simple_evaluator(
             create_number_evaluator!(&info.args[0].data_type, DivEvaluator, info)
         }
         InstOp::Eq => EqEvaluatorFactory::try_new(info),
+        InstOp::EwmVariance | InstOp::Ewma => EwmEvaluator::try_new(info),
         InstOp::Exp => {
             create_float_evaluator!(&info.args[0].data_type, ExpEvaluator, info)
         }
@@ -201,6 +237,7 @@ fn create_simple_evaluator(
                 info
             )
         }
+        InstOp::Flatten => FlattenEvaluator::try_new(info),
         InstOp::Floor => FloorEvaluator::try_new(info),
         InstOp::Get => GetEvaluator::try_new(info),
         InstOp::Gt => match (info.args[0].is_literal(), info.args[1].is_literal()) {
@@ -231,13 +268,15 @@ fn create_simple_evaluator(
         },
         InstOp::Hash => HashEvaluator::try_new(info),
         InstOp::If => IfEvaluator::try_new(info),
+        InstOp::Index => IndexEvaluator::try_new(info),
         InstOp::IsValid => IsValidEvaluator::try_new(info),
-        // HACK: the `json` function is converted into the `json_field` instruction during
-        // simplification of the dfg. This is not a pattern intended to be followed; it's
-        // weird how both the `InstOp::Json` and `InstOp::JsonField` exist in the dfg and
-        // rely on simplification for conversion.
+        // HACK: accessing fields or elements of the `json` function is converted into the
+        // `json_field` and `json_index` instructions on the input string when the dfg is
+        // created. This is not a pattern intended to be followed; it's weird how both the
+        // `InstOp::Json` and `InstOp::JsonField` exist in the dfg.
         InstOp::Json => anyhow::bail!("No evaluator defined for json function"),
-        InstOp::JsonField => JsonFieldEvaluator::try_new(info),
+        InstOp::JsonField | InstOp::JsonIndex => JsonFieldEvaluator::try_new(info),
+        InstOp::Keys => KeysEvaluator::try_new(info),
         InstOp::Lag => {
             create_ordered_evaluator!(&info.args[1].data_type, PrimitiveLagEvaluator, info)
         }
@@ -252,6 +291,14 @@ fn create_simple_evaluator(
             )
         }
         InstOp::Len => LenEvaluator::try_new(info),
+        InstOp::ListContains => ListContainsEvaluator::try_new(info),
+        InstOp::ListLen => ListLenEvaluator::try_new(info),
+        InstOp::ListSum => match &info.args[0].data_type {
+            DataType::List(field) => {
+                create_number_evaluator!(field.data_type(), ListSumEvaluator, info)
+            }
+            other => anyhow::bail!("expected list input to list_sum, saw {:?}", other),
+        },
         InstOp::LogicalAnd => LogicalAndKleeneEvaluator::try_new(info),
         InstOp::LogicalOr => LogicalOrKleeneEvaluator::try_new(info),
         InstOp::Lower => LowerEvaluator::try_new(info),
@@ -290,6 +337,7 @@ fn create_simple_evaluator(
         InstOp::Min => {
             create_ordered_evaluator!(&info.args[0].data_type, ArrowAggEvaluator, Min, info)
         }
+        InstOp::Mode => create_key_evaluator!(&info.args[0].data_type, ModeEvaluator, info),
         InstOp::MonthOfYear => MonthOfYearEvaluator::try_new(info),
         InstOp::MonthOfYear0 => MonthOfYear0Evaluator::try_new(info),
         InstOp::Months => MonthsEvaluator::try_new(info),
@@ -303,12 +351,19 @@ fn create_simple_evaluator(
         InstOp::Neq => NeqEvaluatorFactory::try_new(info),
         InstOp::Not => NotEvaluator::try_new(info),
         InstOp::NullIf => NullIfEvaluator::try_new(info),
+        InstOp::Pad => PadEvaluator::try_new(info),
+        InstOp::ParseJson => ParseJsonEvaluator::try_new(info),
+        InstOp::Percentile => PercentileEvaluator::try_new(info),
         InstOp::Powf => {
             create_float_evaluator!(&info.args[0].data_type, PowfEvaluator, info)
         }
+        InstOp::RegexExtract => RegexExtractEvaluator::try_new(info),
+        InstOp::RegexMatch => RegexMatchEvaluator::try_new(info),
+        InstOp::RegexReplace => RegexReplaceEvaluator::try_new(info),
         InstOp::Round => RoundEvaluator::try_new(info),
         InstOp::Seconds => SecondsEvaluator::try_new(info),
         InstOp::SecondsBetween => SecondsBetweenEvaluator::try_new(info),
+        InstOp::Split => SplitEvaluator::try_new(info),
         InstOp::Sub => {
             create_number_evaluator!(&info.args[0].data_type, SubEvaluator, info)
         }
@@ -317,7 +372,10 @@ fn create_simple_evaluator(
             create_number_evaluator!(&info.args[0].data_type, ArrowAggEvaluator, Sum, info)
         }
         InstOp::TimeOf => TimeOfEvaluator::try_new(info),
+        InstOp::TopK => create_key_evaluator!(&info.args[0].data_type, TopKEvaluator, info),
+        InstOp::Trim => TrimEvaluator::try_new(info),
         InstOp::Upper => UpperEvaluator::try_new(info),
+        InstOp::Values => ValuesEvaluator::try_new(info),
         InstOp::Variance => {
             create_number_evaluator!(
                 &info.args[0].data_type,
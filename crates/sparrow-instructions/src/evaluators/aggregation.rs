@@ -1,17 +1,29 @@
 mod boolean;
+mod collect;
+mod ewm;
 mod function;
 mod generic;
 mod numeric_properties;
+mod percentile;
 mod primitive;
 mod string;
 mod token;
+mod top_k;
+mod trailing;
 mod two_stacks;
+mod windowed;
 
 pub use boolean::*;
+pub use collect::*;
+pub use ewm::*;
 pub use function::*;
 pub use generic::*;
 pub use numeric_properties::*;
+pub use percentile::*;
 pub use primitive::*;
 pub use string::*;
 pub use token::*;
+pub use top_k::*;
+pub use trailing::*;
 pub use two_stacks::*;
+pub use windowed::*;
@@ -7,8 +7,9 @@ use sparrow_plan::ValueRef;
 
 use super::two_stacks_first_boolean_evaluator::TwoStacksFirstBooleanEvaluator;
 use crate::{
-    AggregationArgs, BooleanAccumToken, Evaluator, EvaluatorFactory, RuntimeInfo, StateToken,
-    StaticInfo, TwoStacksBooleanAccumToken,
+    read_boolean_inputs, write_boolean_outputs, AggregationArgs, BooleanAccumToken, Evaluator,
+    EvaluatorFactory, FirstBoolean, RuntimeInfo, StateToken, StaticInfo, TrailingAggEvaluator,
+    TwoStacksBooleanAccumToken,
 };
 
 /// Evaluator for the `First` instruction on booleans.
@@ -44,8 +45,10 @@ impl Evaluator for FirstBooleanEvaluator {
                 );
                 result
             }
-            AggregationArgs::Sliding { .. } => {
-                unreachable!("Expected Non-windowed or Since windowed aggregation, saw Sliding.")
+            AggregationArgs::Sliding { .. } | AggregationArgs::Trailing { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding or Trailing."
+                )
             }
         }
     }
@@ -71,6 +74,14 @@ impl EvaluatorFactory for FirstBooleanEvaluator {
                 let token = TwoStacksBooleanAccumToken::new();
                 Ok(Box::new(TwoStacksFirstBooleanEvaluator { token, args }))
             }
+            AggregationArgs::Trailing { input, duration } => {
+                TrailingAggEvaluator::<FirstBoolean>::try_new(
+                    input,
+                    &duration,
+                    read_boolean_inputs,
+                    write_boolean_outputs,
+                )
+            }
         }
     }
 }
@@ -7,8 +7,9 @@ use sparrow_plan::ValueRef;
 
 use super::two_stacks_last_boolean_evaluator::TwoStacksLastBooleanEvaluator;
 use crate::{
-    AggregationArgs, BooleanAccumToken, Evaluator, EvaluatorFactory, RuntimeInfo, StateToken,
-    StaticInfo, TwoStacksBooleanAccumToken,
+    read_boolean_inputs, write_boolean_outputs, AggregationArgs, BooleanAccumToken, Evaluator,
+    EvaluatorFactory, LastBoolean, RuntimeInfo, StateToken, StaticInfo, TrailingAggEvaluator,
+    TwoStacksBooleanAccumToken,
 };
 
 /// Evaluator for the `last` instruction on booleans.
@@ -44,8 +45,10 @@ impl Evaluator for LastBooleanEvaluator {
                 );
                 result
             }
-            AggregationArgs::Sliding { .. } => {
-                unreachable!("Expected Non-windowed or Since windowed aggregation, saw Sliding.")
+            AggregationArgs::Sliding { .. } | AggregationArgs::Trailing { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding or Trailing."
+                )
             }
         }
     }
@@ -71,6 +74,14 @@ impl EvaluatorFactory for LastBooleanEvaluator {
                 let token = TwoStacksBooleanAccumToken::new();
                 Ok(Box::new(TwoStacksLastBooleanEvaluator { token, args }))
             }
+            AggregationArgs::Trailing { input, duration } => {
+                TrailingAggEvaluator::<LastBoolean>::try_new(
+                    input,
+                    &duration,
+                    read_boolean_inputs,
+                    write_boolean_outputs,
+                )
+            }
         }
     }
 }
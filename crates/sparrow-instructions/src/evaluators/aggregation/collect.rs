@@ -0,0 +1,5 @@
+//! Collect aggregation evaluators.
+
+mod collect_evaluator;
+
+pub use collect_evaluator::*;
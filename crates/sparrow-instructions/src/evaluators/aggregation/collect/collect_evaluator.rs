@@ -0,0 +1,359 @@
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, BooleanArray, ListArray, PrimitiveArray, StringArray};
+use arrow::buffer::OffsetBuffer;
+use arrow::datatypes::{ArrowPrimitiveType, DataType, FieldRef};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sparrow_arrow::downcast::{
+    downcast_boolean_array, downcast_primitive_array, downcast_string_array,
+};
+use sparrow_arrow::scalar_value::ScalarValue;
+
+use crate::{
+    AggregationArgs, Collect, Evaluator, EvaluatorFactory, StaticInfo, WindowedAggEvaluator,
+    WindowedAggregation,
+};
+
+/// The types of values which may be collected into a list.
+pub trait CollectType: 'static {
+    /// The native representation of values stored in the collect state.
+    type Native: Clone + Serialize + DeserializeOwned + std::fmt::Debug + Send + Sync;
+
+    /// Returns the values in the given input array.
+    fn values(array: &dyn Array) -> anyhow::Result<Vec<Option<Self::Native>>>;
+
+    /// Creates an array of the given type containing the collected values.
+    fn to_array(data_type: &DataType, values: Vec<Self::Native>) -> ArrayRef;
+
+    /// Creates an array of the given type containing the values, which may
+    /// be `null`.
+    fn to_nullable_array(data_type: &DataType, values: Vec<Option<Self::Native>>) -> ArrayRef;
+}
+
+/// Collects primitive values.
+pub struct CollectPrimitive<T: ArrowPrimitiveType>(PhantomData<T>);
+
+impl<T> CollectType for CollectPrimitive<T>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Serialize + DeserializeOwned,
+{
+    type Native = T::Native;
+
+    fn values(array: &dyn Array) -> anyhow::Result<Vec<Option<T::Native>>> {
+        let array: &PrimitiveArray<T> = downcast_primitive_array(array)?;
+        Ok(array.iter().collect())
+    }
+
+    fn to_array(data_type: &DataType, values: Vec<T::Native>) -> ArrayRef {
+        Arc::new(PrimitiveArray::<T>::from_iter_values(values).with_data_type(data_type.clone()))
+    }
+
+    fn to_nullable_array(data_type: &DataType, values: Vec<Option<T::Native>>) -> ArrayRef {
+        Arc::new(PrimitiveArray::<T>::from_iter(values).with_data_type(data_type.clone()))
+    }
+}
+
+/// Collects boolean values.
+pub struct CollectBoolean;
+
+impl CollectType for CollectBoolean {
+    type Native = bool;
+
+    fn values(array: &dyn Array) -> anyhow::Result<Vec<Option<bool>>> {
+        Ok(downcast_boolean_array(array)?.iter().collect())
+    }
+
+    fn to_array(_data_type: &DataType, values: Vec<bool>) -> ArrayRef {
+        Arc::new(BooleanArray::from(values))
+    }
+
+    fn to_nullable_array(_data_type: &DataType, values: Vec<Option<bool>>) -> ArrayRef {
+        Arc::new(BooleanArray::from(values))
+    }
+}
+
+/// Collects string values.
+pub struct CollectString;
+
+impl CollectType for CollectString {
+    type Native = String;
+
+    fn values(array: &dyn Array) -> anyhow::Result<Vec<Option<String>>> {
+        let array: &StringArray = downcast_string_array(array)?;
+        Ok(array.iter().map(|s| s.map(str::to_owned)).collect())
+    }
+
+    fn to_array(_data_type: &DataType, values: Vec<String>) -> ArrayRef {
+        Arc::new(StringArray::from_iter_values(values))
+    }
+
+    fn to_nullable_array(_data_type: &DataType, values: Vec<Option<String>>) -> ArrayRef {
+        Arc::new(StringArray::from(values))
+    }
+}
+
+pub type CollectPrimitiveEvaluator<T> = CollectEvaluator<CollectPrimitive<T>>;
+pub type CollectBooleanEvaluator = CollectEvaluator<CollectBoolean>;
+pub type CollectStringEvaluator = CollectEvaluator<CollectString>;
+
+/// Evaluator for the `collect` instruction.
+///
+/// Collects the non-null inputs for each entity into a list, keeping at most
+/// `max` of the most recent values.
+pub type CollectEvaluator<T> = WindowedAggEvaluator<CollectAggregation<T>>;
+
+/// Collects the inputs into a list.
+pub struct CollectAggregation<T: CollectType> {
+    /// The maximum number of values to collect, or `None` if unbounded.
+    max: Option<usize>,
+    /// The field of the items in the resulting list.
+    field: FieldRef,
+    _phantom: PhantomData<fn(T) -> T>,
+}
+
+impl<T: CollectType> WindowedAggregation for CollectAggregation<T> {
+    type AggF = Collect<T::Native>;
+    type Output = Vec<T::Native>;
+
+    fn read_inputs(&self, input: &ArrayRef) -> anyhow::Result<Vec<Option<T::Native>>> {
+        T::values(input.as_ref())
+    }
+
+    fn extract(&self, accum: &VecDeque<T::Native>) -> Vec<T::Native> {
+        let skip = self.max.map_or(0, |max| accum.len().saturating_sub(max));
+        accum.iter().skip(skip).cloned().collect()
+    }
+
+    fn write_outputs(&self, outputs: Vec<Vec<T::Native>>) -> anyhow::Result<ArrayRef> {
+        let offsets = OffsetBuffer::from_lengths(outputs.iter().map(Vec::len));
+        let values = T::to_array(
+            self.field.data_type(),
+            outputs.into_iter().flatten().collect(),
+        );
+        Ok(Arc::new(ListArray::try_new(
+            self.field.clone(),
+            offsets,
+            values,
+            None,
+        )?))
+    }
+
+    fn compact(&self, accum: &mut VecDeque<T::Native>) {
+        if let Some(max) = self.max {
+            while accum.len() > max {
+                accum.pop_front();
+            }
+        }
+    }
+}
+
+impl<T: CollectType> EvaluatorFactory for CollectEvaluator<T> {
+    fn try_new(mut info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        // The max follows the input: [input, max, ticks, duration]
+        anyhow::ensure!(
+            info.args.len() == 4,
+            "Collect should have 4 arguments. Saw {:?}",
+            info.args.len()
+        );
+        let max = info.args.remove(1);
+        let max = match max.value_ref.literal_value() {
+            Some(ScalarValue::Null | ScalarValue::Int64(None)) => None,
+            Some(ScalarValue::Int64(Some(max))) if *max > 0 => Some(*max as usize),
+            unexpected => anyhow::bail!(
+                "Expected positive literal max for collect, saw {:?}",
+                unexpected
+            ),
+        };
+
+        let field = match info.result_type {
+            DataType::List(field) => field.clone(),
+            unexpected => anyhow::bail!("Expected list result for collect, saw {:?}", unexpected),
+        };
+
+        let args = AggregationArgs::from_input(info.args)?;
+        Ok(Box::new(Self::new(
+            args,
+            CollectAggregation {
+                max,
+                field,
+                _phantom: PhantomData,
+            },
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int64Builder, ListBuilder, TimestampNanosecondArray, UInt32Array};
+    use arrow::datatypes::{Field, Int64Type};
+
+    use super::*;
+    use crate::{TrailingAccumToken, TrailingAggEvaluator};
+
+    fn aggregation(max: Option<usize>) -> CollectAggregation<CollectPrimitive<Int64Type>> {
+        CollectAggregation {
+            max,
+            field: Arc::new(Field::new("item", DataType::Int64, true)),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn expected(lists: Vec<Vec<i64>>) -> ArrayRef {
+        let mut builder = ListBuilder::new(Int64Builder::new());
+        for list in lists {
+            builder.values().append_slice(&list);
+            builder.append(true);
+        }
+        Arc::new(builder.finish())
+    }
+
+    #[test]
+    fn test_collect_since_max() {
+        let entity_indices = vec![0, 0, 1, 0, 0, 0];
+        let input = vec![Some(1), Some(2), Some(10), None, Some(3), Some(4)];
+        let ticks = BooleanArray::from(vec![
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            None,
+            Some(false),
+        ]);
+
+        let aggregation = aggregation(Some(2));
+        let mut accum = Vec::new();
+        let result = CollectEvaluator::aggregate_since(
+            &aggregation,
+            &mut accum,
+            2,
+            &entity_indices,
+            input,
+            &ticks,
+        );
+        assert_eq!(
+            &aggregation.write_outputs(result).unwrap(),
+            &expected(vec![
+                vec![1],
+                vec![1, 2],
+                vec![10],
+                vec![1, 2],
+                vec![3],
+                vec![3, 4]
+            ])
+        );
+        // Only the values which may be reported are retained.
+        assert_eq!(accum[0], VecDeque::from(vec![3, 4]));
+    }
+
+    #[test]
+    fn test_collect_sliding() {
+        let entity_indices = vec![0; 5];
+        let input = vec![Some(1), Some(5), Some(3), None, Some(2)];
+        let ticks = BooleanArray::from(vec![true, false, true, false, true]);
+
+        let aggregation = aggregation(None);
+        let mut accum = Vec::new();
+        let result = CollectEvaluator::aggregate_sliding(
+            &aggregation,
+            &mut accum,
+            1,
+            &entity_indices,
+            input,
+            &ticks,
+            2,
+        );
+        assert_eq!(
+            &aggregation.write_outputs(result).unwrap(),
+            &expected(vec![
+                vec![1],
+                vec![1, 5],
+                vec![1, 5, 3],
+                vec![5, 3],
+                vec![5, 3, 2]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_collect_sliding_max() {
+        let entity_indices = vec![0; 4];
+        let input = vec![Some(1), Some(5), Some(3), Some(2)];
+        let ticks = BooleanArray::from(vec![false, false, true, false]);
+
+        let aggregation = aggregation(Some(2));
+        let mut accum = Vec::new();
+        let result = CollectEvaluator::aggregate_sliding(
+            &aggregation,
+            &mut accum,
+            1,
+            &entity_indices,
+            input,
+            &ticks,
+            1,
+        );
+        assert_eq!(
+            &aggregation.write_outputs(result).unwrap(),
+            &expected(vec![vec![1], vec![1, 5], vec![5, 3], vec![2]])
+        );
+    }
+
+    #[test]
+    fn test_collect_trailing() {
+        let aggregation = aggregation(None);
+        let input: ArrayRef = Arc::new(arrow::array::Int64Array::from(vec![
+            Some(1),
+            Some(2),
+            Some(10),
+            Some(3),
+            None,
+        ]));
+
+        let mut token = TrailingAccumToken::new();
+        let result = TrailingAggEvaluator::<Collect<i64>>::aggregate(
+            &mut token,
+            2,
+            &UInt32Array::from(vec![0, 0, 1, 0, 0]),
+            &TimestampNanosecondArray::from(vec![0, 5, 6, 10, 16]),
+            aggregation.read_inputs(&input).unwrap(),
+            10,
+            |accum| aggregation.compact(accum),
+            |stacks| aggregation.extract(&stacks.accum_value()),
+        );
+        assert_eq!(
+            &aggregation.write_outputs(result).unwrap(),
+            &expected(vec![vec![1], vec![1, 2], vec![10], vec![2, 3], vec![3]])
+        );
+    }
+
+    #[test]
+    fn test_collect_trailing_max() {
+        let aggregation = aggregation(Some(2));
+        let input: ArrayRef = Arc::new(arrow::array::Int64Array::from(vec![
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+            Some(5),
+        ]));
+
+        let mut token = TrailingAccumToken::new();
+        let result = TrailingAggEvaluator::<Collect<i64>>::aggregate(
+            &mut token,
+            1,
+            &UInt32Array::from(vec![0, 0, 0, 0, 0]),
+            &TimestampNanosecondArray::from(vec![0, 1, 2, 3, 20]),
+            aggregation.read_inputs(&input).unwrap(),
+            10,
+            |accum| aggregation.compact(accum),
+            |stacks| aggregation.extract(&stacks.accum_value()),
+        );
+        assert_eq!(
+            &aggregation.write_outputs(result).unwrap(),
+            &expected(vec![vec![1], vec![1, 2], vec![2, 3], vec![3, 4], vec![5]])
+        );
+    }
+}
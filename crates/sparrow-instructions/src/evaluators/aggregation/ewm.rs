@@ -0,0 +1,5 @@
+//! Exponentially-weighted moving aggregation evaluators.
+
+mod ewm_evaluator;
+
+pub use ewm_evaluator::*;
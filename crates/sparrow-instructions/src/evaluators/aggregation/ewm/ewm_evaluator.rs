@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, BooleanArray, Float64Array, TimestampNanosecondArray};
+use arrow::datatypes::Float64Type;
+use itertools::izip;
+use sparrow_arrow::downcast::downcast_primitive_array;
+use sparrow_arrow::scalar_value::ScalarValue;
+use sparrow_plan::{InstKind, InstOp, ValueRef};
+
+use crate::{
+    AggregationArgs, Evaluator, EvaluatorFactory, EwmAccumToken, EwmState, RuntimeInfo, StateToken,
+    StaticInfo,
+};
+
+/// Evaluator for the `ewma` and `ewm_variance` instructions.
+///
+/// The weight of each input decays by half every `half_life` of event time,
+/// as described in [EwmState]. Since the decay already discounts older
+/// inputs, only unwindowed aggregations and `since` windows are supported.
+pub struct EwmEvaluator {
+    args: AggregationArgs<ValueRef>,
+    /// The half-life of the weights, in nanoseconds.
+    half_life: i64,
+    /// Whether to produce the variance rather than the mean.
+    variance: bool,
+    token: EwmAccumToken,
+}
+
+impl EvaluatorFactory for EwmEvaluator {
+    fn try_new(mut info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let variance = match info.inst_kind {
+            InstKind::Simple(InstOp::Ewma) => false,
+            InstKind::Simple(InstOp::EwmVariance) => true,
+            unexpected => anyhow::bail!("Unexpected exponentially-weighted {unexpected:?}"),
+        };
+
+        // The half-life follows the input: [input, half_life, ticks, duration]
+        anyhow::ensure!(
+            info.args.len() == 4,
+            "Exponentially-weighted aggregations should have 4 arguments. Saw {:?}",
+            info.args.len()
+        );
+        let half_life = info.args.remove(1);
+        let half_life = match half_life.value_ref.literal_value() {
+            Some(ScalarValue::Int64(Some(half_life))) if *half_life > 0 => *half_life,
+            unexpected => anyhow::bail!("Expected positive literal half-life, saw {unexpected:?}"),
+        };
+
+        // A `since` window is a sliding window of a single tick.
+        let args = match AggregationArgs::from_input(info.args)? {
+            AggregationArgs::Sliding {
+                input,
+                ticks,
+                duration,
+            } if matches!(duration.literal_value(), Some(ScalarValue::Int64(Some(1)))) => {
+                AggregationArgs::Since { input, ticks }
+            }
+            args => args,
+        };
+        anyhow::ensure!(
+            matches!(
+                args,
+                AggregationArgs::NoWindow { .. } | AggregationArgs::Since { .. }
+            ),
+            "Exponentially-weighted aggregations only support 'since' windows"
+        );
+
+        Ok(Box::new(Self {
+            args,
+            half_life,
+            variance,
+            token: EwmAccumToken::default(),
+        }))
+    }
+}
+
+impl Evaluator for EwmEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let grouping = info.grouping();
+        let key_capacity = grouping.num_groups();
+        let entity_indices: &[u32] = grouping.group_indices().values();
+        let time = info.time_column().array_ref()?;
+        let time: &TimestampNanosecondArray = downcast_primitive_array(time.as_ref())?;
+
+        let extract = if self.variance {
+            EwmState::variance
+        } else {
+            EwmState::mean
+        };
+        let result = match &self.args {
+            AggregationArgs::NoWindow { input } => {
+                let input = info.value(input)?.primitive_array::<Float64Type>()?;
+                let ticks = BooleanArray::new_null(input.len());
+                aggregate_since(
+                    &mut self.token,
+                    key_capacity,
+                    entity_indices,
+                    time,
+                    input.as_ref(),
+                    &ticks,
+                    self.half_life,
+                    extract,
+                )
+            }
+            AggregationArgs::Since { input, ticks } => {
+                let input = info.value(input)?.primitive_array::<Float64Type>()?;
+                let ticks = info.value(ticks)?.boolean_array()?;
+                aggregate_since(
+                    &mut self.token,
+                    key_capacity,
+                    entity_indices,
+                    time,
+                    input.as_ref(),
+                    ticks.as_ref(),
+                    self.half_life,
+                    extract,
+                )
+            }
+            _ => anyhow::bail!("Exponentially-weighted aggregations only support 'since' windows"),
+        };
+        Ok(Arc::new(result))
+    }
+
+    fn state_token(&self) -> Option<&dyn StateToken> {
+        Some(&self.token)
+    }
+
+    fn state_token_mut(&mut self) -> Option<&mut dyn StateToken> {
+        Some(&mut self.token)
+    }
+}
+
+/// Update the states with the given inputs and return the results.
+///
+/// Accumulator behavior is to update -> emit -> reset, resulting in
+/// exclusive start bounds and inclusive end bounds.
+#[allow(clippy::too_many_arguments)]
+fn aggregate_since(
+    token: &mut EwmAccumToken,
+    key_capacity: usize,
+    entity_indices: &[u32],
+    time: &TimestampNanosecondArray,
+    input: &Float64Array,
+    ticks: &BooleanArray,
+    half_life: i64,
+    extract: fn(&EwmState) -> Option<f64>,
+) -> Float64Array {
+    assert_eq!(entity_indices.len(), input.len());
+    token.resize(key_capacity);
+
+    izip!(entity_indices, time.values(), input, ticks)
+        .map(|(entity_index, time, input, tick)| {
+            let state = token.get_mut(*entity_index);
+            if let Some(input) = input {
+                state.add(*time, input, half_life);
+            }
+
+            let result = extract(state);
+            if tick == Some(true) {
+                token.reset_value(*entity_index);
+            }
+            result
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ewma_since() {
+        let entity_indices = vec![0, 0, 1, 0, 0];
+        let time = TimestampNanosecondArray::from(vec![0, 10, 10, 20, 30]);
+        let input = Float64Array::from(vec![Some(8.0), Some(2.0), Some(5.0), None, Some(4.0)]);
+        let ticks = BooleanArray::from(vec![
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            None,
+        ]);
+
+        let mut token = EwmAccumToken::default();
+        let result = aggregate_since(
+            &mut token,
+            2,
+            &entity_indices,
+            &time,
+            &input,
+            &ticks,
+            10,
+            EwmState::mean,
+        );
+        assert_eq!(
+            result,
+            Float64Array::from(vec![Some(8.0), Some(4.0), Some(5.0), Some(4.0), Some(4.0)])
+        );
+
+        let mut token = EwmAccumToken::default();
+        let result = aggregate_since(
+            &mut token,
+            2,
+            &entity_indices,
+            &time,
+            &input,
+            &ticks,
+            10,
+            EwmState::variance,
+        );
+        assert_eq!(
+            result,
+            Float64Array::from(vec![None, Some(18.0), None, Some(18.0), None])
+        );
+    }
+}
@@ -2,12 +2,26 @@
 
 pub mod agg_fn;
 pub mod boolean_agg_fn;
+pub mod collect_agg_fn;
 pub mod count_agg_fn;
+pub mod count_distinct_agg_fn;
+pub mod ewm_state;
+pub mod hyper_log_log;
+pub mod percentile_agg_fn;
 pub mod primitive_agg_fn;
 pub mod string_agg_fn;
+pub mod t_digest;
+pub mod top_k_agg_fn;
 
 pub use agg_fn::*;
 pub use boolean_agg_fn::*;
+pub use collect_agg_fn::*;
 pub use count_agg_fn::*;
+pub use count_distinct_agg_fn::*;
+pub use ewm_state::*;
+pub use hyper_log_log::*;
+pub use percentile_agg_fn::*;
 pub use primitive_agg_fn::*;
 pub use string_agg_fn::*;
+pub use t_digest::*;
+pub use top_k_agg_fn::*;
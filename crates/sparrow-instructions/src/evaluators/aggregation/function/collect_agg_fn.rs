@@ -0,0 +1,41 @@
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use super::agg_fn::AggFn;
+
+/// Placeholder struct for the implementation of the [[AggFn]] for `collect`.
+///
+/// The accumulator holds the collected values from oldest to newest. Since the
+/// maximum number of values is not known to the aggregation function,
+/// `extract` produces all collected values.
+pub struct Collect<V>(PhantomData<fn(V) -> V>);
+
+impl<V> AggFn for Collect<V>
+where
+    V: Clone + Debug + Send + Sync,
+{
+    type InT = V;
+    type AccT = VecDeque<V>;
+    type OutT = Vec<V>;
+
+    fn zero() -> Self::AccT {
+        VecDeque::new()
+    }
+
+    fn merge(acc1: &mut Self::AccT, acc2: &Self::AccT) {
+        acc1.extend(acc2.iter().cloned())
+    }
+
+    fn extract(acc: &Self::AccT) -> Option<Self::OutT> {
+        Some(acc.iter().cloned().collect())
+    }
+
+    fn add_one(acc: &mut Self::AccT, input: &Self::InT) {
+        acc.push_back(input.clone())
+    }
+
+    fn name() -> &'static str {
+        "collect"
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+use super::agg_fn::AggFn;
+use super::hyper_log_log::HyperLogLog;
+
+/// Placeholder struct for the implementation of the [[AggFn]] for
+/// `count_distinct`.
+///
+/// Inputs are the 64-bit hashes of the values, so distinct values are
+/// identified by their hash. The accumulator is the set of hashes seen.
+pub struct CountDistinct;
+
+impl AggFn for CountDistinct {
+    type InT = u64;
+    type AccT = HashSet<u64>;
+    type OutT = u32;
+
+    fn zero() -> Self::AccT {
+        HashSet::new()
+    }
+
+    fn merge(acc1: &mut Self::AccT, acc2: &Self::AccT) {
+        acc1.extend(acc2)
+    }
+
+    fn extract(acc: &Self::AccT) -> Option<Self::OutT> {
+        Some(acc.len() as u32)
+    }
+
+    fn add_one(acc: &mut Self::AccT, input: &Self::InT) {
+        acc.insert(*input);
+    }
+
+    fn name() -> &'static str {
+        "count_distinct"
+    }
+}
+
+/// Placeholder struct for the implementation of the [[AggFn]] for
+/// `approx_count_distinct`.
+///
+/// Inputs are the 64-bit hashes of the values. The accumulator is a
+/// [HyperLogLog] sketch, which uses bounded memory regardless of the number
+/// of distinct values.
+pub struct ApproxCountDistinct;
+
+impl AggFn for ApproxCountDistinct {
+    type InT = u64;
+    type AccT = HyperLogLog;
+    type OutT = u32;
+
+    fn zero() -> Self::AccT {
+        HyperLogLog::default()
+    }
+
+    fn merge(acc1: &mut Self::AccT, acc2: &Self::AccT) {
+        acc1.merge(acc2)
+    }
+
+    fn extract(acc: &Self::AccT) -> Option<Self::OutT> {
+        Some(acc.estimate().min(u32::MAX as u64) as u32)
+    }
+
+    fn add_one(acc: &mut Self::AccT, input: &Self::InT) {
+        acc.add_hash(*input)
+    }
+
+    fn name() -> &'static str {
+        "approx_count_distinct"
+    }
+}
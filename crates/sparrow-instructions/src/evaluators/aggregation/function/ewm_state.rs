@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+
+/// The state of an exponentially-weighted moving average and variance.
+///
+/// Each input has a weight which halves every `half_life` nanoseconds of
+/// event time after the input. Rather than storing the inputs, the weighted
+/// mean and the weighted sum of squared deviations are maintained as of the
+/// time of the latest input, using a weighted form of Welford's algorithm.
+/// When a new input arrives, the existing weights are decayed by the time
+/// elapsed since the latest input.
+///
+/// Decaying all weights by the same factor changes neither the mean nor the
+/// variance, so the results only change when new inputs arrive.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct EwmState {
+    /// The time (in nanoseconds) of the latest input.
+    time: i64,
+    /// The sum of the weights of the inputs.
+    weight: f64,
+    /// The sum of the squared weights of the inputs.
+    ///
+    /// Used to correct for bias in the variance.
+    weight_squared: f64,
+    /// The weighted mean of the inputs.
+    mean: f64,
+    /// The weighted sum of squared deviations from the mean.
+    m2: f64,
+}
+
+impl EwmState {
+    /// Adds an input occurring at the given time.
+    ///
+    /// Inputs must be added in time order. The `half_life` is in nanoseconds.
+    pub fn add(&mut self, time: i64, value: f64, half_life: i64) {
+        if self.weight > 0.0 {
+            debug_assert!(self.time <= time, "inputs must be added in time order");
+            let elapsed = time.saturating_sub(self.time) as f64;
+            let decay = 0.5f64.powf(elapsed / half_life as f64);
+            self.weight *= decay;
+            self.weight_squared *= decay * decay;
+            self.m2 *= decay;
+        }
+
+        self.time = time;
+        self.weight += 1.0;
+        self.weight_squared += 1.0;
+        let delta = value - self.mean;
+        self.mean += delta / self.weight;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Returns the exponentially-weighted moving average, or `None` if no
+    /// inputs have been added.
+    pub fn mean(&self) -> Option<f64> {
+        (self.weight > 0.0).then_some(self.mean)
+    }
+
+    /// Returns the bias-corrected exponentially-weighted moving variance, or
+    /// `None` if fewer than two inputs have been added.
+    ///
+    /// If all inputs occur at the same time, this is the sample variance.
+    pub fn variance(&self) -> Option<f64> {
+        let effective_weight = self.weight - self.weight_squared / self.weight;
+        // Guard against rounding errors leaving a tiny effective weight when
+        // only one input contributes significantly.
+        if self.weight > 0.0 && effective_weight > f64::EPSILON * self.weight {
+            Some((self.m2 / effective_weight).max(0.0))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3_600_000_000_000;
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.expect("expected a value");
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "Expected {expected} but was {actual}"
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let state = EwmState::default();
+        assert_eq!(state.mean(), None);
+        assert_eq!(state.variance(), None);
+    }
+
+    #[test]
+    fn test_single_input() {
+        let mut state = EwmState::default();
+        state.add(HOUR, 5.0, HOUR);
+        assert_eq!(state.mean(), Some(5.0));
+        assert_eq!(state.variance(), None);
+    }
+
+    #[test]
+    fn test_decay_by_half_life() {
+        let mut state = EwmState::default();
+        state.add(0, 10.0, HOUR);
+        // After one half-life, the first input has half the weight.
+        state.add(HOUR, 4.0, HOUR);
+        assert_close(state.mean(), (0.5 * 10.0 + 4.0) / 1.5);
+
+        // After two more half-lives, the weights are 1/8, 1/4 and 1.
+        state.add(3 * HOUR, 1.0, HOUR);
+        assert_close(
+            state.mean(),
+            (0.125 * 10.0 + 0.25 * 4.0 + 1.0) / (0.125 + 0.25 + 1.0),
+        );
+    }
+
+    #[test]
+    fn test_variance() {
+        // Inputs at the same time have equal weights, giving the sample
+        // variance.
+        let mut state = EwmState::default();
+        for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            state.add(0, value, HOUR);
+        }
+        assert_close(state.mean(), 5.0);
+        assert_close(state.variance(), 32.0 / 7.0);
+
+        // With weights 1/2 and 1, the reliability-weighted variance is
+        // `sum(w (x - mean)^2) / (sum(w) - sum(w^2) / sum(w))`.
+        let mut state = EwmState::default();
+        state.add(0, 1.0, HOUR);
+        state.add(HOUR, 4.0, HOUR);
+        let mean = (0.5 * 1.0 + 4.0) / 1.5;
+        let m2 = 0.5 * (1.0 - mean) * (1.0 - mean) + (4.0 - mean) * (4.0 - mean);
+        assert_close(state.mean(), mean);
+        assert_close(state.variance(), m2 / (1.5 - 1.25 / 1.5));
+    }
+}
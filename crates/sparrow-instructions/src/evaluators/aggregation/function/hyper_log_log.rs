@@ -0,0 +1,222 @@
+use serde::{Deserialize, Serialize};
+
+/// The number of bits of the hash used to select a register.
+const PRECISION: u32 = 12;
+
+/// The number of registers in the dense representation.
+const NUM_REGISTERS: usize = 1 << PRECISION;
+
+/// The number of sparse entries at which the sketch switches to the dense
+/// representation.
+///
+/// Each sparse entry takes 3 bytes, compared to 1 byte per register, so
+/// switching at `m / 8` keeps the sparse representation considerably smaller
+/// than the dense one. This matters for trailing windows, which keep many
+/// small sketches per entity.
+const MAX_SPARSE: usize = NUM_REGISTERS / 8;
+
+/// The registers of the sketch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Registers {
+    /// Non-zero registers as `(index, value)` pairs, sorted by index.
+    Sparse(Vec<(u16, u8)>),
+    /// All registers, indexed by register.
+    Dense(Vec<u8>),
+}
+
+/// A mergeable sketch for estimating the number of distinct values, based on
+/// the HyperLogLog algorithm described by Flajolet et al.
+///
+/// Values are added by their 64-bit hash. The first `p = 12` bits of the hash
+/// select one of `m = 4096` registers, and each register records the largest
+/// number of leading zeros (plus one) seen in the remaining bits. This gives a
+/// standard error of approximately `1.04 / sqrt(m)`, or about 1.6%.
+///
+/// Small sketches are kept in a sparse representation, and converted to dense
+/// registers once they grow large enough.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HyperLogLog {
+    registers: Registers,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self {
+            registers: Registers::Sparse(Vec::new()),
+        }
+    }
+}
+
+impl HyperLogLog {
+    /// Adds a value to the sketch, given the hash of the value.
+    pub fn add_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - PRECISION)) as u16;
+        // The remaining bits, with a sentinel bit so the rank is bounded.
+        let remaining = (hash << PRECISION) | (1 << (PRECISION - 1));
+        let rank = remaining.leading_zeros() as u8 + 1;
+        self.update(index, rank);
+    }
+
+    /// Merges another sketch into this sketch.
+    ///
+    /// The result is the sketch of the union of the values added to each.
+    pub fn merge(&mut self, other: &HyperLogLog) {
+        match &other.registers {
+            Registers::Sparse(entries) => {
+                for (index, rank) in entries {
+                    self.update(*index, *rank);
+                }
+            }
+            Registers::Dense(other_registers) => {
+                let registers = self.dense_registers();
+                for (register, other) in registers.iter_mut().zip(other_registers) {
+                    *register = (*register).max(*other);
+                }
+            }
+        }
+    }
+
+    /// Returns the estimated number of distinct values added to the sketch.
+    pub fn estimate(&self) -> u64 {
+        let registers = match &self.registers {
+            // Until the sketch is dense, few enough registers are set that
+            // the estimate is given by linear counting.
+            Registers::Sparse(entries) => {
+                return linear_count(NUM_REGISTERS - entries.len()).round() as u64;
+            }
+            Registers::Dense(registers) => registers,
+        };
+
+        let m = NUM_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = registers.iter().map(|r| 2f64.powi(-(*r as i32))).sum();
+        let raw = alpha * m * m / sum;
+
+        let zeros = registers.iter().filter(|r| **r == 0).count();
+        let estimate = if raw <= 2.5 * m && zeros > 0 {
+            linear_count(zeros)
+        } else {
+            raw
+        };
+        estimate.round() as u64
+    }
+
+    fn update(&mut self, index: u16, rank: u8) {
+        match &mut self.registers {
+            Registers::Sparse(entries) => {
+                match entries.binary_search_by_key(&index, |(index, _)| *index) {
+                    Ok(position) => {
+                        let entry = &mut entries[position].1;
+                        *entry = (*entry).max(rank);
+                    }
+                    Err(position) => entries.insert(position, (index, rank)),
+                }
+                if entries.len() > MAX_SPARSE {
+                    self.dense_registers();
+                }
+            }
+            Registers::Dense(registers) => {
+                let register = &mut registers[index as usize];
+                *register = (*register).max(rank);
+            }
+        }
+    }
+
+    /// Converts the sketch to the dense representation, if it isn't already,
+    /// and returns the registers.
+    fn dense_registers(&mut self) -> &mut Vec<u8> {
+        if let Registers::Sparse(entries) = &self.registers {
+            let mut registers = vec![0; NUM_REGISTERS];
+            for (index, rank) in entries {
+                registers[*index as usize] = *rank;
+            }
+            self.registers = Registers::Dense(registers);
+        }
+
+        match &mut self.registers {
+            Registers::Dense(registers) => registers,
+            Registers::Sparse(_) => unreachable!("converted to dense"),
+        }
+    }
+}
+
+/// The linear counting estimate given the number of zero registers.
+fn linear_count(zeros: usize) -> f64 {
+    let m = NUM_REGISTERS as f64;
+    m * (m / zeros as f64).ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::UInt64Array;
+
+    use super::*;
+
+    fn sketch(values: impl Iterator<Item = u64>) -> HyperLogLog {
+        let values = UInt64Array::from_iter_values(values);
+        let hashes = sparrow_arrow::hash::hash(&values).unwrap();
+
+        let mut sketch = HyperLogLog::default();
+        for hash in hashes.values() {
+            sketch.add_hash(*hash);
+        }
+        sketch
+    }
+
+    fn assert_within(estimate: u64, expected: u64, tolerance: f64) {
+        let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+        assert!(
+            error <= tolerance,
+            "Estimate {estimate} not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(HyperLogLog::default().estimate(), 0);
+    }
+
+    #[test]
+    fn test_small_counts() {
+        // Duplicates don't change the estimate.
+        let sketch = sketch((0..10).chain(0..10));
+        assert_eq!(sketch.estimate(), 10);
+        assert!(matches!(sketch.registers, Registers::Sparse(_)));
+    }
+
+    #[test]
+    fn test_large_counts() {
+        for count in [1_000, 10_000, 100_000] {
+            let sketch = sketch(0..count);
+            assert!(matches!(sketch.registers, Registers::Dense(_)));
+            assert_within(sketch.estimate(), count, 0.05);
+        }
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut a = sketch(0..300);
+        let b = sketch(200..500);
+        a.merge(&b);
+        assert_within(a.estimate(), 500, 0.05);
+
+        let mut a = sketch(0..300);
+        let b = sketch(200..50_000);
+        a.merge(&b);
+        assert_within(a.estimate(), 50_000, 0.05);
+
+        let mut a = sketch(0..50_000);
+        let b = HyperLogLog::default();
+        a.merge(&b);
+        assert_eq!(a, sketch(0..50_000));
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        for sketch in [sketch(0..10), sketch(0..10_000)] {
+            let bytes = bincode::serialize(&sketch).unwrap();
+            let deserialized: HyperLogLog = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(sketch, deserialized);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use super::agg_fn::AggFn;
+use super::t_digest::TDigest;
+
+/// Placeholder struct for the implementation of the [[AggFn]] for
+/// `percentile` aggregation.
+///
+/// The accumulator is a [TDigest] sketch of the inputs. Since the percentile
+/// to compute is not known to the aggregation function, `extract` produces
+/// the median. Other percentiles may be computed from the accumulator using
+/// [TDigest::quantile].
+pub struct Percentile;
+
+impl AggFn for Percentile {
+    type InT = f64;
+    type AccT = TDigest;
+    type OutT = f64;
+
+    fn zero() -> Self::AccT {
+        TDigest::default()
+    }
+
+    fn merge(acc1: &mut Self::AccT, acc2: &Self::AccT) {
+        acc1.merge(acc2)
+    }
+
+    fn extract(acc: &Self::AccT) -> Option<Self::OutT> {
+        acc.quantile(0.5)
+    }
+
+    fn add_one(acc: &mut Self::AccT, input: &Self::InT) {
+        acc.add(*input)
+    }
+
+    fn name() -> &'static str {
+        "percentile"
+    }
+}
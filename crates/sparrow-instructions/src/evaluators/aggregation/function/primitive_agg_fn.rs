@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use arrow::datatypes::{ArrowPrimitiveType, Float64Type};
+use arrow::datatypes::{ArrowPrimitiveType, Decimal128Type, Float64Type};
 
 use super::agg_fn::{AggFn, ArrowAggFn};
 use crate::NumericProperties;
@@ -357,6 +357,79 @@ where
     }
 }
 
+/// Placeholder struct for the implementation of the [[AggFn]] for `mean`
+/// aggregation of decimals.
+///
+/// Unlike [Mean], this computes the exact sum and count and produces a
+/// decimal with the same scale as the input, rounding half away from zero.
+pub struct DecimalMean;
+
+impl ArrowAggFn for DecimalMean {
+    type InArrowT = Decimal128Type;
+    type OutArrowT = Decimal128Type;
+}
+
+impl AggFn for DecimalMean {
+    type InT = i128;
+    /// The sum (or `None` if it overflowed) and the count.
+    type AccT = Option<(Option<i128>, i128)>;
+    type OutT = i128;
+
+    fn zero() -> Self::AccT {
+        None
+    }
+
+    fn one(input: &Self::InT) -> Self::AccT {
+        Some((Some(*input), 1))
+    }
+
+    fn merge(acc1: &mut Self::AccT, acc2: &Self::AccT) {
+        if let Some((sum2, count2)) = acc2 {
+            if let Some((sum1, count1)) = acc1 {
+                *sum1 = sum1.zip(*sum2).and_then(|(a, b)| a.checked_add(b));
+                *count1 += count2;
+            } else {
+                *acc1 = *acc2
+            }
+        }
+    }
+
+    fn add_one(acc: &mut Self::AccT, input: &Self::InT) {
+        match acc {
+            Some((sum, count)) => {
+                *sum = sum.and_then(|sum| sum.checked_add(*input));
+                *count += 1;
+            }
+            None => *acc = Self::one(input),
+        }
+    }
+
+    fn extract(acc: &Self::AccT) -> Option<Self::OutT> {
+        acc.and_then(|(sum, count)| div_round_half_away(sum?, count))
+    }
+
+    fn name() -> &'static str {
+        "mean"
+    }
+}
+
+/// Divide `numerator` by `denominator`, rounding half away from zero.
+///
+/// Returns `None` if the denominator is zero or the result overflows.
+pub(crate) fn div_round_half_away(numerator: i128, denominator: i128) -> Option<i128> {
+    let quotient = numerator.checked_div(denominator)?;
+    let remainder = numerator.checked_rem(denominator)?;
+    if remainder.unsigned_abs() * 2 >= denominator.unsigned_abs() {
+        if (numerator < 0) != (denominator < 0) {
+            quotient.checked_sub(1)
+        } else {
+            quotient.checked_add(1)
+        }
+    } else {
+        Some(quotient)
+    }
+}
+
 /// Placeholder struct for the implementation of the [[AggFn]] for
 /// `variance` aggregation.
 ///
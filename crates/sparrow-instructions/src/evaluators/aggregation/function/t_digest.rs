@@ -0,0 +1,274 @@
+use std::f64::consts::PI;
+
+use serde::{Deserialize, Serialize};
+
+/// The compression parameter (δ) of the digest.
+///
+/// Larger values retain more centroids, improving accuracy at the cost of
+/// memory. The number of centroids retained after compression is
+/// approximately `δ / 2`.
+const COMPRESSION: f64 = 100.0;
+
+/// The number of centroids at which the digest is compressed.
+const MAX_CENTROIDS: usize = 2 * COMPRESSION as usize;
+
+/// A cluster of input values, summarized by their mean and count.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+impl Centroid {
+    fn absorb(&mut self, other: &Centroid) {
+        let weight = self.weight + other.weight;
+        self.mean += (other.mean - self.mean) * other.weight / weight;
+        self.weight = weight;
+    }
+}
+
+/// A mergeable sketch for estimating quantiles, based on the "merging"
+/// t-digest described by Dunning and Ertl.
+///
+/// Centroids are kept sorted by mean. Centroids near the tails of the
+/// distribution are limited to smaller weights than those near the median,
+/// so extreme quantiles are estimated with higher accuracy. Until more than
+/// `2δ` values have been added, every value is retained as its own centroid.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TDigest {
+    /// The centroids, sorted by mean.
+    centroids: Vec<Centroid>,
+    /// The total weight of all centroids.
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    /// Returns the number of values added to the digest.
+    pub fn count(&self) -> f64 {
+        self.count
+    }
+
+    /// Adds a single value to the digest.
+    ///
+    /// NaN values are ignored.
+    pub fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+
+        self.update_bounds(value, value);
+        self.count += 1.0;
+
+        let centroid = Centroid {
+            mean: value,
+            weight: 1.0,
+        };
+        let index = self.centroids.partition_point(|c| c.mean <= value);
+        self.centroids.insert(index, centroid);
+
+        if self.centroids.len() > MAX_CENTROIDS {
+            self.compress();
+        }
+    }
+
+    /// Merges the values of `other` into this digest.
+    pub fn merge(&mut self, other: &TDigest) {
+        if other.centroids.is_empty() {
+            return;
+        }
+
+        self.update_bounds(other.min, other.max);
+        self.count += other.count;
+
+        let mut merged = Vec::with_capacity(self.centroids.len() + other.centroids.len());
+        let mut left = std::mem::take(&mut self.centroids).into_iter().peekable();
+        let mut right = other.centroids.iter().copied().peekable();
+        loop {
+            let next = match (left.peek(), right.peek()) {
+                (Some(l), Some(r)) if l.mean <= r.mean => left.next(),
+                (Some(_), Some(_)) => right.next(),
+                (Some(_), None) => left.next(),
+                (None, Some(_)) => right.next(),
+                (None, None) => break,
+            };
+            merged.extend(next);
+        }
+        self.centroids = merged;
+
+        if self.centroids.len() > MAX_CENTROIDS {
+            self.compress();
+        }
+    }
+
+    /// Returns the estimated value at quantile `q`, between 0 and 1.
+    ///
+    /// Returns `None` if the digest is empty.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        let first = self.centroids.first()?;
+        if self.centroids.len() == 1 {
+            return Some(first.mean);
+        }
+
+        // Each centroid is treated as being centered at the middle of its
+        // weight, with values linearly interpolated between the centers. The
+        // minimum and maximum are at the ends of the distribution.
+        let target = q.clamp(0.0, 1.0) * self.count;
+        let mut previous_center = 0.0;
+        let mut previous_mean = self.min;
+        let mut weight_so_far = 0.0;
+        for centroid in &self.centroids {
+            let center = weight_so_far + centroid.weight / 2.0;
+            if target < center {
+                return Some(interpolate(
+                    (previous_center, previous_mean),
+                    (center, centroid.mean),
+                    target,
+                ));
+            }
+            previous_center = center;
+            previous_mean = centroid.mean;
+            weight_so_far += centroid.weight;
+        }
+
+        Some(interpolate(
+            (previous_center, previous_mean),
+            (self.count, self.max),
+            target,
+        ))
+    }
+
+    fn update_bounds(&mut self, min: f64, max: f64) {
+        if self.centroids.is_empty() {
+            self.min = min;
+            self.max = max;
+        } else {
+            self.min = self.min.min(min);
+            self.max = self.max.max(max);
+        }
+    }
+
+    /// Merges adjacent centroids, limiting the weight of each centroid
+    /// according to the `k1` scale function.
+    fn compress(&mut self) {
+        let centroids = std::mem::take(&mut self.centroids);
+        let mut centroids = centroids.into_iter();
+        let Some(mut current) = centroids.next() else {
+            return;
+        };
+
+        let mut weight_so_far = 0.0;
+        let mut weight_limit = self.count * q_limit(0.0);
+        for next in centroids {
+            if weight_so_far + current.weight + next.weight <= weight_limit {
+                current.absorb(&next);
+            } else {
+                weight_so_far += current.weight;
+                weight_limit = self.count * q_limit(weight_so_far / self.count);
+                self.centroids.push(current);
+                current = next;
+            }
+        }
+        self.centroids.push(current);
+    }
+}
+
+/// Returns the largest quantile that may be included in a centroid starting
+/// at quantile `q`.
+fn q_limit(q: f64) -> f64 {
+    // The `k1` scale function, k(q) = δ / 2π * asin(2q - 1), and its inverse.
+    let k = COMPRESSION / (2.0 * PI) * (2.0 * q - 1.0).asin();
+    (((k + 1.0) * 2.0 * PI / COMPRESSION).sin() + 1.0) / 2.0
+}
+
+fn interpolate((x0, y0): (f64, f64), (x1, y1): (f64, f64), x: f64) -> f64 {
+    if x1 <= x0 {
+        y1
+    } else {
+        y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        let digest = TDigest::default();
+        assert_eq!(digest.quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_exact_small() {
+        let mut digest = TDigest::default();
+        for value in [4.0, 1.0, 3.0, 2.0] {
+            digest.add(value);
+        }
+
+        assert_eq!(digest.quantile(0.0), Some(1.0));
+        assert_eq!(digest.quantile(0.5), Some(2.5));
+        assert_eq!(digest.quantile(1.0), Some(4.0));
+
+        digest.add(5.0);
+        assert_eq!(digest.quantile(0.5), Some(3.0));
+    }
+
+    #[test]
+    fn test_ignores_nan() {
+        let mut digest = TDigest::default();
+        digest.add(f64::NAN);
+        assert_eq!(digest.quantile(0.5), None);
+        digest.add(1.0);
+        assert_eq!(digest.quantile(0.5), Some(1.0));
+    }
+
+    #[test]
+    fn test_uniform() {
+        let mut digest = TDigest::default();
+        for value in 0..10_000 {
+            digest.add(value as f64);
+        }
+
+        assert!(digest.centroids.len() <= MAX_CENTROIDS);
+        assert_eq!(digest.count(), 10_000.0);
+        assert_eq!(digest.quantile(0.0), Some(0.0));
+        assert_eq!(digest.quantile(1.0), Some(9_999.0));
+        assert_relative_eq!(digest.quantile(0.5).unwrap(), 5_000.0, max_relative = 0.01);
+        assert_relative_eq!(digest.quantile(0.99).unwrap(), 9_900.0, max_relative = 0.01);
+        assert_relative_eq!(digest.quantile(0.01).unwrap(), 100.0, max_relative = 0.1);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut evens = TDigest::default();
+        let mut odds = TDigest::default();
+        for value in 0..5_000 {
+            evens.add((2 * value) as f64);
+            odds.add((2 * value + 1) as f64);
+        }
+
+        evens.merge(&odds);
+        assert!(evens.centroids.len() <= MAX_CENTROIDS);
+        assert_eq!(evens.count(), 10_000.0);
+        assert_eq!(evens.quantile(0.0), Some(0.0));
+        assert_eq!(evens.quantile(1.0), Some(9_999.0));
+        assert_relative_eq!(evens.quantile(0.5).unwrap(), 5_000.0, max_relative = 0.01);
+        assert_relative_eq!(evens.quantile(0.9).unwrap(), 9_000.0, max_relative = 0.01);
+    }
+
+    #[test]
+    fn test_merge_empty() {
+        let mut digest = TDigest::default();
+        digest.merge(&TDigest::default());
+        assert_eq!(digest.quantile(0.5), None);
+
+        let mut other = TDigest::default();
+        other.add(3.0);
+        digest.merge(&other);
+        assert_eq!(digest.quantile(0.5), Some(3.0));
+    }
+}
@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use super::agg_fn::AggFn;
+
+/// The number of times each distinct value has been seen.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "V: Serialize + Eq + Hash",
+    deserialize = "V: Deserialize<'de> + Eq + Hash"
+))]
+pub struct Frequencies<V> {
+    counts: HashMap<V, u32>,
+    /// The most frequent value, with ties broken by the smallest value.
+    ///
+    /// Since counts only increase, this is maintained as occurrences are
+    /// recorded rather than searching the counts for each result.
+    mode: Option<V>,
+}
+
+impl<V> Default for Frequencies<V> {
+    fn default() -> Self {
+        Self {
+            counts: HashMap::new(),
+            mode: None,
+        }
+    }
+}
+
+impl<V: Clone + Eq + Hash + Ord> Frequencies<V> {
+    /// Records an occurrence of the given value.
+    pub fn add(&mut self, value: &V) {
+        let count = match self.counts.get_mut(value) {
+            Some(count) => {
+                *count += 1;
+                *count
+            }
+            None => {
+                self.counts.insert(value.clone(), 1);
+                1
+            }
+        };
+        self.update_mode(value, count);
+    }
+
+    /// Adds the occurrences recorded in `other`.
+    pub fn merge(&mut self, other: &Frequencies<V>) {
+        for (value, count) in &other.counts {
+            let total = self.counts.entry(value.clone()).or_default();
+            *total += count;
+            let total = *total;
+            self.update_mode(value, total);
+        }
+    }
+
+    /// Returns the most frequent value, or `None` if nothing has been recorded.
+    pub fn mode(&self) -> Option<&V> {
+        self.mode.as_ref()
+    }
+
+    /// Returns up to `k` of the most frequent values, from most to least
+    /// frequent.
+    ///
+    /// Values with the same frequency are ordered from smallest to largest, so
+    /// the result is deterministic.
+    pub fn top(&self, k: usize) -> Vec<V> {
+        if k == 1 {
+            return self.mode.iter().cloned().collect();
+        }
+
+        let counts = self.counts.iter().map(|(value, count)| (value, *count));
+        top_counts(counts.collect(), k)
+    }
+
+    /// Returns up to `k` of the most frequent values in the merge of `self`
+    /// and `other`, ordered as in [Frequencies::top].
+    ///
+    /// This avoids copying the values to build the merged frequencies.
+    pub fn merged_top(&self, other: &Frequencies<V>, k: usize) -> Vec<V> {
+        let counts = self.counts.iter().map(|(value, count)| {
+            let other_count = other.counts.get(value).copied().unwrap_or_default();
+            (value, count + other_count)
+        });
+        let other_counts = other
+            .counts
+            .iter()
+            .filter(|(value, _)| !self.counts.contains_key(*value))
+            .map(|(value, count)| (value, *count));
+        top_counts(counts.chain(other_counts).collect(), k)
+    }
+
+    /// Updates the mode after `value` was recorded, bringing it to `count`.
+    fn update_mode(&mut self, value: &V, count: u32) {
+        let is_mode = match &self.mode {
+            Some(mode) => {
+                let mode_count = self.counts.get(mode).copied().unwrap_or_default();
+                count > mode_count || (count == mode_count && value < mode)
+            }
+            None => true,
+        };
+        if is_mode {
+            self.mode = Some(value.clone());
+        }
+    }
+}
+
+/// Returns up to `k` of the values with the highest counts, from highest to
+/// lowest count and then from smallest to largest value.
+///
+/// Only the `k` highest counts are sorted.
+fn top_counts<V: Clone + Ord>(mut counts: Vec<(&V, u32)>, k: usize) -> Vec<V> {
+    let order = |(v1, c1): &(&V, u32), (v2, c2): &(&V, u32)| c2.cmp(c1).then_with(|| v1.cmp(v2));
+    if k < counts.len() {
+        counts.select_nth_unstable_by(k, order);
+        counts.truncate(k);
+    }
+    counts.sort_unstable_by(order);
+    counts.into_iter().map(|(value, _)| value.clone()).collect()
+}
+
+/// Placeholder struct for the implementation of the [[AggFn]] for `top_k`.
+///
+/// The accumulator counts the occurrences of each value. Since the number of
+/// values to return is not known to the aggregation function, `extract`
+/// produces all values from most to least frequent. The most frequent `k`
+/// values may be computed from the accumulator using [Frequencies::top].
+pub struct TopK<V>(PhantomData<fn(V) -> V>);
+
+impl<V> AggFn for TopK<V>
+where
+    V: Clone + Debug + Eq + Hash + Ord + Send + Sync,
+{
+    type InT = V;
+    type AccT = Frequencies<V>;
+    type OutT = Vec<V>;
+
+    fn zero() -> Self::AccT {
+        Frequencies::default()
+    }
+
+    fn merge(acc1: &mut Self::AccT, acc2: &Self::AccT) {
+        acc1.merge(acc2)
+    }
+
+    fn extract(acc: &Self::AccT) -> Option<Self::OutT> {
+        Some(acc.top(usize::MAX))
+    }
+
+    fn add_one(acc: &mut Self::AccT, input: &Self::InT) {
+        acc.add(input)
+    }
+
+    fn name() -> &'static str {
+        "top_k"
+    }
+}
+
+/// Placeholder struct for the implementation of the [[AggFn]] for `mode`.
+///
+/// The accumulator counts the occurrences of each value, and `extract`
+/// produces the most frequent value.
+pub struct Mode<V>(PhantomData<fn(V) -> V>);
+
+impl<V> AggFn for Mode<V>
+where
+    V: Clone + Debug + Eq + Hash + Ord + Send + Sync,
+{
+    type InT = V;
+    type AccT = Frequencies<V>;
+    type OutT = V;
+
+    fn zero() -> Self::AccT {
+        Frequencies::default()
+    }
+
+    fn merge(acc1: &mut Self::AccT, acc2: &Self::AccT) {
+        acc1.merge(acc2)
+    }
+
+    fn extract(acc: &Self::AccT) -> Option<Self::OutT> {
+        acc.mode().cloned()
+    }
+
+    fn add_one(acc: &mut Self::AccT, input: &Self::InT) {
+        acc.add(input)
+    }
+
+    fn name() -> &'static str {
+        "mode"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frequencies(values: &[&'static str]) -> Frequencies<&'static str> {
+        let mut frequencies = Frequencies::default();
+        for value in values {
+            frequencies.add(value);
+        }
+        frequencies
+    }
+
+    #[test]
+    fn test_top() {
+        let frequencies = frequencies(&["b", "a", "c", "b", "c", "d", "c"]);
+        assert_eq!(frequencies.top(2), vec!["c", "b"]);
+        // Ties are broken by value.
+        assert_eq!(frequencies.top(3), vec!["c", "b", "a"]);
+        assert_eq!(frequencies.top(10), vec!["c", "b", "a", "d"]);
+        assert_eq!(Mode::<&str>::extract(&frequencies), Some("c"));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut a = frequencies(&["a", "b", "a"]);
+        a.merge(&frequencies(&["b", "b", "c"]));
+        assert_eq!(TopK::<&str>::extract(&a), Some(vec!["b", "a", "c"]));
+    }
+
+    #[test]
+    fn test_top_selects_k() {
+        let mut frequencies = Frequencies::default();
+        for value in 0..100 {
+            for _ in 0..(value % 7) {
+                frequencies.add(&value);
+            }
+        }
+        assert_eq!(frequencies.top(4), vec![6, 13, 20, 27]);
+        assert_eq!(frequencies.top(0), Vec::<i64>::new());
+    }
+
+    #[test]
+    fn test_mode_after_merge() {
+        let mut a = frequencies(&["c", "c", "a"]);
+        assert_eq!(a.mode(), Some(&"c"));
+
+        // A tie is broken by the smaller value, even when it is merged in.
+        a.merge(&frequencies(&["a", "b", "b", "b"]));
+        assert_eq!(a.mode(), Some(&"b"));
+        a.merge(&frequencies(&["c"]));
+        assert_eq!(a.mode(), Some(&"b"));
+        a.add(&"a");
+        assert_eq!(a.mode(), Some(&"a"));
+        assert_eq!(a.top(1), vec!["a"]);
+    }
+
+    #[test]
+    fn test_merged_top() {
+        let a = frequencies(&["a", "b", "a", "d"]);
+        let b = frequencies(&["b", "b", "c", "d"]);
+        assert_eq!(a.merged_top(&b, 1), vec!["b"]);
+        assert_eq!(a.merged_top(&b, 3), vec!["b", "a", "d"]);
+        assert_eq!(b.merged_top(&a, 10), vec!["b", "a", "d", "c"]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let frequencies = Frequencies::<i64>::default();
+        assert_eq!(frequencies.top(3), Vec::<i64>::new());
+        assert_eq!(Mode::<i64>::extract(&frequencies), None);
+    }
+}
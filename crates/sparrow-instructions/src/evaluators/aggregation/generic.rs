@@ -1,6 +1,8 @@
 //! Generic aggregation evaluators.
 
+mod count_distinct_evaluator;
 mod count_evaluator;
 mod two_stacks_count_evaluator;
 
+pub use count_distinct_evaluator::*;
 pub use count_evaluator::*;
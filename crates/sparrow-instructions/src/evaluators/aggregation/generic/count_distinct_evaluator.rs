@@ -0,0 +1,147 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, UInt32Array};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{
+    AggFn, AggregationArgs, Evaluator, EvaluatorFactory, StaticInfo, WindowedAggEvaluator,
+    WindowedAggregation,
+};
+
+/// Evaluator for the `count_distinct` and `approx_count_distinct`
+/// instructions.
+///
+/// Input values are hashed, and the hashes are accumulated by the aggregation
+/// function -- either [crate::CountDistinct] or [crate::ApproxCountDistinct].
+/// Since both accumulators are mergeable, this supports all windows.
+pub type CountDistinctEvaluator<AggF> = WindowedAggEvaluator<CountDistinctAggregation<AggF>>;
+
+/// Counts the distinct hashes of the inputs.
+pub struct CountDistinctAggregation<AggF>(PhantomData<fn(AggF) -> AggF>);
+
+impl<AggF> WindowedAggregation for CountDistinctAggregation<AggF>
+where
+    AggF: AggFn<InT = u64, OutT = u32> + 'static,
+{
+    type AggF = AggF;
+    type Output = Option<u32>;
+
+    fn read_inputs(&self, input: &ArrayRef) -> anyhow::Result<Vec<Option<u64>>> {
+        hash_valid(input.as_ref())
+    }
+
+    fn extract(&self, accum: &AggF::AccT) -> Option<u32> {
+        AggF::extract(accum)
+    }
+
+    fn write_outputs(&self, outputs: Vec<Option<u32>>) -> anyhow::Result<ArrayRef> {
+        Ok(Arc::new(UInt32Array::from(outputs)))
+    }
+}
+
+impl<AggF> EvaluatorFactory for CountDistinctEvaluator<AggF>
+where
+    AggF: AggFn<InT = u64, OutT = u32> + 'static,
+    AggF::AccT: Serialize + DeserializeOwned + Sync,
+{
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let args = AggregationArgs::from_input(info.args)?;
+        Ok(Box::new(Self::new(
+            args,
+            CountDistinctAggregation(PhantomData),
+        )))
+    }
+}
+
+/// Returns the hash of each non-`null` input, or `None` for `null` inputs.
+fn hash_valid(input: &dyn Array) -> anyhow::Result<Vec<Option<u64>>> {
+    let hashes = sparrow_arrow::hash::hash(input)?;
+    Ok(hashes
+        .values()
+        .iter()
+        .enumerate()
+        .map(|(index, hash)| input.is_valid(index).then_some(*hash))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{BooleanArray, StringArray};
+
+    use super::*;
+    use crate::{ApproxCountDistinct, CountDistinct};
+
+    fn hashes(values: Vec<Option<&str>>) -> Vec<Option<u64>> {
+        hash_valid(&StringArray::from(values)).unwrap()
+    }
+
+    #[test]
+    fn test_count_distinct_since() {
+        let entity_indices = vec![0, 0, 1, 0, 0, 0];
+        let input = hashes(vec![
+            Some("a"),
+            Some("b"),
+            Some("a"),
+            None,
+            Some("a"),
+            Some("a"),
+        ]);
+        let ticks = BooleanArray::from(vec![
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            None,
+            Some(false),
+        ]);
+
+        let mut accum = Vec::new();
+        let result = CountDistinctEvaluator::aggregate_since(
+            &CountDistinctAggregation::<CountDistinct>(PhantomData),
+            &mut accum,
+            2,
+            &entity_indices,
+            input.clone(),
+            &ticks,
+        );
+        assert_eq!(
+            result,
+            vec![Some(1), Some(2), Some(1), Some(2), Some(1), Some(1)]
+        );
+
+        let mut accum = Vec::new();
+        let result = CountDistinctEvaluator::aggregate_since(
+            &CountDistinctAggregation::<ApproxCountDistinct>(PhantomData),
+            &mut accum,
+            2,
+            &entity_indices,
+            input,
+            &ticks,
+        );
+        assert_eq!(
+            result,
+            vec![Some(1), Some(2), Some(1), Some(2), Some(1), Some(1)]
+        );
+    }
+
+    #[test]
+    fn test_count_distinct_sliding() {
+        let entity_indices = vec![0; 5];
+        let input = hashes(vec![Some("a"), Some("b"), Some("a"), Some("c"), Some("c")]);
+        let ticks = BooleanArray::from(vec![true, false, true, false, true]);
+
+        let mut accum = Vec::new();
+        let result = CountDistinctEvaluator::aggregate_sliding(
+            &CountDistinctAggregation::<CountDistinct>(PhantomData),
+            &mut accum,
+            1,
+            &entity_indices,
+            input,
+            &ticks,
+            2,
+        );
+        assert_eq!(result, vec![Some(1), Some(2), Some(2), Some(3), Some(3)]);
+    }
+}
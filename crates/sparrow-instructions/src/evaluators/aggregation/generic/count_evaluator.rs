@@ -8,8 +8,9 @@ use sparrow_plan::ValueRef;
 
 use super::two_stacks_count_evaluator::TwoStacksCountIfEvaluator;
 use crate::{
-    AggregationArgs, CountAccumToken, Evaluator, EvaluatorFactory, RuntimeInfo, StateToken,
-    StaticInfo, TwoStacksCountAccumToken,
+    read_count_if_inputs, write_primitive_outputs, AggregationArgs, Count, CountAccumToken,
+    Evaluator, EvaluatorFactory, RuntimeInfo, StateToken, StaticInfo, TrailingAggEvaluator,
+    TwoStacksCountAccumToken,
 };
 
 /// Evaluator for the `count_if` instruction.
@@ -45,8 +46,10 @@ impl Evaluator for CountIfEvaluator {
                 );
                 result
             }
-            AggregationArgs::Sliding { .. } => {
-                unreachable!("Expected Non-windowed or Since windowed aggregation, saw Sliding.")
+            AggregationArgs::Sliding { .. } | AggregationArgs::Trailing { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding or Trailing."
+                )
             }
         }
     }
@@ -72,6 +75,14 @@ impl EvaluatorFactory for CountIfEvaluator {
                 let token = TwoStacksCountAccumToken::new();
                 Ok(Box::new(TwoStacksCountIfEvaluator { token, args }))
             }
+            AggregationArgs::Trailing { input, duration } => {
+                TrailingAggEvaluator::<Count>::try_new(
+                    input,
+                    &duration,
+                    read_count_if_inputs,
+                    write_primitive_outputs::<UInt32Type>,
+                )
+            }
         }
     }
 }
@@ -119,3 +119,25 @@ impl NumericProperties for u64 {
         a.saturating_add(b)
     }
 }
+
+/// Used for the unscaled values of `Decimal128`.
+///
+/// Note that `as_f64` does not take the decimal scale into account.
+impl NumericProperties for i128 {
+    const ZERO: Self = 0;
+
+    fn max(a: Self, b: Self) -> Self {
+        a.max(b)
+    }
+
+    fn min(a: Self, b: Self) -> Self {
+        a.min(b)
+    }
+
+    fn as_f64(&self) -> f64 {
+        *self as f64
+    }
+    fn saturating_add(a: Self, b: Self) -> Self {
+        a.saturating_add(b)
+    }
+}
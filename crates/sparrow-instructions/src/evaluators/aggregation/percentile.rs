@@ -0,0 +1,5 @@
+//! Percentile aggregation evaluators.
+
+mod percentile_evaluator;
+
+pub use percentile_evaluator::*;
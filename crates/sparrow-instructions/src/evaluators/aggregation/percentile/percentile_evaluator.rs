@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Float64Array};
+use arrow::datatypes::Float64Type;
+use sparrow_arrow::scalar_value::ScalarValue;
+
+use crate::{
+    read_primitive_inputs, AggregationArgs, Evaluator, EvaluatorFactory, Percentile, StaticInfo,
+    TDigest, WindowedAggEvaluator, WindowedAggregation,
+};
+
+/// Evaluator for the `percentile` instruction.
+///
+/// The inputs are summarized in a [TDigest] sketch, from which the requested
+/// percentile is estimated. Since digests are mergeable, this supports all
+/// windows.
+pub type PercentileEvaluator = WindowedAggEvaluator<PercentileAggregation>;
+
+/// Estimates a percentile of the inputs.
+pub struct PercentileAggregation {
+    /// The quantile to compute, between 0 and 1.
+    quantile: f64,
+}
+
+impl WindowedAggregation for PercentileAggregation {
+    type AggF = Percentile;
+    type Output = Option<f64>;
+
+    fn read_inputs(&self, input: &ArrayRef) -> anyhow::Result<Vec<Option<f64>>> {
+        read_primitive_inputs::<Float64Type>(input)
+    }
+
+    fn extract(&self, accum: &TDigest) -> Option<f64> {
+        accum.quantile(self.quantile)
+    }
+
+    fn write_outputs(&self, outputs: Vec<Option<f64>>) -> anyhow::Result<ArrayRef> {
+        Ok(Arc::new(Float64Array::from(outputs)))
+    }
+}
+
+impl EvaluatorFactory for PercentileEvaluator {
+    fn try_new(mut info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        // The percentile follows the input: [input, percentile, ticks, duration]
+        anyhow::ensure!(
+            info.args.len() == 4,
+            "Percentile should have 4 arguments. Saw {:?}",
+            info.args.len()
+        );
+        let percentile = info.args.remove(1);
+        let percentile = match percentile.value_ref.literal_value() {
+            Some(ScalarValue::Float64(Some(p))) if (0.0..=100.0).contains(&p.into_inner()) => {
+                p.into_inner()
+            }
+            unexpected => anyhow::bail!(
+                "Expected literal percentile between 0 and 100, saw {:?}",
+                unexpected
+            ),
+        };
+
+        let args = AggregationArgs::from_input(info.args)?;
+        Ok(Box::new(WindowedAggEvaluator::new(
+            args,
+            PercentileAggregation {
+                quantile: percentile / 100.0,
+            },
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::BooleanArray;
+
+    use super::*;
+
+    #[test]
+    fn test_percentile_since() {
+        let entity_indices = vec![0, 0, 1, 0, 0, 0];
+        let input = vec![Some(1.0), Some(3.0), Some(10.0), None, Some(2.0), Some(5.0)];
+        let ticks = BooleanArray::from(vec![
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            None,
+            Some(false),
+        ]);
+
+        let mut accum = Vec::new();
+        let result = PercentileEvaluator::aggregate_since(
+            &PercentileAggregation { quantile: 0.5 },
+            &mut accum,
+            2,
+            &entity_indices,
+            input,
+            &ticks,
+        );
+        assert_eq!(
+            result,
+            vec![
+                Some(1.0),
+                Some(2.0),
+                Some(10.0),
+                Some(2.0),
+                Some(2.0),
+                Some(3.5)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_percentile_sliding() {
+        let entity_indices = vec![0; 5];
+        let input = vec![Some(1.0), Some(5.0), Some(3.0), Some(4.0), Some(2.0)];
+        let ticks = BooleanArray::from(vec![true, false, true, false, true]);
+
+        let mut accum = Vec::new();
+        let result = PercentileEvaluator::aggregate_sliding(
+            &PercentileAggregation { quantile: 0.0 },
+            &mut accum,
+            1,
+            &entity_indices,
+            input,
+            &ticks,
+            2,
+        );
+        assert_eq!(
+            result,
+            vec![Some(1.0), Some(1.0), Some(1.0), Some(3.0), Some(2.0)]
+        );
+    }
+}
@@ -11,8 +11,9 @@ use sparrow_plan::ValueRef;
 use super::two_stacks_arrow_agg_evaluator::TwoStacksArrowAggEvaluator;
 use crate::evaluators::aggregation::function::agg_fn::ArrowAggFn;
 use crate::{
-    AggregationArgs, Evaluator, EvaluatorFactory, PrimitiveAccumToken, RuntimeInfo, StateToken,
-    StaticInfo, TwoStacksPrimitiveAccumToken,
+    read_primitive_inputs, write_primitive_outputs, AggregationArgs, Evaluator, EvaluatorFactory,
+    PrimitiveAccumToken, RuntimeInfo, StateToken, StaticInfo, TrailingAggEvaluator,
+    TwoStacksPrimitiveAccumToken,
 };
 
 /// Evaluator for arrow aggregations.
@@ -76,8 +77,10 @@ where
 
                 result
             }
-            AggregationArgs::Sliding { .. } => {
-                unreachable!("Expected Non-windowed or Since windowed aggregation, saw Sliding.")
+            AggregationArgs::Sliding { .. } | AggregationArgs::Trailing { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding or Trailing."
+                )
             }
         }
     }
@@ -108,6 +111,12 @@ where
                 let token = TwoStacksPrimitiveAccumToken::new();
                 Ok(Box::new(TwoStacksArrowAggEvaluator::<AggF> { token, args }))
             }
+            AggregationArgs::Trailing { input, duration } => TrailingAggEvaluator::<AggF>::try_new(
+                input,
+                &duration,
+                read_primitive_inputs::<AggF::InArrowT>,
+                write_primitive_outputs::<AggF::OutArrowT>,
+            ),
         }
     }
 }
@@ -75,7 +75,9 @@ where
 
                 result
             }
-            AggregationArgs::NoWindow { .. } | AggregationArgs::Since { .. } => {
+            AggregationArgs::NoWindow { .. }
+            | AggregationArgs::Since { .. }
+            | AggregationArgs::Trailing { .. } => {
                 unreachable!(
                     "Expected Sliding Window aggregation, saw Non-windowed or Since window \
                      aggregation."
@@ -7,8 +7,9 @@ use sparrow_plan::ValueRef;
 
 use super::two_stacks_first_string_evaluator::TwoStacksFirstStringEvaluator;
 use crate::{
-    AggregationArgs, Evaluator, EvaluatorFactory, RuntimeInfo, StateToken, StaticInfo,
-    StringAccumToken, TwoStacksStringAccumToken,
+    read_string_inputs, write_string_outputs, AggregationArgs, Evaluator, EvaluatorFactory,
+    FirstString, RuntimeInfo, StateToken, StaticInfo, StringAccumToken, TrailingAggEvaluator,
+    TwoStacksStringAccumToken,
 };
 
 /// Evaluator for the `First` instruction on strings.
@@ -46,8 +47,10 @@ impl Evaluator for FirstStringEvaluator {
 
                 result
             }
-            AggregationArgs::Sliding { .. } => {
-                unreachable!("Expected Non-windowed or Since windowed aggregation, saw Sliding.")
+            AggregationArgs::Sliding { .. } | AggregationArgs::Trailing { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding or Trailing."
+                )
             }
         }
     }
@@ -73,6 +76,14 @@ impl EvaluatorFactory for FirstStringEvaluator {
                 let token = TwoStacksStringAccumToken::new();
                 Ok(Box::new(TwoStacksFirstStringEvaluator { token, args }))
             }
+            AggregationArgs::Trailing { input, duration } => {
+                TrailingAggEvaluator::<FirstString>::try_new(
+                    input,
+                    &duration,
+                    read_string_inputs,
+                    write_string_outputs,
+                )
+            }
         }
     }
 }
@@ -2,9 +2,11 @@
 
 mod boolean_accum_token;
 mod count_accum_token;
+mod ewm_accum_token;
 pub mod lag_token;
 mod primitive_accum_token;
 mod string_accum_token;
+mod trailing_accum_token;
 mod two_stacks_boolean_accum_token;
 mod two_stacks_count_accum_token;
 mod two_stacks_primitive_accum_token;
@@ -12,8 +14,10 @@ mod two_stacks_string_accum_token;
 
 pub use boolean_accum_token::*;
 pub use count_accum_token::*;
+pub use ewm_accum_token::*;
 pub use primitive_accum_token::*;
 pub use string_accum_token::*;
+pub use trailing_accum_token::*;
 pub use two_stacks_boolean_accum_token::*;
 pub use two_stacks_count_accum_token::*;
 pub use two_stacks_primitive_accum_token::*;
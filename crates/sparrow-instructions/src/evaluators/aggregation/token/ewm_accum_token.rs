@@ -0,0 +1,36 @@
+use crate::{ComputeStore, EwmState, StateToken, StoreKey};
+
+/// Token used for exponentially-weighted moving aggregations.
+///
+/// Values are stored as `[pass_id, instruction_id] -> Vec<EwmState>`.
+#[derive(Default)]
+pub struct EwmAccumToken {
+    /// Stores the state for in-memory usage.
+    accum: Vec<EwmState>,
+}
+
+impl StateToken for EwmAccumToken {
+    fn restore(&mut self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        store.get_to_vec(key, &mut self.accum)
+    }
+
+    fn store(&self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        store.put(key, &self.accum)
+    }
+}
+
+impl EwmAccumToken {
+    pub(crate) fn resize(&mut self, len: usize) {
+        if len > self.accum.len() {
+            self.accum.resize(len, EwmState::default());
+        }
+    }
+
+    pub(crate) fn get_mut(&mut self, entity_index: u32) -> &mut EwmState {
+        &mut self.accum[entity_index as usize]
+    }
+
+    pub(crate) fn reset_value(&mut self, entity_index: u32) {
+        self.accum[entity_index as usize] = EwmState::default();
+    }
+}
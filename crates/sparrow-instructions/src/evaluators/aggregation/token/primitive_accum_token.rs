@@ -12,7 +12,6 @@ use crate::{ComputeStore, StateToken, StoreKey};
 /// storing the entire accum in memory. For other aggregations, the
 /// initial intention is to read/modify/write each individual value into
 /// persistent storage.
-#[derive(Default)]
 pub struct PrimitiveAccumToken<T> {
     /// Stores the state for in-memory usage.
     accum: Vec<T>,
@@ -20,6 +19,16 @@ pub struct PrimitiveAccumToken<T> {
     _phantom: PhantomData<fn(T) -> T>,
 }
 
+// Implemented manually, since deriving would require `T: Default`.
+impl<T> Default for PrimitiveAccumToken<T> {
+    fn default() -> Self {
+        Self {
+            accum: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
 impl<T> StateToken for PrimitiveAccumToken<T>
 where
     Vec<T>: serde::ser::Serialize + serde::de::DeserializeOwned + std::fmt::Debug,
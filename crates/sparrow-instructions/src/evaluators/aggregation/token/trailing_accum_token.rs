@@ -0,0 +1,45 @@
+use crate::{AggFn, ComputeStore, StateToken, StoreKey, TimedTwoStacks};
+
+/// Key used for accumulators over a trailing duration.
+///
+/// Stored as `[pass_id, inst_id] -> Vec<TimedTwoStacks<AggF>>`
+pub struct TrailingAccumToken<AggF>
+where
+    AggF: AggFn,
+{
+    /// Stores the state for in-memory usage.
+    accum: Vec<TimedTwoStacks<AggF>>,
+}
+
+impl<AggF> StateToken for TrailingAccumToken<AggF>
+where
+    AggF: AggFn,
+    Vec<TimedTwoStacks<AggF>>: serde::ser::Serialize + serde::de::DeserializeOwned,
+{
+    fn restore(&mut self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        store.get_to_vec(key, &mut self.accum)
+    }
+
+    fn store(&self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        store.put(key, &self.accum)
+    }
+}
+
+impl<AggF> TrailingAccumToken<AggF>
+where
+    AggF: AggFn,
+{
+    pub(crate) fn new() -> Self {
+        Self { accum: Vec::new() }
+    }
+
+    pub(crate) fn resize(&mut self, len: usize) {
+        if len > self.accum.len() {
+            self.accum.resize(len, TimedTwoStacks::default());
+        }
+    }
+
+    pub(crate) fn get_mut(&mut self, key: u32) -> &mut TimedTwoStacks<AggF> {
+        &mut self.accum[key as usize]
+    }
+}
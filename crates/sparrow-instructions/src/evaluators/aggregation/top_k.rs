@@ -0,0 +1,5 @@
+//! Top-k and mode aggregation evaluators.
+
+mod top_k_evaluator;
+
+pub use top_k_evaluator::*;
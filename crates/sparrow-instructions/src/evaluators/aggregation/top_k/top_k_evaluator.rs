@@ -0,0 +1,184 @@
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, ListArray};
+use arrow::buffer::OffsetBuffer;
+use arrow::datatypes::DataType;
+use sparrow_arrow::scalar_value::ScalarValue;
+use sparrow_plan::{InstKind, InstOp};
+
+use crate::{
+    AggFn, AggregationArgs, CollectType, Evaluator, EvaluatorFactory, Frequencies, Mode,
+    StaticInfo, TopK, WindowedAggEvaluator, WindowedAggregation,
+};
+
+pub type TopKEvaluator<C> = FrequencyEvaluator<C, TopK<<C as CollectType>::Native>>;
+pub type ModeEvaluator<C> = FrequencyEvaluator<C, Mode<<C as CollectType>::Native>>;
+
+/// Evaluator for the `top_k` and `mode` instructions.
+///
+/// The occurrences of each value are counted in [Frequencies], from which the
+/// most frequent values are reported. Since frequencies are mergeable, this
+/// supports all windows.
+pub type FrequencyEvaluator<C, AggF> = WindowedAggEvaluator<FrequencyAggregation<C, AggF>>;
+
+/// Reports the most frequent inputs.
+pub struct FrequencyAggregation<C, AggF> {
+    /// The number of values to report for `top_k`, or `None` for `mode`.
+    k: Option<usize>,
+    result_type: DataType,
+    _phantom: PhantomData<fn(C) -> AggF>,
+}
+
+impl<C, AggF> WindowedAggregation for FrequencyAggregation<C, AggF>
+where
+    C: CollectType,
+    C::Native: Eq + Hash + Ord,
+    AggF: AggFn<InT = C::Native, AccT = Frequencies<C::Native>> + 'static,
+{
+    type AggF = AggF;
+    type Output = Vec<C::Native>;
+
+    fn read_inputs(&self, input: &ArrayRef) -> anyhow::Result<Vec<Option<C::Native>>> {
+        C::values(input.as_ref())
+    }
+
+    fn extract(&self, accum: &Frequencies<C::Native>) -> Vec<C::Native> {
+        accum.top(self.k.unwrap_or(1))
+    }
+
+    fn extract_merged(
+        &self,
+        older: &Frequencies<C::Native>,
+        newer: &Frequencies<C::Native>,
+    ) -> Vec<C::Native> {
+        older.merged_top(newer, self.k.unwrap_or(1))
+    }
+
+    /// Create the result array from the most frequent values for each row.
+    ///
+    /// For `top_k` this is a list of the values. For `mode` this is the most
+    /// frequent value, or `null` if there were no values.
+    fn write_outputs(&self, outputs: Vec<Vec<C::Native>>) -> anyhow::Result<ArrayRef> {
+        if self.k.is_none() {
+            let values = outputs.into_iter().map(|top| top.into_iter().next());
+            return Ok(C::to_nullable_array(&self.result_type, values.collect()));
+        }
+
+        let field = match &self.result_type {
+            DataType::List(field) => field.clone(),
+            unexpected => anyhow::bail!("Expected list result for top_k, saw {:?}", unexpected),
+        };
+        let offsets = OffsetBuffer::from_lengths(outputs.iter().map(Vec::len));
+        let values = C::to_array(field.data_type(), outputs.into_iter().flatten().collect());
+        Ok(Arc::new(ListArray::try_new(field, offsets, values, None)?))
+    }
+}
+
+impl<C, AggF> EvaluatorFactory for FrequencyEvaluator<C, AggF>
+where
+    C: CollectType,
+    C::Native: Eq + Hash + Ord,
+    AggF: AggFn<InT = C::Native, AccT = Frequencies<C::Native>> + 'static,
+{
+    fn try_new(mut info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let k = match info.inst_kind {
+            InstKind::Simple(InstOp::TopK) => {
+                // The k follows the input: [input, k, ticks, duration]
+                anyhow::ensure!(
+                    info.args.len() == 4,
+                    "TopK should have 4 arguments. Saw {:?}",
+                    info.args.len()
+                );
+                let k = info.args.remove(1);
+                match k.value_ref.literal_value() {
+                    Some(ScalarValue::Int64(Some(k))) if *k > 0 => Some(*k as usize),
+                    unexpected => {
+                        anyhow::bail!(
+                            "Expected positive literal k for top_k, saw {:?}",
+                            unexpected
+                        )
+                    }
+                }
+            }
+            InstKind::Simple(InstOp::Mode) => None,
+            unexpected => anyhow::bail!("Unexpected frequency aggregation {unexpected:?}"),
+        };
+
+        let result_type = info.result_type.clone();
+        let args = AggregationArgs::from_input(info.args)?;
+        Ok(Box::new(Self::new(
+            args,
+            FrequencyAggregation {
+                k,
+                result_type,
+                _phantom: PhantomData,
+            },
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::BooleanArray;
+    use arrow::datatypes::Int64Type;
+
+    use super::*;
+    use crate::CollectPrimitive;
+
+    fn top_k(k: usize) -> FrequencyAggregation<CollectPrimitive<Int64Type>, TopK<i64>> {
+        FrequencyAggregation {
+            k: Some(k),
+            result_type: DataType::Int64,
+            _phantom: PhantomData,
+        }
+    }
+
+    #[test]
+    fn test_top_k_since() {
+        let entity_indices = vec![0, 0, 1, 0, 0, 0];
+        let input = vec![Some(1), Some(2), Some(3), Some(2), None, Some(1)];
+        let ticks = BooleanArray::from(vec![
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            None,
+            Some(false),
+        ]);
+
+        let mut accum = Vec::new();
+        let result = TopKEvaluator::aggregate_since(
+            &top_k(2),
+            &mut accum,
+            2,
+            &entity_indices,
+            input,
+            &ticks,
+        );
+        assert_eq!(
+            result,
+            vec![vec![1], vec![1, 2], vec![3], vec![2, 1], vec![], vec![1]]
+        );
+    }
+
+    #[test]
+    fn test_top_k_sliding() {
+        let entity_indices = vec![0; 5];
+        let input = vec![Some(1), Some(2), Some(2), Some(3), Some(3)];
+        let ticks = BooleanArray::from(vec![true, false, true, false, true]);
+
+        let mut accum = Vec::new();
+        let result = TopKEvaluator::aggregate_sliding(
+            &top_k(1),
+            &mut accum,
+            1,
+            &entity_indices,
+            input,
+            &ticks,
+            2,
+        );
+        assert_eq!(result, vec![vec![1], vec![1], vec![2], vec![2], vec![2]]);
+    }
+}
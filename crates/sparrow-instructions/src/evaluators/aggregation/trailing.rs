@@ -0,0 +1,5 @@
+//! Aggregation evaluators over a trailing duration.
+
+mod trailing_agg_evaluator;
+
+pub use trailing_agg_evaluator::*;
@@ -0,0 +1,260 @@
+use std::sync::Arc;
+
+use arrow::array::{
+    ArrayRef, BooleanArray, PrimitiveArray, StringArray, TimestampNanosecondArray, UInt32Array,
+};
+use arrow::datatypes::ArrowPrimitiveType;
+use itertools::izip;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sparrow_arrow::downcast::{
+    downcast_boolean_array, downcast_primitive_array, downcast_string_array,
+};
+use sparrow_arrow::scalar_value::ScalarValue;
+use sparrow_plan::ValueRef;
+
+use crate::{AggFn, Evaluator, RuntimeInfo, StateToken, TimedTwoStacks, TrailingAccumToken};
+
+/// Reads the inputs of a trailing aggregation from an array.
+pub type ReadInputs<AggF> = fn(&ArrayRef) -> anyhow::Result<Vec<Option<<AggF as AggFn>::InT>>>;
+
+/// Creates the result array of a trailing aggregation.
+pub type WriteOutputs<AggF> = fn(Vec<Option<<AggF as AggFn>::OutT>>) -> ArrayRef;
+
+/// Evaluator for aggregations over a trailing duration.
+///
+/// The result for each row aggregates the inputs for the same entity that
+/// occurred within the trailing `duration` of the row. Inputs occurring
+/// exactly `duration` before the row are excluded, resulting in exclusive
+/// start bounds and inclusive end bounds.
+///
+/// This evaluator is specialized for aggregation functions `AggF`, with the
+/// conversion to and from Arrow arrays provided by the caller.
+pub struct TrailingAggEvaluator<AggF: AggFn> {
+    input: ValueRef,
+    /// The trailing duration, in nanoseconds.
+    duration: i64,
+    token: TrailingAccumToken<AggF>,
+    read_inputs: ReadInputs<AggF>,
+    write_outputs: WriteOutputs<AggF>,
+}
+
+impl<AggF> TrailingAggEvaluator<AggF>
+where
+    AggF: AggFn + 'static,
+    AggF::AccT: Serialize + DeserializeOwned + Sync,
+{
+    pub(crate) fn try_new(
+        input: ValueRef,
+        duration: &ValueRef,
+        read_inputs: ReadInputs<AggF>,
+        write_outputs: WriteOutputs<AggF>,
+    ) -> anyhow::Result<Box<dyn Evaluator>> {
+        Ok(Box::new(Self {
+            input,
+            duration: trailing_duration(duration)?,
+            token: TrailingAccumToken::new(),
+            read_inputs,
+            write_outputs,
+        }))
+    }
+}
+
+impl<AggF> Evaluator for TrailingAggEvaluator<AggF>
+where
+    AggF: AggFn,
+    AggF::AccT: Serialize + DeserializeOwned + Sync,
+{
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let grouping = info.grouping();
+        let input = info.value(&self.input)?.array_ref()?;
+        let time = info.time_column().array_ref()?;
+        let time: &TimestampNanosecondArray = downcast_primitive_array(time.as_ref())?;
+
+        let inputs = (self.read_inputs)(&input)?;
+        let outputs = Self::aggregate(
+            &mut self.token,
+            grouping.num_groups(),
+            grouping.group_indices(),
+            time,
+            inputs,
+            self.duration,
+            |_| {},
+            |stacks| AggF::extract(&stacks.accum_value()),
+        );
+        Ok((self.write_outputs)(outputs))
+    }
+
+    fn state_token(&self) -> Option<&dyn StateToken> {
+        Some(&self.token)
+    }
+
+    fn state_token_mut(&mut self) -> Option<&mut dyn StateToken> {
+        Some(&mut self.token)
+    }
+}
+
+impl<AggF: AggFn> TrailingAggEvaluator<AggF> {
+    /// Update the aggregation state with the given inputs and return the
+    /// result of `extract` on the trailing window for each row.
+    ///
+    /// The `compact` function is applied to each updated or merged accumulator,
+    /// and may discard anything that can no longer affect the result.
+    ///
+    /// The `key_capacity` must be greater than all values in the
+    /// `key_indices`.
+    ///
+    /// # Assumptions
+    /// This assumes that the input data has been sorted by occurrence time.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn aggregate<O>(
+        token: &mut TrailingAccumToken<AggF>,
+        key_capacity: usize,
+        key_indices: &UInt32Array,
+        time: &TimestampNanosecondArray,
+        inputs: Vec<Option<AggF::InT>>,
+        duration: i64,
+        compact: impl Fn(&mut AggF::AccT),
+        extract: impl Fn(&TimedTwoStacks<AggF>) -> O,
+    ) -> Vec<O> {
+        assert_eq!(key_indices.len(), inputs.len());
+        assert_eq!(time.len(), inputs.len());
+        token.resize(key_capacity);
+
+        izip!(key_indices.values(), time.values(), inputs)
+            .map(|(entity_index, time, input)| {
+                let stacks = token.get_mut(*entity_index);
+                stacks.evict_through_with(time.saturating_sub(duration), &compact);
+                if let Some(input) = input {
+                    stacks.add_input_with(*time, &input, &compact);
+                }
+                extract(stacks)
+            })
+            .collect()
+    }
+}
+
+/// Returns the duration of a trailing window, in nanoseconds.
+pub(crate) fn trailing_duration(duration: &ValueRef) -> anyhow::Result<i64> {
+    match duration.literal_value() {
+        Some(ScalarValue::Int64(Some(duration))) if *duration > 0 => Ok(*duration),
+        unexpected => anyhow::bail!(
+            "Expected positive literal duration for trailing window, saw {:?}",
+            unexpected
+        ),
+    }
+}
+
+/// Reads the non-null values of a primitive array.
+pub fn read_primitive_inputs<T: ArrowPrimitiveType>(
+    array: &ArrayRef,
+) -> anyhow::Result<Vec<Option<T::Native>>> {
+    let array: &PrimitiveArray<T> = downcast_primitive_array(array.as_ref())?;
+    Ok(array.iter().collect())
+}
+
+/// Creates a primitive array from the outputs.
+pub fn write_primitive_outputs<T: ArrowPrimitiveType>(outputs: Vec<Option<T::Native>>) -> ArrayRef {
+    Arc::new(outputs.into_iter().collect::<PrimitiveArray<T>>())
+}
+
+/// Reads the inputs of `count_if`, counting each `true` input once.
+pub fn read_count_if_inputs(array: &ArrayRef) -> anyhow::Result<Vec<Option<u32>>> {
+    let array = downcast_boolean_array(array.as_ref())?;
+    Ok(array
+        .iter()
+        .map(|input| (input == Some(true)).then_some(1))
+        .collect())
+}
+
+/// Reads the non-null values of a boolean array.
+pub fn read_boolean_inputs(array: &ArrayRef) -> anyhow::Result<Vec<Option<bool>>> {
+    let array = downcast_boolean_array(array.as_ref())?;
+    Ok(array.iter().collect())
+}
+
+/// Creates a boolean array from the outputs.
+pub fn write_boolean_outputs(outputs: Vec<Option<bool>>) -> ArrayRef {
+    Arc::new(BooleanArray::from(outputs))
+}
+
+/// Reads the non-null values of a string array.
+pub fn read_string_inputs(array: &ArrayRef) -> anyhow::Result<Vec<Option<String>>> {
+    let array: &StringArray = downcast_string_array(array.as_ref())?;
+    Ok(array.iter().map(|s| s.map(|s| s.to_owned())).collect())
+}
+
+/// Creates a string array from the outputs.
+pub fn write_string_outputs(outputs: Vec<Option<String>>) -> ArrayRef {
+    Arc::new(outputs.into_iter().collect::<StringArray>())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::datatypes::{Int64Type, UInt32Type};
+
+    use super::*;
+    use crate::{Count, Sum};
+
+    #[test]
+    fn test_trailing_sum() {
+        let key_indices = UInt32Array::from(vec![0, 1, 0, 0, 1, 0]);
+        let time = TimestampNanosecondArray::from(vec![0, 1, 5, 10, 11, 15]);
+        let inputs = vec![Some(1), Some(10), Some(2), None, Some(20), Some(3)];
+
+        let mut token = TrailingAccumToken::<Sum<Int64Type>>::new();
+        let outputs = TrailingAggEvaluator::<Sum<Int64Type>>::aggregate(
+            &mut token,
+            2,
+            &key_indices,
+            &time,
+            inputs,
+            10,
+            |_| {},
+            |stacks| Sum::<Int64Type>::extract(&stacks.accum_value()),
+        );
+
+        // At time 10, the input at time 0 is no longer within the window.
+        // At time 15, the input at time 5 is no longer within the window.
+        assert_eq!(
+            outputs,
+            vec![Some(1), Some(10), Some(3), Some(2), Some(20), Some(3)]
+        );
+    }
+
+    #[test]
+    fn test_trailing_count_across_batches() {
+        let mut token = TrailingAccumToken::<Count>::new();
+
+        let outputs = TrailingAggEvaluator::<Count>::aggregate(
+            &mut token,
+            1,
+            &UInt32Array::from(vec![0, 0]),
+            &TimestampNanosecondArray::from(vec![0, 3]),
+            vec![Some(1), Some(1)],
+            5,
+            |_| {},
+            |stacks| Count::extract(&stacks.accum_value()),
+        );
+        assert_eq!(outputs, vec![Some(1), Some(2)]);
+
+        let outputs = TrailingAggEvaluator::<Count>::aggregate(
+            &mut token,
+            1,
+            &UInt32Array::from(vec![0, 0]),
+            &TimestampNanosecondArray::from(vec![6, 9]),
+            vec![None, Some(1)],
+            5,
+            |_| {},
+            |stacks| Count::extract(&stacks.accum_value()),
+        );
+        // At time 9, the input at time 3 is no longer within the window.
+        assert_eq!(outputs, vec![Some(1), Some(1)]);
+
+        let array = write_primitive_outputs::<UInt32Type>(outputs);
+        assert_eq!(
+            downcast_primitive_array::<UInt32Type>(array.as_ref()).unwrap(),
+            &UInt32Array::from(vec![1, 1])
+        );
+    }
+}
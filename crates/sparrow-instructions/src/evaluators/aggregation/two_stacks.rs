@@ -21,6 +21,32 @@ impl<AccT> WindowPart<AccT> {
     }
 }
 
+/// Fixes up the cumulatives of flipped window parts, ordered from newest to
+/// oldest.
+///
+/// Each part should be the merge of its accumulator and the newer parts before
+/// it. Merging the older accumulator first matters for order-sensitive
+/// aggregations such as `first` and `last`.
+fn flip_cumulatives<'a, AggF: AggFn>(
+    parts: impl Iterator<Item = &'a mut WindowPart<AggF::AccT>>,
+    compact: impl Fn(&mut AggF::AccT),
+) where
+    AggF::AccT: 'a,
+{
+    let mut newer: Option<&AggF::AccT> = None;
+    for part in parts {
+        let mut cumulative = part.accum.clone();
+        if let Some(newer) = newer {
+            AggF::merge(&mut cumulative, newer);
+        }
+        compact(&mut cumulative);
+        part.cumulative = cumulative;
+
+        let part: &'a WindowPart<AggF::AccT> = part;
+        newer = Some(&part.cumulative);
+    }
+}
+
 /// Accumulator for windowed aggregation using Two Stacks implementation.
 ///
 /// The bound indicates that the serde on this struct requires only
@@ -88,45 +114,70 @@ impl<AggF: AggFn> TwoStacks<AggF> {
         }
     }
 
+    /// Returns the older (outgoing) and newer (incoming) parts of the current
+    /// aggregate value without merging them.
+    pub fn accum_parts(&self) -> (Option<&AggF::AccT>, Option<&AggF::AccT>) {
+        (
+            self.outgoing().map(|outgoing| &outgoing.cumulative),
+            Some(&self.incoming().cumulative),
+        )
+    }
+
     /// Adds a single input to the next `incoming` window part.
     pub fn add_input(&mut self, input: &AggF::InT) {
-        AggF::add_one(&mut self.incoming_mut().accum, input);
+        self.add_input_with(input, |_| {})
+    }
+
+    /// Adds a single input to the next `incoming` window part, applying
+    /// `compact` to the updated accumulators.
+    ///
+    /// The `compact` function may discard anything that can no longer affect
+    /// the result once newer inputs are merged in.
+    pub fn add_input_with(&mut self, input: &AggF::InT, compact: impl Fn(&mut AggF::AccT)) {
+        let incoming = self.incoming_mut();
+        AggF::add_one(&mut incoming.accum, input);
+        compact(&mut incoming.accum);
 
         // TODO: If we have a lot of `add_input` calls, we could make them faster by
         // deferring the update to cumulative. Basically, the top "cumulative"
         // isn't set until the next item is pushed. This would affect
         // `accum_value`, etc. One easy way to do this would be to say
         // that every element *doesn't* include it's accumulator?
-        AggF::add_one(&mut self.incoming_mut().cumulative, input);
+        AggF::add_one(&mut incoming.cumulative, input);
+        compact(&mut incoming.cumulative);
     }
 
     /// Evicts the oldest window
     pub fn evict(&mut self) {
+        self.evict_with(|_| {})
+    }
+
+    /// Evicts the oldest window, applying `compact` to any accumulators merged
+    /// while flipping the stacks.
+    pub fn evict_with(&mut self, compact: impl Fn(&mut AggF::AccT)) {
         if self.outgoing.is_empty() {
-            self.flip();
+            self.flip(compact);
         };
         self.outgoing.pop();
-        let recent_cumulative = match self.incoming.last() {
-            Some(part) => part.cumulative.clone(),
+
+        // Only the cumulative of the newest incoming part is read, and the
+        // cumulatives are recomputed when flipping, so it is moved to the new
+        // part rather than cloned.
+        let recent_cumulative = match self.incoming.last_mut() {
+            Some(part) => std::mem::replace(&mut part.cumulative, AggF::zero()),
             None => AggF::zero(),
         };
         self.incoming
             .push(WindowPart::new(AggF::zero(), recent_cumulative));
     }
 
-    fn flip(&mut self) {
+    fn flip(&mut self, compact: impl Fn(&mut AggF::AccT)) {
         debug_assert!(self.outgoing.is_empty());
         std::mem::swap(&mut self.incoming, &mut self.outgoing);
         self.outgoing.reverse();
 
         // Fix up the cumulatives to reflect the new reversed order.
-        // Each item should be the sum of its accumulator and the cumulative
-        // values below it.
-        let mut accum = AggF::zero();
-        for outgoing in &mut self.outgoing {
-            AggF::merge(&mut accum, &outgoing.accum);
-            outgoing.cumulative = accum.clone();
-        }
+        flip_cumulatives::<AggF>(self.outgoing.iter_mut(), compact);
     }
 
     fn incoming(&self) -> &WindowPart<AggF::AccT> {
@@ -151,3 +202,272 @@ impl<AggF: AggFn> TwoStacks<AggF> {
         self.outgoing.last()
     }
 }
+
+/// A window part containing the inputs that occurred at a specific time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TimedWindowPart<AccT> {
+    /// The time (nanoseconds since the epoch) of the inputs in this part.
+    time: i64,
+    part: WindowPart<AccT>,
+}
+
+/// Accumulator for aggregations over a trailing duration using a Two Stacks
+/// implementation.
+///
+/// Unlike [TwoStacks], which evicts a window part each time the window
+/// slides, each part is associated with the time of its inputs and evicted
+/// once that time falls out of the trailing duration.
+#[derive(Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "AggF: AggFn, AggF::AccT: Serialize",
+    deserialize = "AggF: AggFn, AggF::AccT: DeserializeOwned"
+))]
+pub struct TimedTwoStacks<AggF: AggFn> {
+    /// Window parts that haven't been "flipped". Newest at the end.
+    incoming: Vec<TimedWindowPart<AggF::AccT>>,
+    /// Window parts that have been "flipped". Oldest at the end.
+    outgoing: Vec<TimedWindowPart<AggF::AccT>>,
+}
+
+impl<AggF: AggFn> std::fmt::Debug for TimedTwoStacks<AggF> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TimedTwoStacks")
+            .field("incoming", &self.incoming)
+            .field("outgoing", &self.outgoing)
+            .finish()
+    }
+}
+
+impl<AggF: AggFn> Clone for TimedTwoStacks<AggF> {
+    fn clone(&self) -> Self {
+        Self {
+            incoming: self.incoming.clone(),
+            outgoing: self.outgoing.clone(),
+        }
+    }
+}
+
+impl<AggF: AggFn> Default for TimedTwoStacks<AggF> {
+    fn default() -> Self {
+        Self {
+            incoming: Vec::new(),
+            outgoing: Vec::new(),
+        }
+    }
+}
+
+impl<AggF: AggFn> TimedTwoStacks<AggF> {
+    /// Returns the aggregate value of all inputs that haven't been evicted.
+    pub fn accum_value(&self) -> AggF::AccT {
+        // The `outgoing` stack contains values occurring earlier than the
+        // `incoming` stack, so we merge the `incoming` into the `outgoing`.
+        let mut accum = match self.outgoing.last() {
+            Some(outgoing) => outgoing.part.cumulative.clone(),
+            None => AggF::zero(),
+        };
+        if let Some(incoming) = self.incoming.last() {
+            AggF::merge(&mut accum, &incoming.part.cumulative);
+        }
+        accum
+    }
+
+    /// Returns the older (outgoing) and newer (incoming) parts of the current
+    /// aggregate value without merging them.
+    pub fn accum_parts(&self) -> (Option<&AggF::AccT>, Option<&AggF::AccT>) {
+        (
+            self.outgoing
+                .last()
+                .map(|outgoing| &outgoing.part.cumulative),
+            self.incoming
+                .last()
+                .map(|incoming| &incoming.part.cumulative),
+        )
+    }
+
+    /// Adds a single input occurring at `time`.
+    ///
+    /// Inputs must be added in order of non-decreasing time.
+    pub fn add_input(&mut self, time: i64, input: &AggF::InT) {
+        self.add_input_with(time, input, |_| {})
+    }
+
+    /// Adds a single input occurring at `time`, applying `compact` to the
+    /// updated accumulators.
+    ///
+    /// Inputs must be added in order of non-decreasing time.
+    pub fn add_input_with(
+        &mut self,
+        time: i64,
+        input: &AggF::InT,
+        compact: impl Fn(&mut AggF::AccT),
+    ) {
+        match self.incoming.last_mut() {
+            Some(newest) if newest.time == time => {
+                AggF::add_one(&mut newest.part.accum, input);
+                compact(&mut newest.part.accum);
+                AggF::add_one(&mut newest.part.cumulative, input);
+                compact(&mut newest.part.cumulative);
+            }
+            newest => {
+                debug_assert!(newest.as_ref().map_or(true, |newest| newest.time < time));
+                // Only the cumulative of the newest incoming part is read, so
+                // it is moved to the new part rather than cloned.
+                let mut cumulative = match newest {
+                    Some(newest) => std::mem::replace(&mut newest.part.cumulative, AggF::zero()),
+                    None => AggF::zero(),
+                };
+                AggF::add_one(&mut cumulative, input);
+                compact(&mut cumulative);
+                self.incoming.push(TimedWindowPart {
+                    time,
+                    part: WindowPart::new(AggF::one(input), cumulative),
+                });
+            }
+        }
+    }
+
+    /// Evicts all inputs occurring at or before `time`.
+    pub fn evict_through(&mut self, time: i64) {
+        self.evict_through_with(time, |_| {})
+    }
+
+    /// Evicts all inputs occurring at or before `time`, applying `compact` to
+    /// any accumulators merged while flipping the stacks.
+    pub fn evict_through_with(&mut self, time: i64, compact: impl Fn(&mut AggF::AccT)) {
+        loop {
+            if self.outgoing.is_empty() {
+                if self.incoming.is_empty() {
+                    break;
+                }
+                self.flip(&compact);
+            }
+
+            match self.outgoing.last() {
+                Some(oldest) if oldest.time <= time => {
+                    self.outgoing.pop();
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn flip(&mut self, compact: impl Fn(&mut AggF::AccT)) {
+        debug_assert!(self.outgoing.is_empty());
+        std::mem::swap(&mut self.incoming, &mut self.outgoing);
+        self.outgoing.reverse();
+
+        // Fix up the cumulatives to reflect the new reversed order.
+        flip_cumulatives::<AggF>(
+            self.outgoing.iter_mut().map(|outgoing| &mut outgoing.part),
+            compact,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Collect, Count, FirstString, LastString};
+
+    /// Keeps at most the 2 most recent collected values.
+    fn compact_to_two(accum: &mut std::collections::VecDeque<i64>) {
+        while accum.len() > 2 {
+            accum.pop_front();
+        }
+    }
+
+    /// Runs a sliding window of `num_windows` parts over `inputs`.
+    ///
+    /// Each character of `inputs` is added in its own window part, with `_`
+    /// meaning no input. Returns the aggregate before each eviction, using `_`
+    /// for a null result.
+    fn sliding_values<AggF>(num_windows: i64, inputs: &str) -> String
+    where
+        AggF: AggFn<InT = String, AccT = Option<String>>,
+    {
+        let mut stacks = TwoStacks::<AggF>::new(num_windows);
+        let mut values = String::new();
+        for input in inputs.chars() {
+            if input != '_' {
+                stacks.add_input(&input.to_string());
+            }
+            values.push_str(stacks.accum_value().as_deref().unwrap_or("_"));
+            stacks.evict();
+        }
+        values
+    }
+
+    #[test]
+    fn test_two_stacks_first() {
+        assert_eq!(sliding_values::<FirstString>(3, "abcdef"), "aaabcd");
+        assert_eq!(sliding_values::<FirstString>(3, "ab__cd___"), "aaabcccd_");
+    }
+
+    #[test]
+    fn test_two_stacks_last() {
+        assert_eq!(sliding_values::<LastString>(3, "abcdef"), "abcdef");
+        assert_eq!(sliding_values::<LastString>(3, "_____fg_"), "_____fgg");
+        assert_eq!(sliding_values::<LastString>(3, "ab__cd___"), "abbbcddd_");
+    }
+
+    #[test]
+    fn test_two_stacks_compact() {
+        let mut stacks = TwoStacks::<Collect<i64>>::new(3);
+        for input in 0..4 {
+            stacks.add_input_with(&input, compact_to_two);
+            stacks.add_input_with(&(input + 10), compact_to_two);
+            stacks.add_input_with(&(input + 20), compact_to_two);
+            stacks.evict_with(compact_to_two);
+        }
+        let mut value = stacks.accum_value();
+        compact_to_two(&mut value);
+        assert_eq!(value, vec![13, 23]);
+
+        // Each part is bounded, rather than holding all of the newer values.
+        for part in stacks.incoming.iter().chain(&stacks.outgoing) {
+            assert!(part.accum.len() <= 2, "{part:?}");
+            assert!(part.cumulative.len() <= 2, "{part:?}");
+        }
+    }
+
+    #[test]
+    fn test_timed_two_stacks_count() {
+        let mut stacks = TimedTwoStacks::<Count>::default();
+        assert_eq!(stacks.accum_value(), 0);
+
+        stacks.add_input(1, &1);
+        stacks.add_input(2, &1);
+        stacks.add_input(2, &1);
+        stacks.add_input(5, &1);
+        assert_eq!(stacks.accum_value(), 4);
+
+        stacks.evict_through(1);
+        assert_eq!(stacks.accum_value(), 3);
+
+        stacks.add_input(6, &1);
+        assert_eq!(stacks.accum_value(), 4);
+
+        stacks.evict_through(4);
+        assert_eq!(stacks.accum_value(), 2);
+
+        stacks.evict_through(6);
+        assert_eq!(stacks.accum_value(), 0);
+    }
+
+    #[test]
+    fn test_timed_two_stacks_preserves_order() {
+        let mut stacks = TimedTwoStacks::<LastString>::default();
+
+        stacks.add_input(1, &"a".to_owned());
+        stacks.add_input(2, &"b".to_owned());
+        stacks.evict_through(0);
+        stacks.add_input(3, &"c".to_owned());
+        assert_eq!(stacks.accum_value(), Some("c".to_owned()));
+
+        stacks.evict_through(2);
+        assert_eq!(stacks.accum_value(), Some("c".to_owned()));
+
+        stacks.evict_through(3);
+        assert_eq!(stacks.accum_value(), None);
+    }
+}
@@ -0,0 +1,5 @@
+//! Aggregation evaluators supporting all windows.
+
+mod windowed_agg_evaluator;
+
+pub use windowed_agg_evaluator::*;
@@ -0,0 +1,415 @@
+use anyhow::anyhow;
+use arrow::array::{ArrayRef, BooleanArray, TimestampNanosecondArray};
+use arrow::datatypes::Int64Type;
+use itertools::izip;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sparrow_arrow::downcast::downcast_primitive_array;
+use sparrow_plan::ValueRef;
+
+use crate::{
+    trailing_duration, AggFn, AggregationArgs, ComputeStore, Evaluator, PrimitiveAccumToken,
+    RuntimeInfo, StateToken, StoreKey, TrailingAccumToken, TrailingAggEvaluator, TwoStacks,
+    TwoStacksPrimitiveAccumToken,
+};
+
+/// Reads the inputs and creates the results of a [WindowedAggEvaluator].
+///
+/// The accumulators are managed by the evaluator using the aggregation
+/// function `AggF`. The result for each row is extracted from the accumulator
+/// by the aggregation, allowing it to depend on other arguments -- such as the
+/// percentile to compute.
+pub trait WindowedAggregation: Send + Sync + 'static {
+    type AggF: AggFn;
+    /// The result of the aggregation for a single row.
+    type Output;
+
+    /// Returns the inputs to aggregate, with `None` for each `null` input.
+    fn read_inputs(
+        &self,
+        input: &ArrayRef,
+    ) -> anyhow::Result<Vec<Option<<Self::AggF as AggFn>::InT>>>;
+
+    /// Returns the result of the aggregation for the given accumulator.
+    fn extract(&self, accum: &<Self::AggF as AggFn>::AccT) -> Self::Output;
+
+    /// Returns the result of the aggregation for the merge of an older and a
+    /// newer accumulator, such as the parts of a windowed aggregation.
+    ///
+    /// Aggregations may override this to avoid copying the older accumulator
+    /// for each row.
+    fn extract_merged(
+        &self,
+        older: &<Self::AggF as AggFn>::AccT,
+        newer: &<Self::AggF as AggFn>::AccT,
+    ) -> Self::Output {
+        let mut accum = older.clone();
+        Self::AggF::merge(&mut accum, newer);
+        self.extract(&accum)
+    }
+
+    /// Creates the result array from the results for each row.
+    fn write_outputs(&self, outputs: Vec<Self::Output>) -> anyhow::Result<ArrayRef>;
+
+    /// Discards any part of an accumulator that can no longer affect the
+    /// result, even once newer inputs are merged in.
+    ///
+    /// This is called after inputs are added to or merged into an accumulator,
+    /// for every kind of window.
+    fn compact(&self, _accum: &mut <Self::AggF as AggFn>::AccT) {}
+}
+
+/// The accumulator of a [WindowedAggregation].
+type AccT<A> = <<A as WindowedAggregation>::AggF as AggFn>::AccT;
+
+/// Evaluator for aggregations which may be applied to any window.
+///
+/// The accumulator for each entity depends on the window:
+///
+/// - Unwindowed and `since` windowed aggregations use a single accumulator,
+///   which is reset after each tick.
+/// - Sliding windowed aggregations use [TwoStacks] of accumulators, evicting
+///   the oldest after each tick.
+/// - Trailing windowed aggregations use the [TrailingAggEvaluator], evicting
+///   the inputs older than the duration.
+pub struct WindowedAggEvaluator<A: WindowedAggregation> {
+    args: AggregationArgs<ValueRef>,
+    aggregation: A,
+    token: WindowedAccumToken<A::AggF>,
+}
+
+/// The state of a windowed aggregation, depending on the window.
+enum WindowedAccumToken<AggF: AggFn> {
+    /// Accumulators for non-windowed aggregations and `since` windows.
+    Cumulative(PrimitiveAccumToken<AggF::AccT>),
+    Sliding(TwoStacksPrimitiveAccumToken<AggF>),
+    Trailing(TrailingAccumToken<AggF>),
+}
+
+impl<AggF> StateToken for WindowedAccumToken<AggF>
+where
+    AggF: AggFn,
+    AggF::AccT: Serialize + DeserializeOwned,
+{
+    fn restore(&mut self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        match self {
+            Self::Cumulative(token) => token.restore(key, store),
+            Self::Sliding(token) => token.restore(key, store),
+            Self::Trailing(token) => token.restore(key, store),
+        }
+    }
+
+    fn store(&self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        match self {
+            Self::Cumulative(token) => token.store(key, store),
+            Self::Sliding(token) => token.store(key, store),
+            Self::Trailing(token) => token.store(key, store),
+        }
+    }
+}
+
+impl<A> WindowedAggEvaluator<A>
+where
+    A: WindowedAggregation,
+    <A::AggF as AggFn>::AccT: Serialize + DeserializeOwned + Sync,
+{
+    pub(crate) fn new(args: AggregationArgs<ValueRef>, aggregation: A) -> Self {
+        let token = match &args {
+            AggregationArgs::NoWindow { .. } | AggregationArgs::Since { .. } => {
+                WindowedAccumToken::Cumulative(PrimitiveAccumToken::default())
+            }
+            AggregationArgs::Sliding { .. } => {
+                WindowedAccumToken::Sliding(TwoStacksPrimitiveAccumToken::new())
+            }
+            AggregationArgs::Trailing { .. } => {
+                WindowedAccumToken::Trailing(TrailingAccumToken::new())
+            }
+        };
+
+        Self {
+            args,
+            aggregation,
+            token,
+        }
+    }
+}
+
+impl<A> Evaluator for WindowedAggEvaluator<A>
+where
+    A: WindowedAggregation,
+    <A::AggF as AggFn>::AccT: Serialize + DeserializeOwned + Sync,
+{
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let grouping = info.grouping();
+        let key_capacity = grouping.num_groups();
+        let aggregation = &self.aggregation;
+
+        let outputs = match (&self.args, &mut self.token) {
+            (AggregationArgs::NoWindow { input }, WindowedAccumToken::Cumulative(token)) => {
+                let input = aggregation.read_inputs(&info.value(input)?.array_ref()?)?;
+                let ticks = BooleanArray::new_null(input.len());
+
+                let mut accum = token.get_primitive_accum()?;
+                let outputs = Self::aggregate_since(
+                    aggregation,
+                    &mut accum,
+                    key_capacity,
+                    grouping.group_indices().values(),
+                    input,
+                    &ticks,
+                );
+                token.put_primitive_accum(accum)?;
+                outputs
+            }
+            (AggregationArgs::Since { input, ticks }, WindowedAccumToken::Cumulative(token)) => {
+                let input = aggregation.read_inputs(&info.value(input)?.array_ref()?)?;
+                let ticks = info.value(ticks)?.boolean_array()?;
+
+                let mut accum = token.get_primitive_accum()?;
+                let outputs = Self::aggregate_since(
+                    aggregation,
+                    &mut accum,
+                    key_capacity,
+                    grouping.group_indices().values(),
+                    input,
+                    ticks.as_ref(),
+                );
+                token.put_primitive_accum(accum)?;
+                outputs
+            }
+            (
+                AggregationArgs::Sliding {
+                    input,
+                    ticks,
+                    duration,
+                },
+                WindowedAccumToken::Sliding(token),
+            ) => {
+                let input = aggregation.read_inputs(&info.value(input)?.array_ref()?)?;
+                let ticks = info.value(ticks)?.boolean_array()?;
+                let duration = info
+                    .value(duration)?
+                    .try_primitive_literal::<Int64Type>()?
+                    .ok_or_else(|| anyhow!("Expected non-null literal duration"))?;
+                anyhow::ensure!(
+                    duration > 0,
+                    "Expected positive duration for sliding window, saw {:?}",
+                    duration
+                );
+
+                let mut accum = token.get_primitive_accum()?;
+                let outputs = Self::aggregate_sliding(
+                    aggregation,
+                    &mut accum,
+                    key_capacity,
+                    grouping.group_indices().values(),
+                    input,
+                    ticks.as_ref(),
+                    duration,
+                );
+                token.put_primitive_accum(accum)?;
+                outputs
+            }
+            (
+                AggregationArgs::Trailing { input, duration },
+                WindowedAccumToken::Trailing(token),
+            ) => {
+                let input = aggregation.read_inputs(&info.value(input)?.array_ref()?)?;
+                let time = info.time_column().array_ref()?;
+                let time: &TimestampNanosecondArray = downcast_primitive_array(time.as_ref())?;
+
+                TrailingAggEvaluator::<A::AggF>::aggregate(
+                    token,
+                    key_capacity,
+                    grouping.group_indices(),
+                    time,
+                    input,
+                    trailing_duration(duration)?,
+                    |accum| aggregation.compact(accum),
+                    |stacks| Self::extract_parts(aggregation, stacks.accum_parts()),
+                )
+            }
+            _ => unreachable!("Aggregation state should correspond to the window"),
+        };
+
+        self.aggregation.write_outputs(outputs)
+    }
+
+    fn state_token(&self) -> Option<&dyn StateToken> {
+        Some(&self.token)
+    }
+
+    fn state_token_mut(&mut self) -> Option<&mut dyn StateToken> {
+        Some(&mut self.token)
+    }
+}
+
+impl<A: WindowedAggregation> WindowedAggEvaluator<A> {
+    /// Returns the result for the older and newer parts of a windowed
+    /// accumulator.
+    fn extract_parts(aggregation: &A, parts: (Option<&AccT<A>>, Option<&AccT<A>>)) -> A::Output {
+        match parts {
+            (Some(older), Some(newer)) => aggregation.extract_merged(older, newer),
+            (Some(accum), None) | (None, Some(accum)) => aggregation.extract(accum),
+            (None, None) => aggregation.extract(&A::AggF::zero()),
+        }
+    }
+
+    /// Update the accumulators with the given inputs and return the results.
+    ///
+    /// Accumulator behavior is to update -> emit -> reset, resulting in
+    /// exclusive start bounds and inclusive end bounds.
+    pub(crate) fn aggregate_since(
+        aggregation: &A,
+        accum: &mut Vec<<A::AggF as AggFn>::AccT>,
+        key_capacity: usize,
+        entity_indices: &[u32],
+        input: Vec<Option<<A::AggF as AggFn>::InT>>,
+        ticks: &BooleanArray,
+    ) -> Vec<A::Output> {
+        assert_eq!(entity_indices.len(), input.len());
+        if key_capacity > accum.len() {
+            accum.resize(key_capacity, A::AggF::zero());
+        }
+
+        izip!(entity_indices, input, ticks.iter())
+            .map(|(entity_index, input, tick)| {
+                let accum = &mut accum[*entity_index as usize];
+                if let Some(input) = input {
+                    A::AggF::add_one(accum, &input);
+                    aggregation.compact(accum);
+                }
+
+                let result = aggregation.extract(accum);
+                if tick == Some(true) {
+                    *accum = A::AggF::zero();
+                }
+                result
+            })
+            .collect()
+    }
+
+    /// Update the two-stacks accumulators with the given inputs and return the
+    /// results.
+    pub(crate) fn aggregate_sliding(
+        aggregation: &A,
+        accum: &mut Vec<TwoStacks<A::AggF>>,
+        key_capacity: usize,
+        entity_indices: &[u32],
+        input: Vec<Option<<A::AggF as AggFn>::InT>>,
+        ticks: &BooleanArray,
+        duration: i64,
+    ) -> Vec<A::Output> {
+        assert_eq!(entity_indices.len(), input.len());
+        if key_capacity > accum.len() {
+            accum.resize(key_capacity, TwoStacks::new(duration));
+        }
+
+        izip!(entity_indices, input, ticks.iter())
+            .map(|(entity_index, input, tick)| {
+                let accum = &mut accum[*entity_index as usize];
+                if let Some(input) = input {
+                    accum.add_input_with(&input, |accum| aggregation.compact(accum));
+                }
+
+                let result = Self::extract_parts(aggregation, accum.accum_parts());
+                if tick == Some(true) {
+                    accum.evict_with(|accum| aggregation.compact(accum));
+                }
+                result
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::UInt32Array;
+    use arrow::datatypes::UInt32Type;
+
+    use super::*;
+    use crate::{read_primitive_inputs, write_primitive_outputs, Count};
+
+    /// Counts the inputs, reporting `null` when the count is odd.
+    struct EvenCount;
+
+    impl WindowedAggregation for EvenCount {
+        type AggF = Count;
+        type Output = Option<u32>;
+
+        fn read_inputs(&self, input: &ArrayRef) -> anyhow::Result<Vec<Option<u32>>> {
+            read_primitive_inputs::<UInt32Type>(input)
+        }
+
+        fn extract(&self, accum: &u32) -> Option<u32> {
+            (accum % 2 == 0).then_some(*accum)
+        }
+
+        fn write_outputs(&self, outputs: Vec<Option<u32>>) -> anyhow::Result<ArrayRef> {
+            Ok(write_primitive_outputs::<UInt32Type>(outputs))
+        }
+    }
+
+    #[test]
+    fn test_windowed_since() {
+        let entity_indices = vec![0, 0, 1, 0, 0, 0];
+        let input = vec![Some(1), Some(1), Some(1), None, Some(1), Some(1)];
+        let ticks = BooleanArray::from(vec![
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            None,
+            Some(false),
+        ]);
+
+        let mut accum = Vec::new();
+        let result = WindowedAggEvaluator::aggregate_since(
+            &EvenCount,
+            &mut accum,
+            2,
+            &entity_indices,
+            input,
+            &ticks,
+        );
+        assert_eq!(result, vec![None, Some(2), None, Some(2), None, Some(2)]);
+    }
+
+    #[test]
+    fn test_windowed_sliding() {
+        let entity_indices = vec![0; 5];
+        let input = vec![Some(1), Some(1), Some(1), Some(1), Some(1)];
+        let ticks = BooleanArray::from(vec![true, false, true, false, true]);
+
+        let mut accum = Vec::new();
+        let result = WindowedAggEvaluator::aggregate_sliding(
+            &EvenCount,
+            &mut accum,
+            1,
+            &entity_indices,
+            input,
+            &ticks,
+            2,
+        );
+        assert_eq!(result, vec![None, Some(2), None, None, Some(4)]);
+    }
+
+    #[test]
+    fn test_windowed_trailing() {
+        let mut token = TrailingAccumToken::<Count>::new();
+        let input: ArrayRef = Arc::new(UInt32Array::from(vec![Some(1), Some(1), None, Some(1)]));
+        let outputs = TrailingAggEvaluator::<Count>::aggregate(
+            &mut token,
+            1,
+            &UInt32Array::from(vec![0, 0, 0, 0]),
+            &TimestampNanosecondArray::from(vec![0, 3, 6, 9]),
+            EvenCount.read_inputs(&input).unwrap(),
+            5,
+            |_| {},
+            |stacks| EvenCount.extract(&stacks.accum_value()),
+        );
+        assert_eq!(outputs, vec![None, Some(2), None, None]);
+    }
+}
@@ -89,6 +89,11 @@ impl CastEvaluator {
                     input.data_type()
                 )),
             },
+            (DataType::Timestamp(_, _), DataType::Timestamp(_, _)) => {
+                // Timestamps without a time zone are UTC, so changing the
+                // time zone should preserve the instants.
+                sparrow_arrow::utils::cast_timestamp(input, to).map_err(|e| e.into())
+            }
             _ => arrow::compute::cast(input, to).map_err(|e| e.into()),
         }
     }
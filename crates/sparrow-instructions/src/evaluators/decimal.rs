@@ -0,0 +1,360 @@
+//! Evaluators for instructions on `Decimal128` values.
+//!
+//! Arrow's `Decimal128Type` describes a decimal with the default precision and
+//! scale, while the actual type of a decimal column is parameterized. The
+//! generic primitive evaluators are therefore wrapped to report the actual
+//! result type, and arithmetic which depends on the scale (multiplication,
+//! division and mean) is implemented here.
+//!
+//! The result types of arithmetic and `sum` are widened during type inference
+//! to hold every result, so values which still overflow are reported as
+//! errors rather than silently replaced with `null`.
+
+use std::sync::Arc;
+
+use anyhow::Context;
+use arrow::array::{ArrayRef, BooleanArray, Decimal128Array, Decimal128Builder};
+use arrow::datatypes::{DataType, Decimal128Type};
+use itertools::izip;
+use sparrow_arrow::downcast::downcast_primitive_array;
+use sparrow_plan::{InstOp, ValueRef};
+
+use super::{
+    div_round_half_away, ArrowAggEvaluator, ClampEvaluator, DecimalMean, FirstPrimitive,
+    LastPrimitive, Max, Min, NegEvaluator, PrimitiveLagEvaluator, Sum, ZipMaxEvaluator,
+    ZipMinEvaluator,
+};
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StateToken, StaticInfo};
+
+/// Returns true if the instruction should be evaluated using the decimal
+/// evaluators.
+pub(super) fn is_decimal_instruction(op: InstOp, info: &StaticInfo<'_>) -> bool {
+    let input = match op {
+        InstOp::Add
+        | InstOp::Clamp
+        | InstOp::Div
+        | InstOp::Eq
+        | InstOp::First
+        | InstOp::Gt
+        | InstOp::Gte
+        | InstOp::Last
+        | InstOp::Lt
+        | InstOp::Lte
+        | InstOp::Max
+        | InstOp::Mean
+        | InstOp::Min
+        | InstOp::Mul
+        | InstOp::Neg
+        | InstOp::Neq
+        | InstOp::Sub
+        | InstOp::Sum
+        | InstOp::ZipMax
+        | InstOp::ZipMin => info.args.first(),
+        InstOp::Lag => info.args.get(1),
+        _ => return false,
+    };
+    matches!(
+        input.map(|arg| arg.data_type()),
+        Some(DataType::Decimal128(_, _))
+    )
+}
+
+/// Create the evaluator for an instruction with decimal inputs.
+pub(super) fn create_decimal_evaluator(
+    op: InstOp,
+    info: StaticInfo<'_>,
+) -> anyhow::Result<Box<dyn Evaluator>> {
+    let result_type = info.result_type.clone();
+    let evaluator = match op {
+        InstOp::Add | InstOp::Sub | InstOp::Mul | InstOp::Div => {
+            DecimalArithmeticEvaluator::try_new(op, info)?
+        }
+        InstOp::Eq | InstOp::Neq | InstOp::Gt | InstOp::Gte | InstOp::Lt | InstOp::Lte => {
+            return DecimalComparisonEvaluator::try_new(op, info);
+        }
+        InstOp::Clamp => ClampEvaluator::<Decimal128Type>::try_new(info)?,
+        InstOp::First => ArrowAggEvaluator::<FirstPrimitive<Decimal128Type>>::try_new(info)?,
+        InstOp::Lag => PrimitiveLagEvaluator::<Decimal128Type>::try_new(info)?,
+        InstOp::Last => ArrowAggEvaluator::<LastPrimitive<Decimal128Type>>::try_new(info)?,
+        InstOp::Max => ArrowAggEvaluator::<Max<Decimal128Type>>::try_new(info)?,
+        InstOp::Mean => ArrowAggEvaluator::<DecimalMean>::try_new(info)?,
+        InstOp::Min => ArrowAggEvaluator::<Min<Decimal128Type>>::try_new(info)?,
+        InstOp::Neg => NegEvaluator::<Decimal128Type>::try_new(info)?,
+        InstOp::Sum => ArrowAggEvaluator::<Sum<Decimal128Type>>::try_new(info)?,
+        InstOp::ZipMax => ZipMaxEvaluator::<Decimal128Type>::try_new(info)?,
+        InstOp::ZipMin => ZipMinEvaluator::<Decimal128Type>::try_new(info)?,
+        unsupported => anyhow::bail!("Unsupported decimal input for '{unsupported}'"),
+    };
+
+    let DataType::Decimal128(precision, scale) = result_type else {
+        anyhow::bail!("Expected decimal result for '{op}', but was {result_type:?}")
+    };
+    Ok(Box::new(DecimalResultEvaluator {
+        inner: evaluator,
+        precision,
+        scale,
+    }))
+}
+
+/// Wraps an evaluator producing a `Decimal128Array` with the result precision
+/// and scale.
+///
+/// Values which overflow the precision produce an error.
+struct DecimalResultEvaluator {
+    inner: Box<dyn Evaluator>,
+    precision: u8,
+    scale: i8,
+}
+
+impl Evaluator for DecimalResultEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let result = self.inner.evaluate(info)?;
+        let result = downcast_primitive_array::<Decimal128Type>(result.as_ref())?
+            .clone()
+            .with_precision_and_scale(self.precision, self.scale)?;
+        result
+            .validate_decimal_precision(self.precision)
+            .context("Decimal result overflows precision")?;
+        Ok(Arc::new(result))
+    }
+
+    fn state_token(&self) -> Option<&dyn StateToken> {
+        self.inner.state_token()
+    }
+
+    fn state_token_mut(&mut self) -> Option<&mut dyn StateToken> {
+        self.inner.state_token_mut()
+    }
+}
+
+/// Evaluator for `add`, `sub`, `mul` and `div` on decimals.
+///
+/// Both arguments have the same scale. The result has the same scale, except
+/// for `mul` where it may have up to twice the scale. Division by zero
+/// produces `null`, and overflow produces an error.
+struct DecimalArithmeticEvaluator {
+    op: InstOp,
+    lhs: ValueRef,
+    rhs: ValueRef,
+    scale: i8,
+    result_scale: i8,
+}
+
+impl DecimalArithmeticEvaluator {
+    fn try_new(op: InstOp, info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let DataType::Decimal128(_, result_scale) = info.result_type else {
+            anyhow::bail!(
+                "Expected decimal result for '{op}', but was {:?}",
+                info.result_type
+            )
+        };
+        let result_scale = *result_scale;
+        let DataType::Decimal128(_, scale) = info.args[0].data_type() else {
+            anyhow::bail!(
+                "Expected decimal arguments for '{op}', but was {:?}",
+                info.args[0].data_type()
+            )
+        };
+        let scale = *scale;
+        let (lhs, rhs) = info.unpack_arguments()?;
+        Ok(Box::new(Self {
+            op,
+            lhs,
+            rhs,
+            scale,
+            result_scale,
+        }))
+    }
+}
+
+impl Evaluator for DecimalArithmeticEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let lhs = info.value(&self.lhs)?.primitive_array::<Decimal128Type>()?;
+        let rhs = info.value(&self.rhs)?.primitive_array::<Decimal128Type>()?;
+        let result = decimal_arithmetic(
+            self.op,
+            self.scale,
+            self.result_scale,
+            lhs.as_ref(),
+            rhs.as_ref(),
+        )?;
+        Ok(Arc::new(result))
+    }
+}
+
+/// Apply the arithmetic `op` to decimals with the given `scale`, producing
+/// decimals with the `result_scale`.
+fn decimal_arithmetic(
+    op: InstOp,
+    scale: i8,
+    result_scale: i8,
+    lhs: &Decimal128Array,
+    rhs: &Decimal128Array,
+) -> anyhow::Result<Decimal128Array> {
+    anyhow::ensure!(
+        lhs.len() == rhs.len(),
+        "Decimal arrays must be the same length."
+    );
+
+    let pow10 = |exponent: i16| {
+        10i128
+            .checked_pow(exponent.unsigned_abs() as u32)
+            .ok_or_else(|| anyhow::anyhow!("Unsupported decimal scale {exponent}"))
+    };
+    let factor = pow10(scale as i16)?;
+
+    // The product has twice the scale, and is rounded to the result scale.
+    let product_scale = 2 * scale as i16;
+    anyhow::ensure!(
+        result_scale as i16 <= product_scale,
+        "Unexpected scale {result_scale} for product of decimals with scale {scale}"
+    );
+    let product_divisor = pow10(product_scale - result_scale as i16)?;
+
+    let mut result = Decimal128Builder::with_capacity(lhs.len());
+    for (a, b) in izip!(lhs.iter(), rhs.iter()) {
+        let (Some(a), Some(b)) = (a, b) else {
+            result.append_null();
+            continue;
+        };
+        let value = match op {
+            InstOp::Add => a.checked_add(b),
+            InstOp::Sub => a.checked_sub(b),
+            InstOp::Mul => a
+                .checked_mul(b)
+                .and_then(|product| div_round_half_away(product, product_divisor)),
+            InstOp::Div if b == 0 => {
+                result.append_null();
+                continue;
+            }
+            InstOp::Div => decimal_div(a, b, factor, scale),
+            unexpected => anyhow::bail!("Unexpected decimal arithmetic '{unexpected}'"),
+        };
+        let value = value.with_context(|| format!("Decimal overflow in '{op}'"))?;
+        result.append_value(value);
+    }
+    Ok(result.finish())
+}
+
+/// Divide two decimals with the same scale.
+///
+/// The quotient has no scale, so it is rescaled to the original scale.
+fn decimal_div(a: i128, b: i128, factor: i128, scale: i8) -> Option<i128> {
+    if scale >= 0 {
+        div_round_half_away(a.checked_mul(factor)?, b)
+    } else {
+        div_round_half_away(a, b.checked_mul(factor)?)
+    }
+}
+
+/// Evaluator for comparisons between decimals.
+///
+/// Both arguments have the same precision and scale.
+struct DecimalComparisonEvaluator {
+    op: InstOp,
+    lhs: ValueRef,
+    rhs: ValueRef,
+}
+
+impl DecimalComparisonEvaluator {
+    fn try_new(op: InstOp, info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (lhs, rhs) = info.unpack_arguments()?;
+        Ok(Box::new(Self { op, lhs, rhs }))
+    }
+}
+
+impl Evaluator for DecimalComparisonEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let lhs = info.value(&self.lhs)?.primitive_array::<Decimal128Type>()?;
+        let rhs = info.value(&self.rhs)?.primitive_array::<Decimal128Type>()?;
+        let (lhs, rhs) = (lhs.as_ref(), rhs.as_ref());
+        let result: BooleanArray = match self.op {
+            InstOp::Eq => arrow::compute::eq(lhs, rhs)?,
+            InstOp::Neq => arrow::compute::neq(lhs, rhs)?,
+            InstOp::Gt => arrow::compute::gt(lhs, rhs)?,
+            InstOp::Gte => arrow::compute::gt_eq(lhs, rhs)?,
+            InstOp::Lt => arrow::compute::lt(lhs, rhs)?,
+            InstOp::Lte => arrow::compute::lt_eq(lhs, rhs)?,
+            unexpected => anyhow::bail!("Unexpected decimal comparison '{unexpected}'"),
+        };
+        Ok(Arc::new(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimals(values: Vec<Option<i128>>) -> Decimal128Array {
+        Decimal128Array::from(values)
+    }
+
+    #[test]
+    fn test_decimal_add_sub() {
+        let lhs = decimals(vec![Some(1050), None, Some(i128::MAX)]);
+        let rhs = decimals(vec![Some(225), Some(100), Some(1)]);
+
+        let actual = decimal_arithmetic(InstOp::Sub, 2, 2, &lhs, &rhs).unwrap();
+        assert_eq!(actual, decimals(vec![Some(825), None, Some(i128::MAX - 1)]));
+
+        // Overflow is an error, rather than null.
+        assert!(decimal_arithmetic(InstOp::Add, 2, 2, &lhs, &rhs).is_err());
+        let actual = decimal_arithmetic(InstOp::Add, 2, 2, &lhs.slice(0, 2), &rhs.slice(0, 2));
+        assert_eq!(actual.unwrap(), decimals(vec![Some(1275), None]));
+    }
+
+    #[test]
+    fn test_decimal_mul() {
+        // The product has the scale of both arguments.
+        // 10.50 * 2.25 = 23.6250
+        // -10.50 * 2.25 = -23.6250
+        // 0.01 * 0.01 = 0.0001
+        let lhs = decimals(vec![Some(1050), Some(-1050), Some(1)]);
+        let rhs = decimals(vec![Some(225), Some(225), Some(1)]);
+        let actual = decimal_arithmetic(InstOp::Mul, 2, 4, &lhs, &rhs).unwrap();
+        assert_eq!(actual, decimals(vec![Some(236250), Some(-236250), Some(1)]));
+
+        // If the result scale is reduced, the product is rounded.
+        // 23.6250 -> 23.63
+        // -23.6250 -> -23.63
+        // 0.0001 -> 0.00
+        let actual = decimal_arithmetic(InstOp::Mul, 2, 2, &lhs, &rhs).unwrap();
+        assert_eq!(actual, decimals(vec![Some(2363), Some(-2363), Some(0)]));
+
+        let overflow = decimals(vec![Some(i128::MAX / 2)]);
+        assert!(
+            decimal_arithmetic(InstOp::Mul, 0, 0, &overflow, &decimals(vec![Some(3)])).is_err()
+        );
+    }
+
+    #[test]
+    fn test_decimal_div() {
+        // 10.00 / 3.00 = 3.333.. -> 3.33
+        // 2.00 / 3.00 = 0.666.. -> 0.67
+        // -2.00 / 3.00 = -0.666.. -> -0.67
+        // 1.00 / 0.00 -> null
+        let lhs = decimals(vec![Some(1000), Some(200), Some(-200), Some(100)]);
+        let rhs = decimals(vec![Some(300), Some(300), Some(300), Some(0)]);
+        let actual = decimal_arithmetic(InstOp::Div, 2, 2, &lhs, &rhs).unwrap();
+        assert_eq!(actual, decimals(vec![Some(333), Some(67), Some(-67), None]));
+    }
+
+    #[test]
+    fn test_decimal_mean() {
+        use crate::evaluators::AggFn;
+
+        let mut acc = DecimalMean::zero();
+        assert_eq!(DecimalMean::extract(&acc), None);
+
+        // Mean of 1.00, 2.00 and 2.00 is 1.666.. -> 1.67
+        DecimalMean::add_one(&mut acc, &100);
+        DecimalMean::add_one(&mut acc, &200);
+        let mut other = DecimalMean::one(&200);
+        DecimalMean::merge(&mut other, &acc);
+        assert_eq!(DecimalMean::extract(&other), Some(167));
+
+        // Overflowing the sum produces null.
+        DecimalMean::add_one(&mut other, &i128::MAX);
+        assert_eq!(DecimalMean::extract(&other), None);
+    }
+}
@@ -8,15 +8,26 @@ use sparrow_plan::ValueRef;
 
 use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
 
-/// Evaluator for the `json_field` function.
+/// Evaluator for the `json_field` and `json_index` functions.
 ///
-/// This evaluator expects a `string` input and `field` name.
-/// It parses the `string` into a `json` object, then outputs the
-/// value of the `field` name as a `string`, or `null` if the `field`
+/// This evaluator expects a `string` input and a literal `field` name or array
+/// `index`. It parses the `string` into a `json` value, then outputs the
+/// value of the `field` or array element as a `string`, or `null` if it
 /// does not exist.
+///
+/// Objects and arrays are output as `json` strings, allowing nested values to
+/// be accessed by applying this evaluator to the result.
 pub(super) struct JsonFieldEvaluator {
     json_string: ValueRef,
-    field_name: String,
+    element: JsonElement,
+}
+
+/// The element of a `json` value to access.
+enum JsonElement {
+    /// The value of a field within a `json` object.
+    Field(String),
+    /// The value at an index within a `json` array.
+    Index(usize),
 }
 
 impl Evaluator for JsonFieldEvaluator {
@@ -61,7 +72,10 @@ impl JsonFieldEvaluator {
             .iter()
             .map(|json| {
                 if let Some(json) = json {
-                    let value = &json[&self.field_name];
+                    let value = match &self.element {
+                        JsonElement::Field(field_name) => &json[field_name],
+                        JsonElement::Index(index) => &json[*index],
+                    };
                     if value.is_null() {
                         // No value - field does not exist in this json object.
                         None
@@ -85,16 +99,26 @@ impl JsonFieldEvaluator {
 
 impl EvaluatorFactory for JsonFieldEvaluator {
     fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
-        let (json_string, field_name) = info.unpack_arguments()?;
-        let field_name = match field_name {
-            ValueRef::Literal(ScalarValue::Utf8(s)) => s.context("Expected non-null field name")?,
+        let (json_string, element) = info.unpack_arguments()?;
+        let element = match element {
+            ValueRef::Literal(ScalarValue::Utf8(s)) => {
+                JsonElement::Field(s.context("Expected non-null field name")?)
+            }
+            ValueRef::Literal(ScalarValue::Int64(Some(index))) => {
+                let index = usize::try_from(index)
+                    .map_err(|_| anyhow::anyhow!("Expected non-negative index, saw {index}"))?;
+                JsonElement::Index(index)
+            }
             unexpected => {
-                anyhow::bail!("Expected literal utf8 for field name, saw {:?}", unexpected)
+                anyhow::bail!(
+                    "Expected literal utf8 field name or i64 index, saw {:?}",
+                    unexpected
+                )
             }
         };
         Ok(Box::new(Self {
             json_string,
-            field_name,
+            element,
         }))
     }
 }
@@ -164,6 +188,40 @@ mod tests {
         assert!(unwrapped.is_null())
     }
 
+    #[test]
+    fn test_values_from_index_and_nested_fields() {
+        let array: Arc<dyn Array> = Arc::new(StringArray::from(vec![
+            Some("[1, \"two\", { \"a\": [3] }]"),
+            Some("[1]"),
+            Some("{ \"a\": 1 }"),
+            None,
+        ]));
+        let input = ArcRef::new(array)
+            .try_map(|a| downcast_string_array(a))
+            .unwrap();
+        let jsons = JsonFieldEvaluator::parse_to_json(input).unwrap();
+
+        let evaluator = JsonFieldEvaluator {
+            json_string: ValueRef::Inst(0),
+            element: JsonElement::Index(1),
+        };
+        let result = evaluator.values_from_field(jsons.clone()).unwrap();
+        assert_eq!(
+            result,
+            StringArray::from(vec![Some("two"), None, None, None])
+        );
+
+        let evaluator = JsonFieldEvaluator {
+            json_string: ValueRef::Inst(0),
+            element: JsonElement::Index(2),
+        };
+        let result = evaluator.values_from_field(jsons).unwrap();
+        assert_eq!(
+            result,
+            StringArray::from(vec![Some("{\"a\":[3]}"), None, None, None])
+        );
+    }
+
     #[test]
     fn test_creating_with_valid_field_name_type() {
         let node = ValueRef::Inst(0u32);
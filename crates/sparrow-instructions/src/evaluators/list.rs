@@ -0,0 +1,11 @@
+mod flatten;
+mod index;
+mod list_contains;
+mod list_len;
+mod list_sum;
+
+pub(super) use flatten::*;
+pub(super) use index::*;
+pub(super) use list_contains::*;
+pub(super) use list_len::*;
+pub(super) use list_sum::*;
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, ListArray};
+use arrow::buffer::{OffsetBuffer, ScalarBuffer};
+use arrow::datatypes::{DataType, FieldRef};
+use sparrow_arrow::downcast::downcast_list_array;
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for `flatten` on lists of lists.
+#[derive(Debug)]
+pub(in crate::evaluators) struct FlattenEvaluator {
+    list: ValueRef,
+    /// The field of the resulting list.
+    field: FieldRef,
+}
+
+impl EvaluatorFactory for FlattenEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let field = match info.result_type {
+            DataType::List(field) => field.clone(),
+            other => anyhow::bail!("expected list result type, saw {:?}", other),
+        };
+
+        let list = info.unpack_argument()?;
+        Ok(Box::new(Self { list, field }))
+    }
+}
+
+impl Evaluator for FlattenEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let list_input = info.value(&self.list)?.list_array()?;
+        let result = flatten(list_input.as_ref(), self.field.clone())?;
+        Ok(Arc::new(result))
+    }
+}
+
+/// Concatenates the inner lists of each list.
+///
+/// The result is `null` if the outer list is `null`. Inner lists which are
+/// `null` are treated as empty.
+fn flatten(list: &ListArray, field: FieldRef) -> anyhow::Result<ListArray> {
+    let inner = downcast_list_array(list.values().as_ref())?;
+
+    // The outer offsets index into the inner lists, and the inner offsets index
+    // into the inner values. Composing them gives the offsets of each flattened
+    // list in the inner values.
+    let inner_offsets = inner.value_offsets();
+    let offsets: ScalarBuffer<i32> = list
+        .value_offsets()
+        .iter()
+        .map(|offset| inner_offsets[*offset as usize])
+        .collect();
+
+    let result = ListArray::try_new(
+        field,
+        OffsetBuffer::new(offsets),
+        inner.values().clone(),
+        list.nulls().cloned(),
+    )?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int64Builder, ListBuilder};
+    use arrow::datatypes::{Field, Int64Type};
+
+    use super::*;
+
+    #[test]
+    fn test_flatten() {
+        let mut list = ListBuilder::new(ListBuilder::new(Int64Builder::new()));
+
+        // 0: [[1, 2], [3]]
+        list.values().append_value([Some(1), Some(2)]);
+        list.values().append_value([Some(3)]);
+        list.append(true);
+
+        // 1: null
+        list.append(false);
+
+        // 2: [[], null, [4, null]]
+        list.values().append(true);
+        list.values().append(false);
+        list.values().append_value([Some(4), None]);
+        list.append(true);
+
+        // 3: []
+        list.append(true);
+
+        let list = list.finish();
+
+        let field = Arc::new(Field::new("item", DataType::Int64, true));
+        let actual = flatten(&list, field).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+            Some(vec![Some(4), None]),
+            Some(vec![]),
+        ]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_flatten_sliced() {
+        let list = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(3)]),
+            Some(vec![Some(4), Some(5)]),
+        ]);
+        let field = Arc::new(Field::new("item", list.data_type().clone(), true));
+        let list = ListArray::try_new(
+            field,
+            OffsetBuffer::new(vec![0, 1, 3].into()),
+            Arc::new(list),
+            None,
+        )
+        .unwrap()
+        .slice(1, 1);
+
+        let field = Arc::new(Field::new("item", DataType::Int64, true));
+        let actual = flatten(&list, field).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![Some(vec![
+            Some(3),
+            Some(4),
+            Some(5),
+        ])]);
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::Context;
+use arrow::array::{Array, ArrayRef, Int32Array, Int64Array, ListArray};
+use arrow::datatypes::Int64Type;
+use itertools::izip;
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for `index` on lists.
+#[derive(Debug)]
+pub(in crate::evaluators) struct IndexEvaluator {
+    index: ValueRef,
+    list: ValueRef,
+}
+
+impl EvaluatorFactory for IndexEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (index, list) = info.unpack_arguments()?;
+        Ok(Box::new(Self { index, list }))
+    }
+}
+
+impl Evaluator for IndexEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let list_input = info.value(&self.list)?.list_array()?;
+        let index_input = info.value(&self.index)?.primitive_array::<Int64Type>()?;
+
+        list_get(list_input.as_ref(), index_input.as_ref())
+    }
+}
+
+/// Returns the item at the corresponding index of each list.
+///
+/// The result is `null` if the list or index is `null`, or if the index is
+/// out of bounds.
+fn list_get(list: &ListArray, indices: &Int64Array) -> anyhow::Result<ArrayRef> {
+    anyhow::ensure!(list.len() == indices.len());
+    let take_indices: Int32Array = izip!(list.offsets().windows(2), indices.iter())
+        .enumerate()
+        .map(|(row, (offsets, index))| {
+            let index = i32::try_from(index?).ok()?;
+            if list.is_valid(row) && 0 <= index && index < offsets[1] - offsets[0] {
+                Some(offsets[0] + index)
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    arrow::compute::take(list.values(), &take_indices, None).context("take in list_get")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{
+        as_primitive_array, as_string_array, ListBuilder, StringArray, StringBuilder,
+    };
+
+    #[test]
+    fn test_index_i64_list() {
+        let list = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(0), Some(1), Some(2)]),
+            Some(vec![Some(3), None]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4), Some(5)]),
+            Some(vec![Some(6)]),
+        ]);
+        let indices = Int64Array::from(vec![Some(1), Some(1), Some(0), Some(0), Some(-1), None]);
+
+        let actual = list_get(&list, &indices).unwrap();
+        let expected = Int64Array::from(vec![Some(1), None, None, None, None, None]);
+        assert_eq!(as_primitive_array::<Int64Type>(actual.as_ref()), &expected);
+    }
+
+    #[test]
+    fn test_index_string_list() {
+        let mut list = ListBuilder::new(StringBuilder::new());
+        list.append_value([Some("hello"), Some("world")]);
+        list.append_value([Some("hi")]);
+        list.append_value([Some("earth"), None, Some("!")]);
+        let list = list.finish();
+        let indices = Int64Array::from(vec![1, 1, 2]);
+
+        let actual = list_get(&list, &indices).unwrap();
+        let expected = StringArray::from(vec![Some("world"), None, Some("!")]);
+        assert_eq!(as_string_array(actual.as_ref()), &expected);
+    }
+}
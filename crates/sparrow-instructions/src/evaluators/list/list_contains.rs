@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, BooleanArray, ListArray};
+use sparrow_plan::ValueRef;
+
+use crate::evaluators::map::element_indices;
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for `list_contains` on lists.
+#[derive(Debug)]
+pub(in crate::evaluators) struct ListContainsEvaluator {
+    value: ValueRef,
+    list: ValueRef,
+}
+
+impl EvaluatorFactory for ListContainsEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (value, list) = info.unpack_arguments()?;
+        Ok(Box::new(Self { value, list }))
+    }
+}
+
+impl Evaluator for ListContainsEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let list_input = info.value(&self.list)?.list_array()?;
+        let value_input = info.value(&self.value)?.array_ref()?;
+
+        let result = list_contains(list_input.as_ref(), value_input.as_ref())?;
+        Ok(Arc::new(result))
+    }
+}
+
+/// Returns whether each list contains the corresponding value.
+///
+/// The result is `null` if either the list or the value is `null`. Items
+/// which are `null` are never matched.
+fn list_contains(list: &ListArray, values: &dyn Array) -> anyhow::Result<BooleanArray> {
+    anyhow::ensure!(list.len() == values.len());
+    let indices = element_indices(list.offsets(), list.values(), values)?;
+    Ok((0..list.len())
+        .map(|index| {
+            (list.is_valid(index) && values.is_valid(index)).then_some(indices.is_valid(index))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int64Array, ListBuilder, StringArray, StringBuilder};
+    use arrow::datatypes::Int64Type;
+
+    use super::*;
+
+    #[test]
+    fn test_list_contains_i64() {
+        let list = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(0), Some(1), Some(2)]),
+            Some(vec![Some(3), None]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4)]),
+        ]);
+        let values = Int64Array::from(vec![Some(1), Some(4), Some(0), Some(0), None]);
+
+        let actual = list_contains(&list, &values).unwrap();
+        let expected = BooleanArray::from(vec![Some(true), Some(false), None, Some(false), None]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_list_contains_string() {
+        let mut list = ListBuilder::new(StringBuilder::new());
+        list.append_value([Some("hello"), Some("world")]);
+        list.append_value([Some("hi"), None]);
+        let list = list.finish();
+        let values = StringArray::from(vec!["world", "hello"]);
+
+        let actual = list_contains(&list, &values).unwrap();
+        let expected = BooleanArray::from(vec![true, false]);
+        assert_eq!(actual, expected);
+    }
+}
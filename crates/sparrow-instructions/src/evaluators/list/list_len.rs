@@ -0,0 +1,25 @@
+use arrow::array::ArrayRef;
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for the `list_len` function.
+#[derive(Debug)]
+pub(in crate::evaluators) struct ListLenEvaluator {
+    list: ValueRef,
+}
+
+impl EvaluatorFactory for ListLenEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let list = info.unpack_argument()?;
+        Ok(Box::new(Self { list }))
+    }
+}
+
+impl Evaluator for ListLenEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let list_input = info.value(&self.list)?.array_ref()?;
+        let result = arrow::compute::kernels::length::length(list_input.as_ref())?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,93 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, ListArray, PrimitiveArray};
+use arrow::datatypes::{ArrowNativeTypeOp, ArrowNumericType};
+use sparrow_arrow::downcast::downcast_primitive_array;
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for `list_sum` on lists of numbers.
+pub(in crate::evaluators) struct ListSumEvaluator<T: ArrowNumericType> {
+    list: ValueRef,
+    // Use the type parameter and indicate it is invariant.
+    _phantom: PhantomData<fn(T) -> T>,
+}
+
+impl<T: ArrowNumericType> EvaluatorFactory for ListSumEvaluator<T> {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let list = info.unpack_argument()?;
+        Ok(Box::new(Self {
+            list,
+            _phantom: PhantomData,
+        }))
+    }
+}
+
+impl<T: ArrowNumericType> Evaluator for ListSumEvaluator<T> {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let list_input = info.value(&self.list)?.list_array()?;
+        let result = list_sum::<T>(list_input.as_ref())?;
+        Ok(Arc::new(result))
+    }
+}
+
+/// Returns the sum of the items in each list.
+///
+/// Items which are `null` are ignored. The result is `null` if the list is
+/// `null` or contains no non-`null` items.
+fn list_sum<T: ArrowNumericType>(list: &ListArray) -> anyhow::Result<PrimitiveArray<T>> {
+    let items: &PrimitiveArray<T> = downcast_primitive_array(list.values().as_ref())?;
+    let result = list
+        .value_offsets()
+        .windows(2)
+        .enumerate()
+        .map(|(index, offsets)| {
+            if list.is_null(index) {
+                return None;
+            }
+
+            (offsets[0] as usize..offsets[1] as usize)
+                .filter(|item| items.is_valid(*item))
+                .map(|item| items.value(item))
+                .reduce(|sum, item| sum.add_wrapping(item))
+        })
+        .collect();
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Float64Array, Int64Array};
+    use arrow::datatypes::{Float64Type, Int64Type};
+
+    use super::*;
+
+    #[test]
+    fn test_list_sum_i64() {
+        let list = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(0), Some(1), Some(2)]),
+            Some(vec![Some(3), None]),
+            None,
+            Some(vec![]),
+            Some(vec![None]),
+        ]);
+
+        let actual = list_sum::<Int64Type>(&list).unwrap();
+        let expected = Int64Array::from(vec![Some(3), Some(3), None, None, None]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_list_sum_f64() {
+        let list = ListArray::from_iter_primitive::<Float64Type, _, _>(vec![
+            Some(vec![Some(0.5), Some(1.25)]),
+            Some(vec![Some(-3.0)]),
+        ]);
+
+        let actual = list_sum::<Float64Type>(&list).unwrap();
+        let expected = Float64Array::from(vec![1.75, -3.0]);
+        assert_eq!(actual, expected);
+    }
+}
@@ -168,16 +168,18 @@ macro_rules! create_ordered_evaluator {
             DataType::UInt64 => $evaluator::<$aggf<UInt64Type>>::try_new($info),
             DataType::Float32 => $evaluator::<$aggf<Float32Type>>::try_new($info),
             DataType::Float64 => $evaluator::<$aggf<Float64Type>>::try_new($info),
-            DataType::Timestamp(TimeUnit::Second, None) => {
+            // Zoned timestamps use the same primitive types. Results are
+            // given the time zone by the `ZonedTimestampEvaluator`.
+            DataType::Timestamp(TimeUnit::Second, _) => {
                 $evaluator::<$aggf<TimestampSecondType>>::try_new($info)
             }
-            DataType::Timestamp(TimeUnit::Millisecond, None) => {
+            DataType::Timestamp(TimeUnit::Millisecond, _) => {
                 $evaluator::<$aggf<TimestampMillisecondType>>::try_new($info)
             }
-            DataType::Timestamp(TimeUnit::Microsecond, None) => {
+            DataType::Timestamp(TimeUnit::Microsecond, _) => {
                 $evaluator::<$aggf<TimestampMicrosecondType>>::try_new($info)
             }
-            DataType::Timestamp(TimeUnit::Nanosecond, None) => {
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => {
                 $evaluator::<$aggf<TimestampNanosecondType>>::try_new($info)
             }
             unsupported_type => {
@@ -240,16 +242,18 @@ macro_rules! create_typed_evaluator {
             UInt64 => $primitive_evaluator::<$aggf<UInt64Type>>::try_new($info),
             Float32 => $primitive_evaluator::<$aggf<Float32Type>>::try_new($info),
             Float64 => $primitive_evaluator::<$aggf<Float64Type>>::try_new($info),
-            Timestamp(TimeUnit::Microsecond, None) => $primitive_evaluator::<$aggf<
+            // Zoned timestamps use the same primitive types. Results are
+            // given the time zone by the `ZonedTimestampEvaluator`.
+            Timestamp(TimeUnit::Microsecond, _) => $primitive_evaluator::<$aggf<
                 TimestampMicrosecondType,
             >>::try_new($info),
-            Timestamp(TimeUnit::Millisecond, None) => $primitive_evaluator::<$aggf<
+            Timestamp(TimeUnit::Millisecond, _) => $primitive_evaluator::<$aggf<
                 TimestampMillisecondType,
             >>::try_new($info),
-            Timestamp(TimeUnit::Nanosecond, None) => $primitive_evaluator::<$aggf<
+            Timestamp(TimeUnit::Nanosecond, _) => $primitive_evaluator::<$aggf<
                 TimestampNanosecondType,
             >>::try_new($info),
-            Timestamp(TimeUnit::Second, None) => {
+            Timestamp(TimeUnit::Second, _) => {
                 $primitive_evaluator::<$aggf<TimestampSecondType>>::try_new($info)
             }
             Date32 => $primitive_evaluator::<$aggf<Date32Type>>::try_new($info),
@@ -297,7 +301,40 @@ macro_rules! create_typed_evaluator {
     }};
 }
 
+/// Create a `Box<dyn Evaluator>` for an instruction on `key` values.
+///
+/// Key values are those which may be hashed and compared for equality, such
+/// as integers, booleans and strings. Unlike `create_typed_evaluator`, this
+/// doesn't include floating point values.
+///
+/// The `$evaluator` must be a `struct` that takes a single `CollectType`
+/// generic and which implements `Evaluator`.
+macro_rules! create_key_evaluator {
+    ($input_type:expr, $evaluator:ident, $info:expr) => {{
+        use arrow::datatypes::*;
+        use $crate::{CollectBoolean, CollectPrimitive, CollectString};
+        use DataType::*;
+
+        match $input_type {
+            Int8 => $evaluator::<CollectPrimitive<Int8Type>>::try_new($info),
+            Int16 => $evaluator::<CollectPrimitive<Int16Type>>::try_new($info),
+            Int32 => $evaluator::<CollectPrimitive<Int32Type>>::try_new($info),
+            Int64 => $evaluator::<CollectPrimitive<Int64Type>>::try_new($info),
+            UInt16 => $evaluator::<CollectPrimitive<UInt16Type>>::try_new($info),
+            UInt32 => $evaluator::<CollectPrimitive<UInt32Type>>::try_new($info),
+            UInt64 => $evaluator::<CollectPrimitive<UInt64Type>>::try_new($info),
+            Boolean => $evaluator::<CollectBoolean>::try_new($info),
+            Utf8 => $evaluator::<CollectString>::try_new($info),
+            unsupported => Err(anyhow::anyhow!(format!(
+                "Unsupported key type {:?} for {}",
+                unsupported,
+                stringify!($evaluator)
+            ))),
+        }
+    }};
+}
+
 pub(super) use {
-    create_float_evaluator, create_number_evaluator, create_ordered_evaluator,
-    create_signed_evaluator, create_typed_evaluator,
+    create_float_evaluator, create_key_evaluator, create_number_evaluator,
+    create_ordered_evaluator, create_signed_evaluator, create_typed_evaluator,
 };
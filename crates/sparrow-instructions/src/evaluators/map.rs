@@ -1,2 +1,9 @@
+mod contains_key;
 mod get;
+mod keys;
+mod values;
+
+pub(super) use contains_key::*;
 pub(super) use get::*;
+pub(super) use keys::*;
+pub(super) use values::*;
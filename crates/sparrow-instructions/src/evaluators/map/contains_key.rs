@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, BooleanArray, MapArray};
+use sparrow_plan::ValueRef;
+
+use crate::evaluators::map::map_indices;
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for `contains_key` on maps.
+#[derive(Debug)]
+pub(in crate::evaluators) struct ContainsKeyEvaluator {
+    key: ValueRef,
+    map: ValueRef,
+}
+
+impl EvaluatorFactory for ContainsKeyEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (key, map) = info.unpack_arguments()?;
+        Ok(Box::new(Self { key, map }))
+    }
+}
+
+impl Evaluator for ContainsKeyEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let map_input = info.value(&self.map)?.map_array()?;
+        let key_input = info.value(&self.key)?.array_ref()?;
+
+        let result = map_contains_key(&map_input, &key_input)?;
+        Ok(Arc::new(result))
+    }
+}
+
+/// Returns whether each map contains the corresponding key.
+///
+/// The result is `null` if either the map or the key is `null`.
+fn map_contains_key(map: &MapArray, keys: &dyn Array) -> anyhow::Result<BooleanArray> {
+    anyhow::ensure!(map.len() == keys.len());
+    let indices = map_indices(map, keys)?;
+    Ok((0..map.len())
+        .map(|index| {
+            (map.is_valid(index) && keys.is_valid(index)).then_some(indices.is_valid(index))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int64Builder, MapBuilder, StringArray, StringBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_contains_string_key() {
+        let mut map = MapBuilder::new(None, StringBuilder::new(), Int64Builder::new());
+
+        // 0: { "hello": 1 }
+        map.keys().append_value("hello");
+        map.values().append_value(1);
+        map.append(true).unwrap();
+
+        // 1: null
+        map.append(false).unwrap();
+
+        // 2: {}
+        map.append(true).unwrap();
+
+        // 3: { "hi": 2, "hello": null }
+        map.keys().append_value("hi");
+        map.values().append_value(2);
+        map.keys().append_value("hello");
+        map.values().append_null();
+        map.append(true).unwrap();
+
+        // 4: { "hi": 3 }
+        map.keys().append_value("hi");
+        map.values().append_value(3);
+        map.append(true).unwrap();
+
+        let map = map.finish();
+
+        let keys = StringArray::from(vec![
+            Some("hello"),
+            Some("hello"),
+            Some("hello"),
+            Some("hello"),
+            None,
+        ]);
+        let actual = map_contains_key(&map, &keys).unwrap();
+        let expected = BooleanArray::from(vec![Some(true), None, Some(false), Some(true), None]);
+        assert_eq!(actual, expected);
+    }
+}
@@ -67,7 +67,10 @@ fn map_get(map: &MapArray, keys: &dyn Array) -> anyhow::Result<ArrayRef> {
 }
 
 /// Gets the indices in the map where the map keys match the next key in `keys`.
-fn map_indices(map: &MapArray, keys: &dyn Array) -> anyhow::Result<Int32Array> {
+pub(in crate::evaluators) fn map_indices(
+    map: &MapArray,
+    keys: &dyn Array,
+) -> anyhow::Result<Int32Array> {
     anyhow::ensure!(
         map.key_type() == keys.data_type(),
         "Expected map keys {} to be same type as keys {}",
@@ -75,30 +78,48 @@ fn map_indices(map: &MapArray, keys: &dyn Array) -> anyhow::Result<Int32Array> {
         keys.data_type()
     );
 
-    let offsets = map.offsets();
+    element_indices(map.offsets(), map.keys(), keys)
+}
+
+/// Gets the indices of the first element in each slice of `elements` matching
+/// the corresponding key in `keys`.
+///
+/// The slices are delimited by `offsets`, as in the list and map arrays. The
+/// index is `null` if the key is `null` or no element matches the key.
+pub(in crate::evaluators) fn element_indices(
+    offsets: &OffsetBuffer<i32>,
+    elements: &dyn Array,
+    keys: &dyn Array,
+) -> anyhow::Result<Int32Array> {
+    anyhow::ensure!(
+        elements.data_type() == keys.data_type(),
+        "Expected elements {} to be same type as keys {}",
+        elements.data_type(),
+        keys.data_type()
+    );
 
     downcast_primitive_array!(
         keys => {
-            let map_keys = as_primitive_array(map.keys());
-            Ok(accessible_array_map_indices(offsets, map_keys, keys))
+            let elements = as_primitive_array(elements);
+            Ok(accessible_array_map_indices(offsets, elements, keys))
         }
         DataType::Utf8 => {
             let keys = as_string_array(keys);
-            let map_keys = as_string_array(map.keys());
-            Ok(accessible_array_map_indices(offsets, map_keys, keys))
+            let elements = as_string_array(elements);
+            Ok(accessible_array_map_indices(offsets, elements, keys))
         }
         DataType::LargeUtf8 => {
             let keys = as_largestring_array(keys);
-            let map_keys = as_largestring_array(map.keys());
-            Ok(accessible_array_map_indices(offsets, map_keys, keys))
+            let elements = as_largestring_array(elements);
+            Ok(accessible_array_map_indices(offsets, elements, keys))
         }
         DataType::Boolean => {
             let keys = as_boolean_array(keys);
-            let map_keys = as_boolean_array(map.keys());
-            Ok(accessible_array_map_indices(offsets, map_keys, keys))
+            let elements = as_boolean_array(elements);
+            Ok(accessible_array_map_indices(offsets, elements, keys))
         }
         unsupported => {
-            anyhow::bail!("Unsupported key type {:?} for map get", unsupported);
+            anyhow::bail!("Unsupported key type {:?} for element lookup", unsupported);
         }
     )
 }
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, ListArray, MapArray};
+use arrow::datatypes::{DataType, FieldRef};
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for `values` on maps.
+#[derive(Debug)]
+pub(in crate::evaluators) struct ValuesEvaluator {
+    map: ValueRef,
+    /// The field of the resulting list.
+    field: FieldRef,
+}
+
+impl EvaluatorFactory for ValuesEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let field = match info.result_type {
+            DataType::List(field) => field.clone(),
+            other => anyhow::bail!("expected list result type, saw {:?}", other),
+        };
+
+        let map = info.unpack_argument()?;
+        Ok(Box::new(Self { map, field }))
+    }
+}
+
+impl Evaluator for ValuesEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let map_input = info.value(&self.map)?.map_array()?;
+        let result = map_values(&map_input, self.field.clone())?;
+        Ok(Arc::new(result))
+    }
+}
+
+/// Returns a list containing the values of each map.
+fn map_values(map: &MapArray, field: FieldRef) -> anyhow::Result<ListArray> {
+    let result = ListArray::try_new(
+        field,
+        map.offsets().clone(),
+        map.values().clone(),
+        map.nulls().cloned(),
+    )?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int64Builder, ListBuilder, MapBuilder, StringBuilder};
+    use arrow::datatypes::Field;
+
+    use super::*;
+
+    #[test]
+    fn test_map_values() {
+        let mut map = MapBuilder::new(None, StringBuilder::new(), Int64Builder::new());
+
+        // 0: { "hello": 1, "hi": null }
+        map.keys().append_value("hello");
+        map.values().append_value(1);
+        map.keys().append_value("hi");
+        map.values().append_null();
+        map.append(true).unwrap();
+
+        // 1: null
+        map.append(false).unwrap();
+
+        // 2: { "hi": 2 }
+        map.keys().append_value("hi");
+        map.values().append_value(2);
+        map.append(true).unwrap();
+
+        let map = map.finish();
+
+        let field = Arc::new(Field::new("item", DataType::Int64, true));
+        let actual = map_values(&map, field).unwrap();
+
+        let mut expected = ListBuilder::new(Int64Builder::new());
+        expected.append_value([Some(1), None]);
+        expected.append(false);
+        expected.append_value([Some(2)]);
+        let expected = expected.finish();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, StringArray, StructArray};
+use arrow::buffer::NullBuffer;
+use arrow::datatypes::DataType;
+use itertools::Itertools;
+use serde_json::Value;
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for the `parse_json` function.
+///
+/// This evaluator expects a `string` input, which it parses as `json` and
+/// converts to the result type. The `schema` argument is only used to
+/// determine the result type, so its values are ignored.
+///
+/// Fields of records are read from the corresponding fields of `json`
+/// objects. Other values are read as strings, as in `json_field`, and cast to
+/// the expected type. Values which are missing or can't be converted are
+/// `null`.
+pub(super) struct ParseJsonEvaluator {
+    input: ValueRef,
+    result_type: DataType,
+}
+
+impl Evaluator for ParseJsonEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let input = info.value(&self.input)?.string_array::<i32>()?;
+
+        // As with `json_field`, strings that are not json-formatted produce `null`
+        // rather than an error.
+        let jsons: Vec<Option<Value>> = input
+            .iter()
+            .map(|s| s.and_then(|s| serde_json::from_str(s).ok()))
+            .collect();
+        let jsons: Vec<Option<&Value>> = jsons.iter().map(Option::as_ref).collect();
+        json_to_array(&jsons, &self.result_type)
+    }
+}
+
+impl EvaluatorFactory for ParseJsonEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let result_type = info.result_type.clone();
+        let (input, _schema) = info.unpack_arguments()?;
+        Ok(Box::new(Self { input, result_type }))
+    }
+}
+
+/// Convert the `json` values to an array of the given type.
+fn json_to_array(jsons: &[Option<&Value>], data_type: &DataType) -> anyhow::Result<ArrayRef> {
+    match data_type {
+        DataType::Struct(fields) => {
+            let nulls: NullBuffer = jsons
+                .iter()
+                .map(|json| matches!(json, Some(Value::Object(_))))
+                .collect();
+            let columns: Vec<_> = fields
+                .iter()
+                .map(|field| {
+                    let values: Vec<_> = jsons
+                        .iter()
+                        .map(|json| json.and_then(|json| json.get(field.name())))
+                        .collect();
+                    json_to_array(&values, field.data_type())
+                })
+                .try_collect()?;
+            Ok(Arc::new(StructArray::try_new(
+                fields.clone(),
+                columns,
+                Some(nulls),
+            )?))
+        }
+        DataType::Utf8 => Ok(Arc::new(json_to_strings(jsons))),
+        data_type => {
+            let strings = json_to_strings(jsons);
+            Ok(arrow::compute::cast(&strings, data_type)?)
+        }
+    }
+}
+
+/// Convert the `json` values to strings.
+///
+/// Strings are output without quotes, and other values as `json`.
+fn json_to_strings(jsons: &[Option<&Value>]) -> StringArray {
+    jsons
+        .iter()
+        .map(|json| match json {
+            None | Some(Value::Null) => None,
+            Some(Value::String(s)) => Some(s.to_owned()),
+            Some(value) => Some(value.to_string()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Array, BooleanArray, Float64Array, Int64Array};
+    use arrow::datatypes::{Field, Fields};
+    use sparrow_arrow::downcast::downcast_struct_array;
+
+    use super::*;
+
+    #[test]
+    fn test_json_to_struct() {
+        let jsons: Vec<Option<Value>> = vec![
+            r#"{ "a": 10, "b": "dog", "c": { "d": true } }"#,
+            r#"{ "a": "11", "c": { "d": false }, "e": 5 }"#,
+            r#"{ "a": 1.5, "b": ["cat"], "c": 5 }"#,
+            r#"[1, 2]"#,
+            "null",
+        ]
+        .into_iter()
+        .map(|s| serde_json::from_str(s).ok())
+        .collect();
+        let jsons: Vec<_> = jsons.iter().map(Option::as_ref).collect();
+
+        let c_fields = Fields::from(vec![Field::new("d", DataType::Boolean, true)]);
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Struct(c_fields), true),
+        ]);
+        let result = json_to_array(&jsons, &DataType::Struct(fields)).unwrap();
+        let result = downcast_struct_array(result.as_ref()).unwrap();
+
+        assert_eq!(
+            result.nulls().unwrap().iter().collect::<Vec<_>>(),
+            vec![true, true, true, false, false]
+        );
+        assert_eq!(
+            result.column(0).as_ref(),
+            &Int64Array::from(vec![Some(10), Some(11), None, None, None]) as &dyn Array
+        );
+        assert_eq!(
+            result.column(1).as_ref(),
+            &StringArray::from(vec![Some("dog"), None, Some("[\"cat\"]"), None, None])
+                as &dyn Array
+        );
+
+        let c = downcast_struct_array(result.column(2).as_ref()).unwrap();
+        assert_eq!(
+            c.nulls().unwrap().iter().collect::<Vec<_>>(),
+            vec![true, true, false, false, false]
+        );
+        assert_eq!(
+            c.column(0).as_ref(),
+            &BooleanArray::from(vec![Some(true), Some(false), None, None, None]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn test_json_to_primitive() {
+        let jsons: Vec<Option<Value>> = vec![Some("5.5"), Some("\"6\""), Some("{}"), None]
+            .into_iter()
+            .map(|s| s.and_then(|s| serde_json::from_str(s).ok()))
+            .collect();
+        let jsons: Vec<_> = jsons.iter().map(Option::as_ref).collect();
+
+        let result = json_to_array(&jsons, &DataType::Float64).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &Float64Array::from(vec![Some(5.5), Some(6.0), None, None]) as &dyn Array
+        );
+    }
+}
@@ -1,9 +1,14 @@
+use std::convert::Infallible;
 use std::sync::Arc;
 
-use arrow::array::ArrayRef;
-use arrow::datatypes::DataType;
+use anyhow::Context;
+use arrow::array::{ArrayRef, BooleanArray, StringArray};
+use arrow::datatypes::{DataType, Int64Type};
+use itertools::Itertools;
+use regex::Regex;
 use sparrow_arrow::downcast::downcast_primitive_array;
-use sparrow_plan::ValueRef;
+use sparrow_arrow::scalar_value::ScalarValue;
+use sparrow_plan::{InstKind, InstOp, ValueRef};
 
 use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
 
@@ -99,3 +104,226 @@ impl EvaluatorFactory for SubstringEvaluator {
         Ok(Box::new(Self { string, start, end }))
     }
 }
+
+/// Evaluator for the `trim` function.
+pub(super) struct TrimEvaluator {
+    input: ValueRef,
+}
+
+impl Evaluator for TrimEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let input = info.value(&self.input)?.string_array()?;
+        let result = sparrow_kernels::string::trim(input.as_ref())?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for TrimEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let input = info.unpack_argument()?;
+        Ok(Box::new(Self { input }))
+    }
+}
+
+/// Evaluator for the `pad` function.
+pub(super) struct PadEvaluator {
+    string: ValueRef,
+    length: ValueRef,
+    fill: ValueRef,
+}
+
+impl Evaluator for PadEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let string = info.value(&self.string)?.string_array()?;
+        let length = info.value(&self.length)?.primitive_array::<Int64Type>()?;
+        let fill = info.value(&self.fill)?.string_array()?;
+        let result = sparrow_kernels::string::pad(string.as_ref(), length.as_ref(), fill.as_ref())?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for PadEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (length, string, fill) = info.unpack_arguments()?;
+        Ok(Box::new(Self {
+            string,
+            length,
+            fill,
+        }))
+    }
+}
+
+/// Evaluator for the `concat` function.
+pub(super) struct ConcatEvaluator {
+    strings: Vec<ValueRef>,
+}
+
+impl Evaluator for ConcatEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let strings: Vec<_> = self
+            .strings
+            .iter()
+            .map(|string| info.value(string)?.string_array::<i32>())
+            .try_collect()?;
+        let strings: Vec<&StringArray> = strings.iter().map(|string| string.as_ref()).collect();
+        let result = sparrow_kernels::string::concat(&strings)?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for ConcatEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let strings = info.args.into_iter().map(|arg| arg.value_ref).collect();
+        Ok(Box::new(Self { strings }))
+    }
+}
+
+/// Evaluator for the `split` function.
+pub(super) struct SplitEvaluator {
+    string: ValueRef,
+    separator: ValueRef,
+}
+
+impl Evaluator for SplitEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let string = info.value(&self.string)?.string_array()?;
+        let separator = info.value(&self.separator)?.string_array()?;
+        let result = sparrow_kernels::string::split(string.as_ref(), separator.as_ref())?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for SplitEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (separator, string) = info.unpack_arguments()?;
+        Ok(Box::new(Self { string, separator }))
+    }
+}
+
+/// Evaluator for the `contains`, `starts_with` and `ends_with` functions.
+pub(super) struct StringComparisonEvaluator {
+    string: ValueRef,
+    other: ValueRef,
+    compare: fn(&StringArray, &StringArray) -> Result<BooleanArray, Infallible>,
+}
+
+impl Evaluator for StringComparisonEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let string = info.value(&self.string)?.string_array()?;
+        let other = info.value(&self.other)?.string_array()?;
+        let result = (self.compare)(string.as_ref(), other.as_ref())?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for StringComparisonEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let compare = match info.inst_kind {
+            InstKind::Simple(InstOp::Contains) => sparrow_kernels::string::contains,
+            InstKind::Simple(InstOp::StartsWith) => sparrow_kernels::string::starts_with,
+            InstKind::Simple(InstOp::EndsWith) => sparrow_kernels::string::ends_with,
+            unexpected => anyhow::bail!("Unexpected string comparison {unexpected:?}"),
+        };
+        let (other, string) = info.unpack_arguments()?;
+        Ok(Box::new(Self {
+            string,
+            other,
+            compare,
+        }))
+    }
+}
+
+/// Evaluator for the `regex_match` function.
+pub(super) struct RegexMatchEvaluator {
+    string: ValueRef,
+    regex: Regex,
+}
+
+impl Evaluator for RegexMatchEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let string = info.value(&self.string)?.string_array()?;
+        let result = sparrow_kernels::string::regex_match(string.as_ref(), &self.regex)?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for RegexMatchEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (pattern, string) = info.unpack_arguments()?;
+        let regex = literal_regex(&pattern)?;
+        Ok(Box::new(Self { string, regex }))
+    }
+}
+
+/// Evaluator for the `regex_extract` function.
+pub(super) struct RegexExtractEvaluator {
+    string: ValueRef,
+    regex: Regex,
+    group: ValueRef,
+}
+
+impl Evaluator for RegexExtractEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let string = info.value(&self.string)?.string_array()?;
+        let group = info.value(&self.group)?.primitive_array::<Int64Type>()?;
+        let result =
+            sparrow_kernels::string::regex_extract(string.as_ref(), &self.regex, group.as_ref())?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for RegexExtractEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (pattern, string, group) = info.unpack_arguments()?;
+        let regex = literal_regex(&pattern)?;
+        Ok(Box::new(Self {
+            string,
+            regex,
+            group,
+        }))
+    }
+}
+
+/// Evaluator for the `regex_replace` function.
+pub(super) struct RegexReplaceEvaluator {
+    string: ValueRef,
+    regex: Regex,
+    replacement: ValueRef,
+}
+
+impl Evaluator for RegexReplaceEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let string = info.value(&self.string)?.string_array()?;
+        let replacement = info.value(&self.replacement)?.string_array()?;
+        let result = sparrow_kernels::string::regex_replace(
+            string.as_ref(),
+            &self.regex,
+            replacement.as_ref(),
+        )?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for RegexReplaceEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (pattern, replacement, string) = info.unpack_arguments()?;
+        let regex = literal_regex(&pattern)?;
+        Ok(Box::new(Self {
+            string,
+            regex,
+            replacement,
+        }))
+    }
+}
+
+/// Compile the regex for a literal `pattern`.
+///
+/// Patterns are required to be constant so they are only compiled once.
+fn literal_regex(pattern: &ValueRef) -> anyhow::Result<Regex> {
+    match pattern {
+        ValueRef::Literal(ScalarValue::Utf8(Some(pattern))) => {
+            Regex::new(pattern).with_context(|| format!("Invalid regex pattern '{pattern}'"))
+        }
+        unexpected => anyhow::bail!("Expected literal string pattern, saw {unexpected:?}"),
+    }
+}
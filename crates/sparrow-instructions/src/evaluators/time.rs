@@ -2,8 +2,8 @@ use std::sync::Arc;
 
 use anyhow::anyhow;
 use arrow::array::{
-    ArrayRef, Int32Array, IntervalDayTimeArray, IntervalYearMonthArray, TimestampNanosecondArray,
-    UInt32Array,
+    Array, ArrayRef, Int32Array, IntervalDayTimeArray, IntervalYearMonthArray,
+    TimestampNanosecondArray, UInt32Array,
 };
 use arrow::datatypes::{
     ArrowPrimitiveType, DataType, DurationMicrosecondType, DurationMillisecondType,
@@ -11,11 +11,13 @@ use arrow::datatypes::{
     IntervalYearMonthType, TimeUnit, TimestampNanosecondType,
 };
 use arrow::temporal_conversions::timestamp_ns_to_datetime;
-use chrono::Datelike;
+use chrono::{Datelike, TimeZone};
+use chrono_tz::Tz;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use sparrow_arrow::scalar_value::ScalarValue;
 use sparrow_kernels::lag::LagPrimitive;
+use sparrow_kernels::time::parse_time_zone;
 use sparrow_plan::ValueRef;
 
 use crate::evaluators::{Evaluator, RuntimeInfo};
@@ -73,24 +75,49 @@ impl EvaluatorFactory for DaysEvaluator {
 /// Evaluator for the `DayOfMonth` instruction.
 pub(super) struct DayOfMonthEvaluator {
     input: ValueRef,
+    time_zone: Option<Tz>,
 }
 
-#[allow(clippy::redundant_closure)]
+/// Applies `f` to the date time of each timestamp.
+///
+/// If a `time_zone` is given, `f` receives the local date time in that zone.
+/// Otherwise, it receives the UTC date time.
 fn evaluate_time_accessor<O, F>(
     time: &TimestampNanosecondArray,
+    time_zone: Option<Tz>,
     f: F,
 ) -> arrow::array::PrimitiveArray<O>
 where
     O: ArrowPrimitiveType,
     F: Fn(chrono::NaiveDateTime) -> O::Native,
 {
-    time.unary_opt(|t| timestamp_ns_to_datetime(t).map(|t| f(t)))
+    match time_zone {
+        Some(time_zone) => time.unary_opt(|t| {
+            timestamp_ns_to_datetime(t).map(|t| f(time_zone.from_utc_datetime(&t).naive_local()))
+        }),
+        None => time.unary_opt(|t| timestamp_ns_to_datetime(t).map(&f)),
+    }
+}
+
+/// Unpacks the `time` and optional `time_zone` arguments of a calendar
+/// instruction.
+///
+/// The time zone must be a literal string, or null to use UTC.
+fn unpack_time_and_zone(info: StaticInfo<'_>) -> anyhow::Result<(ValueRef, Option<Tz>)> {
+    let (input, time_zone) = info.unpack_arguments()?;
+    let time_zone = match time_zone.literal_value() {
+        Some(ScalarValue::Utf8(Some(name))) => Some(parse_time_zone(name)?),
+        Some(literal) if literal.is_null() => None,
+        _ => anyhow::bail!("time zone must be a literal string, but was {time_zone:?}"),
+    };
+    Ok((input, time_zone))
 }
 
 impl Evaluator for DayOfMonthEvaluator {
     fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
         let time = info.value(&self.input)?.primitive_array()?;
-        let result: UInt32Array = evaluate_time_accessor(time.as_ref(), |t| t.day());
+        let result: UInt32Array =
+            evaluate_time_accessor(time.as_ref(), self.time_zone, |t| t.day());
 
         Ok(Arc::new(result))
     }
@@ -98,20 +125,22 @@ impl Evaluator for DayOfMonthEvaluator {
 
 impl EvaluatorFactory for DayOfMonthEvaluator {
     fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
-        let input = info.unpack_argument()?;
-        Ok(Box::new(Self { input }))
+        let (input, time_zone) = unpack_time_and_zone(info)?;
+        Ok(Box::new(Self { input, time_zone }))
     }
 }
 
 /// Evaluator for the `DayOfMonth0` instruction.
 pub(super) struct DayOfMonth0Evaluator {
     input: ValueRef,
+    time_zone: Option<Tz>,
 }
 
 impl Evaluator for DayOfMonth0Evaluator {
     fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
         let time = info.value(&self.input)?.primitive_array()?;
-        let result: UInt32Array = evaluate_time_accessor(time.as_ref(), |t| t.day0());
+        let result: UInt32Array =
+            evaluate_time_accessor(time.as_ref(), self.time_zone, |t| t.day0());
 
         Ok(Arc::new(result))
     }
@@ -119,48 +148,52 @@ impl Evaluator for DayOfMonth0Evaluator {
 
 impl EvaluatorFactory for DayOfMonth0Evaluator {
     fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
-        let input = info.unpack_argument()?;
-        Ok(Box::new(Self { input }))
+        let (input, time_zone) = unpack_time_and_zone(info)?;
+        Ok(Box::new(Self { input, time_zone }))
     }
 }
 
 /// Evaluator for the `DayOfYear` instruction.
 pub(super) struct DayOfYearEvaluator {
     input: ValueRef,
+    time_zone: Option<Tz>,
 }
 
 impl Evaluator for DayOfYearEvaluator {
     fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
         let time = info.value(&self.input)?.primitive_array()?;
-        let result: UInt32Array = evaluate_time_accessor(time.as_ref(), |t| t.ordinal());
+        let result: UInt32Array =
+            evaluate_time_accessor(time.as_ref(), self.time_zone, |t| t.ordinal());
         Ok(Arc::new(result))
     }
 }
 
 impl EvaluatorFactory for DayOfYearEvaluator {
     fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
-        let input = info.unpack_argument()?;
-        Ok(Box::new(Self { input }))
+        let (input, time_zone) = unpack_time_and_zone(info)?;
+        Ok(Box::new(Self { input, time_zone }))
     }
 }
 
 /// Evaluator for the `DayOfYear0` instruction.
 pub(super) struct DayOfYear0Evaluator {
     input: ValueRef,
+    time_zone: Option<Tz>,
 }
 
 impl Evaluator for DayOfYear0Evaluator {
     fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
         let time = info.value(&self.input)?.primitive_array()?;
-        let result: UInt32Array = evaluate_time_accessor(time.as_ref(), |t| t.ordinal0());
+        let result: UInt32Array =
+            evaluate_time_accessor(time.as_ref(), self.time_zone, |t| t.ordinal0());
         Ok(Arc::new(result))
     }
 }
 
 impl EvaluatorFactory for DayOfYear0Evaluator {
     fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
-        let input = info.unpack_argument()?;
-        Ok(Box::new(Self { input }))
+        let (input, time_zone) = unpack_time_and_zone(info)?;
+        Ok(Box::new(Self { input, time_zone }))
     }
 }
 
@@ -199,60 +232,66 @@ impl EvaluatorFactory for MonthsEvaluator {
 /// Evaluator for the `MonthOfYear` instruction.
 pub(super) struct MonthOfYearEvaluator {
     input: ValueRef,
+    time_zone: Option<Tz>,
 }
 
 impl Evaluator for MonthOfYearEvaluator {
     fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
         let time = info.value(&self.input)?.primitive_array()?;
-        let result: UInt32Array = evaluate_time_accessor(time.as_ref(), |t| t.month());
+        let result: UInt32Array =
+            evaluate_time_accessor(time.as_ref(), self.time_zone, |t| t.month());
         Ok(Arc::new(result))
     }
 }
 
 impl EvaluatorFactory for MonthOfYearEvaluator {
     fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
-        let input = info.unpack_argument()?;
-        Ok(Box::new(Self { input }))
+        let (input, time_zone) = unpack_time_and_zone(info)?;
+        Ok(Box::new(Self { input, time_zone }))
     }
 }
 
 /// Evaluator for the `MonthOfYear0` instruction.
 pub(super) struct MonthOfYear0Evaluator {
     input: ValueRef,
+    time_zone: Option<Tz>,
 }
 
 impl Evaluator for MonthOfYear0Evaluator {
     fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
         let time = info.value(&self.input)?.primitive_array()?;
-        let result: UInt32Array = evaluate_time_accessor(time.as_ref(), |t| t.month0());
+        let result: UInt32Array =
+            evaluate_time_accessor(time.as_ref(), self.time_zone, |t| t.month0());
         Ok(Arc::new(result))
     }
 }
 
 impl EvaluatorFactory for MonthOfYear0Evaluator {
     fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
-        let input = info.unpack_argument()?;
-        Ok(Box::new(Self { input }))
+        let (input, time_zone) = unpack_time_and_zone(info)?;
+        Ok(Box::new(Self { input, time_zone }))
     }
 }
 
 /// Evaluator for the `Year` instruction.
 pub(super) struct YearEvaluator {
     input: ValueRef,
+    time_zone: Option<Tz>,
 }
 
 impl Evaluator for YearEvaluator {
     fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
         let time = info.value(&self.input)?.primitive_array()?;
-        let result: Int32Array = evaluate_time_accessor(time.as_ref(), |t| t.year());
+        let result: Int32Array =
+            evaluate_time_accessor(time.as_ref(), self.time_zone, |t| t.year());
         Ok(Arc::new(result))
     }
 }
 
 impl EvaluatorFactory for YearEvaluator {
     fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
-        let input = info.unpack_argument()?;
-        Ok(Box::new(Self { input }))
+        let (input, time_zone) = unpack_time_and_zone(info)?;
+        Ok(Box::new(Self { input, time_zone }))
     }
 }
 
@@ -457,3 +496,41 @@ where
         }
     }
 }
+
+/// Wraps an evaluator producing timestamps with the time zone of the result.
+///
+/// Most evaluators are generic over the primitive timestamp type, which
+/// doesn't include a time zone. The values are always relative to the UTC
+/// epoch, so the zone is attached without changing them.
+pub(super) struct ZonedTimestampEvaluator {
+    inner: Box<dyn Evaluator>,
+    data_type: DataType,
+}
+
+impl ZonedTimestampEvaluator {
+    pub(super) fn new(inner: Box<dyn Evaluator>, data_type: DataType) -> Self {
+        Self { inner, data_type }
+    }
+}
+
+impl Evaluator for ZonedTimestampEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let result = self.inner.evaluate(info)?;
+        if result.data_type() == &self.data_type {
+            Ok(result)
+        } else {
+            Ok(sparrow_arrow::utils::cast_timestamp(
+                &result,
+                &self.data_type,
+            )?)
+        }
+    }
+
+    fn state_token(&self) -> Option<&dyn StateToken> {
+        self.inner.state_token()
+    }
+
+    fn state_token_mut(&mut self) -> Option<&mut dyn StateToken> {
+        self.inner.state_token_mut()
+    }
+}
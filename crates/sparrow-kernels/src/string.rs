@@ -1,8 +1,20 @@
+mod concat;
+mod contains;
 mod lower;
+mod pad;
+mod regex;
+mod split;
 mod substring;
+mod trim;
 mod upper;
 
+pub use concat::concat;
+pub use contains::{contains, ends_with, starts_with};
 pub use lower::lower;
+pub use pad::pad;
+pub use split::split;
+pub use trim::trim;
 pub use upper::upper;
 
+pub use self::regex::{regex_extract, regex_match, regex_replace};
 pub use self::substring::substring;
@@ -0,0 +1,38 @@
+use std::convert::Infallible;
+
+use arrow::array::{Array, StringArray, StringBuilder};
+
+/// Concatenate the corresponding elements of each of the `strings`.
+///
+/// The result is `null` if any of the elements are `null`.
+pub fn concat(strings: &[&StringArray]) -> Result<StringArray, Infallible> {
+    let len = strings.first().map(|s| s.len()).unwrap_or(0);
+    let mut builder = StringBuilder::with_capacity(len, 0);
+    let mut value = String::new();
+    'rows: for row in 0..len {
+        value.clear();
+        for string in strings {
+            if string.is_null(row) {
+                builder.append_null();
+                continue 'rows;
+            }
+            value.push_str(string.value(row));
+        }
+        builder.append_value(&value);
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concat() {
+        let a = StringArray::from(vec![Some("hello"), Some("a"), None]);
+        let b = StringArray::from(vec![Some(" "), Some(""), Some("b")]);
+        let c = StringArray::from(vec![Some("world"), Some("c"), Some("c")]);
+        let expected = StringArray::from(vec![Some("hello world"), Some("ac"), None]);
+        assert_eq!(concat(&[&a, &b, &c]).unwrap(), expected);
+    }
+}
@@ -0,0 +1,60 @@
+use std::convert::Infallible;
+
+use arrow::array::{BooleanArray, StringArray};
+use itertools::izip;
+
+/// Return whether each element of `base` contains the corresponding `substring`.
+pub fn contains(base: &StringArray, substring: &StringArray) -> Result<BooleanArray, Infallible> {
+    Ok(compare(base, substring, |s, substring| {
+        s.contains(substring)
+    }))
+}
+
+/// Return whether each element of `base` starts with the corresponding `prefix`.
+pub fn starts_with(base: &StringArray, prefix: &StringArray) -> Result<BooleanArray, Infallible> {
+    Ok(compare(base, prefix, |s, prefix| s.starts_with(prefix)))
+}
+
+/// Return whether each element of `base` ends with the corresponding `suffix`.
+pub fn ends_with(base: &StringArray, suffix: &StringArray) -> Result<BooleanArray, Infallible> {
+    Ok(compare(base, suffix, |s, suffix| s.ends_with(suffix)))
+}
+
+fn compare(
+    base: &StringArray,
+    other: &StringArray,
+    f: impl Fn(&str, &str) -> bool,
+) -> BooleanArray {
+    izip!(base.iter(), other.iter())
+        .map(|(s, other)| Some(f(s?, other?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains() {
+        let base = StringArray::from(vec![Some("hello"), Some("hello"), None, Some("hello")]);
+        let substring = StringArray::from(vec![Some("ell"), Some("world"), Some("a"), None]);
+        let expected = BooleanArray::from(vec![Some(true), Some(false), None, None]);
+        assert_eq!(contains(&base, &substring).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_starts_with() {
+        let base = StringArray::from(vec![Some("hello"), Some("hello"), Some("")]);
+        let prefix = StringArray::from(vec![Some("he"), Some("lo"), Some("")]);
+        let expected = BooleanArray::from(vec![Some(true), Some(false), Some(true)]);
+        assert_eq!(starts_with(&base, &prefix).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_ends_with() {
+        let base = StringArray::from(vec![Some("hello"), Some("hello"), None]);
+        let suffix = StringArray::from(vec![Some("he"), Some("lo"), Some("lo")]);
+        let expected = BooleanArray::from(vec![Some(false), Some(true), None]);
+        assert_eq!(ends_with(&base, &suffix).unwrap(), expected);
+    }
+}
@@ -0,0 +1,86 @@
+use std::convert::Infallible;
+
+use arrow::array::{Int64Array, StringArray};
+use itertools::izip;
+
+/// The maximum `length` to pad to, in characters.
+///
+/// Larger lengths produce null, rather than allocating an arbitrarily large
+/// string for each row.
+const MAX_PAD_LENGTH: i64 = 1 << 20;
+
+/// Pad the start of each element of `base` with `fill` to the given `length`.
+///
+/// The `length` is measured in characters. The `fill` is repeated as needed,
+/// and truncated if it doesn't evenly fit. Strings which are already at least
+/// `length` characters are returned unchanged, as are strings padded with an
+/// empty `fill`. The result is null if `length` exceeds 2^20 characters.
+pub fn pad(
+    base: &StringArray,
+    length: &Int64Array,
+    fill: &StringArray,
+) -> Result<StringArray, Infallible> {
+    Ok(izip!(base.iter(), length.iter(), fill.iter())
+        .map(|(s, length, fill)| {
+            let (s, length, fill) = (s?, length?, fill?);
+            if length > MAX_PAD_LENGTH {
+                return None;
+            }
+            let padding = usize::try_from(length)
+                .unwrap_or(0)
+                .saturating_sub(s.chars().count());
+            let mut result: String = fill.chars().cycle().take(padding).collect();
+            result.push_str(s);
+            Some(result)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::Array;
+
+    use super::*;
+
+    #[test]
+    fn test_pad() {
+        let base = StringArray::from(vec![
+            Some("5"),
+            Some("123"),
+            Some("abc"),
+            Some("ab"),
+            Some("ab"),
+            None,
+        ]);
+        let length = Int64Array::from(vec![Some(3), Some(2), Some(6), None, Some(5), Some(5)]);
+        let fill = StringArray::from(vec![
+            Some("0"),
+            Some("0"),
+            Some("xy"),
+            Some("0"),
+            Some(""),
+            Some("0"),
+        ]);
+        let expected = StringArray::from(vec![
+            Some("005"),
+            Some("123"),
+            Some("xyxabc"),
+            None,
+            Some("ab"),
+            None,
+        ]);
+        assert_eq!(pad(&base, &length, &fill).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_pad_length_limit() {
+        let base = StringArray::from(vec!["5", "5", "5"]);
+        let length = Int64Array::from(vec![MAX_PAD_LENGTH, MAX_PAD_LENGTH + 1, i64::MAX]);
+        let fill = StringArray::from(vec!["0", "0", "0"]);
+        let result = pad(&base, &length, &fill).unwrap();
+        assert_eq!(result.value(0).len(), MAX_PAD_LENGTH as usize);
+        assert!(result.value(0).ends_with("005"));
+        assert!(result.is_null(1));
+        assert!(result.is_null(2));
+    }
+}
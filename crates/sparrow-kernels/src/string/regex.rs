@@ -0,0 +1,94 @@
+use std::convert::Infallible;
+
+use arrow::array::{BooleanArray, Int64Array, StringArray};
+use itertools::izip;
+use regex::Regex;
+
+/// Return whether each element of `base` matches the `regex`.
+///
+/// The regex may match any part of the string. Anchors (`^` and `$`) may be
+/// used to require that the entire string matches.
+pub fn regex_match(base: &StringArray, regex: &Regex) -> Result<BooleanArray, Infallible> {
+    Ok(base.iter().map(|s| s.map(|s| regex.is_match(s))).collect())
+}
+
+/// Return the text matched by the capture `group` of the first match of the
+/// `regex` in each element of `base`.
+///
+/// Group `0` is the text matched by the entire regex. The result is `null` if
+/// the regex doesn't match or the group didn't participate in the match.
+pub fn regex_extract(
+    base: &StringArray,
+    regex: &Regex,
+    group: &Int64Array,
+) -> Result<StringArray, Infallible> {
+    Ok(izip!(base.iter(), group.iter())
+        .map(|(s, group)| {
+            let group = usize::try_from(group?).ok()?;
+            let captures = regex.captures(s?)?;
+            captures.get(group).map(|m| m.as_str())
+        })
+        .collect())
+}
+
+/// Replace all matches of the `regex` in each element of `base` with the
+/// corresponding `replacement`.
+///
+/// The replacement may refer to capture groups using `$1` or `${name}`.
+pub fn regex_replace(
+    base: &StringArray,
+    regex: &Regex,
+    replacement: &StringArray,
+) -> Result<StringArray, Infallible> {
+    Ok(izip!(base.iter(), replacement.iter())
+        .map(|(s, replacement)| Some(regex.replace_all(s?, replacement?)))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regex_match() {
+        let regex = Regex::new("^[a-z]+[0-9]$").unwrap();
+        let base = StringArray::from(vec![Some("abc1"), Some("abc"), Some("1abc1"), None]);
+        let expected = BooleanArray::from(vec![Some(true), Some(false), Some(false), None]);
+        assert_eq!(regex_match(&base, &regex).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_regex_extract() {
+        let regex = Regex::new(r"Chrome/(\d+)(\.\d+)?").unwrap();
+        let base = StringArray::from(vec![
+            Some("Mozilla/5.0 Chrome/114.0.0 Safari/537.36"),
+            Some("Mozilla/5.0 Chrome/99 Safari/537.36"),
+            Some("Mozilla/5.0 Firefox/114.0"),
+            Some("Mozilla/5.0 Chrome/114.0.0 Safari/537.36"),
+            Some("Mozilla/5.0 Chrome/114.0.0 Safari/537.36"),
+            None,
+        ]);
+        let group = Int64Array::from(vec![Some(1), Some(2), Some(1), Some(0), Some(5), Some(0)]);
+        let expected = StringArray::from(vec![
+            Some("114"),
+            None,
+            None,
+            Some("Chrome/114.0"),
+            None,
+            None,
+        ]);
+        assert_eq!(regex_extract(&base, &regex, &group).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_regex_replace() {
+        let regex = Regex::new(r"(\w+)@(\w+)").unwrap();
+        let base = StringArray::from(vec![Some("a@b, c@d"), Some("none"), None, Some("a@b")]);
+        let replacement = StringArray::from(vec![Some("$2 at $1"), Some("x"), Some("x"), None]);
+        let expected = StringArray::from(vec![Some("b at a, d at c"), Some("none"), None, None]);
+        assert_eq!(
+            regex_replace(&base, &regex, &replacement).unwrap(),
+            expected
+        );
+    }
+}
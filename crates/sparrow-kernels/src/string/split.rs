@@ -0,0 +1,76 @@
+use std::convert::Infallible;
+
+use arrow::array::{ListArray, ListBuilder, StringArray, StringBuilder};
+use itertools::izip;
+
+/// Split each element of `base` into a list of strings on the `separator`.
+///
+/// If the separator is empty, the string is split into individual characters.
+pub fn split(base: &StringArray, separator: &StringArray) -> Result<ListArray, Infallible> {
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    for (s, separator) in izip!(base.iter(), separator.iter()) {
+        match (s, separator) {
+            (Some(s), Some("")) => {
+                let mut buffer = [0; 4];
+                for c in s.chars() {
+                    builder.values().append_value(c.encode_utf8(&mut buffer));
+                }
+                builder.append(true);
+            }
+            (Some(s), Some(separator)) => {
+                for part in s.split(separator) {
+                    builder.values().append_value(part);
+                }
+                builder.append(true);
+            }
+            _ => builder.append(false),
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::Array;
+    use sparrow_arrow::downcast::downcast_string_array;
+
+    use super::*;
+
+    #[test]
+    fn test_split() {
+        let base = StringArray::from(vec![
+            Some("a,b,,c"),
+            Some("abc"),
+            None,
+            Some("ab"),
+            Some(""),
+        ]);
+        let separator = StringArray::from(vec![Some(","), Some(","), Some(","), Some(""), None]);
+        let actual = split(&base, &separator).unwrap();
+
+        let lists: Vec<Option<Vec<Option<String>>>> = actual
+            .iter()
+            .map(|list| {
+                list.map(|list| {
+                    downcast_string_array::<i32>(list.as_ref())
+                        .unwrap()
+                        .iter()
+                        .map(|s| s.map(str::to_owned))
+                        .collect()
+                })
+            })
+            .collect();
+        let item = |s: &str| Some(s.to_owned());
+        assert_eq!(
+            lists,
+            vec![
+                Some(vec![item("a"), item("b"), item(""), item("c")]),
+                Some(vec![item("abc")]),
+                None,
+                Some(vec![item("a"), item("b")]),
+                None,
+            ]
+        );
+        assert_eq!(actual.null_count(), 2);
+    }
+}
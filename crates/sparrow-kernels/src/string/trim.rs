@@ -0,0 +1,20 @@
+use std::convert::Infallible;
+
+use arrow::array::StringArray;
+
+/// Return each element with leading and trailing whitespace removed.
+pub fn trim(base: &StringArray) -> Result<StringArray, Infallible> {
+    Ok(base.iter().map(|opt_s| opt_s.map(str::trim)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim() {
+        let array = StringArray::from(vec![Some("  hello \t"), None, Some("world"), Some("  ")]);
+        let expected = StringArray::from(vec![Some("hello"), None, Some("world"), Some("")]);
+        assert_eq!(trim(&array).unwrap(), expected);
+    }
+}
@@ -1,5 +1,7 @@
+mod schedule;
 mod time_delta;
 mod time_of;
 
+pub use schedule::*;
 pub use time_delta::*;
 pub use time_of::time_of;
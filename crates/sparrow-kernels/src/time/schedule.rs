@@ -0,0 +1,214 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use chrono::{DateTime, NaiveDateTime, TimeZone, Timelike};
+use chrono_tz::Tz;
+
+/// Parse the IANA name of a time zone, such as `America/New_York` or `UTC`.
+pub fn parse_time_zone(name: &str) -> anyhow::Result<Tz> {
+    Tz::from_str(name).map_err(|e| anyhow::anyhow!("invalid time zone '{name}': {e}"))
+}
+
+/// A cron schedule evaluated in a specific time zone.
+///
+/// All times accepted and returned by the schedule are UTC.
+#[derive(Debug, Clone)]
+pub struct CronSchedule {
+    schedule: cron::Schedule,
+    time_zone: Tz,
+}
+
+impl CronSchedule {
+    /// Parse a cron expression to be evaluated in the given time zone.
+    ///
+    /// Standard 5-field expressions (`minute hour day-of-month month
+    /// day-of-week`) are accepted, as well as 6 and 7 field expressions
+    /// with a leading seconds field and a trailing years field. Days of the
+    /// week may be named (`MON-FRI`) or numbered. In 5-field expressions,
+    /// days are numbered from 0 (Sunday) to 6 (Saturday), with 7 also being
+    /// Sunday, as in standard cron. In 6 and 7 field expressions they are
+    /// numbered from 1 (Sunday) to 7 (Saturday).
+    ///
+    /// If no time zone is given, the schedule is evaluated in UTC.
+    pub fn try_new(expression: &str, time_zone: Option<&str>) -> anyhow::Result<Self> {
+        let time_zone = match time_zone {
+            Some(name) => parse_time_zone(name)?,
+            None => Tz::UTC,
+        };
+
+        // The `cron` crate requires a seconds field, so standard 5-field
+        // expressions tick at the start of the matching minute.
+        let expression = expression.trim();
+        let fields: Vec<_> = expression.split_whitespace().collect();
+        let schedule = if let [minute, hour, day_of_month, month, day_of_week] = fields[..] {
+            let day_of_week = standard_days_of_week(day_of_week);
+            cron::Schedule::from_str(&format!(
+                "0 {minute} {hour} {day_of_month} {month} {day_of_week}"
+            ))
+        } else {
+            cron::Schedule::from_str(expression)
+        }
+        .with_context(|| format!("invalid cron expression '{expression}'"))?;
+
+        Ok(Self {
+            schedule,
+            time_zone,
+        })
+    }
+
+    /// Returns true if the given time matches the schedule.
+    pub fn includes(&self, time: NaiveDateTime) -> bool {
+        time.nanosecond() == 0 && self.schedule.includes(self.localize(time))
+    }
+
+    /// Returns the latest time matching the schedule at or before `time`.
+    pub fn at_or_before(&self, time: NaiveDateTime) -> Option<NaiveDateTime> {
+        let time = time.with_nanosecond(0)?;
+        let local = self.localize(time);
+        if self.schedule.includes(local) {
+            Some(time)
+        } else {
+            self.schedule
+                .after(&local)
+                .next_back()
+                .map(|tick| tick.naive_utc())
+        }
+    }
+
+    /// Returns the earliest time matching the schedule strictly after `time`.
+    pub fn after(&self, time: NaiveDateTime) -> Option<NaiveDateTime> {
+        self.schedule
+            .after(&self.localize(time))
+            .next()
+            .map(|tick| tick.naive_utc())
+    }
+
+    fn localize(&self, time: NaiveDateTime) -> DateTime<Tz> {
+        self.time_zone.from_utc_datetime(&time)
+    }
+}
+
+/// Renumber the days in a standard day-of-week field for the `cron` crate.
+///
+/// Standard cron numbers days from 0 (Sunday) to 6 (Saturday), with 7 also
+/// being Sunday, while the `cron` crate numbers them from 1 (Sunday) to 7
+/// (Saturday). Named days and steps (the number after a `/`) are unchanged.
+fn standard_days_of_week(field: &str) -> String {
+    let renumber = |day: &str| match day.parse::<u32>() {
+        Ok(7) => "1".to_owned(),
+        Ok(day) => (day + 1).to_string(),
+        // Names and wildcards are left for the `cron` crate to parse.
+        Err(_) => day.to_owned(),
+    };
+
+    field
+        .split(',')
+        .map(|item| {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => (range, Some(step)),
+                None => (item, None),
+            };
+            let renumbered = match range.split_once('-') {
+                // A range ending on Sunday (7) would wrap around, so Sunday
+                // is listed separately.
+                Some((first, "7")) if step.is_none() => format!("{}-7,1", renumber(first)),
+                Some((first, last)) => format!("{}-{}", renumber(first), renumber(last)),
+                None => renumber(range),
+            };
+            match step {
+                Some(step) => format!("{renumbered}/{step}"),
+                None => renumbered,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn test_invalid() {
+        assert!(CronSchedule::try_new("not a schedule", None).is_err());
+        assert!(CronSchedule::try_new("0 9 * * MON-FRI", Some("Mars/Olympus")).is_err());
+    }
+
+    #[test]
+    fn test_weekdays_utc() {
+        let schedule = CronSchedule::try_new("0 9 * * MON-FRI", None).unwrap();
+
+        // 2023-06-16 is a Friday.
+        let friday = NaiveDate::from_ymd_opt(2023, 6, 16).unwrap();
+        let monday = NaiveDate::from_ymd_opt(2023, 6, 19).unwrap();
+        assert!(schedule.includes(friday.and_hms_opt(9, 0, 0).unwrap()));
+        assert!(!schedule.includes(friday.and_hms_opt(9, 0, 1).unwrap()));
+        assert!(!schedule.includes(
+            NaiveDate::from_ymd_opt(2023, 6, 17)
+                .unwrap()
+                .and_hms_opt(9, 0, 0)
+                .unwrap()
+        ));
+
+        assert_eq!(
+            schedule.after(friday.and_hms_opt(9, 0, 0).unwrap()),
+            Some(monday.and_hms_opt(9, 0, 0).unwrap())
+        );
+        assert_eq!(
+            schedule.at_or_before(monday.and_hms_opt(8, 59, 59).unwrap()),
+            Some(friday.and_hms_opt(9, 0, 0).unwrap())
+        );
+        assert_eq!(
+            schedule.at_or_before(monday.and_hms_nano_opt(9, 0, 0, 500).unwrap()),
+            Some(monday.and_hms_opt(9, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_numbered_weekdays() {
+        // Standard cron numbers days from 0 (Sunday), so `1-5` is Monday to
+        // Friday.
+        let schedule = CronSchedule::try_new("0 9 * * 1-5", None).unwrap();
+
+        // 2023-06-18 is a Sunday.
+        for day in 18..=24 {
+            let time = NaiveDate::from_ymd_opt(2023, 6, day)
+                .unwrap()
+                .and_hms_opt(9, 0, 0)
+                .unwrap();
+            let is_weekday = (19..=23).contains(&day);
+            assert_eq!(schedule.includes(time), is_weekday, "2023-06-{day}");
+        }
+
+        let sunday = NaiveDate::from_ymd_opt(2023, 6, 18)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        for expression in [
+            "0 9 * * 0",
+            "0 9 * * 7",
+            "0 9 * * SUN",
+            "0 9 * * 5-7",
+            "0 9 * * 0/7",
+        ] {
+            let schedule = CronSchedule::try_new(expression, None).unwrap();
+            assert!(schedule.includes(sunday), "{expression}");
+        }
+        assert!(CronSchedule::try_new("0 9 * * 8", None).is_err());
+    }
+
+    #[test]
+    fn test_time_zone() {
+        let schedule = CronSchedule::try_new("0 9 * * *", Some("America/New_York")).unwrap();
+
+        // 9am in New York is 13:00 UTC during daylight saving time, and 14:00
+        // UTC otherwise.
+        let summer = NaiveDate::from_ymd_opt(2023, 6, 16).unwrap();
+        let winter = NaiveDate::from_ymd_opt(2023, 12, 15).unwrap();
+        assert!(schedule.includes(summer.and_hms_opt(13, 0, 0).unwrap()));
+        assert!(schedule.includes(winter.and_hms_opt(14, 0, 0).unwrap()));
+        assert!(!schedule.includes(winter.and_hms_opt(9, 0, 0).unwrap()));
+    }
+}
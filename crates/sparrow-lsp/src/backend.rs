@@ -0,0 +1,273 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use sparrow_compiler::DataContext;
+use tower_lsp::jsonrpc::Result;
+use tower_lsp::lsp_types::{
+    CompletionOptions, CompletionParams, CompletionResponse, Diagnostic,
+    DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
+    DidChangeWatchedFilesRegistrationOptions, DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams, FileSystemWatcher, GlobPattern, GotoDefinitionParams,
+    GotoDefinitionResponse, Hover, HoverParams, HoverProviderCapability, InitializeParams,
+    InitializeResult, InitializedParams, MessageType, OneOf, Registration, ServerCapabilities,
+    ServerInfo, TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+};
+use tower_lsp::{Client, LanguageServer};
+use tracing::{error, warn};
+
+use crate::completion::completions;
+use crate::definition::goto_definition;
+use crate::diagnostics::document_diagnostics;
+use crate::document::{
+    formula_directory, formulas_for, read_formula_files, Document, FormulaFile, FENL_EXTENSION,
+};
+use crate::hover::hover;
+use crate::schema::Schema;
+
+/// The Fenl language server.
+pub(crate) struct Backend {
+    client: Client,
+    schema: Schema,
+    /// The latest text of each open document.
+    texts: DashMap<Url, String>,
+    /// The open documents, as of their most recent analysis.
+    documents: DashMap<Url, Document>,
+    /// The Fenl files in each directory, read when first needed.
+    ///
+    /// Entries are discarded when the client reports changes to Fenl files
+    /// in the directory.
+    formula_files: DashMap<PathBuf, Arc<Vec<FormulaFile>>>,
+}
+
+impl Backend {
+    pub(crate) fn new(client: Client, schema: Schema) -> Self {
+        Self {
+            client,
+            schema,
+            texts: DashMap::new(),
+            documents: DashMap::new(),
+            formula_files: DashMap::new(),
+        }
+    }
+
+    fn data_context(&self) -> DataContext {
+        match self.schema.data_context() {
+            Ok(data_context) => data_context,
+            Err(e) => {
+                error!("Failed to create data context: {:?}", e);
+                DataContext::default()
+            }
+        }
+    }
+
+    /// Return the Fenl files in the given directory, reading them if needed.
+    async fn cached_formula_files(&self, directory: PathBuf) -> Arc<Vec<FormulaFile>> {
+        if let Some(files) = self.formula_files.get(&directory) {
+            return files.clone();
+        }
+
+        let read_directory = directory.clone();
+        let files =
+            match tokio::task::spawn_blocking(move || read_formula_files(&read_directory)).await {
+                Ok(files) => Arc::new(files),
+                Err(e) => {
+                    error!("Failed to read formulas in {directory:?}: {e}");
+                    return Arc::default();
+                }
+            };
+        self.formula_files.insert(directory, files.clone());
+        files
+    }
+
+    /// Analyze the latest text of the document, returning the diagnostics.
+    ///
+    /// Returns `None` if the document was closed or changed while it was
+    /// being analyzed, in which case the results are discarded.
+    async fn analyze(&self, uri: &Url) -> Option<Vec<Diagnostic>> {
+        let text = self.texts.get(uri)?.clone();
+        let files = match formula_directory(uri) {
+            Some(directory) => self.cached_formula_files(directory).await,
+            None => Arc::default(),
+        };
+        let formulas = formulas_for(uri, &files, |formula_uri| {
+            self.texts.get(formula_uri).map(|text| text.clone())
+        });
+        let last_parsed = self
+            .documents
+            .get(uri)
+            .and_then(|document| document.last_parsed.clone());
+        let data_context = self.data_context();
+
+        // Analysis is compute-bound, so it shouldn't block the async tasks
+        // handling other requests.
+        let analyzed_uri = uri.clone();
+        let analyzed = tokio::task::spawn_blocking(move || {
+            let document = Document::new(text, formulas, data_context, last_parsed);
+            let diagnostics = document_diagnostics(&analyzed_uri, &document);
+            (document, diagnostics)
+        })
+        .await;
+        let (document, diagnostics) = match analyzed {
+            Ok(analyzed) => analyzed,
+            Err(e) => {
+                error!("Failed to analyze {uri}: {e}");
+                return None;
+            }
+        };
+
+        if self.texts.get(uri).as_deref() != Some(&document.text) {
+            return None;
+        }
+        self.documents.insert(uri.clone(), document);
+        Some(diagnostics)
+    }
+
+    /// Analyze an open document and publish the resulting diagnostics.
+    async fn publish(&self, uri: Url, version: Option<i32>) {
+        if let Some(diagnostics) = self.analyze(&uri).await {
+            self.client
+                .publish_diagnostics(uri, diagnostics, version)
+                .await;
+        }
+    }
+
+    /// Return the URIs of the open documents.
+    fn open_documents(&self) -> Vec<Url> {
+        self.texts.iter().map(|entry| entry.key().clone()).collect()
+    }
+
+    /// Update the text of a document and publish the resulting diagnostics.
+    async fn update(&self, uri: Url, text: String, version: Option<i32>) {
+        self.texts.insert(uri.clone(), text);
+        self.publish(uri.clone(), version).await;
+
+        // Other open documents may use this document as a formula, so they
+        // need to be analyzed again.
+        for other in self.open_documents() {
+            if other != uri {
+                self.publish(other, None).await;
+            }
+        }
+    }
+}
+
+#[tower_lsp::async_trait]
+impl LanguageServer for Backend {
+    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+        Ok(InitializeResult {
+            capabilities: ServerCapabilities {
+                text_document_sync: Some(TextDocumentSyncCapability::Kind(
+                    TextDocumentSyncKind::FULL,
+                )),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
+                completion_provider: Some(CompletionOptions {
+                    trigger_characters: Some(vec![".".to_owned()]),
+                    ..CompletionOptions::default()
+                }),
+                definition_provider: Some(OneOf::Left(true)),
+                ..ServerCapabilities::default()
+            },
+            server_info: Some(ServerInfo {
+                name: "sparrow-lsp".to_owned(),
+                version: Some(env!("CARGO_PKG_VERSION").to_owned()),
+            }),
+        })
+    }
+
+    async fn initialized(&self, _: InitializedParams) {
+        // Watch the Fenl files, so that formulas read from files that aren't
+        // open are updated when they change.
+        let options = DidChangeWatchedFilesRegistrationOptions {
+            watchers: vec![FileSystemWatcher {
+                glob_pattern: GlobPattern::String(format!("**/*.{FENL_EXTENSION}")),
+                kind: None,
+            }],
+        };
+        let registration = Registration {
+            id: "fenl-files".to_owned(),
+            method: "workspace/didChangeWatchedFiles".to_owned(),
+            register_options: serde_json::to_value(options).ok(),
+        };
+        if let Err(e) = self.client.register_capability(vec![registration]).await {
+            warn!("Failed to watch Fenl files: {e}");
+        }
+
+        self.client
+            .log_message(MessageType::INFO, "Fenl language server initialized")
+            .await;
+    }
+
+    async fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let document = params.text_document;
+        self.update(document.uri, document.text, Some(document.version))
+            .await;
+    }
+
+    async fn did_change(&self, mut params: DidChangeTextDocumentParams) {
+        // The server requests full synchronization, so the last change
+        // contains the entire text.
+        if let Some(change) = params.content_changes.pop() {
+            let document = params.text_document;
+            self.update(document.uri, change.text, Some(document.version))
+                .await;
+        }
+    }
+
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        let uri = params.text_document.uri;
+        self.texts.remove(&uri);
+        self.documents.remove(&uri);
+        self.client.publish_diagnostics(uri, Vec::new(), None).await;
+    }
+
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        for change in params.changes {
+            if let Some(directory) = formula_directory(&change.uri) {
+                self.formula_files.remove(&directory);
+            }
+        }
+
+        for uri in self.open_documents() {
+            self.publish(uri, None).await;
+        }
+    }
+
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let position = params.text_document_position_params;
+        let Some(document) = self.documents.get(&position.text_document.uri) else {
+            return Ok(None);
+        };
+        Ok(hover(&document, document.offset(position.position)))
+    }
+
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        let position = params.text_document_position;
+        let Some(document) = self.documents.get(&position.text_document.uri) else {
+            return Ok(None);
+        };
+        let items = completions(
+            &document,
+            &self.data_context(),
+            document.offset(position.position),
+        );
+        Ok(Some(CompletionResponse::Array(items)))
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>> {
+        let position = params.text_document_position_params;
+        let uri = position.text_document.uri;
+        let Some(document) = self.documents.get(&uri) else {
+            return Ok(None);
+        };
+        let location = goto_definition(&uri, &document, document.offset(position.position));
+        Ok(location.map(GotoDefinitionResponse::Scalar))
+    }
+}
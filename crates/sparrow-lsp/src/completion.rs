@@ -0,0 +1,224 @@
+use arrow::datatypes::DataType;
+use sparrow_compiler::{nearest_matches, registered_functions, DataContext};
+use sparrow_syntax::{FeatureSetPart, FenlType};
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind};
+
+use crate::document::Document;
+
+/// A candidate for completion.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Candidate {
+    label: String,
+    /// Details about the candidate, such as the signature of a function.
+    detail: Option<String>,
+    kind: Kind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Kind {
+    Field,
+    Binding,
+    Formula,
+    Table,
+    Function,
+}
+
+impl AsRef<str> for Candidate {
+    fn as_ref(&self) -> &str {
+        &self.label
+    }
+}
+
+/// Return the completions at the given byte `offset` in the document.
+///
+/// After a `.` the fields of the record being referenced are completed.
+/// Otherwise, the names of functions, tables, formulas and bindings are
+/// completed.
+///
+/// Candidates starting with the identifier being typed are returned first,
+/// followed by the nearest matches, allowing for typos.
+pub(crate) fn completions(
+    document: &Document,
+    data_context: &DataContext,
+    offset: usize,
+) -> Vec<CompletionItem> {
+    let text = &document.text[..offset.min(document.text.len())];
+    let prefix_start = identifier_start(text);
+    let prefix = &text[prefix_start..];
+
+    let candidates = if text[..prefix_start].ends_with('.') {
+        field_candidates(document, data_context, prefix_start - 1)
+    } else {
+        name_candidates(document, data_context)
+    };
+
+    let mut matches: Vec<_> = candidates
+        .iter()
+        .filter(|candidate| candidate.label.starts_with(prefix))
+        .cloned()
+        .collect();
+    if !prefix.is_empty() {
+        for nearest in nearest_matches(prefix, candidates.into_iter()) {
+            if !matches.contains(&nearest) {
+                matches.push(nearest);
+            }
+        }
+    }
+
+    matches
+        .into_iter()
+        .enumerate()
+        .map(|(index, candidate)| CompletionItem {
+            label: candidate.label,
+            detail: candidate.detail,
+            kind: Some(match candidate.kind {
+                Kind::Field => CompletionItemKind::FIELD,
+                Kind::Binding => CompletionItemKind::VARIABLE,
+                Kind::Formula => CompletionItemKind::VARIABLE,
+                Kind::Table => CompletionItemKind::STRUCT,
+                Kind::Function => CompletionItemKind::FUNCTION,
+            }),
+            // Preserve the order, with prefix matches before nearest matches.
+            sort_text: Some(format!("{index:04}")),
+            ..CompletionItem::default()
+        })
+        .collect()
+}
+
+fn name_candidates(document: &Document, data_context: &DataContext) -> Vec<Candidate> {
+    let bindings = document.bound_names.iter().map(|name| Candidate {
+        label: name.clone(),
+        detail: None,
+        kind: Kind::Binding,
+    });
+    let formulas = document.formulas.iter().map(|formula| Candidate {
+        label: formula.name.clone(),
+        detail: Some(format!("formula {}", formula.uri)),
+        kind: Kind::Formula,
+    });
+    let tables = data_context.table_infos().map(|table| Candidate {
+        label: table.name().to_owned(),
+        detail: Some("table".to_owned()),
+        kind: Kind::Table,
+    });
+    let functions = registered_functions()
+        .filter(|function| !function.is_internal())
+        .map(|function| Candidate {
+            label: function.name().to_owned(),
+            detail: Some(function.signature_str().to_owned()),
+            kind: Kind::Function,
+        });
+
+    let mut candidates: Vec<_> = bindings
+        .chain(formulas)
+        .chain(tables)
+        .chain(functions)
+        .collect();
+    candidates.sort();
+    candidates.dedup_by(|a, b| a.label == b.label);
+    candidates
+}
+
+/// Return the fields of the record ending at `dot`.
+///
+/// The type of the record is determined from the most recent version of the
+/// document that parsed, if it is unchanged up to the `.`. This allows
+/// completing fields while the document doesn't parse, such as when the
+/// field name hasn't been written. If the record is a reference to a table,
+/// the fields of the table are used.
+fn field_candidates(document: &Document, data_context: &DataContext, dot: usize) -> Vec<Candidate> {
+    let base = &document.text[..dot];
+    let analyzed_type = document
+        .last_parsed
+        .as_ref()
+        .filter(|(text, _)| text.get(..dot) == Some(base))
+        .and_then(|(_, analysis)| analysis.type_ending_at(FeatureSetPart::Query, dot));
+
+    let fields = match analyzed_type {
+        Some(FenlType::Concrete(DataType::Struct(fields))) => fields.clone(),
+        _ => {
+            let table_name = &base[identifier_start(base)..];
+            match data_context
+                .table_infos()
+                .find(|table| table.name() == table_name)
+            {
+                Some(table) => table.schema().fields().clone(),
+                None => return Vec::new(),
+            }
+        }
+    };
+
+    fields
+        .iter()
+        .map(|field| Candidate {
+            label: field.name().clone(),
+            detail: Some(field.data_type().to_string()),
+            kind: Kind::Field,
+        })
+        .collect()
+}
+
+/// Return the byte offset of the start of the identifier at the end of `text`.
+fn identifier_start(text: &str) -> usize {
+    text.char_indices()
+        .rev()
+        .find(|(_, c)| !(c.is_ascii_alphanumeric() || *c == '_'))
+        .map(|(index, c)| index + c.len_utf8())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::Schema;
+
+    fn completion_labels(document: &Document, offset: usize) -> Vec<String> {
+        let data_context = Schema::for_test().data_context().unwrap();
+        completions(document, &data_context, offset)
+            .into_iter()
+            .map(|item| item.label)
+            .collect()
+    }
+
+    #[test]
+    fn test_complete_function_prefix() {
+        let document = Document::for_test("Purchases.amount | coun", vec![]);
+        let labels = completion_labels(&document, document.text.len());
+        assert_eq!(labels[..3], ["count", "count_distinct", "count_if"]);
+    }
+
+    #[test]
+    fn test_complete_function_typo() {
+        let document = Document::for_test("Purchases.amount | cuont", vec![]);
+        let labels = completion_labels(&document, document.text.len());
+        assert_eq!(labels[0], "count");
+    }
+
+    #[test]
+    fn test_complete_table_fields() {
+        // The document doesn't parse, so the fields come from the table.
+        let document = Document::for_test("Purchases.", vec![]);
+        let labels = completion_labels(&document, document.text.len());
+        assert_eq!(labels, ["time", "user", "amount", "item"]);
+
+        let document = Document::for_test("Purchases.amnt", vec![]);
+        let labels = completion_labels(&document, document.text.len());
+        assert_eq!(labels[0], "amount");
+    }
+
+    #[test]
+    fn test_complete_record_fields() {
+        let data_context = Schema::for_test().data_context().unwrap();
+        let parsed = Document::for_test("let r = { a: 1, b: Purchases.item } in r", vec![]);
+
+        // The record type comes from the last version that parsed.
+        let document = Document::new(
+            format!("{}.", parsed.text),
+            vec![],
+            data_context,
+            parsed.last_parsed.clone(),
+        );
+        let labels = completion_labels(&document, document.text.len());
+        assert_eq!(labels, ["a", "b"]);
+    }
+}
@@ -0,0 +1,64 @@
+use tower_lsp::lsp_types::{Location, Position, Range, Url};
+
+use crate::document::Document;
+use crate::syntax::{definition_at, Definition};
+
+/// Return the location of the definition of the name at the given byte
+/// `offset`.
+///
+/// Names bound by `let` (or parameters of a `def`) go to the binding within
+/// the document. Other names go to the file defining the formula, if any.
+pub(crate) fn goto_definition(uri: &Url, document: &Document, offset: usize) -> Option<Location> {
+    let expr = document.expr.as_ref()?;
+    match definition_at(expr, offset)? {
+        Definition::Local(location) => Some(Location::new(
+            uri.clone(),
+            document.range(location.start()..location.end()),
+        )),
+        Definition::Global(name) => {
+            let formula = document.formula(&name)?;
+            let start = Position::new(0, 0);
+            Some(Location::new(formula.uri.clone(), Range::new(start, start)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::FormulaFile;
+
+    #[test]
+    fn test_goto_local_definition() {
+        let uri = Url::parse("file:///fenl/query.fenl").unwrap();
+        let text = "let x = Purchases.amount\nin x + 1.0";
+        let document = Document::for_test(text, vec![]);
+
+        let location = goto_definition(&uri, &document, text.rfind('x').unwrap());
+        let range = Range::new(Position::new(0, 4), Position::new(0, 5));
+        assert_eq!(location, Some(Location::new(uri, range)));
+    }
+
+    #[test]
+    fn test_goto_formula_definition() {
+        let uri = Url::parse("file:///fenl/query.fenl").unwrap();
+        let formula_uri = Url::parse("file:///fenl/total.fenl").unwrap();
+        let formula = FormulaFile {
+            name: "total".to_owned(),
+            uri: formula_uri.clone(),
+            text: "sum(Purchases.amount)".to_owned(),
+        };
+        let document = Document::for_test("total + 1.0", vec![formula]);
+
+        let location = goto_definition(&uri, &document, 1);
+        let start = Position::new(0, 0);
+        assert_eq!(
+            location,
+            Some(Location::new(formula_uri, Range::new(start, start)))
+        );
+
+        // Tables aren't defined by a file.
+        let document = Document::for_test("Purchases.amount", vec![]);
+        assert_eq!(goto_definition(&uri, &document, 1), None);
+    }
+}
@@ -0,0 +1,160 @@
+use codespan_reporting::diagnostic::{LabelStyle, Severity};
+use sparrow_compiler::CollectedDiagnostic;
+use sparrow_syntax::FeatureSetPart;
+use tower_lsp::lsp_types::{
+    Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location, NumberOrString,
+    Position, Range, Url,
+};
+
+use crate::document::Document;
+
+/// Convert the diagnostics reported for the document to LSP diagnostics.
+///
+/// Only diagnostics reported against the document itself are included.
+/// Diagnostics within formulas are reported when those files are open.
+pub(crate) fn document_diagnostics(uri: &Url, document: &Document) -> Vec<Diagnostic> {
+    let Some(analysis) = &document.analysis else {
+        return vec![analysis_failed()];
+    };
+
+    analysis
+        .diagnostics
+        .iter()
+        .filter_map(|diagnostic| to_lsp_diagnostic(uri, document, diagnostic))
+        .collect()
+}
+
+fn to_lsp_diagnostic(
+    uri: &Url,
+    document: &Document,
+    diagnostic: &CollectedDiagnostic,
+) -> Option<Diagnostic> {
+    let (primary, secondary): (Vec<_>, Vec<_>) = diagnostic
+        .labels()
+        .iter()
+        .filter(|label| label.file_id == FeatureSetPart::Query)
+        .partition(|label| label.style == LabelStyle::Primary);
+
+    // Diagnostics without a label in the document (such as those within
+    // formulas) aren't reported against the document.
+    let primary = primary.first().or_else(|| secondary.first())?;
+
+    let mut message = diagnostic.message().to_owned();
+    if !primary.message.is_empty() {
+        message.push_str(": ");
+        message.push_str(&primary.message);
+    }
+    for note in diagnostic.notes() {
+        message.push('\n');
+        message.push_str(note);
+    }
+
+    let severity = match diagnostic.severity() {
+        Severity::Bug | Severity::Error => DiagnosticSeverity::ERROR,
+        Severity::Warning => DiagnosticSeverity::WARNING,
+        Severity::Note => DiagnosticSeverity::INFORMATION,
+        Severity::Help => DiagnosticSeverity::HINT,
+    };
+
+    let related_information = secondary
+        .iter()
+        .filter(|label| !label.message.is_empty())
+        .map(|label| DiagnosticRelatedInformation {
+            location: Location::new(uri.clone(), document.range(label.range.clone())),
+            message: label.message.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    Some(Diagnostic {
+        range: document.range(primary.range.clone()),
+        severity: Some(severity),
+        code: Some(NumberOrString::String(diagnostic.code_str().to_owned())),
+        source: Some("fenl".to_owned()),
+        message,
+        related_information: if related_information.is_empty() {
+            None
+        } else {
+            Some(related_information)
+        },
+        ..Diagnostic::default()
+    })
+}
+
+/// Return a diagnostic for a document that couldn't be analyzed.
+fn analysis_failed() -> Diagnostic {
+    Diagnostic {
+        range: Range::new(Position::new(0, 0), Position::new(0, 0)),
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some("fenl".to_owned()),
+        message: "Internal error analyzing the document".to_owned(),
+        ..Diagnostic::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::FormulaFile;
+
+    fn uri() -> Url {
+        Url::parse("file:///fenl/query.fenl").unwrap()
+    }
+
+    #[test]
+    fn test_diagnostic_range() {
+        let text = "let x = Purchases.amount\nin x + Purchases.imte";
+        let diagnostics = document_diagnostics(&uri(), &Document::for_test(text, vec![]));
+
+        assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+        let diagnostic = &diagnostics[0];
+        assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(
+            diagnostic.range,
+            Range::new(Position::new(1, 17), Position::new(1, 21))
+        );
+    }
+
+    #[test]
+    fn test_diagnostic_related_information() {
+        let text = "Purchases.amount\n  + Purchases.item";
+        let diagnostics = document_diagnostics(&uri(), &Document::for_test(text, vec![]));
+
+        assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+        let diagnostic = &diagnostics[0];
+        assert_eq!(
+            diagnostic.range,
+            Range::new(Position::new(1, 2), Position::new(1, 3))
+        );
+        let related: Vec<_> = diagnostic
+            .related_information
+            .iter()
+            .flatten()
+            .map(|related| (related.location.range, related.message.as_str()))
+            .collect();
+        assert_eq!(
+            related,
+            vec![
+                (
+                    Range::new(Position::new(0, 0), Position::new(0, 16)),
+                    "Type: f64"
+                ),
+                (
+                    Range::new(Position::new(1, 4), Position::new(1, 18)),
+                    "Type: string"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diagnostics_within_formulas_are_skipped() {
+        let formula = FormulaFile {
+            name: "total".to_owned(),
+            uri: Url::parse("file:///fenl/total.fenl").unwrap(),
+            text: "Purchases.imte".to_owned(),
+        };
+        let document = Document::for_test("total", vec![formula]);
+        let diagnostics = document_diagnostics(&uri(), &document);
+        assert_eq!(diagnostics, vec![]);
+    }
+}
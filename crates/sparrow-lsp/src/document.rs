@@ -0,0 +1,229 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use sparrow_api::kaskada::v1alpha::{FeatureSet, Formula};
+use sparrow_compiler::{analyze_for_editor, DataContext, EditorAnalysis};
+use sparrow_syntax::{Expr, ExprRef, FeatureSetPart};
+use tower_lsp::lsp_types::Url;
+use tracing::error;
+
+use crate::line_index::LineIndex;
+use crate::syntax::bound_names;
+
+/// The extension of Fenl files.
+pub(crate) const FENL_EXTENSION: &str = "fenl";
+
+/// An open Fenl document and the results of analyzing it.
+///
+/// Each document is analyzed as the query of a feature set. The other Fenl
+/// files in the same directory are available as formulas, named after the
+/// file. For instance, `purchases.fenl` may be referenced as `purchases`.
+#[derive(Debug)]
+pub(crate) struct Document {
+    pub(crate) text: String,
+    pub(crate) line_index: LineIndex,
+    /// The parsed expression, if the text parsed without errors.
+    pub(crate) expr: Option<ExprRef>,
+    /// The names bound by `let` and `def` within the expression.
+    pub(crate) bound_names: Vec<String>,
+    /// The formulas available to the document.
+    pub(crate) formulas: Vec<FormulaFile>,
+    /// The results of analyzing the document, if the analysis succeeded.
+    pub(crate) analysis: Option<Arc<EditorAnalysis>>,
+    /// The text and analysis of the most recent version of the document
+    /// that parsed without errors.
+    ///
+    /// While typing, the document often doesn't parse. This allows using the
+    /// types from the last version that did, such as to complete the fields
+    /// of a record.
+    pub(crate) last_parsed: Option<(String, Arc<EditorAnalysis>)>,
+}
+
+/// A formula defined by a Fenl file.
+#[derive(Debug)]
+pub(crate) struct FormulaFile {
+    pub(crate) name: String,
+    pub(crate) uri: Url,
+    pub(crate) text: String,
+}
+
+impl Document {
+    /// Create and analyze the document.
+    ///
+    /// The `last_parsed` text and analysis of a previous version of the
+    /// document, if any, are retained if this version doesn't parse.
+    ///
+    /// Analyzing the document may take a while, so this shouldn't be called
+    /// from async tasks.
+    pub(crate) fn new(
+        text: String,
+        formulas: Vec<FormulaFile>,
+        data_context: DataContext,
+        last_parsed: Option<(String, Arc<EditorAnalysis>)>,
+    ) -> Self {
+        let line_index = LineIndex::new(&text);
+        let expr = Expr::try_from_str(FeatureSetPart::Query, &text).ok();
+
+        let mut names = Vec::new();
+        if let Some(expr) = &expr {
+            bound_names(expr, &mut names);
+        }
+        names.sort();
+        names.dedup();
+
+        let feature_set = FeatureSet {
+            formulas: formulas
+                .iter()
+                .map(|formula| Formula {
+                    name: formula.name.clone(),
+                    formula: formula.text.clone(),
+                    source_location: formula.uri.to_string(),
+                })
+                .collect(),
+            query: text.clone(),
+        };
+
+        let mut data_context = data_context;
+        let analysis = match analyze_for_editor(&mut data_context, &feature_set) {
+            Ok(analysis) => Some(Arc::new(analysis)),
+            Err(e) => {
+                error!("Failed to analyze document: {:?}", e);
+                None
+            }
+        };
+
+        let last_parsed = match (&expr, &analysis) {
+            (Some(_), Some(analysis)) => Some((text.clone(), analysis.clone())),
+            _ => last_parsed,
+        };
+
+        Self {
+            text,
+            line_index,
+            expr,
+            bound_names: names,
+            formulas,
+            analysis,
+            last_parsed,
+        }
+    }
+
+    /// Return the byte offset of the given position.
+    pub(crate) fn offset(&self, position: tower_lsp::lsp_types::Position) -> usize {
+        self.line_index.offset(&self.text, position)
+    }
+
+    /// Return the range of positions for the given byte range.
+    pub(crate) fn range(&self, range: std::ops::Range<usize>) -> tower_lsp::lsp_types::Range {
+        self.line_index.range(&self.text, range)
+    }
+
+    pub(crate) fn formula(&self, name: &str) -> Option<&FormulaFile> {
+        self.formulas.iter().find(|formula| formula.name == name)
+    }
+
+    /// Create and analyze a document using [Schema::for_test] for testing
+    /// purposes.
+    ///
+    /// [Schema::for_test]: crate::schema::Schema::for_test
+    #[cfg(test)]
+    pub(crate) fn for_test(text: &str, formulas: Vec<FormulaFile>) -> Self {
+        let data_context = crate::schema::Schema::for_test()
+            .data_context()
+            .expect("valid test data context");
+        Self::new(text.to_owned(), formulas, data_context, None)
+    }
+}
+
+/// Return the formula name for a Fenl file, if it may be referenced.
+pub(crate) fn formula_name(path: &Path) -> Option<&str> {
+    if path.extension().and_then(|ext| ext.to_str()) != Some(FENL_EXTENSION) {
+        return None;
+    }
+
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .filter(|stem| sparrow_syntax::is_valid_ident(stem))
+}
+
+/// Return the directory containing the formulas for the document at `uri`.
+pub(crate) fn formula_directory(uri: &Url) -> Option<PathBuf> {
+    uri.to_file_path()
+        .ok()
+        .and_then(|path| path.parent().map(Path::to_path_buf))
+}
+
+/// Read the Fenl files in `directory` that may be referenced as formulas.
+///
+/// This blocks while reading the files.
+pub(crate) fn read_formula_files(directory: &Path) -> Vec<FormulaFile> {
+    let entries = match std::fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(e) => {
+            error!("Failed to list formulas in {directory:?}: {e}");
+            return Vec::new();
+        }
+    };
+
+    let mut files: Vec<_> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            let name = formula_name(&path)?.to_owned();
+            let uri = Url::from_file_path(&path).ok()?;
+            let text = std::fs::read_to_string(&path).ok()?;
+            Some(FormulaFile { name, uri, text })
+        })
+        .collect();
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+    files
+}
+
+/// Return the formulas available to the document at `uri`.
+///
+/// These are the other Fenl `files` in the same directory, as read by
+/// [read_formula_files]. The text of each is determined by `open_text`,
+/// falling back to the text read from the file for files that aren't open.
+pub(crate) fn formulas_for(
+    uri: &Url,
+    files: &[FormulaFile],
+    open_text: impl Fn(&Url) -> Option<String>,
+) -> Vec<FormulaFile> {
+    files
+        .iter()
+        .filter(|file| &file.uri != uri)
+        .map(|file| FormulaFile {
+            name: file.name.clone(),
+            uri: file.uri.clone(),
+            text: open_text(&file.uri).unwrap_or_else(|| file.text.clone()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_formulas_for() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("query.fenl"), "total + 1").unwrap();
+        std::fs::write(dir.path().join("total.fenl"), "5").unwrap();
+        std::fs::write(dir.path().join("other.fenl"), "6").unwrap();
+        std::fs::write(dir.path().join("not-an-ident.fenl"), "7").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let query = Url::from_file_path(dir.path().join("query.fenl")).unwrap();
+        let other = Url::from_file_path(dir.path().join("other.fenl")).unwrap();
+        let files = read_formula_files(dir.path());
+        let formulas = formulas_for(&query, &files, |uri| {
+            (uri == &other).then(|| "10".to_owned())
+        });
+
+        let formulas: Vec<_> = formulas
+            .iter()
+            .map(|formula| (formula.name.as_str(), formula.text.as_str()))
+            .collect();
+        assert_eq!(formulas, vec![("other", "10"), ("total", "5")]);
+    }
+}
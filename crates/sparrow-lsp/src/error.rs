@@ -0,0 +1,15 @@
+#[derive(derive_more::Display, Debug)]
+pub enum Error {
+    #[display(fmt = "failed to open schema file")]
+    OpenSchema,
+    #[display(fmt = "missing schema extension")]
+    MissingSchemaExtension,
+    #[display(fmt = "invalid schema extension '{_0:?}'")]
+    InvalidSchemaExtension(String),
+    #[display(fmt = "failed to deserialize schema file")]
+    DeserializeSchema,
+    #[display(fmt = "invalid tables in schema")]
+    InvalidTables,
+}
+
+impl error_stack::Context for Error {}
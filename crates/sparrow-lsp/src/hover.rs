@@ -0,0 +1,96 @@
+use sparrow_compiler::get_function;
+use sparrow_syntax::FeatureSetPart;
+use tower_lsp::lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind};
+
+use crate::document::Document;
+use crate::syntax::call_at;
+
+/// Return the hover information at the given byte `offset`.
+///
+/// For function calls this is the signature of the function. For other
+/// expressions it is the type of the innermost expression at the offset.
+pub(crate) fn hover(document: &Document, offset: usize) -> Option<Hover> {
+    if let Some(name) = document
+        .expr
+        .as_ref()
+        .and_then(|expr| call_at(expr, offset))
+    {
+        // User-defined functions shadow built-in functions.
+        if !document.bound_names.contains(name.inner()) {
+            if let Ok(function) = get_function(name.inner()) {
+                let location = name.location();
+                return Some(markdown_hover(
+                    function.signature_str(),
+                    document.range(location.start()..location.end()),
+                ));
+            }
+        }
+    }
+
+    let analysis = document.analysis.as_ref()?;
+    let (location, fenl_type) = analysis.type_at(FeatureSetPart::Query, offset)?;
+    Some(markdown_hover(
+        &fenl_type.to_string(),
+        document.range(location.start()..location.end()),
+    ))
+}
+
+fn markdown_hover(code: &str, range: tower_lsp::lsp_types::Range) -> Hover {
+    Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: format!("```fenl\n{code}\n```"),
+        }),
+        range: Some(range),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tower_lsp::lsp_types::{Position, Range};
+
+    use super::*;
+
+    fn hover_at(text: &str, offset: usize) -> Option<(String, Range)> {
+        let document = Document::for_test(text, vec![]);
+        let hover = hover(&document, offset)?;
+        let HoverContents::Markup(contents) = hover.contents else {
+            panic!("expected markup, saw {:?}", hover.contents)
+        };
+        Some((contents.value, hover.range.unwrap()))
+    }
+
+    #[test]
+    fn test_hover_type() {
+        let text = "let x = Purchases.amount\nin { x, y: x > 10.0 }";
+        let (value, range) = hover_at(text, text.find("amount").unwrap()).unwrap();
+        assert_eq!(value, "```fenl\nf64\n```");
+        assert_eq!(range, Range::new(Position::new(0, 8), Position::new(0, 24)));
+
+        let (value, range) = hover_at(text, text.find('{').unwrap()).unwrap();
+        assert_eq!(value, "```fenl\n{x: f64, y: bool}\n```");
+        assert_eq!(range, Range::new(Position::new(1, 3), Position::new(1, 21)));
+    }
+
+    #[test]
+    fn test_hover_function() {
+        let text = "sum(Purchases.amount)";
+        let (value, range) = hover_at(text, 1).unwrap();
+        let signature = get_function("sum").unwrap().signature_str();
+        assert_eq!(value, format!("```fenl\n{signature}\n```"));
+        assert_eq!(range, Range::new(Position::new(0, 0), Position::new(0, 3)));
+
+        // Operators show the signature of the corresponding function.
+        let text = "Purchases.amount > 10.0";
+        let (value, _) = hover_at(text, text.find('>').unwrap()).unwrap();
+        assert_eq!(value, "```fenl\ngt<O: ordered>(a: O, b: O) -> bool\n```");
+    }
+
+    #[test]
+    fn test_hover_shadowed_function() {
+        // A user-defined `sum` shadows the built-in function.
+        let text = "let sum = Purchases.amount\nin sum + 1.0";
+        let (value, _) = hover_at(text, text.rfind("sum").unwrap()).unwrap();
+        assert_eq!(value, "```fenl\nf64\n```");
+    }
+}
@@ -0,0 +1,104 @@
+use tower_lsp::lsp_types::{Position, Range};
+
+/// Converts between byte offsets and LSP positions within a document.
+///
+/// The compiler reports locations as byte offsets, while the language server
+/// protocol uses a line and a character offset counted in UTF-16 code units.
+#[derive(Debug)]
+pub(crate) struct LineIndex {
+    /// The byte offset of the start of each line.
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub(crate) fn new(text: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        Self { line_starts }
+    }
+
+    /// Return the position of the given byte `offset` in `text`.
+    pub(crate) fn position(&self, text: &str, offset: usize) -> Position {
+        let offset = offset.min(text.len());
+        let line = self
+            .line_starts
+            .binary_search(&offset)
+            .unwrap_or_else(|next_line| next_line - 1);
+        let line_start = self.line_starts[line];
+        let character = text
+            .get(line_start..offset)
+            .map(|prefix| prefix.encode_utf16().count())
+            .unwrap_or(0);
+        Position::new(line as u32, character as u32)
+    }
+
+    /// Return the range of positions for the given byte range in `text`.
+    pub(crate) fn range(&self, text: &str, range: std::ops::Range<usize>) -> Range {
+        Range::new(
+            self.position(text, range.start),
+            self.position(text, range.end),
+        )
+    }
+
+    /// Return the byte offset of the given `position` in `text`.
+    ///
+    /// Positions past the end of a line are clamped to the end of the line,
+    /// and positions past the end of the document to the end of the
+    /// document.
+    pub(crate) fn offset(&self, text: &str, position: Position) -> usize {
+        let Some(&line_start) = self.line_starts.get(position.line as usize) else {
+            return text.len();
+        };
+        let line_end = self
+            .line_starts
+            .get(position.line as usize + 1)
+            .copied()
+            .unwrap_or(text.len());
+
+        let mut remaining = position.character as usize;
+        for (index, c) in text[line_start..line_end].char_indices() {
+            if remaining == 0 || c == '\n' {
+                return line_start + index;
+            }
+            remaining = remaining.saturating_sub(c.len_utf16());
+        }
+        line_end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_and_offset() {
+        let text = "let x = 5\nin x + 1\n";
+        let index = LineIndex::new(text);
+
+        assert_eq!(index.position(text, 0), Position::new(0, 0));
+        assert_eq!(index.position(text, 4), Position::new(0, 4));
+        assert_eq!(index.position(text, 10), Position::new(1, 0));
+        assert_eq!(index.position(text, 13), Position::new(1, 3));
+        assert_eq!(index.position(text, text.len()), Position::new(2, 0));
+
+        assert_eq!(index.offset(text, Position::new(0, 4)), 4);
+        assert_eq!(index.offset(text, Position::new(1, 3)), 13);
+        // Past the end of the line.
+        assert_eq!(index.offset(text, Position::new(0, 100)), 9);
+        // Past the end of the document.
+        assert_eq!(index.offset(text, Position::new(5, 0)), text.len());
+    }
+
+    #[test]
+    fn test_utf16_positions() {
+        // `é` is two bytes in UTF-8 and one code unit in UTF-16, while `😀` is
+        // four bytes in UTF-8 and two code units in UTF-16.
+        let text = "\"é😀\" + x";
+        let index = LineIndex::new(text);
+
+        let x_offset = text.find('x').unwrap();
+        assert_eq!(index.position(text, x_offset), Position::new(0, 8));
+        assert_eq!(index.offset(text, Position::new(0, 8)), x_offset);
+    }
+}
@@ -0,0 +1,76 @@
+//! A language server for Fenl.
+//!
+//! The server communicates over stdin and stdout using the Language Server
+//! Protocol, providing diagnostics, hover types, completion and
+//! go-to-definition for Fenl files.
+//!
+//! Each open `.fenl` file is analyzed as a query. The other `.fenl` files in
+//! the same directory are available as formulas named after the file, and
+//! the tables are described by the (optional) schema file.
+
+#![warn(
+    rust_2018_idioms,
+    nonstandard_style,
+    future_incompatible,
+    clippy::mod_module_files,
+    clippy::print_stdout,
+    clippy::print_stderr
+)]
+
+use std::path::PathBuf;
+
+use clap::Parser;
+use tower_lsp::{LspService, Server};
+use tracing_subscriber::EnvFilter;
+
+mod backend;
+mod completion;
+mod definition;
+mod diagnostics;
+mod document;
+mod error;
+mod hover;
+mod line_index;
+mod schema;
+mod syntax;
+
+use crate::backend::Backend;
+use crate::error::Error;
+use crate::schema::Schema;
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "sparrow-lsp", rename_all = "kebab-case", version)]
+pub struct LspOptions {
+    /// Path to a file describing the tables available to Fenl expressions.
+    ///
+    /// Uses the same JSON or YAML format as the `--schema` option of
+    /// `sparrow-main batch`.
+    #[arg(long, env = "SPARROW_LSP_SCHEMA")]
+    schema: Option<PathBuf>,
+}
+
+#[tokio::main]
+async fn main() -> error_stack::Result<(), Error> {
+    let options = LspOptions::parse();
+
+    // Stdout is used for the protocol, so logs are written to stderr.
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_env_filter(EnvFilter::from_default_env())
+        .with_ansi(false)
+        .init();
+
+    let schema = match &options.schema {
+        Some(path) => Schema::try_from(path)?,
+        None => Schema::default(),
+    };
+    // Report problems with the schema on startup.
+    schema.data_context()?;
+
+    let (service, socket) = LspService::new(|client| Backend::new(client, schema));
+    Server::new(tokio::io::stdin(), tokio::io::stdout(), socket)
+        .serve(service)
+        .await;
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use error_stack::{IntoReport, IntoReportCompat, ResultExt};
+use serde::Deserialize;
+use sparrow_api::kaskada::v1alpha::ComputeTable;
+use sparrow_compiler::DataContext;
+
+use crate::error::Error;
+
+/// The tables available to Fenl expressions being edited.
+///
+/// This uses the same format as the schema files used by `sparrow-main`.
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct Schema {
+    /// Tables that are available to the expressions.
+    pub(crate) tables: Vec<ComputeTable>,
+}
+
+impl Schema {
+    pub(crate) fn try_from(path: &Path) -> error_stack::Result<Self, Error> {
+        let file = File::open(path)
+            .into_report()
+            .change_context(Error::OpenSchema)?;
+        let reader = BufReader::new(file);
+
+        match &path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => serde_json::from_reader(reader)
+                .into_report()
+                .change_context(Error::DeserializeSchema),
+            Some("yaml") => serde_yaml::from_reader(reader)
+                .into_report()
+                .change_context(Error::DeserializeSchema),
+            Some(extension) => Err(error_stack::report!(Error::InvalidSchemaExtension(
+                (*extension).to_owned()
+            ))),
+            None => Err(error_stack::report!(Error::MissingSchemaExtension)),
+        }
+    }
+
+    /// Create the data context for analyzing expressions against the schema.
+    pub(crate) fn data_context(&self) -> error_stack::Result<DataContext, Error> {
+        DataContext::try_from_tables(self.tables.clone())
+            .into_report()
+            .change_context(Error::InvalidTables)
+    }
+
+    /// Create a schema with a single table for testing purposes.
+    ///
+    /// The `Purchases` table has the type `{ time: timestamp_ns, user: string,
+    /// amount: f64, item: string }`.
+    #[cfg(test)]
+    pub(crate) fn for_test() -> Self {
+        serde_yaml::from_str(
+            r#"
+            tables:
+              - config:
+                  name: Purchases
+                  uuid: 00000000-0000-0000-0000-000000000000
+                  time_column_name: time
+                  group_column_name: user
+                  grouping: user
+                metadata:
+                  file_count: 0
+                  schema:
+                    fields:
+                      - name: time
+                        data_type:
+                          kind: !Primitive 24 # TIMESTAMP_NANOSECOND
+                        nullable: false
+                      - name: user
+                        data_type:
+                          kind: !Primitive 14 # STRING
+                        nullable: true
+                      - name: amount
+                        data_type:
+                          kind: !Primitive 13 # F64
+                        nullable: true
+                      - name: item
+                        data_type:
+                          kind: !Primitive 14 # STRING
+                        nullable: true
+                file_sets: []
+            "#,
+        )
+        .expect("valid test schema")
+    }
+}
@@ -0,0 +1,227 @@
+//! Queries over the parsed (unresolved) Fenl expression.
+
+use sparrow_syntax::{Expr, ExprOp, Located, Location};
+
+/// Where the name referenced at a position is defined.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Definition {
+    /// The name is bound by a `let` or is a parameter of a `def`.
+    ///
+    /// Contains the location of the name in the binding.
+    Local(Location),
+    /// The name isn't bound within the expression, so it refers to a
+    /// formula or table.
+    Global(String),
+}
+
+/// Return the definition of the reference at the given byte `offset`.
+pub(crate) fn definition_at(expr: &Expr, offset: usize) -> Option<Definition> {
+    let mut scope = Vec::new();
+    find_definition(expr, offset, &mut scope)
+}
+
+fn find_definition<'a>(
+    expr: &'a Expr,
+    offset: usize,
+    scope: &mut Vec<&'a Located<String>>,
+) -> Option<Definition> {
+    let args = expr.args();
+    match expr.op() {
+        ExprOp::Reference(reference) => {
+            if !contains(reference.location(), offset) {
+                return None;
+            }
+            let definition = scope
+                .iter()
+                .rev()
+                .find(|name| name.inner() == reference.inner())
+                .map(|name| Definition::Local(name.location().clone()))
+                .unwrap_or_else(|| Definition::Global(reference.inner().clone()));
+            Some(definition)
+        }
+        ExprOp::Let(names, _) => {
+            let depth = scope.len();
+            // The last name corresponds to the `let_body`.
+            let bindings = names.len() - 1;
+            let mut result = None;
+            for (name, value) in names.iter().zip(args.iter()).take(bindings) {
+                if contains(name.location(), offset) {
+                    result = Some(Definition::Local(name.location().clone()));
+                    break;
+                }
+                result = find_definition(value.value().inner(), offset, scope);
+                if result.is_some() {
+                    break;
+                }
+                scope.push(name);
+            }
+            let result = result.or_else(|| {
+                args.get(bindings)
+                    .and_then(|body| find_definition(body.value().inner(), offset, scope))
+            });
+            scope.truncate(depth);
+            result
+        }
+        ExprOp::Def(signature, _) => {
+            let depth = scope.len();
+            let parameters = signature.arg_names();
+            if let Some(parameter) = parameters
+                .iter()
+                .find(|name| contains(name.location(), offset))
+            {
+                return Some(Definition::Local(parameter.location().clone()));
+            }
+
+            scope.extend(parameters.iter());
+            let result = args
+                .get(0)
+                .and_then(|definition| find_definition(definition.value().inner(), offset, scope));
+            scope.truncate(depth);
+
+            result.or_else(|| {
+                args.get(1)
+                    .and_then(|body| find_definition(body.value().inner(), offset, scope))
+            })
+        }
+        _ => args
+            .iter()
+            .find_map(|arg| find_definition(arg.value().inner(), offset, scope)),
+    }
+}
+
+/// Return the name of the function called at the given byte `offset`.
+pub(crate) fn call_at(expr: &Expr, offset: usize) -> Option<&Located<String>> {
+    match expr.op() {
+        ExprOp::Call(name) if contains(name.location(), offset) => Some(name),
+        _ => expr
+            .args()
+            .iter()
+            .find_map(|arg| call_at(arg.value().inner(), offset)),
+    }
+}
+
+/// Collect the names bound by `let` and `def` within the expression.
+///
+/// This includes the names of user-defined functions and their parameters.
+pub(crate) fn bound_names(expr: &Expr, names: &mut Vec<String>) {
+    match expr.op() {
+        ExprOp::Let(bindings, _) => {
+            // The last name corresponds to the `let_body`.
+            let bindings = &bindings[..bindings.len() - 1];
+            names.extend(bindings.iter().map(|name| name.inner().clone()));
+        }
+        ExprOp::Def(signature, _) => {
+            names.push(signature.name().to_owned());
+            names.extend(
+                signature
+                    .arg_names()
+                    .iter()
+                    .map(|name| name.inner().clone()),
+            );
+        }
+        _ => (),
+    }
+
+    for arg in expr.args().iter() {
+        bound_names(arg.value().inner(), names);
+    }
+}
+
+fn contains(location: &Location, offset: usize) -> bool {
+    location.start() <= offset && offset <= location.end()
+}
+
+#[cfg(test)]
+mod tests {
+    use sparrow_syntax::FeatureSetPart;
+
+    use super::*;
+
+    fn parse(input: &str) -> sparrow_syntax::ExprRef {
+        Expr::try_from_str(FeatureSetPart::Query, input).unwrap()
+    }
+
+    fn local(input: &str, name: &str) -> Definition {
+        let start = input.find(name).unwrap();
+        Definition::Local(Location::new(
+            FeatureSetPart::Query,
+            start,
+            start + name.len(),
+        ))
+    }
+
+    #[test]
+    fn test_definition_of_let_binding() {
+        let input = "let foo = Table.x\nlet bar = foo + 1\nin { foo, bar }";
+        let expr = parse(input);
+
+        let reference = input.rfind("foo").unwrap();
+        assert_eq!(definition_at(&expr, reference), Some(local(input, "foo")));
+
+        let reference = input.find("foo + 1").unwrap();
+        assert_eq!(definition_at(&expr, reference), Some(local(input, "foo")));
+
+        let table = input.find("Table").unwrap();
+        assert_eq!(
+            definition_at(&expr, table),
+            Some(Definition::Global("Table".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_definition_of_shadowed_binding() {
+        let input = "let x = 1\nin (let x = 2 in x) + x";
+        let expr = parse(input);
+
+        let inner = input.find("in x").unwrap() + 3;
+        let inner_binding = input.find("x = 2").unwrap();
+        assert_eq!(
+            definition_at(&expr, inner),
+            Some(Definition::Local(Location::new(
+                FeatureSetPart::Query,
+                inner_binding,
+                inner_binding + 1
+            )))
+        );
+
+        let outer = input.len() - 1;
+        assert_eq!(definition_at(&expr, outer), Some(local(input, "x")));
+    }
+
+    #[test]
+    fn test_definition_of_def_parameter() {
+        let input = "def double(x) = x + x\nin double(y)";
+        let expr = parse(input);
+
+        let reference = input.find("x + x").unwrap();
+        assert_eq!(definition_at(&expr, reference), Some(local(input, "x")));
+
+        // Parameters aren't in scope in the body.
+        let y = input.find("y").unwrap();
+        assert_eq!(
+            definition_at(&expr, y),
+            Some(Definition::Global("y".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_call_at() {
+        let input = "sum(Table.x) + count(Table.y)";
+        let expr = parse(input);
+
+        let count = call_at(&expr, input.find("count").unwrap() + 2).unwrap();
+        assert_eq!(count.inner(), "count");
+        assert!(call_at(&expr, input.find("Table").unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_bound_names() {
+        let input = "let a = 1\ndef f(b) = b + a\nlet c = f(a)\nin c";
+        let expr = parse(input);
+
+        let mut names = Vec::new();
+        bound_names(&expr, &mut names);
+        names.sort();
+        assert_eq!(names, vec!["a", "b", "c", "f"]);
+    }
+}
@@ -125,10 +125,12 @@ impl BatchCommand {
                     plan: Some(plan),
                     tables,
                     destination: Some(output_to),
+                    additional_destinations: vec![],
                     limits: Some(self.limits),
                     compute_snapshot_config: None,
                     changed_since: None,
                     final_result_time: None,
+                    preserve_time_zones: false,
                 },
                 None,
                 self.flight_record_path,
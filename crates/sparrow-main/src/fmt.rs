@@ -0,0 +1,102 @@
+use std::io::{Read, Write};
+use std::path::PathBuf;
+
+use error_stack::{IntoReport, ResultExt};
+
+/// Options for the Fmt command.
+#[derive(clap::Args, Debug)]
+#[command(version, rename_all = "kebab-case")]
+pub struct FmtCommand {
+    /// Fenl files to format in place.
+    ///
+    /// If no files are given, the expression is read from stdin and the
+    /// formatted expression is written to stdout.
+    pub files: Vec<PathBuf>,
+
+    /// Check whether the files are formatted without modifying them.
+    ///
+    /// Fails if any of the files would be changed by formatting.
+    #[arg(long)]
+    pub check: bool,
+}
+
+#[derive(derive_more::Display, Debug)]
+pub enum Error {
+    #[display(fmt = "failed to read input")]
+    Read,
+    #[display(fmt = "failed to parse input")]
+    Parse,
+    #[display(fmt = "failed to write output")]
+    Write,
+    #[display(fmt = "input is not formatted")]
+    Unformatted,
+}
+
+impl error_stack::Context for Error {}
+
+#[derive(derive_more::Display, Debug)]
+#[display(fmt = "path: '{}'", "_0.display()")]
+struct FmtPath(PathBuf);
+
+impl FmtCommand {
+    pub fn execute(self) -> error_stack::Result<(), Error> {
+        if self.files.is_empty() {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .into_report()
+                .change_context(Error::Read)?;
+            let formatted = format(&input)?;
+            if self.check {
+                error_stack::ensure!(formatted == input, Error::Unformatted);
+            } else {
+                std::io::stdout()
+                    .write_all(formatted.as_bytes())
+                    .into_report()
+                    .change_context(Error::Write)?;
+            }
+            return Ok(());
+        }
+
+        let mut unformatted = Vec::new();
+        for path in self.files {
+            let input = std::fs::read_to_string(&path)
+                .into_report()
+                .change_context(Error::Read)
+                .attach_printable_lazy(|| FmtPath(path.clone()))?;
+            let formatted = format(&input).attach_printable_lazy(|| FmtPath(path.clone()))?;
+            if formatted == input {
+                continue;
+            }
+
+            if self.check {
+                unformatted.push(path);
+            } else {
+                std::fs::write(&path, formatted)
+                    .into_report()
+                    .change_context(Error::Write)
+                    .attach_printable_lazy(|| FmtPath(path.clone()))?;
+            }
+        }
+
+        if unformatted.is_empty() {
+            Ok(())
+        } else {
+            let report = unformatted.into_iter().fold(
+                error_stack::Report::new(Error::Unformatted),
+                |report, path| report.attach_printable(FmtPath(path)),
+            );
+            Err(report)
+        }
+    }
+}
+
+fn format(input: &str) -> error_stack::Result<String, Error> {
+    sparrow_syntax::format_expr(input).map_err(|errors| {
+        let mut report = error_stack::Report::new(Error::Parse);
+        for error in errors {
+            report = report.attach_printable(format!("{error:?}"));
+        }
+        report
+    })
+}
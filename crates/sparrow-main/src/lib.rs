@@ -9,6 +9,7 @@
 )]
 
 pub(crate) mod batch;
+mod fmt;
 mod materialize;
 mod prepare;
 mod script;
@@ -16,6 +17,7 @@ mod serve;
 pub mod tracing_setup;
 
 pub use batch::BatchCommand;
+pub use fmt::FmtCommand;
 pub use materialize::MaterializeCommand;
 pub use prepare::PrepareCommand;
 pub use serve::*;
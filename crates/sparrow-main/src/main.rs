@@ -12,7 +12,7 @@ use clap::{command, Parser};
 use error_stack::{FutureExt, ResultExt};
 use opentelemetry::global;
 use sparrow_main::tracing_setup::{setup_tracing, TracingOptions};
-use sparrow_main::{BatchCommand, MaterializeCommand, PrepareCommand, ServeCommand};
+use sparrow_main::{BatchCommand, FmtCommand, MaterializeCommand, PrepareCommand, ServeCommand};
 use tracing::error;
 
 #[cfg(not(target_os = "windows"))]
@@ -49,6 +49,8 @@ enum Command {
     Prepare(PrepareCommand),
     /// Create a long-running process that materializes results to a destination.
     Materialize(MaterializeCommand),
+    /// Format Fenl files.
+    Fmt(FmtCommand),
     /// License report and notice.
     License,
 }
@@ -85,6 +87,7 @@ async fn main_body(options: SparrowOptions) -> error_stack::Result<(), Error> {
         Command::Serve(serve) => serve.execute().await.change_context(Error)?,
         Command::Batch(batch) => batch.execute().await.change_context(Error)?,
         Command::Prepare(prepare) => prepare.execute().change_context(Error).await?,
+        Command::Fmt(fmt) => fmt.execute().change_context(Error)?,
         Command::License => {
             println!("{NOTICE}");
         }
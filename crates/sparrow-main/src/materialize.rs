@@ -114,10 +114,12 @@ impl MaterializeCommand {
                 plan: Some(plan),
                 tables,
                 destination: Some(output_to),
+                additional_destinations: vec![],
                 limits: None,
                 compute_snapshot_config: None,
                 changed_since: None,
                 final_result_time: None,
+                preserve_time_zones: false,
             },
             Some(script.bounded_lateness_ns),
             self.flight_record_path,
@@ -349,6 +349,7 @@ async fn debug_message(
         is_query_done: true,
         progress: None,
         destination: None,
+        additional_destinations: vec![],
         flight_record_path: uploaded_flight_record_path,
         plan_yaml_path: uploaded_plan_yaml_path,
         compute_snapshots: Vec::new(),
@@ -761,12 +762,14 @@ mod tests {
                     file_sets: vec![file_set],
                 }],
                 destination: Some(output_to),
+                additional_destinations: vec![],
                 // These are weird. Wren doesn't send "no limits" and "no query hash"
                 // when their missing. Instead, it sends the defaults.
                 limits: Some(Limits::default()),
                 compute_snapshot_config: None,
                 changed_since: None,
                 final_result_time: None,
+                preserve_time_zones: false,
             },
         )
         .await
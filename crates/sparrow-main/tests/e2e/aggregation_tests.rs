@@ -171,6 +171,183 @@ async fn test_variance_f64() {
     "###);
 }
 
+#[tokio::test]
+async fn test_ewma_and_ewm_variance_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ ewma: ewma(Numbers.m, seconds(2)), ewm_variance: ewm_variance(Numbers.m, half_life = seconds(2)) }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,ewma,ewm_variance
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,5.0,
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,24.0,
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,13.0,72.0
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,13.0,72.0
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,12.428571428571429,24.42857142857143
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,12.428571428571429,24.42857142857143
+    "###);
+}
+
+#[tokio::test]
+async fn test_ewma_since_predicate_f64() {
+    insta::assert_snapshot!(QueryFixture::new("{ ewma: ewma(Numbers.m, seconds(1), window = since(Numbers.n > 7)) }").run_to_csv(&f64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,ewma
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,5.2
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,24.3
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,17.6
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,17.6
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,12.4
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,12.4
+    "###);
+}
+
+#[tokio::test]
+async fn test_ewma_non_positive_half_life() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ ewma: ewma(Numbers.m, seconds(0)) }").run_to_csv(&f64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0008
+        message: Invalid arguments
+        formatted:
+          - "error[E0008]: Invalid arguments"
+          - "  --> Query:1:25"
+          - "  |"
+          - "1 | { ewma: ewma(Numbers.m, seconds(0)) }"
+          - "  |                         ^^^^^^^^^^ Argument 'half_life' to 'ewma' must be a positive duration"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_ewma_sliding_unsupported() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ ewma: ewma(Numbers.m, seconds(1), window = sliding(2, daily())) }").run_to_csv(&f64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0008
+        message: Invalid arguments
+        formatted:
+          - "error[E0008]: Invalid arguments"
+          - "  --> Query:1:46"
+          - "  |"
+          - "1 | { ewma: ewma(Numbers.m, seconds(1), window = sliding(2, daily())) }"
+          - "  |                                              ^^^^^^^ Window 'sliding' is not supported by 'ewma'"
+          - "  |"
+          - "  = Supported windows: 'since'"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_median_f64() {
+    insta::assert_snapshot!(QueryFixture::new("{ median: median(Numbers.m)}").run_to_csv(&f64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,median
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,5.2
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,24.3
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,11.400000000000002
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,11.400000000000002
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,12.4
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,12.4
+    "###);
+}
+
+#[tokio::test]
+async fn test_percentile_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ p0: percentile(Numbers.m, 0), p90: percentile(Numbers.m, 90.0)}").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,p0,p90
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,5.0,5.0
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,24.0,24.0
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,5.0,17.0
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,5.0,17.0
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,5.0,17.0
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,5.0,17.0
+    "###);
+}
+
+#[tokio::test]
+async fn test_percentile_out_of_range() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ p: percentile(Numbers.m, 101) }").run_to_csv(&f64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0008
+        message: Invalid arguments
+        formatted:
+          - "error[E0008]: Invalid arguments"
+          - "  --> Query:1:28"
+          - "  |"
+          - "1 | { p: percentile(Numbers.m, 101) }"
+          - "  |                            ^^^ Argument 'p' to 'percentile' must be between 0 and 100, but was 101"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_collect_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ len: list_len(collect(Numbers.m)), sum: list_sum(collect(Numbers.m, max = 2)), first: index(0, collect(Numbers.m, max = 2)) }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,len,sum,first
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,1,5,5
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,1,24,24
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,2,22,5
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,2,22,5
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,3,29,17
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,3,29,17
+    "###);
+}
+
+#[tokio::test]
+async fn test_collect_since_predicate_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ sum_field: list_sum(collect(Numbers.m, window=since(Numbers.n > 7))) }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,sum_field
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,5
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,24
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,17
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,17
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,12
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,12
+    "###);
+}
+
+#[tokio::test]
+async fn test_collect_sliding_tick_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ sum_field: list_sum(collect(Numbers.m, window=sliding(2, daily()))) }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,sum_field
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,5
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,24
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,22
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,22
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,34
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,34
+    "###);
+}
+
+#[tokio::test]
+async fn test_collect_non_positive_max() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ c: collect(Numbers.m, max = 0) }").run_to_csv(&i64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0008
+        message: Invalid arguments
+        formatted:
+          - "error[E0008]: Invalid arguments"
+          - "  --> Query:1:31"
+          - "  |"
+          - "1 | { c: collect(Numbers.m, max = 0) }"
+          - "  |                               ^ Argument 'max' to 'collect' must be positive, but was 0"
+          - ""
+          - ""
+    "###);
+}
+
 #[tokio::test]
 async fn test_stddev_i64() {
     insta::assert_snapshot!(QueryFixture::new("{ m: Numbers.m, stddev: stddev(Numbers.m)}").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
@@ -354,6 +531,94 @@ async fn test_count_timestamp_ns() {
     "###);
 }
 
+#[tokio::test]
+async fn test_count_distinct_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ exact: count_distinct(Strings.n), approx: approx_count_distinct(Strings.n) }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,exact,approx
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,1,1
+    1996-12-20T00:40:57.000000000,9223372036854775808,11753611437813598533,B,1,1
+    1996-12-20T00:41:57.000000000,9223372036854775808,11753611437813598533,B,2,2
+    1996-12-20T00:42:57.000000000,9223372036854775808,11753611437813598533,B,2,2
+    1996-12-20T00:43:57.000000000,9223372036854775808,11753611437813598533,B,3,3
+    1996-12-20T00:44:57.000000000,9223372036854775808,11753611437813598533,B,3,3
+    "###);
+}
+
+#[tokio::test]
+async fn test_count_distinct_string() {
+    insta::assert_snapshot!(QueryFixture::new("{ exact: count_distinct(Strings.s), approx: approx_count_distinct(Strings.s) }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,exact,approx
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,1,1
+    1996-12-20T00:40:57.000000000,9223372036854775808,11753611437813598533,B,1,1
+    1996-12-20T00:41:57.000000000,9223372036854775808,11753611437813598533,B,2,2
+    1996-12-20T00:42:57.000000000,9223372036854775808,11753611437813598533,B,3,3
+    1996-12-20T00:43:57.000000000,9223372036854775808,11753611437813598533,B,3,3
+    1996-12-20T00:44:57.000000000,9223372036854775808,11753611437813598533,B,4,4
+    "###);
+}
+
+#[tokio::test]
+async fn test_count_distinct_since_predicate_boolean() {
+    insta::assert_snapshot!(QueryFixture::new("{ all: count_distinct(Booleans.a), since: count_distinct(Booleans.a, window=since(Booleans.b)), approx_since: approx_count_distinct(Booleans.a, window=since(Booleans.b)) }").run_to_csv(&boolean_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,all,since,approx_since
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,1,1,1
+    1996-12-20T00:40:57.000000000,9223372036854775808,11753611437813598533,B,1,1,1
+    1996-12-20T00:41:57.000000000,9223372036854775808,11753611437813598533,B,1,1,1
+    1996-12-20T00:42:57.000000000,9223372036854775808,11753611437813598533,B,2,1,1
+    1996-12-20T00:43:57.000000000,9223372036854775808,11753611437813598533,B,2,2,2
+    1996-12-20T00:44:57.000000000,9223372036854775808,11753611437813598533,B,2,1,1
+    1996-12-20T00:45:57.000000000,9223372036854775808,11753611437813598533,B,2,1,1
+    "###);
+}
+
+#[tokio::test]
+async fn test_top_k_and_mode_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ mode: mode(Strings.n), first: index(0, top_k(Strings.n, 2)), second: index(1, top_k(Strings.n, 2)), len: list_len(top_k(Strings.n, 2)) }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,mode,first,second,len
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,0,0,,1
+    1996-12-20T00:40:57.000000000,9223372036854775808,11753611437813598533,B,5,5,,1
+    1996-12-20T00:41:57.000000000,9223372036854775808,11753611437813598533,B,-2,-2,5,2
+    1996-12-20T00:42:57.000000000,9223372036854775808,11753611437813598533,B,-2,-2,5,2
+    1996-12-20T00:43:57.000000000,9223372036854775808,11753611437813598533,B,-2,-2,2,2
+    1996-12-20T00:44:57.000000000,9223372036854775808,11753611437813598533,B,-2,-2,2,2
+    "###);
+}
+
+#[tokio::test]
+async fn test_mode_since_predicate_boolean() {
+    insta::assert_snapshot!(QueryFixture::new("{ all: mode(Booleans.a), since: mode(Booleans.a, window=since(Booleans.b)) }").run_to_csv(&boolean_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,all,since
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,true,true
+    1996-12-20T00:40:57.000000000,9223372036854775808,11753611437813598533,B,false,false
+    1996-12-20T00:41:57.000000000,9223372036854775808,11753611437813598533,B,false,false
+    1996-12-20T00:42:57.000000000,9223372036854775808,11753611437813598533,B,false,true
+    1996-12-20T00:43:57.000000000,9223372036854775808,11753611437813598533,B,false,false
+    1996-12-20T00:44:57.000000000,9223372036854775808,11753611437813598533,B,false,false
+    1996-12-20T00:45:57.000000000,9223372036854775808,11753611437813598533,B,false,false
+    "###);
+}
+
+#[tokio::test]
+async fn test_top_k_non_positive_k() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ top: top_k(Strings.n, 0) }").run_to_csv(&strings_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0008
+        message: Invalid arguments
+        formatted:
+          - "error[E0008]: Invalid arguments"
+          - "  --> Query:1:25"
+          - "  |"
+          - "1 | { top: top_k(Strings.n, 0) }"
+          - "  |                         ^ Argument 'k' to 'top_k' must be positive, but was 0"
+          - ""
+          - ""
+    "###);
+}
+
 #[tokio::test]
 async fn test_count_if_condition() {
     insta::assert_snapshot!(QueryFixture::new("{ count_if: count_if(Numbers.m > 10)}").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
@@ -252,7 +252,7 @@ async fn test_unrecognized_function() {
           - "1 | { n: cel(Numbers.n) }"
           - "  |      ^^^ No function named 'cel'"
           - "  |"
-          - "  = Nearest matches: 'ceil', 'eq', 'len', 'mul', 'neg'"
+          - "  = Nearest matches: 'ceil', 'eq', 'get', 'len', 'mul'"
           - ""
           - ""
     "###);
@@ -474,7 +474,7 @@ async fn test_parse_error_missing_parentheses() {
           - "1 | { n: Numbers.n"
           - "  |               ^ Unexpected EOF"
           - "  |"
-          - "  = Expected \")\",\",\",\"]\",\"in\",\"let\",\"}\""
+          - "  = Expected \")\",\",\",\"]\",\"def\",\"in\",\"let\",\"}\""
           - ""
           - ""
     "###);
@@ -497,7 +497,7 @@ async fn test_parse_error_unrecognized() {
           - "1 | limit x = 5 in { n: Numbers.n}"
           - "  |       ^ Invalid token 'x'"
           - "  |"
-          - "  = Expected \"!=\", \"(\", \")\", \"*\", \"+\", \",\", \"-\", \".\", \"/\", \":\", \"<\", \"<=\", \"<>\", \"=\", \"=\", \"==\", \">\", \">=\", \"[\", \"]\", \"and\", \"as\", \"in\", \"let\", \"or\", \"|\", \"}\""
+          - "  = Expected \"!=\", \"(\", \")\", \"*\", \"+\", \",\", \"-\", \".\", \"/\", \":\", \"<\", \"<=\", \"<>\", \"=\", \"=\", \"==\", \">\", \">=\", \"[\", \"]\", \"and\", \"as\", \"def\", \"in\", \"let\", \"or\", \"|\", \"}\""
           - ""
           - ""
       - severity: error
@@ -584,6 +584,82 @@ async fn test_invalid_let_binding_not_ident() {
     "###);
 }
 
+#[tokio::test]
+async fn test_def_body_type_error() {
+    // The error is reported within the definition, rather than at the call.
+    insta::assert_yaml_snapshot!(QueryFixture::new("def negate(x) = !x in { n: negate(Numbers.n) }").run_to_csv(&i64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:17"
+          - "  |"
+          - "1 | def negate(x) = !x in { n: negate(Numbers.n) }"
+          - "  |                 ^- Actual type: i64"
+          - "  |                 | "
+          - "  |                 Invalid types for parameter 'input' in call to 'not'"
+          - "  |"
+          - "  --> built-in signature 'not(input: bool) -> bool':1:12"
+          - "  |"
+          - "1 | not(input: bool) -> bool"
+          - "  |            ---- Expected type: bool"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_def_unbound_reference() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("def add_y(x) = x + y in { n: add_y(Numbers.n) }").run_to_csv(&i64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0006
+        message: Unbound reference
+        formatted:
+          - "error[E0006]: Unbound reference"
+          - "  --> Query:1:20"
+          - "  |"
+          - "1 | def add_y(x) = x + y in { n: add_y(Numbers.n) }"
+          - "  |                    ^ No reference named 'y'"
+          - "  |"
+          - "  = Nearest matches: 'Numbers'"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_def_recursive() {
+    // The definition is resolved before the function is in scope.
+    insta::assert_yaml_snapshot!(QueryFixture::new("def f(x) = f(x) in { n: f(Numbers.n) }").run_to_csv(&i64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0007
+        message: Undefined function
+        formatted:
+          - "error[E0007]: Undefined function"
+          - "  --> Query:1:12"
+          - "  |"
+          - "1 | def f(x) = f(x) in { n: f(Numbers.n) }"
+          - "  |            ^ No function named 'f'"
+          - "  |"
+          - "  = Nearest matches: 'if', 'eq', 'gt', 'lt', 'add'"
+          - ""
+          - ""
+    "###);
+}
+
 #[tokio::test]
 async fn test_invalid_expr() {
     insta::assert_yaml_snapshot!(QueryFixture::new("let x = 5 in { n: Numbers.n + $ } ").run_to_csv(&i64_data_fixture().await).await.unwrap_err(), @r###"
@@ -708,7 +784,7 @@ async fn test_invalid_arguments_unexpected_operator() {
           - "1 | { n: ceil(+ Numbers.n) } "
           - "  |           ^ Invalid token '+'"
           - "  |"
-          - "  = Expected \"!\", \"$input\", \"(\", \")\", \",\", \"-\", \"let\", \"{\", ident, literal"
+          - "  = Expected \"!\", \"$input\", \"(\", \")\", \",\", \"-\", \"def\", \"let\", \"{\", ident, literal"
           - ""
           - ""
     "###);
@@ -1,5 +1,8 @@
 //! e2e tests for the collection operators.
 
+use arrow::array::{
+    Int64Array, Int64Builder, ListBuilder, StringArray, TimestampNanosecondArray, UInt64Array,
+};
 use sparrow_api::kaskada::v1alpha::TableConfig;
 use uuid::Uuid;
 
@@ -23,6 +26,47 @@ pub(crate) async fn collection_data_fixture() -> DataFixture {
         .unwrap()
 }
 
+/// Create a simple table with a list column.
+async fn list_data_fixture() -> DataFixture {
+    let mut items = ListBuilder::new(Int64Builder::new());
+    items.append_value([Some(1), Some(2), Some(3)]);
+    items.append_value([Some(5), None]);
+    items.append(false);
+    items.append(true);
+    items.append_value([Some(2)]);
+    let items = items.finish();
+
+    let table = crate::ParquetTableBuilder::new()
+        .add_column(
+            "time",
+            false,
+            TimestampNanosecondArray::from(vec![1000, 2000, 3000, 4000, 5000]),
+        )
+        .add_column("subsort", false, UInt64Array::from(vec![0, 1, 2, 3, 4]))
+        .add_column(
+            "key",
+            false,
+            StringArray::from(vec!["a", "a", "b", "b", "a"]),
+        )
+        .add_column("n", true, Int64Array::from(vec![0, 1, 2, 3, 4]))
+        .add_column("items", true, items);
+
+    DataFixture::new()
+        .with_table_from_parquet(
+            TableConfig::new_with_table_source(
+                "Input",
+                &Uuid::new_v4(),
+                "time",
+                Some("subsort"),
+                "key",
+                "",
+            ),
+            table,
+        )
+        .await
+        .unwrap()
+}
+
 #[tokio::test]
 async fn test_string_to_i64_get_static_key() {
     insta::assert_snapshot!(QueryFixture::new("{ f1: get(\"f1\", Input.s_to_i64) }").run_to_csv(&collection_data_fixture().await).await.unwrap(), @r###"
@@ -162,3 +206,77 @@ async fn test_incompatible_key_types() {
           - ""
     "###);
 }
+
+#[tokio::test]
+async fn test_map_contains_key() {
+    insta::assert_snapshot!(QueryFixture::new("{ f1: contains_key(\"f1\", Input.s_to_i64), f3: Input.s_to_i64 | contains_key(\"f3\") }").run_to_csv(&collection_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,f1,f3
+    1996-12-19T16:39:57.000000000,0,2359047937476779835,1,true,false
+    1996-12-19T16:40:57.000000000,0,2359047937476779835,1,true,false
+    1996-12-19T16:40:59.000000000,0,2359047937476779835,1,true,false
+    1996-12-19T16:41:57.000000000,0,2359047937476779835,1,false,false
+    1996-12-19T16:42:57.000000000,0,2359047937476779835,1,true,true
+    "###);
+}
+
+#[tokio::test]
+async fn test_map_keys_and_values() {
+    insta::assert_snapshot!(QueryFixture::new("{ num_keys: list_len(keys(Input.s_to_i64)), first_value: index(0, values(Input.s_to_i64)) }").run_to_csv(&collection_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,num_keys,first_value
+    1996-12-19T16:39:57.000000000,0,2359047937476779835,1,2,0
+    1996-12-19T16:40:57.000000000,0,2359047937476779835,1,2,1
+    1996-12-19T16:40:59.000000000,0,2359047937476779835,1,2,5
+    1996-12-19T16:41:57.000000000,0,2359047937476779835,1,1,13
+    1996-12-19T16:42:57.000000000,0,2359047937476779835,1,2,15
+    "###);
+}
+
+#[tokio::test]
+async fn test_list_functions() {
+    insta::assert_snapshot!(QueryFixture::new("{ len: list_len(Input.items), first: index(0, Input.items), second: Input.items | index(1), sum: list_sum(Input.items), has_two: list_contains(2, Input.items) }").run_to_csv(&list_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,len,first,second,sum,has_two
+    1970-01-01T00:00:00.000001000,0,7636293598395510443,a,3,1,2,6,true
+    1970-01-01T00:00:00.000002000,1,7636293598395510443,a,2,5,,5,false
+    1970-01-01T00:00:00.000003000,2,2637710838665036908,b,,,,,
+    1970-01-01T00:00:00.000004000,3,2637710838665036908,b,0,,,,false
+    1970-01-01T00:00:00.000005000,4,7636293598395510443,a,1,2,,2,true
+    "###);
+}
+
+#[tokio::test]
+async fn test_list_index_dynamic() {
+    insta::assert_snapshot!(QueryFixture::new("{ n: Input.n, item: index(Input.n, Input.items) }").run_to_csv(&list_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,n,item
+    1970-01-01T00:00:00.000001000,0,7636293598395510443,a,0,1
+    1970-01-01T00:00:00.000002000,1,7636293598395510443,a,1,
+    1970-01-01T00:00:00.000003000,2,2637710838665036908,b,2,
+    1970-01-01T00:00:00.000004000,3,2637710838665036908,b,3,
+    1970-01-01T00:00:00.000005000,4,7636293598395510443,a,4,
+    "###);
+}
+
+#[tokio::test]
+async fn test_flatten_requires_nested_list() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ f: flatten(Input.items) }")
+        .run_to_csv(&list_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:6"
+          - "  |"
+          - "1 | { f: flatten(Input.items) }"
+          - "  |      ^^^^^^^ ----------- Type: i64"
+          - "  |      |        "
+          - "  |      Invalid types for call to 'flatten'"
+          - "  |"
+          - "  = Expected 'list'"
+          - ""
+          - ""
+    "###);
+}
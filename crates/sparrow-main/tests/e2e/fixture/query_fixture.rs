@@ -1,7 +1,7 @@
 use std::fs::File;
 use std::path::PathBuf;
 
-use arrow::record_batch::RecordBatchReader;
+use arrow::record_batch::{RecordBatch, RecordBatchReader};
 use chrono::NaiveDateTime;
 use futures::{StreamExt, TryStreamExt};
 use itertools::Itertools;
@@ -14,8 +14,8 @@ use sparrow_api::kaskada::v1alpha::ComputeSnapshot;
 use sparrow_api::kaskada::v1alpha::ComputeSnapshotConfig;
 use sparrow_api::kaskada::v1alpha::{destination, Destination};
 use sparrow_api::kaskada::v1alpha::{
-    CompileRequest, ExecuteRequest, FeatureSet, FileType, Formula, ObjectStoreDestination,
-    PerEntityBehavior,
+    CompileRequest, ComputePlan, ExecuteRequest, ExecuteResponse, FeatureSet, FileType, Formula,
+    ObjectStoreDestination, PerEntityBehavior,
 };
 use sparrow_compiler::InternalCompileOptions;
 use sparrow_qfr::kaskada::sparrow::v1alpha::FlightRecordHeader;
@@ -127,6 +127,12 @@ impl QueryFixture {
         self
     }
 
+    /// Output timestamp columns with a time zone in that time zone.
+    pub fn with_preserve_time_zones(mut self) -> Self {
+        self.execute_request.preserve_time_zones = true;
+        self
+    }
+
     /// Modify the compile options to disable simplification.
     pub fn without_simplification(mut self) -> Self {
         self.internal_compile_options.simplifier_iteration_limit = 0;
@@ -195,6 +201,44 @@ impl QueryFixture {
         Ok(std::fs::read_to_string(output_file).unwrap())
     }
 
+    /// Run a query and return the results as a JSON Lines string.
+    pub async fn run_to_jsonl(&self, data: &DataFixture) -> Result<String, crate::EndToEndError> {
+        let output_dir = tempfile::TempDir::new().unwrap();
+        let result = self.run(data, FileType::Jsonl, output_dir.path()).await?;
+        let output_file = result
+            .inner
+            .into_iter()
+            .exactly_one()
+            .expect("multiple output file not yet supported");
+        let output_file = output_file.to_string_lossy().to_string();
+        let output_file = output_file.strip_prefix("file://").expect("file:// prefix");
+
+        Ok(std::fs::read_to_string(output_file).unwrap())
+    }
+
+    /// Run a query writing to a temporary Arrow IPC file, and return the
+    /// batches read from it.
+    pub async fn run_to_arrow_ipc(
+        &self,
+        data: &DataFixture,
+    ) -> Result<Vec<RecordBatch>, crate::EndToEndError> {
+        let output_dir = tempfile::TempDir::new().unwrap();
+        let result = self
+            .run(data, FileType::ArrowIpc, output_dir.path())
+            .await?;
+        let output_file = result
+            .inner
+            .into_iter()
+            .exactly_one()
+            .expect("multiple output file not yet supported");
+        let output_file = output_file.to_string_lossy().to_string();
+        let output_file = output_file.strip_prefix("file://").expect("file:// prefix");
+
+        let file = File::open(output_file).unwrap();
+        let reader = arrow::ipc::reader::FileReader::try_new(file, None).unwrap();
+        Ok(reader.map(|batch| batch.unwrap()).collect())
+    }
+
     /// Run a query writing to a temporary Parquet file, and return it.
     pub async fn run_to_parquet(
         &self,
@@ -242,20 +286,7 @@ impl QueryFixture {
         output_format: FileType,
         output_dir: &std::path::Path,
     ) -> Result<RunResult<Vec<PathBuf>>, crate::EndToEndError> {
-        let request = CompileRequest {
-            tables: data.tables(),
-            ..self.compile_request.clone()
-        };
-
-        // TODO: Look at caching / reusing the compiled plan.
-        let compile_result =
-            sparrow_compiler::compile_proto(request, self.internal_compile_options.clone()).await?;
-
-        let plan = if let Some(plan) = compile_result.plan {
-            plan
-        } else {
-            return Err(compile_result.fenl_diagnostics.unwrap_or_default().into());
-        };
+        let plan = self.compile(data).await?;
 
         let destination = ObjectStoreDestination {
             output_prefix_uri: format!("file:///{}", output_dir.display()),
@@ -269,6 +300,7 @@ impl QueryFixture {
         let request = ExecuteRequest {
             plan: Some(plan),
             destination: Some(output_to),
+            additional_destinations: vec![],
             tables: data.tables(),
 
             ..self.execute_request.clone()
@@ -297,6 +329,52 @@ impl QueryFixture {
             snapshots,
         })
     }
+
+    /// Run a query writing to each of the `destinations`, returning the
+    /// responses.
+    ///
+    /// The first destination is the primary destination. Execution errors are
+    /// returned as the report, so that the failures of each destination may
+    /// be inspected.
+    pub async fn run_to_destinations(
+        &self,
+        data: &DataFixture,
+        destinations: Vec<Destination>,
+    ) -> error_stack::Result<Vec<ExecuteResponse>, sparrow_runtime::execute::error::Error> {
+        let plan = self.compile(data).await.expect("query compiles");
+
+        let mut destinations = destinations.into_iter();
+        let request = ExecuteRequest {
+            plan: Some(plan),
+            destination: destinations.next(),
+            additional_destinations: destinations.collect(),
+            tables: data.tables(),
+
+            ..self.execute_request.clone()
+        };
+
+        sparrow_runtime::execute::execute(request, None, None, FlightRecordHeader::default())
+            .await?
+            .try_collect()
+            .await
+    }
+
+    /// Compile the query against the tables in `data`.
+    async fn compile(&self, data: &DataFixture) -> Result<ComputePlan, crate::EndToEndError> {
+        let request = CompileRequest {
+            tables: data.tables(),
+            ..self.compile_request.clone()
+        };
+
+        // TODO: Look at caching / reusing the compiled plan.
+        let compile_result =
+            sparrow_compiler::compile_proto(request, self.internal_compile_options.clone()).await?;
+
+        match compile_result.plan {
+            Some(plan) => Ok(plan),
+            None => Err(compile_result.fenl_diagnostics.unwrap_or_default().into()),
+        }
+    }
 }
 
 /// Return the hash of a parquet file as an uppercase hex string.
@@ -111,3 +111,16 @@ async fn test_unused_formula_does_not_report_query_error() {
     1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,
     "###);
 }
+
+#[tokio::test]
+async fn test_def_function() {
+    insta::assert_snapshot!(QueryFixture::new("def scale(x, factor: f64) = x * factor in { n: Numbers.n, scaled: scale(Numbers.n, 2), sum_m: scale(sum(Numbers.m), 0.5) }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,n,scaled,sum_m
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,10,20.0,2.5
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,3,6.0,12.0
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,6,12.0,11.0
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,9,18.0,11.0
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,,,17.0
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,,,17.0
+    "###);
+}
@@ -64,6 +64,33 @@ pub(crate) async fn invalid_json_data_fixture() -> DataFixture {
         .unwrap()
 }
 
+/// Create a simple table with a json string column 'json' containing nested
+/// objects and arrays.
+///
+/// This csv parser escapes quotes with double quotes.
+pub(crate) async fn nested_json_data_fixture() -> DataFixture {
+    DataFixture::new()
+        .with_table_from_csv(
+            TableConfig::new_with_table_source(
+                "Json",
+                &Uuid::new_v4(),
+                "time",
+                Some("subsort"),
+                "key",
+                "",
+            ),
+            indoc! { r#"
+    time,subsort,key,json
+    1996-12-19T16:39:57-08:00,0,A,"{""c"": {""b"": ""dog"", ""d"": [1, 2]}}"
+    1996-12-19T16:40:57-08:00,0,B,"{""c"": {""b"": ""lizard"", ""d"": [3]}}"
+    1996-12-19T16:41:57-08:00,0,B,"{""a"": 1, ""c"": 3.3}"
+    1996-12-19T16:42:57-08:00,0,B,"{""c"": {""b"": {""e"": true}, ""d"": [""abc"", [""x""]]}}"
+    "#},
+        )
+        .await
+        .unwrap()
+}
+
 #[tokio::test]
 async fn test_json_parses_field() {
     insta::assert_snapshot!(QueryFixture::new("let json = json(Json.json) in { a_test: json.a as i64, b_test: json(Json.json).b }").run_to_csv(&json_data_fixture().await).await.unwrap(), @r###"
@@ -161,32 +188,61 @@ async fn test_json_of_json_object_errors() {
 }
 
 #[tokio::test]
-async fn test_nested_json_produces_error() {
-    // There's a way we can probably produce a better error message,
-    // but, on the other hand, it's marked as an experimental feature, plus
-    // this returns an error rather than incorrect results :shrug:
-    //
-    // The `dfg` would need to check if it recursively encounters the pattern
-    // `(field_ref (json ?value ?op) ?field ?op)`
-    insta::assert_yaml_snapshot!(QueryFixture::new("{ out: json(Json.json).a.b }").run_to_csv(&json_data_fixture().await).await.unwrap_err(), @r###"
+async fn test_nested_json_field_and_index() {
+    insta::assert_snapshot!(QueryFixture::new("let json = json(Json.json) in { b: json.c.b, first: json.c.d[0] as i64, second: json.c.d[1], len: len(json.c.d) }").run_to_csv(&nested_json_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,b,first,second,len
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,dog,1,2,5
+    1996-12-20T00:40:57.000000000,9223372036854775808,11753611437813598533,B,lizard,3,,3
+    1996-12-20T00:41:57.000000000,9223372036854775808,11753611437813598533,B,,,,
+    1996-12-20T00:42:57.000000000,9223372036854775808,11753611437813598533,B,"{""e"":true}",,"[""x""]",13
+    "###)
+}
+
+#[tokio::test]
+async fn test_json_index_requires_literal() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ out: json(Json.json).c.d[Json.subsort] }").run_to_csv(&nested_json_data_fixture().await).await.unwrap_err(), @r###"
     ---
     code: Client specified an invalid argument
     message: 1 errors in Fenl statements; see diagnostics
     fenl_diagnostics:
       - severity: error
-        code: E0001
-        message: Illegal field reference
+        code: E0014
+        message: Invalid non-constant argument
         formatted:
-          - "error[E0001]: Illegal field reference"
-          - "  --> Query:1:8"
+          - "error[E0014]: Invalid non-constant argument"
+          - "  --> Query:1:28"
           - "  |"
-          - "1 | { out: json(Json.json).a.b }"
-          - "  |        ^^^^^^^^^^^^^^^^^ No fields for non-record base type string"
+          - "1 | { out: json(Json.json).c.d[Json.subsort] }"
+          - "  |                            ^^^^^^^^^^^^ Index into 'json' must be a constant integer, but was not"
           - ""
           - ""
     "###);
 }
 
+#[tokio::test]
+async fn test_parse_json_to_record() {
+    insta::assert_snapshot!(QueryFixture::new("let parsed = parse_json(Json.json, { a: 0, b: \"\" }) in { a: parsed.a, b: parsed.b }").run_to_csv(&json_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,a,b
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,10,dog
+    1996-12-20T00:40:57.000000000,9223372036854775808,11753611437813598533,B,4,lizard
+    1996-12-20T00:41:57.000000000,9223372036854775808,11753611437813598533,B,1,
+    1996-12-20T00:42:57.000000000,9223372036854775808,11753611437813598533,B,12,cat
+    1996-12-20T00:43:57.000000000,9223372036854775808,3650215962958587783,A,34,
+    1996-12-20T00:44:57.000000000,9223372036854775808,11753611437813598533,B,6,dog
+    "###)
+}
+
+#[tokio::test]
+async fn test_parse_json_nested_record() {
+    insta::assert_snapshot!(QueryFixture::new("let parsed = parse_json(Json.json, { c: { b: \"\", d: \"\" } }) in { b: parsed.c.b, d: parsed.c.d }").run_to_csv(&nested_json_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,b,d
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,dog,"[1,2]"
+    1996-12-20T00:40:57.000000000,9223372036854775808,11753611437813598533,B,lizard,[3]
+    1996-12-20T00:41:57.000000000,9223372036854775808,11753611437813598533,B,,
+    1996-12-20T00:42:57.000000000,9223372036854775808,11753611437813598533,B,"{""e"":true}","[""abc"",[""x""]]"
+    "###)
+}
+
 #[tokio::test]
 async fn test_json_as_output_field_produces_error() {
     insta::assert_yaml_snapshot!(QueryFixture::new("{ out: json(Json.json) }").run_to_csv(&json_data_fixture().await).await.unwrap_err(), @r###"
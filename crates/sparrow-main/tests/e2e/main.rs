@@ -37,10 +37,14 @@ mod shift_tests;
 mod string_tests;
 mod tick_tests;
 mod time_tests;
+mod time_zone_tests;
 mod when_tests;
 mod windowed_aggregation_tests;
 mod with_key_tests;
 
+// Tests for output file types.
+mod output_tests;
+
 // Tests for Parquet functionality.
 mod parquet_tests;
 
@@ -0,0 +1,159 @@
+//! e2e tests for the output file types.
+
+use sparrow_api::kaskada::v1alpha::{
+    destination, Destination, FileType, ObjectStoreDestination, RedisDestination,
+};
+
+use crate::fixtures::i64_data_fixture;
+use crate::QueryFixture;
+
+fn object_store_destination(output_dir: &tempfile::TempDir, file_type: FileType) -> Destination {
+    Destination {
+        destination: Some(destination::Destination::ObjectStore(
+            ObjectStoreDestination {
+                output_prefix_uri: format!("file:///{}", output_dir.path().display()),
+                file_type: file_type.into(),
+                output_paths: None,
+            },
+        )),
+    }
+}
+
+/// Read the single output file written to an object store destination.
+fn read_output(destination: &Destination) -> String {
+    let Some(destination::Destination::ObjectStore(store)) = &destination.destination else {
+        panic!("expected object store destination, but was {destination:?}")
+    };
+    let paths = &store.output_paths.as_ref().expect("output paths").paths;
+    assert_eq!(
+        paths.len(),
+        1,
+        "expected one output file, but was {paths:?}"
+    );
+    let path = paths[0].strip_prefix("file://").expect("file:// prefix");
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[tokio::test]
+async fn test_i64_to_jsonl() {
+    insta::assert_snapshot!(QueryFixture::new("{ m: Numbers.m, n: Numbers.n }").run_to_jsonl(&i64_data_fixture().await).await.unwrap(), @r###"
+    {"_key":"A","_key_hash":3650215962958587783,"_subsort":9223372036854775808,"_time":"1996-12-20T00:39:57","m":5,"n":10}
+    {"_key":"B","_key_hash":11753611437813598533,"_subsort":9223372036854775808,"_time":"1996-12-20T00:39:58","m":24,"n":3}
+    {"_key":"A","_key_hash":3650215962958587783,"_subsort":9223372036854775808,"_time":"1996-12-20T00:39:59","m":17,"n":6}
+    {"_key":"A","_key_hash":3650215962958587783,"_subsort":9223372036854775808,"_time":"1996-12-20T00:40:00","n":9}
+    {"_key":"A","_key_hash":3650215962958587783,"_subsort":9223372036854775808,"_time":"1996-12-20T00:40:01","m":12}
+    {"_key":"A","_key_hash":3650215962958587783,"_subsort":9223372036854775808,"_time":"1996-12-20T00:40:02"}
+    "###);
+}
+
+#[tokio::test]
+async fn test_i64_to_arrow_ipc() {
+    let batches = QueryFixture::new("{ m: Numbers.m, n: Numbers.n }")
+        .run_to_arrow_ipc(&i64_data_fixture().await)
+        .await
+        .unwrap();
+
+    // Write the batches read back from the Arrow IPC file as CSV, for comparison.
+    let mut csv = Vec::new();
+    let mut writer = arrow::csv::Writer::new(&mut csv);
+    for batch in &batches {
+        writer.write(batch).unwrap();
+    }
+    drop(writer);
+
+    insta::assert_snapshot!(String::from_utf8(csv).unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,n
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,5,10
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,24,3
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,17,6
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,,9
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,12,
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_multiple_destinations() {
+    let csv_dir = tempfile::TempDir::new().unwrap();
+    let jsonl_dir = tempfile::TempDir::new().unwrap();
+    let second_csv_dir = tempfile::TempDir::new().unwrap();
+    let responses = QueryFixture::new("{ m: Numbers.m, n: Numbers.n }")
+        .run_to_destinations(
+            &i64_data_fixture().await,
+            vec![
+                object_store_destination(&csv_dir, FileType::Csv),
+                object_store_destination(&jsonl_dir, FileType::Jsonl),
+                object_store_destination(&second_csv_dir, FileType::Csv),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let response = responses.last().unwrap();
+    assert!(response.is_query_done);
+
+    // Every destination receives all of the rows.
+    let progress = response.progress.as_ref().unwrap();
+    assert_eq!(progress.produced_output_rows, 6);
+    assert_eq!(progress.additional_destination_output_rows, vec![6, 6]);
+
+    assert_eq!(response.additional_destinations.len(), 2);
+    let csv = read_output(response.destination.as_ref().unwrap());
+    assert_eq!(read_output(&response.additional_destinations[1]), csv);
+    insta::assert_snapshot!(csv, @r###"
+    _time,_subsort,_key_hash,_key,m,n
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,5,10
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,24,3
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,17,6
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,,9
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,12,
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,,
+    "###);
+    insta::assert_snapshot!(read_output(&response.additional_destinations[0]), @r###"
+    {"_key":"A","_key_hash":3650215962958587783,"_subsort":9223372036854775808,"_time":"1996-12-20T00:39:57","m":5,"n":10}
+    {"_key":"B","_key_hash":11753611437813598533,"_subsort":9223372036854775808,"_time":"1996-12-20T00:39:58","m":24,"n":3}
+    {"_key":"A","_key_hash":3650215962958587783,"_subsort":9223372036854775808,"_time":"1996-12-20T00:39:59","m":17,"n":6}
+    {"_key":"A","_key_hash":3650215962958587783,"_subsort":9223372036854775808,"_time":"1996-12-20T00:40:00","n":9}
+    {"_key":"A","_key_hash":3650215962958587783,"_subsort":9223372036854775808,"_time":"1996-12-20T00:40:01","m":12}
+    {"_key":"A","_key_hash":3650215962958587783,"_subsort":9223372036854775808,"_time":"1996-12-20T00:40:02"}
+    "###);
+}
+
+#[tokio::test]
+async fn test_multiple_destinations_report_each_failure() {
+    let csv_dir = tempfile::TempDir::new().unwrap();
+    let redis = |redis| Destination {
+        destination: Some(destination::Destination::Redis(redis)),
+    };
+    let error = QueryFixture::new("{ m: Numbers.m, n: Numbers.n }")
+        .run_to_destinations(
+            &i64_data_fixture().await,
+            vec![
+                object_store_destination(&csv_dir, FileType::Csv),
+                redis(RedisDestination::default()),
+                redis(RedisDestination {
+                    host_name: "localhost".to_owned(),
+                    database_number: 16,
+                    ..RedisDestination::default()
+                }),
+            ],
+        )
+        .await
+        .unwrap_err();
+
+    // The failures of both destinations are reported.
+    let error = format!("{error:?}");
+    assert!(
+        error.contains("writing to destination 1 (redis)"),
+        "{error}"
+    );
+    assert!(error.contains("missing host name"), "{error}");
+    assert!(
+        error.contains("writing to destination 2 (redis)"),
+        "{error}"
+    );
+    assert!(
+        error.contains("database number must be between 0 and 15"),
+        "{error}"
+    );
+}
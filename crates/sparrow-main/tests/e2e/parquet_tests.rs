@@ -61,8 +61,31 @@ use crate::{DataFixture, QueryFixture};
 /// ```
 ///
 /// https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#decimal
-async fn test_decimal_column_fails_prepare() {
-    let data_fixture = DataFixture::new()
+async fn test_decimal_column_prepares() {
+    insta::assert_snapshot!(QueryFixture::new("{ m: Numbers.m, n: Numbers.n }").run_to_csv(&decimal_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,n
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,5.2,10.70
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,24.3,3.80
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,17.4,10.92
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,,9.80
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,12.7,
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,,
+    1997-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,5.2,10.70
+    1997-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,24.3,3.80
+    1997-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,17.4,10.92
+    1997-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,,9.80
+    1997-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,12.7,
+    1997-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,,
+    "###);
+}
+
+/// Create the `Numbers` table from the Parquet files with decimal columns.
+///
+/// The column `m` is a `decimal(3, 1)` and the column `n` is a
+/// `decimal(4, 2)`. See [test_decimal_column_prepares] for how the files were
+/// produced.
+async fn decimal_data_fixture() -> DataFixture {
+    DataFixture::new()
         .with_table_from_files(
             TableConfig::new_with_table_source(
                 "Numbers",
@@ -77,11 +100,84 @@ async fn test_decimal_column_fails_prepare() {
                 "regressions/decimal_fixed_len_part2.parquet",
             ],
         )
-        .await;
-    assert_eq!(
-        data_fixture.err().unwrap().to_string(),
-        "Internal error: invalid schema provided\n"
-    );
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_decimal_aggregations() {
+    insta::assert_snapshot!(QueryFixture::new("{ m: Numbers.m, sum_m: sum(Numbers.m), min_m: min(Numbers.m), max_n: max(Numbers.n), mean_m: mean(Numbers.m) }").run_to_csv(&decimal_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,sum_m,min_m,max_n,mean_m
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,5.2,5.2,5.2,10.70,5.2
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,24.3,24.3,24.3,3.80,24.3
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,17.4,22.6,5.2,10.92,11.3
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,,22.6,5.2,10.92,11.3
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,12.7,35.3,5.2,10.92,11.8
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,,35.3,5.2,10.92,11.8
+    1997-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,5.2,40.5,5.2,10.92,10.1
+    1997-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,24.3,48.6,24.3,3.80,24.3
+    1997-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,17.4,57.9,5.2,10.92,11.6
+    1997-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,,57.9,5.2,10.92,11.6
+    1997-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,12.7,70.6,5.2,10.92,11.8
+    1997-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,,70.6,5.2,10.92,11.8
+    "###);
+}
+
+#[tokio::test]
+async fn test_decimal_arithmetic() {
+    insta::assert_snapshot!(QueryFixture::new("{ add: Numbers.m + Numbers.n, sub: Numbers.m - Numbers.n, mul: Numbers.m * Numbers.n, div: Numbers.m / Numbers.n }").run_to_csv(&decimal_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,add,sub,mul,div
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,15.90,-5.50,55.6400,0.49
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,28.10,20.50,92.3400,6.39
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,28.32,6.48,190.0080,1.59
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,,,,
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,,,,
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,,,,
+    1997-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,15.90,-5.50,55.6400,0.49
+    1997-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,28.10,20.50,92.3400,6.39
+    1997-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,28.32,6.48,190.0080,1.59
+    1997-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,,,,
+    1997-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,,,,
+    1997-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,,,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_decimal_comparison() {
+    insta::assert_snapshot!(QueryFixture::new("{ lt: Numbers.m < Numbers.n, gte: Numbers.m >= Numbers.n, eq: Numbers.m == Numbers.n, lt_literal: Numbers.m < 10 }").run_to_csv(&decimal_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,lt,gte,eq,lt_literal
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,true,false,false,true
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,false,true,false,false
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,false,true,false,false
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,,,,
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,,,,false
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,,,,
+    1997-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,true,false,false,true
+    1997-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,false,true,false,false
+    1997-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,false,true,false,false
+    1997-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,,,,
+    1997-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,,,,false
+    1997-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,,,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_decimal_casts() {
+    insta::assert_snapshot!(QueryFixture::new("{ m: Numbers.m, m_f64: Numbers.m as f64, m_i64: Numbers.m as i64, n_f64: Numbers.n as f64, n_i64: Numbers.n as i64 }").run_to_csv(&decimal_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,m_f64,m_i64,n_f64,n_i64
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,5.2,5.2,5,10.7,10
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,24.3,24.3,24,3.8,3
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,17.4,17.4,17,10.92,10
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,,,,9.8,9
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,12.7,12.7,12,,
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,,,,,
+    1997-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,5.2,5.2,5,10.7,10
+    1997-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,24.3,24.3,24,3.8,3
+    1997-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,17.4,17.4,17,10.92,10
+    1997-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,,,,9.8,9
+    1997-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,12.7,12.7,12,,
+    1997-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,,,,,
+    "###);
 }
 
 #[tokio::test]
@@ -58,3 +58,63 @@ async fn test_substring() {
     1996-12-20T00:44:57.000000000,9223372036854775808,11753611437813598533,B,go,oodbye,goodbye,goodbye
     "###);
 }
+
+#[tokio::test]
+async fn test_contains_starts_with_ends_with() {
+    insta::assert_snapshot!(QueryFixture::new("{ contains: Strings.s | contains(\"llo\"), starts_with: Strings.s | starts_with(Strings.t), ends_with: Strings.s | ends_with(\"e\") }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,contains,starts_with,ends_with
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,true,true,false
+    1996-12-20T00:40:57.000000000,9223372036854775808,11753611437813598533,B,false,false,false
+    1996-12-20T00:41:57.000000000,9223372036854775808,11753611437813598533,B,true,true,false
+    1996-12-20T00:42:57.000000000,9223372036854775808,11753611437813598533,B,false,false,false
+    1996-12-20T00:43:57.000000000,9223372036854775808,11753611437813598533,B,false,false,false
+    1996-12-20T00:44:57.000000000,9223372036854775808,11753611437813598533,B,false,true,true
+    "###);
+}
+
+#[tokio::test]
+async fn test_regex_functions() {
+    insta::assert_snapshot!(QueryFixture::new("{ matches: Strings.s | regex_match(\"^[a-z]+$\"), extract: Strings.s | regex_extract(\"(l+)(o)\", group = 1), replace: Strings.s | regex_replace(\"[aeiou]\", \"_\") }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,matches,extract,replace
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,false,ll,hEll_
+    1996-12-20T00:40:57.000000000,9223372036854775808,11753611437813598533,B,false,,W_rld
+    1996-12-20T00:41:57.000000000,9223372036854775808,11753611437813598533,B,false,ll,h_ll_ w_rld
+    1996-12-20T00:42:57.000000000,9223372036854775808,11753611437813598533,B,false,,
+    1996-12-20T00:43:57.000000000,9223372036854775808,11753611437813598533,B,false,,
+    1996-12-20T00:44:57.000000000,9223372036854775808,11753611437813598533,B,true,,g__dby_
+    "###);
+}
+
+#[tokio::test]
+async fn test_invalid_regex_pattern() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ matches: Strings.s | regex_match(\"(\") }").run_to_csv(&strings_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0008
+        message: Invalid arguments
+        formatted:
+          - "error[E0008]: Invalid arguments"
+          - "  --> Query:1:36"
+          - "  |"
+          - "1 | { matches: Strings.s | regex_match(\"(\") }"
+          - "  |                                    ^^^ Invalid regex pattern: unclosed group"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_split_concat_trim_pad() {
+    insta::assert_snapshot!(QueryFixture::new("{ words: Strings.s | split(\" \") | list_len(), concat: concat(Strings.s, \"-\", Strings.t), trim: concat(\"[\", trim(concat(\" \", Strings.s, \" \")), \"]\"), pad: Strings.s | pad(7, fill = \"*\") }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,words,concat,trim,pad
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,1,hEllo-hEllo,[hEllo],**hEllo
+    1996-12-20T00:40:57.000000000,9223372036854775808,11753611437813598533,B,1,World-world,[World],**World
+    1996-12-20T00:41:57.000000000,9223372036854775808,11753611437813598533,B,2,hello world-hello world,[hello world],hello world
+    1996-12-20T00:42:57.000000000,9223372036854775808,11753611437813598533,B,1,-greetings,[],*******
+    1996-12-20T00:43:57.000000000,9223372036854775808,11753611437813598533,B,1,-salutations,[],*******
+    1996-12-20T00:44:57.000000000,9223372036854775808,11753611437813598533,B,1,goodbye-,[goodbye],goodbye
+    "###);
+}
@@ -549,6 +549,68 @@ async fn test_tick_with_when_produces_values_on_window_bounds() {
     "###);
 }
 
+#[tokio::test]
+async fn test_since_every() {
+    // Ticks every 60 seconds are aligned to the epoch, so they are the same as `minutely()`.
+    insta::assert_snapshot!(QueryFixture::new("{ n: Foo.n, every_minute: count(Foo, window=since(every(seconds(60)))) }").run_to_csv(&data_fixture_over_minutes().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,n,every_minute
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,10.0,1
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,3.9,1
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,6.2,2
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,9.25,3
+    1996-12-20T00:40:00.000000000,18446744073709551615,3650215962958587783,A,,3
+    1996-12-20T00:40:00.000000000,18446744073709551615,11753611437813598533,B,,1
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,3.0,1
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,8.0,2
+    1996-12-20T00:40:02.000000000,9223372036854775808,11753611437813598533,B,8.0,1
+    1996-12-20T00:40:03.000000000,9223372036854775808,3650215962958587783,A,,3
+    1996-12-20T00:40:04.000000000,9223372036854775808,3650215962958587783,A,10.0,4
+    1996-12-20T00:41:00.000000000,18446744073709551615,3650215962958587783,A,,4
+    1996-12-20T00:41:00.000000000,18446744073709551615,11753611437813598533,B,,1
+    1996-12-20T00:41:04.000000000,9223372036854775808,3650215962958587783,A,10.0,1
+    "###);
+}
+
+#[tokio::test]
+async fn test_since_every_with_offset() {
+    // Ticks every hour, 30 minutes after the hour.
+    insta::assert_snapshot!(QueryFixture::new("{ n: Foo.n, sum_since: sum(Foo.n, window=since(every(seconds(3600), offset = seconds(1800)))) }").run_to_csv(&data_fixture_over_hours().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,n,sum_since
+    1996-12-20T07:39:57.000000000,9223372036854775808,3650215962958587783,A,10.0,10.0
+    1996-12-20T07:39:58.000000000,9223372036854775808,11753611437813598533,B,3.9,3.9
+    1996-12-20T08:00:00.000000000,9223372036854775808,3650215962958587783,A,6.2,16.2
+    1996-12-20T08:30:00.000000000,18446744073709551615,3650215962958587783,A,,16.2
+    1996-12-20T08:30:00.000000000,18446744073709551615,11753611437813598533,B,,3.9
+    1996-12-20T08:44:00.000000000,9223372036854775808,3650215962958587783,A,9.25,9.25
+    1996-12-20T08:45:01.000000000,9223372036854775808,3650215962958587783,A,3.0,12.25
+    1996-12-20T09:20:02.000000000,9223372036854775808,3650215962958587783,A,8.0,20.25
+    1996-12-20T09:25:02.000000000,9223372036854775808,11753611437813598533,B,23.9,23.9
+    1996-12-20T09:30:00.000000000,18446744073709551615,3650215962958587783,A,,20.25
+    1996-12-20T09:30:00.000000000,18446744073709551615,11753611437813598533,B,,23.9
+    1996-12-20T10:30:00.000000000,18446744073709551615,3650215962958587783,A,,
+    1996-12-20T10:30:00.000000000,18446744073709551615,11753611437813598533,B,,
+    1996-12-20T10:30:03.000000000,9223372036854775808,3650215962958587783,A,,
+    1996-12-20T11:30:00.000000000,18446744073709551615,3650215962958587783,A,,
+    1996-12-20T11:30:00.000000000,18446744073709551615,11753611437813598533,B,,
+    1996-12-20T11:40:04.000000000,9223372036854775808,3650215962958587783,A,10.0,10.0
+    "###);
+}
+
+#[tokio::test]
+async fn test_when_every() {
+    insta::assert_snapshot!(QueryFixture::new("{ sum_when_every: sum(Foo.n, window=since(every(days(1)))) | when(every(days(1))) }").run_to_csv(&data_fixture_over_days().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,sum_when_every
+    1996-12-20T00:00:00.000000000,18446744073709551615,3650215962958587783,A,10.0
+    1996-12-20T00:00:00.000000000,18446744073709551615,11753611437813598533,B,3.9
+    1996-12-21T00:00:00.000000000,18446744073709551615,3650215962958587783,A,
+    1996-12-21T00:00:00.000000000,18446744073709551615,11753611437813598533,B,
+    1996-12-22T00:00:00.000000000,18446744073709551615,3650215962958587783,A,26.45
+    1996-12-22T00:00:00.000000000,18446744073709551615,11753611437813598533,B,
+    1996-12-23T00:00:00.000000000,18446744073709551615,3650215962958587783,A,
+    1996-12-23T00:00:00.000000000,18446744073709551615,11753611437813598533,B,23.9
+    "###);
+}
+
 #[tokio::test]
 async fn test_tick_when_finished() {
     // This tests the use of `... | last() | when(finished())` as part of producing
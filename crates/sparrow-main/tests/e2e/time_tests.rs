@@ -12,10 +12,65 @@
 //! * `seconds()`: Same situation as `days`. Tested indirectly by
 //!   `add_time_duration_s`.
 
+use indoc::indoc;
+use sparrow_api::kaskada::v1alpha::TableConfig;
+use uuid::Uuid;
+
 use crate::fixtures::{
     boolean_data_fixture, i64_data_fixture, strings_data_fixture, timestamp_ns_data_fixture,
 };
-use crate::QueryFixture;
+use crate::{DataFixture, QueryFixture};
+
+/// A dataset with inputs around the new year in UTC, which is still the
+/// previous year in `America/New_York`.
+async fn data_fixture_over_new_year() -> DataFixture {
+    DataFixture::new()
+        .with_table_from_csv(
+            TableConfig::new_with_table_source(
+                "Foo",
+                &Uuid::new_v4(),
+                "time",
+                Some("subsort"),
+                "key",
+                "",
+            ),
+            indoc! {"
+    time,subsort,key,n
+    2022-12-31T18:30:00-05:00,0,A,1
+    2022-12-31T22:00:00-05:00,0,A,2
+    2023-01-01T01:00:00-05:00,0,B,3
+    "},
+        )
+        .await
+        .unwrap()
+}
+
+/// A dataset with inputs spanning the start of daylight saving time in
+/// `America/New_York` on 2023-03-12, when the local day is 23 hours.
+async fn data_fixture_over_dst_start() -> DataFixture {
+    DataFixture::new()
+        .with_table_from_csv(
+            TableConfig::new_with_table_source(
+                "Foo",
+                &Uuid::new_v4(),
+                "time",
+                Some("subsort"),
+                "key",
+                "",
+            ),
+            indoc! {"
+    time,subsort,key,n
+    2023-03-10T20:00:00-05:00,0,A,1
+    2023-03-10T21:00:00-05:00,0,B,2
+    2023-03-11T07:00:00-05:00,0,A,3
+    2023-03-12T08:00:00-04:00,0,A,4
+    2023-03-12T23:30:00-04:00,0,A,5
+    2023-03-13T00:30:00-04:00,0,B,6
+    "},
+        )
+        .await
+        .unwrap()
+}
 
 #[tokio::test]
 async fn test_time_of_boolean() {
@@ -187,6 +242,69 @@ async fn test_year() {
     "###);
 }
 
+#[tokio::test]
+async fn test_day_of_month_time_zone() {
+    insta::assert_snapshot!(QueryFixture::new("{ day_of_month: day_of_month(Times.time, time_zone = \"America/New_York\") }").run_to_csv(&timestamp_ns_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,day_of_month
+    1994-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,19
+    1995-10-20T00:40:57.000000000,9223372036854775808,11753611437813598533,B,19
+    1996-08-20T00:41:57.000000000,9223372036854775808,11753611437813598533,B,19
+    1997-12-12T00:42:57.000000000,9223372036854775808,11753611437813598533,B,11
+    1998-12-13T00:43:57.000000000,9223372036854775808,11753611437813598533,B,12
+    2004-12-06T00:44:57.000000000,9223372036854775808,11753611437813598533,B,5
+    "###);
+}
+
+#[tokio::test]
+async fn test_day_of_year_time_zone() {
+    insta::assert_snapshot!(QueryFixture::new("{ day_of_year: day_of_year(Times.time, time_zone = \"America/New_York\") }").run_to_csv(&timestamp_ns_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,day_of_year
+    1994-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,353
+    1995-10-20T00:40:57.000000000,9223372036854775808,11753611437813598533,B,292
+    1996-08-20T00:41:57.000000000,9223372036854775808,11753611437813598533,B,232
+    1997-12-12T00:42:57.000000000,9223372036854775808,11753611437813598533,B,345
+    1998-12-13T00:43:57.000000000,9223372036854775808,11753611437813598533,B,346
+    2004-12-06T00:44:57.000000000,9223372036854775808,11753611437813598533,B,340
+    "###);
+}
+
+#[tokio::test]
+async fn test_calendar_functions_time_zone_over_new_year() {
+    insta::assert_snapshot!(QueryFixture::new("{
+        day_of_month: day_of_month(Foo.time, time_zone = \"America/New_York\"),
+        month_of_year: month_of_year(Foo.time, time_zone = \"America/New_York\"),
+        year: year(Foo.time, time_zone = \"America/New_York\"),
+        utc_year: year(Foo.time)
+    }").run_to_csv(&data_fixture_over_new_year().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,day_of_month,month_of_year,year,utc_year
+    2022-12-31T23:30:00.000000000,9223372036854775808,3650215962958587783,A,31,12,2022,2022
+    2023-01-01T03:00:00.000000000,9223372036854775808,3650215962958587783,A,31,12,2022,2023
+    2023-01-01T06:00:00.000000000,9223372036854775808,11753611437813598533,B,1,1,2023,2023
+    "###);
+}
+
+#[tokio::test]
+async fn test_since_daily_time_zone_over_dst_start() {
+    // The local midnights are at 05:00 UTC before daylight saving time starts
+    // and 04:00 UTC after, so the inputs at 03:30 UTC on 2023-03-13 are still
+    // in the (23 hour) local day of 2023-03-12.
+    insta::assert_snapshot!(QueryFixture::new("{ n: Foo.n, sum_since: sum(Foo.n, window=since(daily(time_zone = \"America/New_York\"))) }").run_to_csv(&data_fixture_over_dst_start().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,n,sum_since
+    2023-03-11T01:00:00.000000000,9223372036854775808,3650215962958587783,A,1,1
+    2023-03-11T02:00:00.000000000,9223372036854775808,11753611437813598533,B,2,2
+    2023-03-11T05:00:00.000000000,18446744073709551615,3650215962958587783,A,,1
+    2023-03-11T05:00:00.000000000,18446744073709551615,11753611437813598533,B,,2
+    2023-03-11T12:00:00.000000000,9223372036854775808,3650215962958587783,A,3,3
+    2023-03-12T05:00:00.000000000,18446744073709551615,3650215962958587783,A,,3
+    2023-03-12T05:00:00.000000000,18446744073709551615,11753611437813598533,B,,
+    2023-03-12T12:00:00.000000000,9223372036854775808,3650215962958587783,A,4,4
+    2023-03-13T03:30:00.000000000,9223372036854775808,3650215962958587783,A,5,9
+    2023-03-13T04:00:00.000000000,18446744073709551615,3650215962958587783,A,,9
+    2023-03-13T04:00:00.000000000,18446744073709551615,11753611437813598533,B,,
+    2023-03-13T04:30:00.000000000,9223372036854775808,11753611437813598533,B,6,6
+    "###);
+}
+
 #[tokio::test]
 async fn test_add_time_duration_s() {
     insta::assert_snapshot!(QueryFixture::new("{ add_time: Times.time | add_time(seconds(Times.n)) }").run_to_csv(&timestamp_ns_data_fixture().await).await.unwrap(), @r###"
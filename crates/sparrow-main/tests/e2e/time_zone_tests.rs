@@ -0,0 +1,103 @@
+//! e2e tests for timestamp columns with a time zone.
+
+use arrow::array::{StringArray, TimestampMillisecondArray, TimestampNanosecondArray, UInt64Array};
+use sparrow_api::kaskada::v1alpha::TableConfig;
+use uuid::Uuid;
+
+use crate::{fixture::DataFixture, QueryFixture};
+
+/// Create a table with timestamp columns in `America/New_York`.
+///
+/// The `zoned` values span the start of daylight saving time on 2023-03-12.
+/// The `other` values are all 2023-03-12T07:00:00Z (03:00 in New York).
+async fn zoned_data_fixture() -> DataFixture {
+    let table = crate::ParquetTableBuilder::new()
+        .add_column(
+            "time",
+            false,
+            TimestampNanosecondArray::from(vec![
+                1_678_536_000_000_000_000,
+                1_678_600_800_000_000_000,
+                1_678_608_000_000_000_000,
+                1_678_611_600_000_000_000,
+            ]),
+        )
+        .add_column("subsort", false, UInt64Array::from(vec![0, 1, 2, 3]))
+        .add_column("key", false, StringArray::from(vec!["a", "b", "a", "a"]))
+        .add_column(
+            "zoned",
+            true,
+            TimestampMillisecondArray::from(vec![
+                Some(1_678_536_000_000),
+                Some(1_678_602_600_000),
+                Some(1_678_606_200_000),
+                None,
+            ])
+            .with_timezone("America/New_York"),
+        )
+        .add_column(
+            "other",
+            true,
+            TimestampMillisecondArray::from(vec![1_678_604_400_000; 4])
+                .with_timezone("America/New_York"),
+        );
+
+    DataFixture::new()
+        .with_table_from_parquet(
+            TableConfig::new_with_table_source(
+                "Times",
+                &Uuid::new_v4(),
+                "time",
+                Some("subsort"),
+                "key",
+                "",
+            ),
+            table,
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_zoned_timestamp_output_as_utc() {
+    insta::assert_snapshot!(QueryFixture::new("{ zoned: Times.zoned }").run_to_csv(&zoned_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,zoned
+    2023-03-11T12:00:00.000000000,0,7636293598395510443,a,2023-03-11T12:00:00.000000000
+    2023-03-12T06:00:00.000000000,1,2637710838665036908,b,2023-03-12T06:30:00.000000000
+    2023-03-12T08:00:00.000000000,2,7636293598395510443,a,2023-03-12T07:30:00.000000000
+    2023-03-12T09:00:00.000000000,3,7636293598395510443,a,
+    "###);
+}
+
+#[tokio::test]
+async fn test_zoned_timestamp_output_preserving_time_zones() {
+    insta::assert_snapshot!(QueryFixture::new("{ zoned: Times.zoned }").with_preserve_time_zones().run_to_csv(&zoned_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,zoned
+    2023-03-11T12:00:00.000000000,0,7636293598395510443,a,2023-03-11T07:00:00.000000000-05:00
+    2023-03-12T06:00:00.000000000,1,2637710838665036908,b,2023-03-12T01:30:00.000000000-05:00
+    2023-03-12T08:00:00.000000000,2,7636293598395510443,a,2023-03-12T03:30:00.000000000-04:00
+    2023-03-12T09:00:00.000000000,3,7636293598395510443,a,
+    "###);
+}
+
+#[tokio::test]
+async fn test_zoned_timestamp_comparisons() {
+    insta::assert_snapshot!(QueryFixture::new("{ gt: Times.zoned > Times.other, eq: Times.zoned == Times.other, lte: Times.zoned <= Times.other }").run_to_csv(&zoned_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,gt,eq,lte
+    2023-03-11T12:00:00.000000000,0,7636293598395510443,a,false,false,true
+    2023-03-12T06:00:00.000000000,1,2637710838665036908,b,false,false,true
+    2023-03-12T08:00:00.000000000,2,7636293598395510443,a,true,false,false
+    2023-03-12T09:00:00.000000000,3,7636293598395510443,a,,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_zoned_timestamp_aggregations() {
+    insta::assert_snapshot!(QueryFixture::new("{ max: max(Times.zoned), min: Times.zoned | min(), last: last(Times.zoned) }").with_preserve_time_zones().run_to_csv(&zoned_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,max,min,last
+    2023-03-11T12:00:00.000000000,0,7636293598395510443,a,2023-03-11T07:00:00.000000000-05:00,2023-03-11T07:00:00.000000000-05:00,2023-03-11T07:00:00.000000000-05:00
+    2023-03-12T06:00:00.000000000,1,2637710838665036908,b,2023-03-12T01:30:00.000000000-05:00,2023-03-12T01:30:00.000000000-05:00,2023-03-12T01:30:00.000000000-05:00
+    2023-03-12T08:00:00.000000000,2,7636293598395510443,a,2023-03-12T03:30:00.000000000-04:00,2023-03-11T07:00:00.000000000-05:00,2023-03-12T03:30:00.000000000-04:00
+    2023-03-12T09:00:00.000000000,3,7636293598395510443,a,2023-03-12T03:30:00.000000000-04:00,2023-03-11T07:00:00.000000000-05:00,2023-03-12T03:30:00.000000000-04:00
+    "###);
+}
@@ -609,3 +609,40 @@ async fn test_final_sliding_window_constant() {
     1996-12-20T00:40:04.000000001,18446744073709551615,11753611437813598533,B,3.9
     "###);
 }
+
+#[tokio::test]
+async fn test_trailing_window() {
+    // Rows at exactly 3 seconds before the current row are excluded from the window.
+    insta::assert_snapshot!(QueryFixture::new("{ count: count(Foo, window=trailing(seconds(3))), max: max(Foo.n, window=trailing(seconds(3))), last: last(Foo.vegetable, window=trailing(seconds(3))) }").run_to_csv(&window_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,count,max,last
+    1996-12-20T00:39:57.000000000,9223372036854775808,3650215962958587783,A,1,10.0,arugula
+    1996-12-20T00:39:58.000000000,9223372036854775808,11753611437813598533,B,1,3.9,beet
+    1996-12-20T00:39:59.000000000,9223372036854775808,3650215962958587783,A,2,10.0,carrot
+    1996-12-20T00:40:00.000000000,9223372036854775808,3650215962958587783,A,2,9.25,dill
+    1996-12-20T00:40:01.000000000,9223372036854775808,3650215962958587783,A,3,9.25,edamame
+    1996-12-20T00:40:02.000000000,9223372036854775808,3650215962958587783,A,3,9.25,fennel
+    1996-12-20T00:40:03.000000000,9223372036854775808,3650215962958587783,A,3,8.0,green beans
+    1996-12-20T00:40:04.000000000,9223372036854775808,3650215962958587783,A,3,10.0,habanero
+    "###);
+}
+
+#[tokio::test]
+async fn test_trailing_non_positive_duration_produces_diagnostic() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ count: count(Foo, window=trailing(seconds(0)))  }").run_to_csv(&window_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0008
+        message: Invalid arguments
+        formatted:
+          - "error[E0008]: Invalid arguments"
+          - "  --> Query:1:37"
+          - "  |"
+          - "1 | { count: count(Foo, window=trailing(seconds(0)))  }"
+          - "  |                                     ^^^^^^^^^^ Argument 'duration' to 'trailing' must be positive"
+          - ""
+          - ""
+    "###);
+}
@@ -0,0 +1,42 @@
+use std::borrow::Cow;
+
+use arrow_schema::DataType;
+use sparrow_arrow::scalar_value::ScalarValue;
+
+use crate::ExprId;
+
+/// A stateful aggregation computed for each key by an aggregate step.
+///
+/// The arguments of the aggregation, and the conditions of its window, are
+/// computed by the expressions of the step.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct Aggregation {
+    /// The aggregation function being applied, such as `sum` or `ewma`.
+    pub name: Cow<'static, str>,
+    /// Zero or more literal-valued arguments, such as the half-life of `ewma`.
+    pub literal_args: Vec<ScalarValue>,
+    /// Arguments to the aggregation.
+    ///
+    /// These are indices referencing the expressions of the aggregate step.
+    pub args: Vec<ExprId>,
+    /// The window the aggregation is computed over.
+    pub window: Window,
+    /// The type produced by the aggregation.
+    pub result_type: DataType,
+}
+
+/// The window an aggregation is computed over.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Window {
+    /// Aggregate all inputs for the key.
+    Unbounded,
+    /// Aggregate the inputs since the most recent row where the condition
+    /// was true.
+    Since { condition: ExprId },
+    /// Aggregate the inputs within the most recent windows, each of which
+    /// ends at a row where the condition was true.
+    Sliding { condition: ExprId, windows: i64 },
+    /// Aggregate the inputs within the duration before each row.
+    Trailing { duration_ns: i64 },
+}
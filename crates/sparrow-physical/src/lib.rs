@@ -10,10 +10,12 @@
 
 //! Physical execution plans for Kaskada queries.
 
+mod aggregation;
 mod expr;
 mod plan;
 mod step;
 
+pub use aggregation::*;
 pub use expr::*;
 pub use plan::*;
 pub use step::*;
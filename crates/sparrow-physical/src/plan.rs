@@ -1,6 +1,10 @@
+use std::fmt;
+
+use arrow_schema::DataType;
 use index_vec::IndexVec;
+use sparrow_arrow::scalar_value::ScalarValue;
 
-use crate::{Step, StepId};
+use crate::{Exprs, Step, StepId, StepKind};
 
 /// A plan is a directed, acyclic graph of steps.
 ///
@@ -15,6 +19,132 @@ pub struct Plan {
     pub pipelines: Vec<Pipeline>,
 }
 
+/// Renders the plan compactly, for debugging and snapshot tests.
+///
+/// Each step is written on one line, followed by the expressions and
+/// aggregations it computes (indented). The pipelines are written last.
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (id, step) in self.steps.iter_enumerated() {
+            let (kind, exprs) = match &step.kind {
+                StepKind::Scan { table_name } => (format!("scan {table_name}"), None),
+                StepKind::Merge => ("merge".to_owned(), None),
+                StepKind::Project { exprs } => ("project".to_owned(), Some(exprs)),
+                StepKind::Filter { exprs } => ("filter".to_owned(), Some(exprs)),
+                StepKind::Repartition {
+                    num_partitions,
+                    keys,
+                } => (format!("repartition {num_partitions}"), Some(keys)),
+                StepKind::Aggregate { exprs, .. } => ("aggregate".to_owned(), Some(exprs)),
+                StepKind::Tick {
+                    behavior,
+                    time_zone: None,
+                } => (format!("tick {behavior:?}"), None),
+                StepKind::Tick {
+                    behavior,
+                    time_zone: Some(time_zone),
+                } => (format!("tick {behavior:?} in {time_zone}"), None),
+                StepKind::ShiftTo { time } => ("shift_to".to_owned(), Some(time)),
+                StepKind::ShiftUntil { condition } => ("shift_until".to_owned(), Some(condition)),
+                StepKind::LookupRequest { foreign_key_hash } => {
+                    ("lookup_request".to_owned(), Some(foreign_key_hash))
+                }
+                StepKind::LookupResponse {
+                    requesting_key_hash,
+                } => ("lookup_response".to_owned(), Some(requesting_key_hash)),
+                StepKind::Error => ("error".to_owned(), None),
+            };
+
+            write!(f, "{id}: {kind} inputs=[")?;
+            write_separated(f, &step.inputs, |f, input| write!(f, "{input}"))?;
+            write!(f, "] schema=[")?;
+            write_separated(f, step.schema.fields(), |f, field| {
+                write!(f, "{}: {}", field.name(), FormatType(field.data_type()))
+            })?;
+            writeln!(f, "]")?;
+
+            if let Some(exprs) = exprs {
+                write_exprs(f, exprs)?;
+            }
+
+            if let StepKind::Aggregate { aggregations, .. } = &step.kind {
+                for aggregation in aggregations {
+                    write!(f, "  aggregate: {}[", aggregation.name)?;
+                    write_separated(f, &aggregation.literal_args, |f, arg| write!(f, "{arg}"))?;
+                    write!(f, "](")?;
+                    write_separated(f, &aggregation.args, |f, arg| write!(f, "{arg}"))?;
+                    writeln!(
+                        f,
+                        ") {:?} -> {}",
+                        aggregation.window,
+                        FormatType(&aggregation.result_type)
+                    )?;
+                }
+            }
+        }
+
+        write!(f, "pipelines: [")?;
+        write_separated(f, &self.pipelines, |f, pipeline| {
+            write!(f, "[")?;
+            write_separated(f, &pipeline.steps, |f, step| write!(f, "{step}"))?;
+            write!(f, "]")
+        })?;
+        write!(f, "]")
+    }
+}
+
+/// Write each expression on its own (indented) line, followed by the outputs.
+fn write_exprs(f: &mut fmt::Formatter<'_>, exprs: &Exprs) -> fmt::Result {
+    for (expr_id, expr) in exprs.exprs.iter_enumerated() {
+        write!(f, "  {expr_id}: {}[", expr.name)?;
+        write_separated(f, &expr.literal_args, |f, arg| match arg {
+            // Quote strings, so column names and empty strings are legible.
+            ScalarValue::Utf8(Some(value)) => write!(f, "{value:?}"),
+            other => write!(f, "{other}"),
+        })?;
+        write!(f, "](")?;
+        write_separated(f, &expr.args, |f, arg| write!(f, "{arg}"))?;
+        writeln!(f, ") -> {}", FormatType(&expr.result_type))?;
+    }
+    write!(f, "  outputs: [")?;
+    write_separated(f, &exprs.outputs, |f, output| write!(f, "{output}"))?;
+    writeln!(f, "]")
+}
+
+/// Formats data types compactly, showing only the names and types of fields.
+struct FormatType<'a>(&'a DataType);
+
+impl<'a> fmt::Display for FormatType<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            DataType::Struct(fields) => {
+                write!(f, "{{")?;
+                write_separated(f, fields, |f, field| {
+                    write!(f, "{}: {}", field.name(), FormatType(field.data_type()))
+                })?;
+                write!(f, "}}")
+            }
+            DataType::List(field) => write!(f, "List<{}>", FormatType(field.data_type())),
+            other => write!(f, "{other:?}"),
+        }
+    }
+}
+
+/// Write the items separated by `", "`.
+fn write_separated<'a, T: 'a>(
+    f: &mut fmt::Formatter<'_>,
+    items: impl IntoIterator<Item = &'a T>,
+    mut write_item: impl FnMut(&mut fmt::Formatter<'_>, &'a T) -> fmt::Result,
+) -> fmt::Result {
+    for (index, item) in items.into_iter().enumerate() {
+        if index > 0 {
+            write!(f, ", ")?;
+        }
+        write_item(f, item)?;
+    }
+    Ok(())
+}
+
 /// Information about a specific "pipeline" within the plan.
 ///
 /// Pipelines take a single input through a linear sequence of
@@ -1,6 +1,6 @@
 use arrow_schema::SchemaRef;
 
-use crate::Exprs;
+use crate::{Aggregation, Exprs};
 
 index_vec::define_index_type! {
     /// The identifier (index) of a step.
@@ -70,5 +70,151 @@ pub enum StepKind {
         /// Each output corresponds to a part of the key.
         keys: Exprs,
     },
+    /// Compute stateful aggregations for each key.
+    ///
+    /// The output includes the same rows and columns as the input, followed
+    /// by a column for each aggregation containing its value for the key as
+    /// of that row.
+    Aggregate {
+        /// Expressions computing the arguments and window conditions of the
+        /// aggregations from the input.
+        exprs: Exprs,
+        /// The aggregations to compute.
+        ///
+        /// Each aggregation produces one of the fields following the input
+        /// fields in the schema.
+        aggregations: Vec<Aggregation>,
+    },
+    /// Produce a row for each key at the times described by the behavior.
+    ///
+    /// The keys are those seen in the input, and the output contains only
+    /// the tick rows.
+    Tick {
+        behavior: TickBehavior,
+        /// The IANA name of the time zone calendar and cron ticks are computed
+        /// in, such as `America/New_York`. If `None`, ticks are computed in UTC.
+        time_zone: Option<String>,
+    },
+    /// Shift each row of the input to a later time.
+    ShiftTo {
+        /// Expressions to compute the time to shift each row to.
+        ///
+        /// There should be a single output producing a timestamp.
+        time: Exprs,
+    },
+    /// Delay each row of the input until the condition is true for the key.
+    ShiftUntil {
+        /// Expressions to compute the condition.
+        ///
+        /// There should be a single output producing a boolean value.
+        condition: Exprs,
+    },
+    /// Send each row of the input to the partition of the key being looked up.
+    ///
+    /// The key hash of the requesting row is retained so the response can be
+    /// sent back to it.
+    LookupRequest {
+        /// Expressions to compute the hash of the foreign key.
+        ///
+        /// There should be a single output producing the key hash.
+        foreign_key_hash: Exprs,
+    },
+    /// Send the looked up values back to the requesting keys.
+    LookupResponse {
+        /// Expressions to compute the key hash of the requesting rows.
+        ///
+        /// There should be a single output producing the key hash.
+        requesting_key_hash: Exprs,
+    },
     Error,
 }
+
+/// The times at which a tick step produces rows.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TickBehavior {
+    /// Tick once after all other inputs have been processed.
+    Finished,
+    Minutely,
+    Hourly,
+    Daily,
+    Monthly,
+    Yearly,
+    /// Tick at each time `t` such that `t - offset_ns` is a multiple of
+    /// `period_ns`.
+    Every {
+        period_ns: i64,
+        offset_ns: i64,
+    },
+    /// Tick at times matching a cron schedule, such as `0 9 * * MON-FRI`.
+    Cron {
+        schedule: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_schema::DataType;
+    use sparrow_arrow::scalar_value::ScalarValue;
+
+    use super::*;
+    use crate::{Expr, Window};
+
+    #[test]
+    fn test_physical_step_kinds_yaml() {
+        let kinds = vec![
+            StepKind::Aggregate {
+                exprs: Exprs {
+                    exprs: index_vec::index_vec![
+                        Expr {
+                            name: "column".into(),
+                            literal_args: vec![ScalarValue::Utf8(Some("amount".to_owned()))],
+                            args: vec![],
+                            result_type: DataType::Int64,
+                        },
+                        Expr {
+                            name: "column".into(),
+                            literal_args: vec![ScalarValue::Utf8(Some("reset".to_owned()))],
+                            args: vec![],
+                            result_type: DataType::Boolean,
+                        },
+                    ],
+                    outputs: vec![],
+                },
+                aggregations: vec![
+                    Aggregation {
+                        name: "sum".into(),
+                        literal_args: vec![],
+                        args: vec![0.into()],
+                        window: Window::Since {
+                            condition: 1.into(),
+                        },
+                        result_type: DataType::Int64,
+                    },
+                    Aggregation {
+                        name: "count".into(),
+                        literal_args: vec![],
+                        args: vec![0.into()],
+                        window: Window::Unbounded,
+                        result_type: DataType::UInt32,
+                    },
+                ],
+            },
+            StepKind::Tick {
+                behavior: TickBehavior::Daily,
+                time_zone: Some("America/New_York".to_owned()),
+            },
+            StepKind::Tick {
+                behavior: TickBehavior::Every {
+                    period_ns: 1000,
+                    offset_ns: 0,
+                },
+                time_zone: None,
+            },
+        ];
+
+        // Use serde_yaml directly, since insta uses a non-standard Yaml formatter.
+        let yaml = serde_yaml::to_string(&kinds).unwrap();
+        insta::assert_snapshot!(yaml)
+    }
+}
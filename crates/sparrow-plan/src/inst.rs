@@ -54,25 +54,57 @@ pub enum InstOp {
         signature = "add_time<D: timedelta>(delta: D, time: timestamp_ns) -> timestamp_ns"
     ))]
     AddTime,
+    #[strum(props(
+        dfg_signature = "approx_count_distinct<K: key>(input: K, window: window = null) -> u32",
+        plan_signature = "approx_count_distinct<K: key>(input: K, ticks: bool = null, \
+                          slide_duration: i64 = null) -> u32"
+    ))]
+    ApproxCountDistinct,
     #[strum(props(signature = "ceil<N: number>(n: N) -> N"))]
     Ceil,
     #[strum(props(signature = "clamp<N: number>(value: N, min: N = null, max: N = null) -> N"))]
     Clamp,
     #[strum(props(signature = "coalesce<T: any>(values+: T) -> T"))]
     Coalesce,
+    #[strum(props(
+        dfg_signature = "collect<T: any>(input: T, max: i64, window: window = null) -> list<T>",
+        plan_signature = "collect<T: any>(input: T, max: i64, ticks: bool = null, slide_duration: \
+                          i64 = null) -> list<T>"
+    ))]
+    Collect,
+    #[strum(props(signature = "concat(strings+: string) -> string"))]
+    Concat,
+    #[strum(props(signature = "contains(substring: string, s: string) -> bool"))]
+    Contains,
+    #[strum(props(signature = "contains_key<K: key, V: any>(key: K, map: map<K, V>) -> bool"))]
+    ContainsKey,
     #[strum(props(
         dfg_signature = "count_if<T: any>(input: T, window: window = null) -> u32",
         plan_signature = "count_if<T: any>(input: T, ticks: bool = null, slide_duration: i64 = null) -> \
                           u32"
     ))]
     CountIf,
-    #[strum(props(signature = "day_of_month(time: timestamp_ns) -> u32"))]
+    #[strum(props(
+        dfg_signature = "count_distinct<K: key>(input: K, window: window = null) -> u32",
+        plan_signature = "count_distinct<K: key>(input: K, ticks: bool = null, slide_duration: \
+                          i64 = null) -> u32"
+    ))]
+    CountDistinct,
+    #[strum(props(
+        signature = "day_of_month(time: timestamp_ns, const time_zone: string = null) -> u32"
+    ))]
     DayOfMonth,
-    #[strum(props(signature = "day_of_month0(time: timestamp_ns) -> u32"))]
+    #[strum(props(
+        signature = "day_of_month0(time: timestamp_ns, const time_zone: string = null) -> u32"
+    ))]
     DayOfMonth0,
-    #[strum(props(signature = "day_of_year(time: timestamp_ns) -> u32"))]
+    #[strum(props(
+        signature = "day_of_year(time: timestamp_ns, const time_zone: string = null) -> u32"
+    ))]
     DayOfYear,
-    #[strum(props(signature = "day_of_year0(time: timestamp_ns) -> u32"))]
+    #[strum(props(
+        signature = "day_of_year0(time: timestamp_ns, const time_zone: string = null) -> u32"
+    ))]
     DayOfYear0,
     #[strum(props(signature = "days(days: i64) -> interval_days"))]
     Days,
@@ -82,8 +114,22 @@ pub enum InstOp {
     DaysBetween,
     #[strum(props(signature = "div<N: number>(a: N, b: N) -> N"))]
     Div,
+    #[strum(props(signature = "ends_with(suffix: string, s: string) -> bool"))]
+    EndsWith,
     #[strum(props(signature = "eq<T: any>(a: T, b: T) -> bool"))]
     Eq,
+    #[strum(props(
+        dfg_signature = "ewm_variance(input: f64, half_life: i64, window: window = null) -> f64",
+        plan_signature = "ewm_variance(input: f64, half_life: i64, ticks: bool = null, \
+                          slide_duration: i64 = null) -> f64"
+    ))]
+    EwmVariance,
+    #[strum(props(
+        dfg_signature = "ewma(input: f64, half_life: i64, window: window = null) -> f64",
+        plan_signature = "ewma(input: f64, half_life: i64, ticks: bool = null, slide_duration: \
+                          i64 = null) -> f64"
+    ))]
+    Ewma,
     #[strum(props(signature = "exp(power: f64) -> f64"))]
     Exp,
     #[strum(props(
@@ -91,6 +137,8 @@ pub enum InstOp {
         plan_signature = "first<T: any>(input: T, ticks: bool = null, slide_duration: i64 = null) -> T"
     ))]
     First,
+    #[strum(props(signature = "flatten<L: list>(input: list<L>) -> L"))]
+    Flatten,
     #[strum(props(signature = "floor<N: number>(n: N) -> N"))]
     Floor,
     #[strum(props(signature = "get<K: key, V: any>(key: K, map: map<K, V>) -> V"))]
@@ -103,14 +151,21 @@ pub enum InstOp {
     Hash,
     #[strum(props(signature = "if<T: any>(condition: bool, value: T) -> T"))]
     If,
+    #[strum(props(signature = "index<T: any>(i: i64, list: list<T>) -> T"))]
+    Index,
     #[strum(props(signature = "is_valid<T: any>(input: T) -> bool"))]
     IsValid,
     // HACK: This instruction does not show up in the plan/does not have an evaluator.
-    // It is converted to `JsonField` during simplification.
+    // Accessing fields or elements of the `json` is converted to `JsonField` or
+    // `JsonIndex` on the input string.
     #[strum(props(signature = "json(s: string) -> json"))]
     Json,
     #[strum(props(signature = "json_field(s: string, field: string) -> string"))]
     JsonField,
+    #[strum(props(signature = "json_index(s: string, index: i64) -> string"))]
+    JsonIndex,
+    #[strum(props(signature = "keys<K: key, V: any>(map: map<K, V>) -> list<K>"))]
+    Keys,
     #[strum(props(signature = "lag<O: ordered>(n: i64, input: O) -> O"))]
     Lag,
     #[strum(props(
@@ -120,6 +175,12 @@ pub enum InstOp {
     Last,
     #[strum(props(signature = "len(s: string) -> i32"))]
     Len,
+    #[strum(props(signature = "list_contains<T: key>(value: T, list: list<T>) -> bool"))]
+    ListContains,
+    #[strum(props(signature = "list_len<T: any>(input: list<T>) -> i32"))]
+    ListLen,
+    #[strum(props(signature = "list_sum<N: number>(input: list<N>) -> N"))]
+    ListSum,
     #[strum(props(signature = "logical_and(a: bool, b: bool) -> bool"))]
     LogicalAnd,
     #[strum(props(signature = "logical_or(a: bool, b: bool) -> bool"))]
@@ -136,9 +197,9 @@ pub enum InstOp {
     ))]
     Max,
     #[strum(props(
-        dfg_signature = "mean<N: number>(input: N, window: window = null) -> f64",
-        plan_signature = "mean<N: number>(input: N, ticks: bool = null, slide_duration: i64 = null) -> \
-                          f64"
+        dfg_signature = "mean<F: fractional>(input: F, window: window = null) -> F",
+        plan_signature = "mean<F: fractional>(input: F, ticks: bool = null, slide_duration: i64 = \
+                          null) -> F"
     ))]
     Mean,
     #[strum(props(
@@ -146,9 +207,18 @@ pub enum InstOp {
         plan_signature = "min<O: ordered>(input: O, ticks: bool = null, slide_duration: i64 = null) -> O"
     ))]
     Min,
-    #[strum(props(signature = "month_of_year(time: timestamp_ns) -> u32"))]
+    #[strum(props(
+        dfg_signature = "mode<K: key>(input: K, window: window = null) -> K",
+        plan_signature = "mode<K: key>(input: K, ticks: bool = null, slide_duration: i64 = null) -> K"
+    ))]
+    Mode,
+    #[strum(props(
+        signature = "month_of_year(time: timestamp_ns, const time_zone: string = null) -> u32"
+    ))]
     MonthOfYear,
-    #[strum(props(signature = "month_of_year0(time: timestamp_ns) -> u32"))]
+    #[strum(props(
+        signature = "month_of_year0(time: timestamp_ns, const time_zone: string = null) -> u32"
+    ))]
     MonthOfYear0,
     #[strum(props(signature = "months(months: i64) -> interval_months"))]
     Months,
@@ -166,8 +236,26 @@ pub enum InstOp {
     Not,
     #[strum(props(signature = "null_if<T: any>(condition: bool, value: T) -> T"))]
     NullIf,
+    #[strum(props(signature = "pad(length: i64, s: string, fill: string) -> string"))]
+    Pad,
+    #[strum(props(signature = "parse_json<T: any>(s: string, schema: T) -> T"))]
+    ParseJson,
+    #[strum(props(
+        dfg_signature = "percentile(input: f64, p: f64, window: window = null) -> f64",
+        plan_signature = "percentile(input: f64, p: f64, ticks: bool = null, slide_duration: i64 = \
+                          null) -> f64"
+    ))]
+    Percentile,
     #[strum(props(signature = "powf(base: f64, power: f64) -> f64"))]
     Powf,
+    #[strum(props(signature = "regex_extract(pattern: string, s: string, group: i64) -> string"))]
+    RegexExtract,
+    #[strum(props(signature = "regex_match(pattern: string, s: string) -> bool"))]
+    RegexMatch,
+    #[strum(props(
+        signature = "regex_replace(pattern: string, replacement: string, s: string) -> string"
+    ))]
+    RegexReplace,
     #[strum(props(signature = "round<N: number>(n: N) -> N"))]
     Round,
     #[strum(props(signature = "seconds(seconds: i64) -> duration_s"))]
@@ -176,6 +264,10 @@ pub enum InstOp {
         signature = "seconds_between(t1: timestamp_ns, t2: timestamp_ns) -> duration_s"
     ))]
     SecondsBetween,
+    #[strum(props(signature = "split(separator: string, s: string) -> list<string>"))]
+    Split,
+    #[strum(props(signature = "starts_with(prefix: string, s: string) -> bool"))]
+    StartsWith,
     #[strum(props(signature = "sub<N: number>(a: N, b: N) -> N"))]
     Sub,
     #[strum(props(
@@ -190,15 +282,25 @@ pub enum InstOp {
     Sum,
     #[strum(props(signature = "time_of<T: any>(input: T) -> timestamp_ns"))]
     TimeOf,
+    #[strum(props(
+        dfg_signature = "top_k<K: key>(input: K, k: i64, window: window = null) -> list<K>",
+        plan_signature = "top_k<K: key>(input: K, k: i64, ticks: bool = null, slide_duration: i64 \
+                          = null) -> list<K>"
+    ))]
+    TopK,
+    #[strum(props(signature = "trim(s: string) -> string"))]
+    Trim,
     #[strum(props(signature = "upper(s: string) -> string"))]
     Upper,
+    #[strum(props(signature = "values<K: key, V: any>(map: map<K, V>) -> list<V>"))]
+    Values,
     #[strum(props(
         dfg_signature = "variance<N: number>(input: N, window: window = null) -> f64",
         plan_signature = "variance<N: number>(input: N, ticks: bool = null, slide_duration: i64 = null) \
                           -> f64"
     ))]
     Variance,
-    #[strum(props(signature = "year(time: timestamp_ns) -> i32"))]
+    #[strum(props(signature = "year(time: timestamp_ns, const time_zone: string = null) -> i32"))]
     Year,
     #[strum(props(signature = "zip_max<O: ordered>(a: O, b: O) -> O"))]
     ZipMax,
@@ -211,7 +313,21 @@ impl InstOp {
         use InstOp::*;
         matches!(
             self,
-            Sum | Last | First | CountIf | Min | Max | Mean | Variance
+            Sum | Last
+                | First
+                | ApproxCountDistinct
+                | Collect
+                | CountDistinct
+                | CountIf
+                | EwmVariance
+                | Ewma
+                | Min
+                | Max
+                | Mean
+                | Mode
+                | Percentile
+                | TopK
+                | Variance
         )
     }
 
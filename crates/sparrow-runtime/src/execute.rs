@@ -52,6 +52,9 @@ pub async fn execute(
     let destination = request
         .destination
         .ok_or(Error::MissingField("destination"))?;
+    let destinations = std::iter::once(destination)
+        .chain(request.additional_destinations)
+        .collect();
 
     let changed_since_time = request.changed_since.unwrap_or(Timestamp {
         seconds: 0,
@@ -222,6 +225,7 @@ pub async fn execute(
     let runtime_options = RuntimeOptions {
         limits: request.limits.unwrap_or_default(),
         flight_record_path: None,
+        preserve_time_zones: request.preserve_time_zones,
     };
 
     let compute_executor = ComputeExecutor::try_spawn(
@@ -229,7 +233,7 @@ pub async fn execute(
         &late_bindings,
         &runtime_options,
         progress_updates_rx,
-        destination,
+        destinations,
         None,
     )
     .await
@@ -330,6 +334,7 @@ pub async fn materialize(
     let runtime_options = RuntimeOptions {
         limits: Limits::default(),
         flight_record_path: None,
+        preserve_time_zones: false,
     };
 
     let compute_executor = ComputeExecutor::try_spawn(
@@ -337,7 +342,7 @@ pub async fn materialize(
         &late_bindings,
         &runtime_options,
         progress_updates_rx,
-        destination,
+        vec![destination],
         Some(stop_signal_rx),
     )
     .await
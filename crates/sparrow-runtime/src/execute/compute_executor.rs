@@ -55,7 +55,7 @@ impl ComputeExecutor {
         late_bindings: &EnumMap<LateBoundValue, Option<ScalarValue>>,
         runtime_options: &RuntimeOptions,
         progress_updates_rx: tokio::sync::mpsc::Receiver<ProgressUpdate>,
-        destination: v1alpha::Destination,
+        destinations: Vec<v1alpha::Destination>,
         stop_signal_rx: Option<tokio::sync::watch::Receiver<bool>>,
     ) -> error_stack::Result<Self, Error> {
         let mut spawner = ComputeTaskSpawner::new();
@@ -81,13 +81,14 @@ impl ComputeExecutor {
 
         spawner.spawn(
             "output".to_owned(),
-            info_span!("Output Writer", ?destination),
+            info_span!("Output Writer", ?destinations),
             crate::execute::output::write(
                 &context,
                 runtime_options.limits.clone(),
+                runtime_options.preserve_time_zones,
                 futures::StreamExt::boxed(tokio_stream::wrappers::ReceiverStream::new(output_rx)),
                 context.progress_updates_tx.clone(),
-                destination,
+                destinations,
             )
             .change_context(Internal("error writing output"))?
             .map_err(|e| e.change_context(Internal("error writing output"))),
@@ -109,6 +109,8 @@ enum SerializedSpread<'a> {
     UnlatchedFloat32(Boo<'a, UnlatchedPrimitiveSpread<datatypes::Float32Type>>),
     LatchedFloat64(Boo<'a, LatchedPrimitiveSpread<datatypes::Float64Type>>),
     UnlatchedFloat64(Boo<'a, UnlatchedPrimitiveSpread<datatypes::Float64Type>>),
+    LatchedDecimal128(Boo<'a, LatchedPrimitiveSpread<datatypes::Decimal128Type>>),
+    UnlatchedDecimal128(Boo<'a, UnlatchedPrimitiveSpread<datatypes::Decimal128Type>>),
     LatchedTimestampMicrosecond(
         Boo<'a, LatchedPrimitiveSpread<datatypes::TimestampMicrosecondType>>,
     ),
@@ -205,6 +207,8 @@ impl<'a> SerializedSpread<'a> {
             SerializedSpread::UnlatchedFloat32(spread) => into_spread_impl(spread),
             SerializedSpread::LatchedFloat64(spread) => into_spread_impl(spread),
             SerializedSpread::UnlatchedFloat64(spread) => into_spread_impl(spread),
+            SerializedSpread::LatchedDecimal128(spread) => into_spread_impl(spread),
+            SerializedSpread::UnlatchedDecimal128(spread) => into_spread_impl(spread),
             SerializedSpread::LatchedTimestampMicrosecond(spread) => into_spread_impl(spread),
             SerializedSpread::UnlatchedTimestampMicrosecond(spread) => into_spread_impl(spread),
             SerializedSpread::LatchedTimestampMillisecond(spread) => into_spread_impl(spread),
@@ -277,6 +281,7 @@ impl Spread {
             DataType::Float16 => create_primitive::<Float16Type>(latched),
             DataType::Float32 => create_primitive::<Float32Type>(latched),
             DataType::Float64 => create_primitive::<Float64Type>(latched),
+            DataType::Decimal128(_, _) => create_primitive::<Decimal128Type>(latched),
             DataType::Timestamp(TimeUnit::Microsecond, _) => {
                 create_primitive::<TimestampMicrosecondType>(latched)
             }
@@ -528,6 +533,11 @@ impl_to_serialized_spread_primitives!(UnlatchedUInt64, LatchedUInt64, datatypes:
 impl_to_serialized_spread_primitives!(UnlatchedFloat16, LatchedFloat16, datatypes::Float16Type);
 impl_to_serialized_spread_primitives!(UnlatchedFloat32, LatchedFloat32, datatypes::Float32Type);
 impl_to_serialized_spread_primitives!(UnlatchedFloat64, LatchedFloat64, datatypes::Float64Type);
+impl_to_serialized_spread_primitives!(
+    UnlatchedDecimal128,
+    LatchedDecimal128,
+    datatypes::Decimal128Type
+);
 
 impl_to_serialized_spread_primitives!(
     UnlatchedTimestampMicrosecond,
@@ -661,7 +671,9 @@ where
             builder.append_nulls(signal.len() - last_signal_end_exclusive);
         }
 
-        Ok(Arc::new(builder.finish()))
+        // Preserve parameterized types, such as the precision and scale of decimals.
+        let result = builder.finish().with_data_type(values.data_type().clone());
+        Ok(Arc::new(result))
     }
 
     fn spread_true(
@@ -676,11 +688,11 @@ where
     fn spread_false(
         &mut self,
         grouping: &GroupingIndices,
-        _value_type: &DataType,
+        value_type: &DataType,
     ) -> anyhow::Result<ArrayRef> {
         // TODO: It may be faster to call `make_array` directly, but maybe Rust will
         // inline the call to `new_null_array`.
-        Ok(new_null_array(&T::DATA_TYPE, grouping.len()))
+        Ok(new_null_array(value_type, grouping.len()))
     }
 }
 
@@ -738,6 +750,7 @@ where
         }
 
         let values: &PrimitiveArray<T> = downcast_primitive_array(values.as_ref())?;
+        let data_type = values.data_type().clone();
         let mut values = values.iter();
 
         // TODO: Could use "next set bit" operations to more quickly handle
@@ -768,7 +781,7 @@ where
 
         // SAFETY: Primitive iterators have trusted length.
         let result: PrimitiveArray<T> = unsafe { PrimitiveArray::from_trusted_len_iter(result) };
-        let result = Arc::new(result);
+        let result = Arc::new(result.with_data_type(data_type));
         Ok(result)
     }
 
@@ -806,7 +819,7 @@ where
     fn spread_false(
         &mut self,
         grouping: &GroupingIndices,
-        _value_type: &DataType,
+        value_type: &DataType,
     ) -> anyhow::Result<ArrayRef> {
         // Resize the state if needed.
         if grouping.num_groups() > self.values.len() {
@@ -825,7 +838,7 @@ where
 
         // SAFETY: Primitive iterators have trusted length.
         let result: PrimitiveArray<T> = unsafe { PrimitiveArray::from_trusted_len_iter(result) };
-        let result = Arc::new(result);
+        let result = Arc::new(result.with_data_type(value_type.clone()));
 
         Ok(result)
     }
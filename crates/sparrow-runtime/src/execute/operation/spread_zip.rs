@@ -74,6 +74,9 @@ pub(super) fn spread_zip(
         arrow::datatypes::DataType::Float64 => {
             primitive_spread_zip_inefficient::<Float64Type>(mask, truthy, falsy)
         }
+        DataType::Decimal128(_, _) => {
+            primitive_spread_zip_inefficient::<Decimal128Type>(mask, truthy, falsy)
+        }
         DataType::Timestamp(TimeUnit::Microsecond, _) => {
             primitive_spread_zip_inefficient::<TimestampMicrosecondType>(mask, truthy, falsy)
         }
@@ -212,6 +215,7 @@ fn primitive_spread_zip_inefficient<T: ArrowPrimitiveType>(
     truthy: &dyn Array,
     falsy: &dyn Array,
 ) -> anyhow::Result<ArrayRef> {
+    let data_type = truthy.data_type().clone();
     let truthy: &PrimitiveArray<T> = downcast_primitive_array(truthy)?;
     let falsy: &PrimitiveArray<T> = downcast_primitive_array(falsy)?;
     let mut truthy = truthy.iter();
@@ -226,7 +230,7 @@ fn primitive_spread_zip_inefficient<T: ArrowPrimitiveType>(
         };
     }
 
-    Ok(Arc::new(builder.finish()))
+    Ok(Arc::new(builder.finish().with_data_type(data_type)))
 }
 
 fn string_spread_zip(
@@ -17,7 +17,7 @@ use error_stack::{IntoReport, IntoReportCompat, ResultExt};
 use itertools::{izip, Itertools};
 use serde::{Deserialize, Serialize};
 use sparrow_api::kaskada::v1alpha::operation_plan;
-use sparrow_api::kaskada::v1alpha::operation_plan::tick_operation::TickBehavior;
+use sparrow_api::kaskada::v1alpha::operation_plan::tick_operation::{Cron, Every, TickBehavior};
 use sparrow_arrow::downcast::downcast_primitive_array;
 use sparrow_core::KeyTriple;
 use sparrow_instructions::{ComputeStore, GroupingIndices, StoreKey};
@@ -65,6 +65,12 @@ pub(super) struct TickOperation {
     key_hashes: SortedKeyHashMap,
     /// Configures when to tick at.
     behavior: TickBehavior,
+    /// The period of the ticks, for `TickBehavior::Every`.
+    every: Option<Every>,
+    /// The schedule of the ticks, for `TickBehavior::Cron`.
+    cron: Option<Cron>,
+    /// The time zone of calendar ticks, or empty for UTC.
+    time_zone: String,
 }
 
 impl std::fmt::Debug for TickOperation {
@@ -74,6 +80,9 @@ impl std::fmt::Debug for TickOperation {
             .field("current_time", &self.current_time)
             .field("key_hashes", &format!("{} entries", self.key_hashes.len()))
             .field("behavior", &self.behavior)
+            .field("every", &self.every)
+            .field("cron", &self.cron)
+            .field("time_zone", &self.time_zone)
             .finish_non_exhaustive()
     }
 }
@@ -106,15 +115,12 @@ impl Operation for TickOperation {
         self.current_time = state.current_time;
         self.next_tick = state.next_tick;
 
-        let producer: Box<dyn TickProducer> = match self.behavior {
-            TickBehavior::Minutely => Box::new(MinutelyTickProducer),
-            TickBehavior::Hourly => Box::new(HourlyTickProducer),
-            TickBehavior::Daily => Box::new(DailyTickProducer),
-            TickBehavior::Monthly => Box::new(MonthlyTickProducer),
-            TickBehavior::Yearly => Box::new(YearlyTickProducer),
-            TickBehavior::Finished => anyhow::bail!("Final ticks should use separate operation"),
-            unknown => anyhow::bail!("Unknown tick behavior {:?}", unknown),
-        };
+        let producer = tick_producer(
+            self.behavior,
+            self.every.as_ref(),
+            self.cron.as_ref(),
+            &self.time_zone,
+        )?;
 
         // If `next_tick` is 0, we can assume that it has not been initialized yet.
         if self.next_tick.timestamp_nanos() == 0 {
@@ -156,9 +162,15 @@ impl Operation for TickOperation {
         // tick iter with incorrect bounds.
         if self.tick_iter.is_none() {
             if let Some(incoming) = self.input_stream.next().await {
-                let mut tick_iter = initialize_tick_iter(&incoming, self.behavior)
-                    .into_report()
-                    .change_context(Error::internal())?;
+                let mut tick_iter = initialize_tick_iter(
+                    &incoming,
+                    self.behavior,
+                    self.every.as_ref(),
+                    self.cron.as_ref(),
+                    &self.time_zone,
+                )
+                .into_report()
+                .change_context(Error::internal())?;
                 if let Some(next_tick) = tick_iter.next() {
                     self.next_tick = next_tick
                 } else {
@@ -223,6 +235,9 @@ impl TickOperation {
             current_time: 0,
             key_hashes: SortedKeyHashMap::new(),
             behavior: operation.behavior(),
+            every: operation.every,
+            cron: operation.cron,
+            time_zone: operation.time_zone,
         }))
     }
 
@@ -399,16 +414,14 @@ impl TickOperation {
 }
 
 /// Initializes the tick iter using the bounds of the first incoming batch.
-fn initialize_tick_iter(batch: &Batch, tick_behavior: TickBehavior) -> anyhow::Result<TickIter> {
-    let producer: Box<dyn TickProducer> = match tick_behavior {
-        TickBehavior::Minutely => Box::new(MinutelyTickProducer),
-        TickBehavior::Hourly => Box::new(HourlyTickProducer),
-        TickBehavior::Daily => Box::new(DailyTickProducer),
-        TickBehavior::Monthly => Box::new(MonthlyTickProducer),
-        TickBehavior::Yearly => Box::new(YearlyTickProducer),
-        TickBehavior::Finished => anyhow::bail!("Final ticks should use separate operation"),
-        unknown => anyhow::bail!("Unknown tick behavior {:?}", unknown),
-    };
+fn initialize_tick_iter(
+    batch: &Batch,
+    tick_behavior: TickBehavior,
+    every: Option<&Every>,
+    cron: Option<&Cron>,
+    time_zone: &str,
+) -> anyhow::Result<TickIter> {
+    let producer = tick_producer(tick_behavior, every, cron, time_zone)?;
 
     // The tick iter is initialized from the first record batch's minimum time.
     // The tick iter will produces ticks from this time until the time of the last
@@ -547,6 +560,9 @@ mod tests {
                 operation_plan::TickOperation {
                     input: 0,
                     behavior: (TickBehavior::Hourly as i32),
+                    every: None,
+                    cron: None,
+                    time_zone: String::new(),
                 },
             )),
         };
@@ -603,6 +619,9 @@ mod tests {
             current_time: 0,
             key_hashes: SortedKeyHashMap::new(),
             behavior,
+            every: None,
+            cron: None,
+            time_zone: String::new(),
         }
     }
 
@@ -991,6 +1010,9 @@ mod tests {
                 current_time: current1,
                 key_hashes: keys1.clone(),
                 behavior: TickBehavior::Hourly,
+                every: None,
+                cron: None,
+                time_zone: String::new(),
             };
             original_operation.store_to(0, &store).unwrap();
 
@@ -1001,6 +1023,9 @@ mod tests {
                 current_time: 0,
                 key_hashes: SortedKeyHashMap::new(),
                 behavior: TickBehavior::Hourly,
+                every: None,
+                cron: None,
+                time_zone: String::new(),
             };
             restored_operation.restore_from(0, &store).unwrap();
 
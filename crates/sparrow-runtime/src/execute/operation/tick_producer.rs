@@ -1,5 +1,8 @@
 use anyhow::Context;
-use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+use chrono_tz::Tz;
+use sparrow_api::kaskada::v1alpha::operation_plan::tick_operation::{Cron, Every, TickBehavior};
+use sparrow_kernels::time::{parse_time_zone, CronSchedule};
 
 use crate::min_heap::{HasPriority, MinHeap};
 
@@ -232,6 +235,194 @@ impl TickProducer for YearlyTickProducer {
     }
 }
 
+/// Produces ticks at a fixed period, offset from the epoch.
+#[derive(Debug, Clone)]
+pub(super) struct EveryTickProducer {
+    period_ns: i64,
+    offset_ns: i64,
+}
+
+impl EveryTickProducer {
+    pub(super) fn try_new(period_ns: i64, offset_ns: i64) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            period_ns > 0,
+            "Tick period must be positive, but was {period_ns}ns"
+        );
+        Ok(Self {
+            period_ns,
+            offset_ns: offset_ns.rem_euclid(period_ns),
+        })
+    }
+
+    /// Nanoseconds since the most recent tick (inclusive).
+    fn since_tick_ns(&self, time: NaiveDateTime) -> i64 {
+        (time.timestamp_nanos() - self.offset_ns).rem_euclid(self.period_ns)
+    }
+}
+
+fn from_timestamp_nanos(nanos: i64) -> anyhow::Result<NaiveDateTime> {
+    NaiveDateTime::from_timestamp_opt(
+        nanos.div_euclid(1_000_000_000),
+        nanos.rem_euclid(1_000_000_000) as u32,
+    )
+    .context("time not representable")
+}
+
+impl TickProducer for EveryTickProducer {
+    fn is_tick(&self, time: NaiveDateTime) -> bool {
+        self.since_tick_ns(time) == 0
+    }
+
+    fn truncate(&self, time: NaiveDateTime) -> anyhow::Result<NaiveDateTime> {
+        from_timestamp_nanos(time.timestamp_nanos() - self.since_tick_ns(time))
+    }
+
+    fn next_tick(&self, tick: NaiveDateTime) -> anyhow::Result<NaiveDateTime> {
+        debug_assert!(
+            self.is_tick(tick),
+            "Expected time to be aligned to {self:?}, but was: {tick:?}"
+        );
+
+        let next = tick
+            .timestamp_nanos()
+            .checked_add(self.period_ns)
+            .context("next tick not representable")?;
+        from_timestamp_nanos(next)
+    }
+}
+
+/// Produces ticks at times matching a cron schedule.
+#[derive(Debug, Clone)]
+pub(super) struct CronTickProducer {
+    schedule: CronSchedule,
+}
+
+impl CronTickProducer {
+    pub(super) fn try_new(schedule: &str, time_zone: &str) -> anyhow::Result<Self> {
+        let time_zone = (!time_zone.is_empty()).then_some(time_zone);
+        Ok(Self {
+            schedule: CronSchedule::try_new(schedule, time_zone)?,
+        })
+    }
+}
+
+impl TickProducer for CronTickProducer {
+    fn is_tick(&self, time: NaiveDateTime) -> bool {
+        self.schedule.includes(time)
+    }
+
+    fn truncate(&self, time: NaiveDateTime) -> anyhow::Result<NaiveDateTime> {
+        self.schedule
+            .at_or_before(time)
+            .context("no cron tick before time")
+    }
+
+    fn next_tick(&self, tick: NaiveDateTime) -> anyhow::Result<NaiveDateTime> {
+        debug_assert!(
+            self.is_tick(tick),
+            "Expected time to be aligned to {self:?}, but was: {tick:?}"
+        );
+
+        self.schedule.after(tick).context("no cron tick after time")
+    }
+}
+
+/// Places the boundaries of a calendar tick producer in a time zone.
+///
+/// The wrapped producer operates on the local date time in the time zone,
+/// which is converted from and to UTC.
+pub(super) struct ZonedTickProducer {
+    inner: Box<dyn TickProducer>,
+    time_zone: Tz,
+}
+
+impl ZonedTickProducer {
+    pub(super) fn new(inner: Box<dyn TickProducer>, time_zone: Tz) -> Self {
+        Self { inner, time_zone }
+    }
+
+    fn to_local(&self, time: NaiveDateTime) -> NaiveDateTime {
+        self.time_zone.from_utc_datetime(&time).naive_local()
+    }
+
+    fn to_utc(&self, local: NaiveDateTime) -> anyhow::Result<NaiveDateTime> {
+        let utc = self
+            .time_zone
+            .from_local_datetime(&local)
+            .earliest()
+            // If the local time was skipped by a daylight saving transition,
+            // tick at the end of the gap instead.
+            .or_else(|| {
+                self.time_zone
+                    .from_local_datetime(&(local + Duration::hours(1)))
+                    .earliest()
+            })
+            .with_context(|| {
+                format!("local time {local} not representable in {}", self.time_zone)
+            })?;
+        Ok(utc.naive_utc())
+    }
+}
+
+impl TickProducer for ZonedTickProducer {
+    fn is_tick(&self, time: NaiveDateTime) -> bool {
+        self.truncate(time).map_or(false, |tick| tick == time)
+    }
+
+    fn truncate(&self, time: NaiveDateTime) -> anyhow::Result<NaiveDateTime> {
+        self.to_utc(self.inner.truncate(self.to_local(time))?)
+    }
+
+    fn next_tick(&self, tick: NaiveDateTime) -> anyhow::Result<NaiveDateTime> {
+        // The local time may not be a tick of the inner producer if the local
+        // tick was skipped by a daylight saving transition.
+        self.to_utc(self.inner.next(self.to_local(tick))?)
+    }
+}
+
+/// Create the tick producer for the given behavior.
+///
+/// The `every` configuration is required for `TickBehavior::Every`, and the
+/// `cron` configuration is required for `TickBehavior::Cron`. A non-empty
+/// `time_zone` places the boundaries of calendar ticks in that time zone.
+pub(super) fn tick_producer(
+    behavior: TickBehavior,
+    every: Option<&Every>,
+    cron: Option<&Cron>,
+    time_zone: &str,
+) -> anyhow::Result<Box<dyn TickProducer>> {
+    let producer: Box<dyn TickProducer> = match behavior {
+        TickBehavior::Minutely => Box::new(MinutelyTickProducer),
+        TickBehavior::Hourly => Box::new(HourlyTickProducer),
+        TickBehavior::Daily => Box::new(DailyTickProducer),
+        TickBehavior::Monthly => Box::new(MonthlyTickProducer),
+        TickBehavior::Yearly => Box::new(YearlyTickProducer),
+        TickBehavior::Every => {
+            let every = every.context("missing period for every tick")?;
+            Box::new(EveryTickProducer::try_new(
+                every.period_ns,
+                every.offset_ns,
+            )?)
+        }
+        TickBehavior::Cron => {
+            let cron = cron.context("missing schedule for cron tick")?;
+            Box::new(CronTickProducer::try_new(&cron.schedule, &cron.time_zone)?)
+        }
+        TickBehavior::Finished => anyhow::bail!("Final ticks should use separate operation"),
+        unknown => anyhow::bail!("Unknown tick behavior {:?}", unknown),
+    };
+
+    match behavior {
+        TickBehavior::Daily | TickBehavior::Monthly | TickBehavior::Yearly
+            if !time_zone.is_empty() =>
+        {
+            let time_zone = parse_time_zone(time_zone)?;
+            Ok(Box::new(ZonedTickProducer::new(producer, time_zone)))
+        }
+        _ => Ok(producer),
+    }
+}
+
 /// An iterator over the merged times produced by one or more tick producers.
 pub(super) struct TickIter {
     producers: Vec<Box<dyn TickProducer>>,
@@ -886,6 +1077,237 @@ mod tests {
         }
     }
 
+    mod every {
+        use chrono::NaiveDate;
+
+        use super::super::{EveryTickProducer, TickProducer};
+
+        const MINUTE_NS: i64 = 60 * 1_000_000_000;
+
+        #[test]
+        fn test_invalid_period() {
+            assert!(EveryTickProducer::try_new(0, 0).is_err());
+            assert!(EveryTickProducer::try_new(-MINUTE_NS, 0).is_err());
+        }
+
+        #[test]
+        fn test_is_tick() {
+            // Every 15 minutes, offset by 5 minutes.
+            let producer = EveryTickProducer::try_new(15 * MINUTE_NS, 5 * MINUTE_NS).unwrap();
+
+            let date = NaiveDate::from_ymd_opt(2020, 3, 14).unwrap();
+            assert!(producer.is_tick(date.and_hms_opt(2, 5, 0).unwrap()));
+            assert!(producer.is_tick(date.and_hms_opt(2, 50, 0).unwrap()));
+            assert!(!producer.is_tick(date.and_hms_opt(2, 0, 0).unwrap()));
+            assert!(!producer.is_tick(date.and_hms_opt(2, 5, 1).unwrap()));
+        }
+
+        #[test]
+        fn test_negative_offset() {
+            // An offset of -5 minutes is equivalent to an offset of 10 minutes.
+            let producer = EveryTickProducer::try_new(15 * MINUTE_NS, -5 * MINUTE_NS).unwrap();
+
+            let date = NaiveDate::from_ymd_opt(2020, 3, 14).unwrap();
+            assert!(producer.is_tick(date.and_hms_opt(2, 10, 0).unwrap()));
+            assert!(!producer.is_tick(date.and_hms_opt(2, 5, 0).unwrap()));
+        }
+
+        #[test]
+        fn test_truncate() {
+            let producer = EveryTickProducer::try_new(15 * MINUTE_NS, 5 * MINUTE_NS).unwrap();
+
+            let date = NaiveDate::from_ymd_opt(2020, 3, 14).unwrap();
+            assert_eq!(
+                date.and_hms_opt(2, 5, 0).unwrap(),
+                producer
+                    .truncate(date.and_hms_opt(2, 19, 59).unwrap())
+                    .unwrap()
+            );
+            assert_eq!(
+                date.and_hms_opt(1, 50, 0).unwrap(),
+                producer
+                    .truncate(date.and_hms_opt(2, 4, 0).unwrap())
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn test_truncate_before_epoch() {
+            let producer = EveryTickProducer::try_new(15 * MINUTE_NS, 0).unwrap();
+
+            let date = NaiveDate::from_ymd_opt(1969, 12, 31).unwrap();
+            assert_eq!(
+                date.and_hms_opt(23, 45, 0).unwrap(),
+                producer
+                    .truncate(date.and_hms_opt(23, 50, 0).unwrap())
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn test_next() {
+            let producer = EveryTickProducer::try_new(15 * MINUTE_NS, 5 * MINUTE_NS).unwrap();
+
+            let date = NaiveDate::from_ymd_opt(2020, 3, 14).unwrap();
+            assert_eq!(
+                date.and_hms_opt(2, 20, 0).unwrap(),
+                producer.next(date.and_hms_opt(2, 5, 0).unwrap()).unwrap()
+            );
+            assert_eq!(
+                date.and_hms_opt(2, 5, 0).unwrap(),
+                producer.next(date.and_hms_opt(1, 59, 0).unwrap()).unwrap()
+            );
+            assert_eq!(
+                date.succ_opt().unwrap().and_hms_opt(0, 5, 0).unwrap(),
+                producer.next(date.and_hms_opt(23, 50, 0).unwrap()).unwrap()
+            );
+        }
+    }
+
+    mod cron {
+        use chrono::NaiveDate;
+
+        use super::super::{CronTickProducer, TickProducer};
+
+        #[test]
+        fn test_invalid_schedule() {
+            assert!(CronTickProducer::try_new("every day", "").is_err());
+            assert!(CronTickProducer::try_new("0 9 * * *", "Nowhere").is_err());
+        }
+
+        #[test]
+        fn test_is_tick() {
+            let producer = CronTickProducer::try_new("0 9 * * MON-FRI", "").unwrap();
+
+            // 2020-03-13 is a Friday.
+            let friday = NaiveDate::from_ymd_opt(2020, 3, 13).unwrap();
+            let saturday = NaiveDate::from_ymd_opt(2020, 3, 14).unwrap();
+            assert!(producer.is_tick(friday.and_hms_opt(9, 0, 0).unwrap()));
+            assert!(!producer.is_tick(friday.and_hms_opt(10, 0, 0).unwrap()));
+            assert!(!producer.is_tick(saturday.and_hms_opt(9, 0, 0).unwrap()));
+        }
+
+        #[test]
+        fn test_next() {
+            let producer = CronTickProducer::try_new("0 9 * * MON-FRI", "").unwrap();
+
+            let friday = NaiveDate::from_ymd_opt(2020, 3, 13).unwrap();
+            let monday = NaiveDate::from_ymd_opt(2020, 3, 16).unwrap();
+            assert_eq!(
+                friday.and_hms_opt(9, 0, 0).unwrap(),
+                producer.next(friday.and_hms_opt(8, 0, 0).unwrap()).unwrap()
+            );
+            assert_eq!(
+                monday.and_hms_opt(9, 0, 0).unwrap(),
+                producer.next(friday.and_hms_opt(9, 0, 0).unwrap()).unwrap()
+            );
+            assert_eq!(
+                monday.and_hms_opt(9, 0, 0).unwrap(),
+                producer
+                    .next(friday.and_hms_opt(23, 0, 0).unwrap())
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn test_time_zone() {
+            // 9am in Los Angeles is 16:00 UTC during daylight saving time.
+            let producer =
+                CronTickProducer::try_new("0 9 * * MON-FRI", "America/Los_Angeles").unwrap();
+
+            let friday = NaiveDate::from_ymd_opt(2020, 3, 13).unwrap();
+            assert!(producer.is_tick(friday.and_hms_opt(16, 0, 0).unwrap()));
+            assert_eq!(
+                friday.and_hms_opt(16, 0, 0).unwrap(),
+                producer
+                    .truncate(friday.and_hms_opt(20, 0, 0).unwrap())
+                    .unwrap()
+            );
+        }
+    }
+
+    mod zoned {
+        use chrono::NaiveDate;
+        use chrono_tz::Tz;
+
+        use super::super::{
+            DailyTickProducer, MonthlyTickProducer, TickProducer, ZonedTickProducer,
+        };
+
+        #[test]
+        fn test_daily_is_tick() {
+            let producer =
+                ZonedTickProducer::new(Box::new(DailyTickProducer), Tz::America__New_York);
+
+            // Midnight in New York is 04:00 UTC during daylight saving time and
+            // 05:00 UTC otherwise.
+            let summer = NaiveDate::from_ymd_opt(2020, 7, 14).unwrap();
+            let winter = NaiveDate::from_ymd_opt(2020, 1, 14).unwrap();
+            assert!(producer.is_tick(summer.and_hms_opt(4, 0, 0).unwrap()));
+            assert!(!producer.is_tick(summer.and_hms_opt(0, 0, 0).unwrap()));
+            assert!(producer.is_tick(winter.and_hms_opt(5, 0, 0).unwrap()));
+            assert!(!producer.is_tick(winter.and_hms_opt(4, 0, 0).unwrap()));
+        }
+
+        #[test]
+        fn test_daily_truncate() {
+            let producer =
+                ZonedTickProducer::new(Box::new(DailyTickProducer), Tz::America__New_York);
+
+            let date = NaiveDate::from_ymd_opt(2020, 7, 14).unwrap();
+            assert_eq!(
+                date.and_hms_opt(4, 0, 0).unwrap(),
+                producer
+                    .truncate(date.and_hms_opt(23, 0, 0).unwrap())
+                    .unwrap()
+            );
+            // 02:00 UTC is still the previous day in New York.
+            assert_eq!(
+                date.pred_opt().unwrap().and_hms_opt(4, 0, 0).unwrap(),
+                producer
+                    .truncate(date.and_hms_opt(2, 0, 0).unwrap())
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn test_daily_next_across_transition() {
+            let producer =
+                ZonedTickProducer::new(Box::new(DailyTickProducer), Tz::America__New_York);
+
+            // Daylight saving time started on 2020-03-08.
+            let before = NaiveDate::from_ymd_opt(2020, 3, 8).unwrap();
+            let after = NaiveDate::from_ymd_opt(2020, 3, 9).unwrap();
+            assert_eq!(
+                after.and_hms_opt(4, 0, 0).unwrap(),
+                producer
+                    .next_tick(before.and_hms_opt(5, 0, 0).unwrap())
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn test_monthly_next() {
+            let producer = ZonedTickProducer::new(Box::new(MonthlyTickProducer), Tz::Asia__Tokyo);
+
+            // Midnight in Tokyo is 15:00 UTC on the previous day.
+            assert_eq!(
+                NaiveDate::from_ymd_opt(2020, 3, 31)
+                    .unwrap()
+                    .and_hms_opt(15, 0, 0)
+                    .unwrap(),
+                producer
+                    .next(
+                        NaiveDate::from_ymd_opt(2020, 3, 14)
+                            .unwrap()
+                            .and_hms_opt(0, 0, 0)
+                            .unwrap()
+                    )
+                    .unwrap()
+            );
+        }
+    }
+
     #[test]
     fn test_tick_iter_daily() {
         let times: Vec<_> = TickIter::try_new(
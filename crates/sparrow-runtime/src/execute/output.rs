@@ -5,6 +5,7 @@ use arrow::array::UInt64Array;
 use arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
 use arrow::record_batch::RecordBatch;
 use error_stack::{FutureExt as ESFutureExt, IntoReport, Result, ResultExt};
+use futures::future::BoxFuture;
 use futures::stream::BoxStream;
 use futures::{FutureExt, StreamExt};
 use itertools::Itertools;
@@ -12,6 +13,7 @@ use sparrow_api::kaskada::v1alpha::destination::Destination;
 use sparrow_api::kaskada::v1alpha::execute_request::Limits;
 use sparrow_api::kaskada::v1alpha::{self, data_type};
 use sparrow_arrow::downcast::{downcast_primitive_array, downcast_struct_array};
+use sparrow_arrow::utils::cast_timestamp;
 
 use crate::execute::key_hash_inverse::ThreadSafeKeyHashInverse;
 use crate::execute::operation::OperationContext;
@@ -28,10 +30,17 @@ pub enum Error {
     Schema {
         detail: String,
     },
+    #[display(fmt = "writing to destination {index} ({dest_name})")]
     WritingToDestination {
+        index: usize,
         dest_name: String,
     },
     UnspecifiedDestination,
+    #[display(fmt = "converting output column '{column}' to {data_type:?}")]
+    ConvertingColumn {
+        column: String,
+        data_type: DataType,
+    },
     #[cfg(not(feature = "pulsar"))]
     FeatureNotEnabled {
         feature: String,
@@ -40,19 +49,34 @@ pub enum Error {
 
 impl error_stack::Context for Error {}
 
-/// Write the batches to the given output destination.
+/// Number of batches buffered for each destination when writing to
+/// multiple destinations.
+const DESTINATION_BUFFER_SIZE: usize = 4;
+
+/// Write the batches to the given output destinations.
+///
+/// The first destination is the primary destination of the query. When
+/// there are multiple destinations, each post-processed batch is sent to
+/// every destination. A failure writing to one destination doesn't stop
+/// the others from completing.
 pub(super) fn write(
     context: &OperationContext,
     limits: Limits,
+    preserve_time_zones: bool,
     batches: BoxStream<'static, Batch>,
     progress_updates_tx: tokio::sync::mpsc::Sender<ProgressUpdate>,
-    destination: v1alpha::Destination,
+    destinations: Vec<v1alpha::Destination>,
 ) -> error_stack::Result<impl Future<Output = Result<(), Error>> + 'static, Error> {
-    let sink_schema = determine_output_schema(context)?;
+    error_stack::ensure!(!destinations.is_empty(), Error::UnspecifiedDestination);
+    let sink_schema = determine_output_schema(context, preserve_time_zones)?;
 
     // Clone things that need to move into the async stream.
     let sink_schema_clone = sink_schema.clone();
     let key_hash_inverse = context.key_hash_inverse.clone();
+
+    // A batch that fails to post-process ends the output early. The error is
+    // reported once the destinations have finished writing the prior batches.
+    let (post_process_error_tx, post_process_error_rx) = tokio::sync::oneshot::channel();
     let batches = async_stream::stream! {
         // Move / copy into the stream.
         let sink_schema = sink_schema_clone;
@@ -74,7 +98,14 @@ pub(super) fn write(
                 batch
             };
 
-            yield post_process_batch(&sink_schema, batch, &key_hash_inverse).await;
+            match post_process_batch(&sink_schema, batch, &key_hash_inverse).await {
+                Ok(batch) => yield batch,
+                Err(e) => {
+                    // The receiver is only dropped once writing is done.
+                    let _ = post_process_error_tx.send(e);
+                    break;
+                }
+            }
 
             if limit_rows && remaining == 0 {
                 break;
@@ -83,6 +114,124 @@ pub(super) fn write(
     }
     .boxed();
 
+    let writing = write_to_destinations(
+        context,
+        destinations,
+        sink_schema,
+        progress_updates_tx,
+        batches,
+    )?;
+    Ok(async move {
+        let result = writing.await;
+        match post_process_error_rx.await {
+            Ok(error) => Err(error),
+            Err(_) => result,
+        }
+    })
+}
+
+/// Write the post-processed batches to each of the destinations.
+fn write_to_destinations(
+    context: &OperationContext,
+    destinations: Vec<v1alpha::Destination>,
+    sink_schema: SchemaRef,
+    progress_updates_tx: tokio::sync::mpsc::Sender<ProgressUpdate>,
+    batches: BoxStream<'static, RecordBatch>,
+) -> error_stack::Result<BoxFuture<'static, Result<(), Error>>, Error> {
+    if destinations.len() == 1 {
+        let destination = destinations.into_iter().next().expect("one destination");
+        return write_to_destination(
+            context,
+            0,
+            destination,
+            sink_schema,
+            progress_updates_tx,
+            batches,
+        );
+    }
+
+    let mut senders = Vec::with_capacity(destinations.len());
+    let mut writers = Vec::with_capacity(destinations.len());
+    for (index, destination) in destinations.into_iter().enumerate() {
+        let (tx, rx) = tokio::sync::mpsc::channel(DESTINATION_BUFFER_SIZE);
+        writers.push(write_to_destination(
+            context,
+            index,
+            destination,
+            sink_schema.clone(),
+            progress_updates_tx.clone(),
+            tokio_stream::wrappers::ReceiverStream::new(rx).boxed(),
+        )?);
+        senders.push(tx);
+    }
+
+    Ok(async move {
+        let (_, results) = futures::future::join(
+            broadcast(batches, senders),
+            futures::future::join_all(writers),
+        )
+        .await;
+        results
+            .into_iter()
+            .filter_map(|result| result.err())
+            .reduce(|mut errors, error| {
+                errors.extend_one(error);
+                errors
+            })
+            .map_or(Ok(()), Err)
+    }
+    .boxed())
+}
+
+/// Send each batch to every destination, returning the number of rows sent to
+/// each destination.
+///
+/// Each destination receives batches through a channel holding at most
+/// [DESTINATION_BUFFER_SIZE] batches, so a slow destination applies
+/// backpressure rather than buffering the output. Writers that have stopped
+/// (due to an error) close their receiver. They receive no further batches,
+/// but the other destinations continue.
+async fn broadcast(
+    mut batches: BoxStream<'static, RecordBatch>,
+    senders: Vec<tokio::sync::mpsc::Sender<RecordBatch>>,
+) -> Vec<usize> {
+    let mut rows = vec![0; senders.len()];
+    let mut senders: Vec<_> = senders.into_iter().enumerate().collect();
+    while let Some(batch) = batches.next().await {
+        let mut open = Vec::with_capacity(senders.len());
+        for (index, sender) in senders {
+            if sender.send(batch.clone()).await.is_ok() {
+                rows[index] += batch.num_rows();
+                open.push((index, sender));
+            } else {
+                tracing::warn!(
+                    "Destination {index} stopped after receiving {} rows",
+                    rows[index]
+                );
+            }
+        }
+        senders = open;
+
+        if senders.is_empty() {
+            break;
+        }
+    }
+
+    // Dropping the senders signals the end of input to the writers.
+    rows
+}
+
+/// Write the batches to a single output destination.
+///
+/// The `index` identifies the destination in progress updates.
+fn write_to_destination(
+    context: &OperationContext,
+    index: usize,
+    destination: v1alpha::Destination,
+    sink_schema: SchemaRef,
+    progress_updates_tx: tokio::sync::mpsc::Sender<ProgressUpdate>,
+    batches: BoxStream<'static, RecordBatch>,
+) -> error_stack::Result<BoxFuture<'static, Result<(), Error>>, Error> {
     let destination = destination
         .destination
         .ok_or(Error::UnspecifiedDestination)?;
@@ -91,17 +240,20 @@ pub(super) fn write(
             context.object_stores.clone(),
             destination,
             sink_schema,
+            index,
             progress_updates_tx,
             batches,
         )
         .change_context(Error::WritingToDestination {
+            index,
             dest_name: "object_store".to_owned(),
         })
         .boxed()),
         Destination::Redis(redis) => {
             Ok(
-                redis::write(redis, sink_schema, progress_updates_tx, batches)
+                redis::write(redis, sink_schema, index, progress_updates_tx, batches)
                     .change_context(Error::WritingToDestination {
+                        index,
                         dest_name: "redis".to_owned(),
                     })
                     .boxed(),
@@ -116,8 +268,9 @@ pub(super) fn write(
         #[cfg(feature = "pulsar")]
         Destination::Pulsar(pulsar) => {
             Ok(
-                pulsar::write(pulsar, sink_schema, progress_updates_tx, batches)
+                pulsar::write(pulsar, sink_schema, index, progress_updates_tx, batches)
                     .change_context(Error::WritingToDestination {
+                        index,
                         dest_name: "pulsar".to_owned(),
                     })
                     .boxed(),
@@ -131,7 +284,7 @@ async fn post_process_batch(
     sink_schema: &SchemaRef,
     batch: Batch,
     key_hash_inverse: &Arc<ThreadSafeKeyHashInverse>,
-) -> RecordBatch {
+) -> Result<RecordBatch, Error> {
     // TODO: Move this into the writer once it's standard.
     // TODO: Support a single output column?
     // Unpack the one struct column into the corresponding fields.
@@ -164,7 +317,19 @@ async fn post_process_batch(
         fields.extend_from_slice(struct_array.columns());
     }
 
-    RecordBatch::try_new(sink_schema.clone(), fields).expect("resulting batch is valid")
+    // Zoned timestamps that aren't preserved in the output are written as UTC.
+    for (column, field) in fields.iter_mut().zip(sink_schema.fields()).skip(4) {
+        if column.data_type() != field.data_type() {
+            *column = cast_timestamp(column, field.data_type())
+                .into_report()
+                .change_context_lazy(|| Error::ConvertingColumn {
+                    column: field.name().to_owned(),
+                    data_type: field.data_type().clone(),
+                })?;
+        }
+    }
+
+    Ok(RecordBatch::try_new(sink_schema.clone(), fields).expect("resulting batch is valid"))
 }
 
 /// Determine the output schema.
@@ -174,7 +339,13 @@ async fn post_process_batch(
 /// This uses the `key_hash_inverse
 /// This currently requires knowledge of how we will post-process output batches
 /// (by adding the key column back).
-fn determine_output_schema(context: &OperationContext) -> Result<SchemaRef, Error> {
+///
+/// Unless `preserve_time_zones` is set, zoned timestamp columns are output
+/// as UTC timestamps without a time zone.
+fn determine_output_schema(
+    context: &OperationContext,
+    preserve_time_zones: bool,
+) -> Result<SchemaRef, Error> {
     let key_type = context.key_hash_inverse.key_type.clone();
 
     // There should be cleaner ways to determine the output schema.
@@ -213,21 +384,24 @@ fn determine_output_schema(context: &OperationContext) -> Result<SchemaRef, Erro
         ]);
 
         for field in data_fields.fields.iter() {
-            fields.push(Field::new(
-                &field.name,
-                field
-                    .data_type
-                    .as_ref()
-                    .ok_or(Error::Schema {
-                        detail: format!("missing data_type for field {:?}", &field),
-                    })?
-                    .try_into()
-                    .into_report()
-                    .change_context(Error::Schema {
-                        detail: "unable to convert Protobuf DataType to Arrow DataType".to_owned(),
-                    })?,
-                true,
-            ));
+            let data_type: DataType = field
+                .data_type
+                .as_ref()
+                .ok_or(Error::Schema {
+                    detail: format!("missing data_type for field {:?}", &field),
+                })?
+                .try_into()
+                .into_report()
+                .change_context(Error::Schema {
+                    detail: "unable to convert Protobuf DataType to Arrow DataType".to_owned(),
+                })?;
+            let data_type = match data_type {
+                DataType::Timestamp(unit, Some(_)) if !preserve_time_zones => {
+                    DataType::Timestamp(unit, None)
+                }
+                data_type => data_type,
+            };
+            fields.push(Field::new(&field.name, data_type, true));
         }
 
         Ok(Arc::new(Schema::new(fields)))
@@ -237,3 +411,45 @@ fn determine_output_schema(context: &OperationContext) -> Result<SchemaRef, Erro
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::Int64Array;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_broadcast_backpressure() {
+        let schema = Arc::new(Schema::new(vec![Field::new("n", DataType::Int64, true)]));
+        let batches: Vec<_> = (0..10)
+            .map(|n| {
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(Int64Array::from(vec![n]))])
+                    .unwrap()
+            })
+            .collect();
+
+        let (fast_tx, mut fast_rx) = tokio::sync::mpsc::channel(DESTINATION_BUFFER_SIZE);
+        let (slow_tx, slow_rx) = tokio::sync::mpsc::channel(DESTINATION_BUFFER_SIZE);
+        let broadcast = tokio::spawn(broadcast(
+            futures::stream::iter(batches).boxed(),
+            vec![fast_tx, slow_tx],
+        ));
+        let fast = tokio::spawn(async move {
+            let mut received = 0;
+            while fast_rx.recv().await.is_some() {
+                received += 1;
+            }
+            received
+        });
+
+        // The slow destination isn't reading, so sending blocks once its buffer
+        // is full, even though the fast destination is keeping up.
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert!(!broadcast.is_finished());
+
+        // Once the slow destination stops, the others receive the remaining batches.
+        drop(slow_rx);
+        assert_eq!(broadcast.await.unwrap(), vec![10, DESTINATION_BUFFER_SIZE]);
+        assert_eq!(fast.await.unwrap(), 10);
+    }
+}
@@ -48,6 +48,19 @@ enum WriterState {
         url: ObjectStoreUrl,
         writer: Box<dyn AsyncWrite + Send + Unpin>,
     },
+    Jsonl {
+        url: ObjectStoreUrl,
+        writer: Box<dyn AsyncWrite + Send + Unpin>,
+    },
+    ArrowIpc {
+        url: ObjectStoreUrl,
+        /// The IPC writer, which encodes into an in-memory buffer.
+        ///
+        /// After each batch the encoded bytes are drained from the buffer and
+        /// written to the object store.
+        ipc_writer: arrow::ipc::writer::FileWriter<Vec<u8>>,
+        writer: Box<dyn AsyncWrite + Send + Unpin>,
+    },
 }
 
 impl WriterState {
@@ -63,6 +76,8 @@ impl WriterState {
             FileType::Unspecified => error_stack::bail!(Error::UnspecifiedFileType),
             FileType::Parquet => "parquet",
             FileType::Csv => "csv",
+            FileType::Jsonl => "jsonl",
+            FileType::ArrowIpc => "arrow",
         };
         let url = output_prefix
             .join(&format!(
@@ -110,6 +125,17 @@ impl WriterState {
                 // Then return the state
                 Ok(Self::Csv { url, writer })
             }
+            FileType::Jsonl => Ok(Self::Jsonl { url, writer }),
+            FileType::ArrowIpc => {
+                let ipc_writer = arrow::ipc::writer::FileWriter::try_new(Vec::new(), &schema)
+                    .into_report()
+                    .change_context(Error::Write)?;
+                Ok(Self::ArrowIpc {
+                    url,
+                    ipc_writer,
+                    writer,
+                })
+            }
         }
     }
 
@@ -150,6 +176,46 @@ impl WriterState {
                     .change_context(Error::Write)?;
                 Ok(())
             }
+            WriterState::Jsonl { writer, .. } => {
+                // As with CSV, each batch is serialized to a buffer and then
+                // written to the object store.
+                let mut buffer = Vec::new();
+                {
+                    let mut json_writer = arrow::json::LineDelimitedWriter::new(&mut buffer);
+                    json_writer
+                        .write(&batch)
+                        .into_report()
+                        .change_context(Error::Write)?;
+                    json_writer
+                        .finish()
+                        .into_report()
+                        .change_context(Error::Write)?;
+                }
+                writer
+                    .write_all(&buffer)
+                    .await
+                    .into_report()
+                    .change_context(Error::Write)?;
+                Ok(())
+            }
+            WriterState::ArrowIpc {
+                ipc_writer, writer, ..
+            } => {
+                ipc_writer
+                    .write(&batch)
+                    .into_report()
+                    .change_context(Error::Write)?;
+                // Upload the bytes encoded so far. Bytes still held in the IPC
+                // writer's internal buffer are uploaded with a later batch or
+                // when the file is closed.
+                let buffer = std::mem::take(ipc_writer.get_mut());
+                writer
+                    .write_all(&buffer)
+                    .await
+                    .into_report()
+                    .change_context(Error::Write)?;
+                Ok(())
+            }
         }
     }
 
@@ -165,7 +231,32 @@ impl WriterState {
             }
             WriterState::Csv {
                 url, mut writer, ..
+            }
+            | WriterState::Jsonl {
+                url, mut writer, ..
+            } => {
+                writer
+                    .shutdown()
+                    .await
+                    .into_report()
+                    .change_context(Error::Write)?;
+                Ok(url)
+            }
+            WriterState::ArrowIpc {
+                url,
+                ipc_writer,
+                mut writer,
             } => {
+                // Finishing the IPC writer writes the footer.
+                let buffer = ipc_writer
+                    .into_inner()
+                    .into_report()
+                    .change_context(Error::Write)?;
+                writer
+                    .write_all(&buffer)
+                    .await
+                    .into_report()
+                    .change_context(Error::Write)?;
                 writer
                     .shutdown()
                     .await
@@ -182,12 +273,14 @@ pub(super) async fn write(
     object_stores: Arc<ObjectStoreRegistry>,
     destination: ObjectStoreDestination,
     schema: SchemaRef,
+    index: usize,
     progress_updates_tx: tokio::sync::mpsc::Sender<ProgressUpdate>,
     mut batches: BoxStream<'static, RecordBatch>,
 ) -> error_stack::Result<(), Error> {
     // Inform tracker of destination type
     progress_updates_tx
         .send(ProgressUpdate::Destination {
+            index,
             destination: Destination::ObjectStore(destination.clone()),
         })
         .await
@@ -251,7 +344,7 @@ pub(super) async fn write(
             state.as_mut().expect("opened above").write(batch).await?;
             num_rows_in_file += num_rows;
             progress_updates_tx
-                .try_send(ProgressUpdate::Output { num_rows })
+                .try_send(ProgressUpdate::Output { index, num_rows })
                 .into_report()
                 .change_context(Error::ProgressUpdate)?;
         }
@@ -262,7 +355,10 @@ pub(super) async fn write(
 
             tracing::info!("Wrote {num_rows_in_file} rows to file {url}");
             progress_updates_tx
-                .try_send(ProgressUpdate::FilesProduced { paths: vec![url] })
+                .try_send(ProgressUpdate::FilesProduced {
+                    index,
+                    paths: vec![url],
+                })
                 .into_report()
                 .change_context(Error::ProgressUpdate)?;
 
@@ -277,7 +373,10 @@ pub(super) async fn write(
 
         tracing::info!("Wrote {num_rows_in_file} to {url}");
         progress_updates_tx
-            .try_send(ProgressUpdate::FilesProduced { paths: vec![url] })
+            .try_send(ProgressUpdate::FilesProduced {
+                index,
+                paths: vec![url],
+            })
             .into_report()
             .change_context(Error::ProgressUpdate)?;
     }
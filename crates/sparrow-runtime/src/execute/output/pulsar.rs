@@ -60,6 +60,7 @@ const BATCH_SIZE: u32 = 1000;
 pub(super) async fn write(
     pulsar: PulsarDestination,
     schema: SchemaRef,
+    index: usize,
     progress_updates_tx: tokio::sync::mpsc::Sender<ProgressUpdate>,
     mut batches: BoxStream<'static, RecordBatch>,
 ) -> error_stack::Result<(), Error> {
@@ -91,6 +92,7 @@ pub(super) async fn write(
     // Inform tracker of output type
     progress_updates_tx
         .send(ProgressUpdate::Destination {
+            index,
             destination: destination::Destination::Pulsar(PulsarDestination {
                 config: Some(pulsar.clone()),
             }),
@@ -162,7 +164,7 @@ pub(super) async fn write(
             .change_context(Error::SendingMessage)?;
 
         progress_updates_tx
-            .send(ProgressUpdate::Output { num_rows })
+            .send(ProgressUpdate::Output { index, num_rows })
             .await
             .into_report()
             .change_context(Error::ProgressUpdate)?;
@@ -1,21 +1,475 @@
-use crate::execute::progress_reporter::ProgressUpdate;
+use arrow::array::Array;
 use arrow::datatypes::SchemaRef;
 use arrow::record_batch::RecordBatch;
-use error_stack::Result;
+use arrow::util::display::{ArrayFormatter, FormatOptions};
+use error_stack::{IntoReport, ResultExt};
 use futures::stream::BoxStream;
+use futures::StreamExt;
+use hashbrown::HashMap;
+use redis::{ConnectionAddr, ConnectionInfo, RedisConnectionInfo};
+use sparrow_api::kaskada::v1alpha::destination::Destination;
 use sparrow_api::kaskada::v1alpha::RedisDestination;
 
+use crate::execute::progress_reporter::ProgressUpdate;
+
+/// The default port used by Redis.
+const DEFAULT_REDIS_PORT: u16 = 6379;
+
+/// Index of the `_time` column in output batches.
+const TIME_COLUMN: usize = 0;
+
+/// Index of the `_key` column in output batches.
+const KEY_COLUMN: usize = 3;
+
+/// Index of the first result column in output batches.
+const FIRST_VALUE_COLUMN: usize = 4;
+
 #[derive(derive_more::Display, Debug)]
-#[display(fmt = "Redis Destination is unsupported")]
-pub struct Error;
+pub enum Error {
+    #[display(fmt = "failed to report output progress")]
+    ProgressUpdate,
+    #[display(fmt = "invalid redis destination: {_0}")]
+    InvalidDestination(&'static str),
+    #[display(fmt = "failed to connect to redis")]
+    Connect,
+    #[display(fmt = "failed to format output row")]
+    Format,
+    #[display(fmt = "error writing output to redis")]
+    Write,
+}
 
 impl error_stack::Context for Error {}
 
+/// Write the latest value for each entity to Redis.
+///
+/// Each entity is stored as a Redis hash keyed by the (inverted) entity key.
+/// The hash contains the `_time` of the latest row as well as a field for
+/// each non-null result column. Rows for the same entity overwrite the
+/// previous hash, so after the query completes Redis contains the latest
+/// result for every entity.
 pub(super) async fn write(
-    _redis: RedisDestination,
-    _schema: SchemaRef,
-    _progress_updates_tx: tokio::sync::mpsc::Sender<ProgressUpdate>,
-    _batches: BoxStream<'static, RecordBatch>,
-) -> Result<(), Error> {
-    error_stack::bail!(Error);
+    redis: RedisDestination,
+    schema: SchemaRef,
+    index: usize,
+    progress_updates_tx: tokio::sync::mpsc::Sender<ProgressUpdate>,
+    mut batches: BoxStream<'static, RecordBatch>,
+) -> error_stack::Result<(), Error> {
+    let connection_info = connection_info(&redis)?;
+
+    // Inform tracker of destination type
+    progress_updates_tx
+        .send(ProgressUpdate::Destination {
+            index,
+            destination: Destination::Redis(redis),
+        })
+        .await
+        .into_report()
+        .change_context(Error::ProgressUpdate)?;
+
+    tracing::info!(
+        "Writing results with schema {schema:?} to redis at {}",
+        connection_info.addr
+    );
+    let client = redis::Client::open(connection_info)
+        .into_report()
+        .change_context(Error::Connect)?;
+    let mut connection = client
+        .get_multiplexed_tokio_connection()
+        .await
+        .into_report()
+        .change_context(Error::Connect)?;
+
+    while let Some(batch) = batches.next().await {
+        let num_rows = batch.num_rows();
+        if num_rows == 0 {
+            continue;
+        }
+
+        let mut pipeline = redis::pipe();
+        pipeline.atomic();
+        for (key, fields) in latest_per_key(&batch)? {
+            // Delete the previous hash so fields that are now null are removed.
+            pipeline.del(&key).ignore();
+            pipeline.hset_multiple(&key, &fields).ignore();
+        }
+        pipeline
+            .query_async::<_, ()>(&mut connection)
+            .await
+            .into_report()
+            .change_context(Error::Write)?;
+
+        progress_updates_tx
+            .send(ProgressUpdate::Output { index, num_rows })
+            .await
+            .into_report()
+            .change_context(Error::ProgressUpdate)?;
+    }
+
+    Ok(())
+}
+
+/// Create the Redis connection info from the destination.
+fn connection_info(redis: &RedisDestination) -> error_stack::Result<ConnectionInfo, Error> {
+    error_stack::ensure!(
+        !redis.host_name.trim().is_empty(),
+        Error::InvalidDestination("missing host name")
+    );
+    error_stack::ensure!(
+        (0..=15).contains(&redis.database_number),
+        Error::InvalidDestination("database number must be between 0 and 15")
+    );
+    error_stack::ensure!(
+        redis.tls_cert.is_empty() && redis.tls_key.is_empty() && redis.tls_ca_cert.is_empty(),
+        Error::InvalidDestination("custom TLS certificates are not supported")
+    );
+
+    let port = match redis.port {
+        0 => DEFAULT_REDIS_PORT,
+        port => u16::try_from(port)
+            .map_err(|_| Error::InvalidDestination("port must be between 1 and 65535"))?,
+    };
+
+    let host = redis.host_name.clone();
+    let addr = if redis.use_tls {
+        ConnectionAddr::TcpTls {
+            host,
+            port,
+            insecure: redis.insecure_skip_verify,
+            tls_params: None,
+        }
+    } else {
+        ConnectionAddr::Tcp(host, port)
+    };
+
+    let password = if redis.password.is_empty() {
+        None
+    } else {
+        Some(redis.password.clone())
+    };
+
+    Ok(ConnectionInfo {
+        addr,
+        redis: RedisConnectionInfo {
+            db: redis.database_number as i64,
+            username: None,
+            password,
+        },
+    })
+}
+
+/// The `(field, value)` pairs of a Redis hash.
+type HashFields = Vec<(String, String)>;
+
+/// Return the hash fields for the last row of each entity in the batch.
+///
+/// Rows are ordered by time, so later rows for an entity replace earlier
+/// ones. Rows with a null key are skipped, since there is no Redis key to
+/// write them to.
+fn latest_per_key(batch: &RecordBatch) -> error_stack::Result<Vec<(String, HashFields)>, Error> {
+    let options = FormatOptions::default();
+    let format_column = |index: usize| {
+        ArrayFormatter::try_new(batch.column(index).as_ref(), &options)
+            .into_report()
+            .change_context(Error::Format)
+    };
+
+    let keys = batch.column(KEY_COLUMN);
+    let key_formatter = format_column(KEY_COLUMN)?;
+    let mut latest: HashMap<String, usize> = HashMap::new();
+    for row in 0..batch.num_rows() {
+        if keys.is_valid(row) {
+            latest.insert(key_formatter.value(row).to_string(), row);
+        }
+    }
+
+    let time_formatter = format_column(TIME_COLUMN)?;
+    let value_formatters: Vec<_> = (FIRST_VALUE_COLUMN..batch.num_columns())
+        .map(format_column)
+        .collect::<error_stack::Result<_, _>>()?;
+
+    let schema = batch.schema();
+    let mut result: Vec<_> = latest
+        .into_iter()
+        .map(|(key, row)| {
+            let mut fields = Vec::with_capacity(1 + value_formatters.len());
+            fields.push(("_time".to_owned(), time_formatter.value(row).to_string()));
+            for (offset, formatter) in value_formatters.iter().enumerate() {
+                let index = FIRST_VALUE_COLUMN + offset;
+                if batch.column(index).is_valid(row) {
+                    fields.push((
+                        schema.field(index).name().clone(),
+                        formatter.value(row).to_string(),
+                    ));
+                }
+            }
+            (row, key, fields)
+        })
+        .collect();
+
+    // Write entities in the order their latest rows appeared.
+    result.sort_by_key(|(row, _, _)| *row);
+    Ok(result
+        .into_iter()
+        .map(|(_, key, fields)| (key, fields))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::Arc;
+
+    use arrow::array::{Int64Array, StringArray, TimestampNanosecondArray, UInt64Array};
+    use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+
+    use super::*;
+
+    fn test_schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new(
+                "_time",
+                DataType::Timestamp(TimeUnit::Nanosecond, None),
+                false,
+            ),
+            Field::new("_subsort", DataType::UInt64, false),
+            Field::new("_key_hash", DataType::UInt64, false),
+            Field::new("_key", DataType::Utf8, true),
+            Field::new("total", DataType::Int64, true),
+        ]))
+    }
+
+    /// Serve a single Redis connection, recording the commands received.
+    ///
+    /// Commands within a `MULTI` transaction are queued, and `EXEC` replies
+    /// with an integer result for each of them. Other commands, such as the
+    /// `CLIENT SETINFO` sent when connecting, are acknowledged but not
+    /// recorded. This is enough of the RESP protocol to accept the pipelines
+    /// written by the destination.
+    fn serve_resp(listener: TcpListener) -> Vec<Vec<String>> {
+        let (stream, _) = listener.accept().unwrap();
+        let mut writer = stream.try_clone().unwrap();
+        let mut reader = BufReader::new(stream);
+
+        let read_line = |reader: &mut BufReader<_>| {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            line.trim_end().to_owned()
+        };
+
+        let mut commands = Vec::new();
+        let mut queued = None;
+        loop {
+            let header = read_line(&mut reader);
+            let Some(len) = header.strip_prefix('*') else {
+                // The connection was closed.
+                break;
+            };
+
+            let mut command = Vec::new();
+            for _ in 0..len.parse::<usize>().unwrap() {
+                let len = read_line(&mut reader);
+                let len: usize = len.strip_prefix('$').unwrap().parse().unwrap();
+                let mut arg = vec![0; len + 2];
+                reader.read_exact(&mut arg).unwrap();
+                arg.truncate(len);
+                command.push(String::from_utf8(arg).unwrap());
+            }
+
+            let reply = match (command[0].as_str(), queued) {
+                ("MULTI", None) => {
+                    queued = Some(0);
+                    "+OK\r\n".to_owned()
+                }
+                ("EXEC", Some(count)) => {
+                    queued = None;
+                    format!("*{count}\r\n{}", ":1\r\n".repeat(count))
+                }
+                (_, Some(count)) => {
+                    queued = Some(count + 1);
+                    "+QUEUED\r\n".to_owned()
+                }
+                (_, None) => {
+                    writer.write_all(b"+OK\r\n").unwrap();
+                    continue;
+                }
+            };
+            writer.write_all(reply.as_bytes()).unwrap();
+            commands.push(command);
+        }
+        commands
+    }
+
+    #[test]
+    fn test_latest_per_key() {
+        let batch = RecordBatch::try_new(
+            test_schema(),
+            vec![
+                Arc::new(TimestampNanosecondArray::from(vec![1, 2, 3, 4])),
+                Arc::new(UInt64Array::from(vec![0, 0, 0, 0])),
+                Arc::new(UInt64Array::from(vec![10, 20, 10, 30])),
+                Arc::new(StringArray::from(vec![
+                    Some("a"),
+                    Some("b"),
+                    Some("a"),
+                    None,
+                ])),
+                Arc::new(Int64Array::from(vec![Some(5), Some(7), None, Some(9)])),
+            ],
+        )
+        .unwrap();
+
+        let latest = latest_per_key(&batch).unwrap();
+        assert_eq!(
+            latest,
+            vec![
+                (
+                    "b".to_owned(),
+                    vec![
+                        (
+                            "_time".to_owned(),
+                            "1970-01-01T00:00:00.000000002".to_owned()
+                        ),
+                        ("total".to_owned(), "7".to_owned())
+                    ]
+                ),
+                (
+                    "a".to_owned(),
+                    vec![(
+                        "_time".to_owned(),
+                        "1970-01-01T00:00:00.000000003".to_owned()
+                    )]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_connection_info() {
+        let destination = RedisDestination {
+            host_name: "localhost".to_owned(),
+            use_tls: true,
+            database_number: 3,
+            password: "secret".to_owned(),
+            ..RedisDestination::default()
+        };
+        let info = connection_info(&destination).unwrap();
+        assert!(matches!(
+            info.addr,
+            ConnectionAddr::TcpTls { ref host, port: DEFAULT_REDIS_PORT, insecure: false, .. } if host == "localhost"
+        ));
+        assert!(matches!(
+            info.addr,
+            ConnectionAddr::TcpTls {
+                tls_params: None,
+                ..
+            }
+        ));
+        assert_eq!(info.redis.db, 3);
+        assert_eq!(info.redis.password.as_deref(), Some("secret"));
+
+        let insecure = RedisDestination {
+            host_name: "localhost".to_owned(),
+            port: 6380,
+            use_tls: true,
+            insecure_skip_verify: true,
+            ..RedisDestination::default()
+        };
+        let info = connection_info(&insecure).unwrap();
+        assert!(matches!(
+            info.addr,
+            ConnectionAddr::TcpTls {
+                port: 6380,
+                insecure: true,
+                ..
+            }
+        ));
+
+        let plain = RedisDestination {
+            host_name: "localhost".to_owned(),
+            ..RedisDestination::default()
+        };
+        let info = connection_info(&plain).unwrap();
+        assert!(matches!(
+            info.addr,
+            ConnectionAddr::Tcp(ref host, DEFAULT_REDIS_PORT) if host == "localhost"
+        ));
+        assert_eq!(info.redis.password, None);
+
+        let invalid = RedisDestination {
+            host_name: "localhost".to_owned(),
+            database_number: 16,
+            ..RedisDestination::default()
+        };
+        assert!(connection_info(&invalid).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_write_replaces_hashes() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::task::spawn_blocking(move || serve_resp(listener));
+
+        let batch = RecordBatch::try_new(
+            test_schema(),
+            vec![
+                Arc::new(TimestampNanosecondArray::from(vec![1, 2, 3])),
+                Arc::new(UInt64Array::from(vec![0, 0, 0])),
+                Arc::new(UInt64Array::from(vec![10, 20, 10])),
+                Arc::new(StringArray::from(vec!["a", "b", "a"])),
+                Arc::new(Int64Array::from(vec![Some(5), Some(7), None])),
+            ],
+        )
+        .unwrap();
+
+        let destination = RedisDestination {
+            host_name: "127.0.0.1".to_owned(),
+            port: port as i32,
+            ..RedisDestination::default()
+        };
+        let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel(10);
+        write(
+            destination,
+            test_schema(),
+            0,
+            progress_tx,
+            futures::stream::iter(vec![batch]).boxed(),
+        )
+        .await
+        .unwrap();
+
+        // Each entity is deleted and rewritten within a single transaction,
+        // so the null `total` for `a` removes the previous value.
+        let commands = server.await.unwrap();
+        assert_eq!(
+            commands,
+            vec![
+                vec!["MULTI"],
+                vec!["DEL", "b"],
+                vec![
+                    "HMSET",
+                    "b",
+                    "_time",
+                    "1970-01-01T00:00:00.000000002",
+                    "total",
+                    "7"
+                ],
+                vec!["DEL", "a"],
+                vec!["HMSET", "a", "_time", "1970-01-01T00:00:00.000000003"],
+                vec!["EXEC"],
+            ]
+        );
+
+        assert!(matches!(
+            progress_rx.recv().await,
+            Some(ProgressUpdate::Destination { index: 0, .. })
+        ));
+        assert!(matches!(
+            progress_rx.recv().await,
+            Some(ProgressUpdate::Output {
+                index: 0,
+                num_rows: 3
+            })
+        ));
+    }
 }
@@ -8,6 +8,7 @@ use sparrow_api::kaskada::v1alpha::Destination;
 use sparrow_api::kaskada::v1alpha::ObjectStoreDestination;
 use sparrow_api::kaskada::v1alpha::ProgressInformation;
 use sparrow_api::kaskada::v1alpha::PulsarConfig;
+use sparrow_api::kaskada::v1alpha::RedisDestination;
 use sparrow_api::kaskada::v1alpha::{ExecuteResponse, LongQueryState};
 use tokio_stream::StreamExt;
 
@@ -31,10 +32,20 @@ struct ProgressTracker {
     logged_no_batches: bool,
     /// The progress inforamation to include in the streaming RPC response.
     progress: ProgressInformation,
+    /// Progress for each of the destinations, indexed by destination.
+    ///
+    /// The first destination is the primary destination of the request.
+    destinations: Vec<DestinationProgress>,
+}
+
+#[derive(Default)]
+struct DestinationProgress {
     /// The paths to the output files produced so far.
     ///
     /// If the output is not configured to write to files, this will be empty.
     output_paths: Vec<String>,
+    /// The number of rows written to the destination so far.
+    output_rows: i64,
     /// Information on where the outputs are materialized to.
     destination: Option<destination::Destination>,
 }
@@ -42,17 +53,26 @@ struct ProgressTracker {
 #[derive(Debug)]
 pub(crate) enum ProgressUpdate {
     /// Informs the progress tracker of the output destination.
+    ///
+    /// The `index` identifies the destination the update applies to.
+    /// The primary destination has index `0`.
     Destination {
+        index: usize,
         destination: destination::Destination,
     },
     /// Progress update reported for each table indicating total size.
     InputMetadata { total_num_rows: usize },
     /// Progress update indicating the given number of rows have been read.
     Input { num_rows: usize },
-    /// Progress update indicating the given number of rows have been output.
-    Output { num_rows: usize },
-    /// Progress update reporting the output files produced.
-    FilesProduced { paths: Vec<ObjectStoreUrl> },
+    /// Progress update indicating the given number of rows have been output
+    /// to the destination with the given index.
+    Output { index: usize, num_rows: usize },
+    /// Progress update reporting the output files produced for the
+    /// destination with the given index.
+    FilesProduced {
+        index: usize,
+        paths: Vec<ObjectStoreUrl>,
+    },
     /// Sent to indicate all operations have completed.
     ///
     /// For now, contains the compute snapshots, as we only snapshot
@@ -81,16 +101,24 @@ impl ProgressTracker {
                 max_event_time: 0,
                 output_time: 0,
                 produced_output_rows: 0,
+                additional_destination_output_rows: vec![],
             },
-            output_paths: vec![],
-            destination: None,
+            destinations: vec![],
         }
     }
 
+    fn destination_progress(&mut self, index: usize) -> &mut DestinationProgress {
+        if self.destinations.len() <= index {
+            self.destinations
+                .resize_with(index + 1, DestinationProgress::default);
+        }
+        &mut self.destinations[index]
+    }
+
     fn process_update(&mut self, stats: ProgressUpdate) {
         match stats {
-            ProgressUpdate::Destination { destination } => {
-                self.destination = Some(destination);
+            ProgressUpdate::Destination { index, destination } => {
+                self.destination_progress(index).destination = Some(destination);
             }
             ProgressUpdate::InputMetadata { total_num_rows } => {
                 self.progress.total_input_rows += total_num_rows as i64;
@@ -98,13 +126,20 @@ impl ProgressTracker {
             ProgressUpdate::Input { num_rows } => {
                 self.progress.processed_input_rows += num_rows as i64;
             }
-            ProgressUpdate::Output { num_rows } => {
-                self.output_batches_since_progress += 1;
-                self.progress.produced_output_rows += num_rows as i64;
+            ProgressUpdate::Output { index, num_rows } => {
+                // Every destination receives the same rows, but may write them
+                // at different rates. The primary destination determines the
+                // produced rows, and the others are reported separately.
+                if index == 0 {
+                    self.output_batches_since_progress += 1;
+                    self.progress.produced_output_rows += num_rows as i64;
+                }
+                self.destination_progress(index).output_rows += num_rows as i64;
             }
-            ProgressUpdate::FilesProduced { paths } => {
+            ProgressUpdate::FilesProduced { index, paths } => {
+                let output_paths = &mut self.destination_progress(index).output_paths;
                 for path in paths {
-                    self.output_paths.push(path.to_string());
+                    output_paths.push(path.to_string());
                 }
             }
             ProgressUpdate::ExecutionComplete { .. } | ProgressUpdate::ExecutionFailed { .. } => {
@@ -127,19 +162,48 @@ impl ProgressTracker {
 
         self.output_batches_since_progress = 0;
 
-        let destination = self.destination_to_output()?;
+        let (destination, additional_destinations) = self.destinations_to_output()?;
         Ok(ExecuteResponse {
             state: LongQueryState::Running as i32,
             is_query_done: false,
-            progress: Some(self.progress.clone()),
+            progress: Some(self.progress_information()),
             flight_record_path: None,
             plan_yaml_path: None,
             compute_snapshots: Vec::new(),
             destination: Some(destination),
+            additional_destinations,
         })
     }
 
-    fn destination_to_output(&mut self) -> error_stack::Result<Destination, Error> {
+    /// Return the progress information, including the rows written to each
+    /// of the additional destinations.
+    fn progress_information(&self) -> ProgressInformation {
+        ProgressInformation {
+            additional_destination_output_rows: self
+                .destinations
+                .iter()
+                .skip(1)
+                .map(|destination| destination.output_rows)
+                .collect(),
+            ..self.progress.clone()
+        }
+    }
+
+    /// Return the primary destination and the additional destinations.
+    fn destinations_to_output(
+        &self,
+    ) -> error_stack::Result<(Destination, Vec<Destination>), Error> {
+        let mut destinations = self.destinations.iter().map(|d| d.to_output());
+        let destination = destinations
+            .next()
+            .ok_or(Error::Internal("expected destination"))??;
+        let additional_destinations = destinations.collect::<Result<_, _>>()?;
+        Ok((destination, additional_destinations))
+    }
+}
+
+impl DestinationProgress {
+    fn to_output(&self) -> error_stack::Result<Destination, Error> {
         // Clone the output paths in for object store destinations
         let destination = self
             .destination
@@ -181,9 +245,14 @@ impl ProgressTracker {
                     })),
                 })
             }
-            destination::Destination::Redis(_) => {
-                error_stack::bail!(Error::UnsupportedOutput { output: "redis" })
-            }
+            destination::Destination::Redis(redis) => Ok(Destination {
+                // Don't echo the credentials back in the response.
+                destination: Some(destination::Destination::Redis(RedisDestination {
+                    password: String::new(),
+                    tls_key: String::new(),
+                    ..redis.clone()
+                })),
+            }),
         }
     }
 }
@@ -237,8 +306,8 @@ pub(super) fn progress_stream(
                                     }
                                 }
 
-                                let output = match tracker.destination_to_output() {
-                                    Ok(output) => output,
+                                let (output, additional_outputs) = match tracker.destinations_to_output() {
+                                    Ok(outputs) => outputs,
                                     Err(e) => {
                                         yield Err(e);
                                         continue;
@@ -248,11 +317,12 @@ pub(super) fn progress_stream(
                                 let final_result = Ok(ExecuteResponse {
                                     state: LongQueryState::Running as i32,
                                     is_query_done: true,
-                                    progress: Some(tracker.progress),
+                                    progress: Some(tracker.progress_information()),
                                     flight_record_path: None,
                                     plan_yaml_path: None,
                                     compute_snapshots,
                                     destination: Some(output),
+                                    additional_destinations: additional_outputs,
                                 });
                                 yield final_result;
                                 break
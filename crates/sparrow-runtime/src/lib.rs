@@ -51,6 +51,10 @@ pub(crate) struct RuntimeOptions {
     /// Path to store the Query Flight Record to.
     /// Defaults to not storing anything.
     pub flight_record_path: Option<PathBuf>,
+
+    /// Whether zoned timestamp columns are output in their original time
+    /// zone. Defaults to outputting them as UTC.
+    pub preserve_time_zones: bool,
 }
 
 /// Initial size of the upload buffer.
@@ -3,7 +3,10 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use arrow::array::ArrowPrimitiveType;
-use arrow::datatypes::{DataType, Field, FieldRef, Schema, SchemaRef, TimestampMillisecondType};
+use arrow::datatypes::{
+    DataType, Field, FieldRef, Schema, SchemaRef, TimestampMillisecondType,
+    DECIMAL128_MAX_PRECISION,
+};
 use error_stack::{IntoReport, IntoReportCompat, ResultExt};
 use tempfile::NamedTempFile;
 
@@ -30,6 +33,8 @@ pub enum Error {
     ReadSchema,
     #[display(fmt = "failed to get pulsar schema: {_0}")]
     PulsarSchema(String),
+    #[display(fmt = "failed to get kafka schema: {_0}")]
+    KafkaSchema(String),
     #[display(fmt = "unsupport column detected: '{_0}")]
     UnsupportedColumn(String),
 }
@@ -47,6 +52,15 @@ pub struct RawMetadata {
     pub table_schema: SchemaRef,
 }
 
+/// For Kafka, we keep the schema used for decoding the Avro messages alongside
+/// the metadata, since the consumer needs it to read messages.
+pub struct KafkaMetadata {
+    /// The schema of the messages in the topic.
+    pub kafka_schema: streams::kafka::schema::KafkaSchema,
+    /// The schema of the topic as used by Sparrow.
+    pub sparrow_metadata: RawMetadata,
+}
+
 /// For Pulsar, we want to keep the original user_schema around for use
 /// by the consumer.  This is because we want the RawMetadata.raw_schema
 /// to include the publish time metadata, but if we include that when creating the
@@ -75,6 +89,12 @@ impl RawMetadata {
                 let string_reader = BufReader::new(Cursor::new(content));
                 Self::try_from_csv_reader(string_reader)
             }
+            source_data::Source::JsonlPath(path) => {
+                Self::try_from_jsonl(path, object_store_registry).await
+            }
+            source_data::Source::ArrowIpcPath(path) => {
+                Self::try_from_arrow_ipc(path, object_store_registry).await
+            }
         }
     }
 
@@ -94,8 +114,10 @@ impl RawMetadata {
             .sparrow_metadata)
     }
 
-    pub async fn try_from_kafka_subscription(_: &KafkaConfig) -> error_stack::Result<Self, Error> {
-        todo!()
+    pub async fn try_from_kafka_subscription(
+        config: &KafkaConfig,
+    ) -> error_stack::Result<Self, Error> {
+        Ok(Self::try_from_kafka(config).await?.sparrow_metadata)
     }
 
     /// Create `RawMetadata` from a raw schema.
@@ -136,26 +158,49 @@ impl RawMetadata {
         path: &str,
         object_stores: &ObjectStoreRegistry,
     ) -> error_stack::Result<Self, Error> {
-        let object_store_url = ObjectStoreUrl::from_str(path)
-            .change_context_lazy(|| Error::ObjectStore(path.to_owned()))?;
+        // Pass the file (which implements read) directly. If the file was
+        // downloaded, it will be deleted when the reader completes.
+        Self::try_from_csv_reader(local_file(path, object_stores).await?)
+    }
 
-        if let Some(local_path) = object_store_url.local_path() {
-            let file = file_from_path(local_path)
-                .into_report()
-                .change_context_lazy(|| Error::LocalFile)?;
-            Self::try_from_csv_reader(file)
-        } else {
-            let download_file = NamedTempFile::new()
-                .into_report()
-                .change_context_lazy(|| Error::Download)?;
-            object_stores
-                .download(object_store_url, download_file.path())
-                .await
-                .change_context_lazy(|| Error::Download)?;
-            // Pass the download_file (which implements read) directly.
-            // The file will be deleted when the reader completes.
-            Self::try_from_csv_reader(download_file)
-        }
+    /// Create a `RawMetadata` from a JSON Lines string path and object store registry.
+    ///
+    /// As with CSV, this needs a local copy of the file to infer the schema.
+    async fn try_from_jsonl(
+        path: &str,
+        object_stores: &ObjectStoreRegistry,
+    ) -> error_stack::Result<Self, Error> {
+        let file = local_file(path, object_stores).await?;
+        Self::try_from_jsonl_reader(BufReader::new(file))
+    }
+
+    /// Create a `RawMetadata` from an Arrow IPC string path and object store registry.
+    ///
+    /// The schema is read from the header of a local copy of the file.
+    async fn try_from_arrow_ipc(
+        path: &str,
+        object_stores: &ObjectStoreRegistry,
+    ) -> error_stack::Result<Self, Error> {
+        Self::try_from_arrow_ipc_reader(local_file(path, object_stores).await?)
+    }
+
+    /// Create a `RawMetadata` from a Kafka topic.
+    pub(crate) async fn try_from_kafka(
+        config: &KafkaConfig,
+    ) -> error_stack::Result<KafkaMetadata, Error> {
+        let kafka_schema = streams::kafka::schema::get_kafka_schema(config)
+            .await
+            .change_context_lazy(|| Error::KafkaSchema("unable to get schema".to_owned()))?;
+
+        tracing::debug!(
+            "kafka schema fields: {:?}",
+            kafka_schema.arrow_schema.fields
+        );
+        let raw_schema = Arc::new(kafka_schema.arrow_schema.clone());
+        Ok(KafkaMetadata {
+            kafka_schema,
+            sparrow_metadata: Self::from_raw_schema(raw_schema)?,
+        })
     }
 
     /// Create a `RawMetadata` from a Pulsar topic.
@@ -212,6 +257,81 @@ impl RawMetadata {
 
         Self::from_raw_schema(Arc::new(raw_schema))
     }
+
+    /// Create a `RawMetadata` from a reader of a JSON Lines file.
+    fn try_from_jsonl_reader<R>(reader: R) -> error_stack::Result<Self, Error>
+    where
+        R: std::io::BufRead,
+    {
+        let raw_schema = arrow::json::reader::infer_json_schema(reader, None)
+            .into_report()
+            .change_context(Error::ReadSchema)?;
+
+        Self::from_raw_schema(Arc::new(raw_schema))
+    }
+
+    /// Create a `RawMetadata` from a reader of an Arrow IPC file.
+    fn try_from_arrow_ipc_reader<R>(reader: R) -> error_stack::Result<Self, Error>
+    where
+        R: std::io::Read + std::io::Seek,
+    {
+        let reader = arrow::ipc::reader::FileReader::try_new(reader, None)
+            .into_report()
+            .change_context(Error::ReadSchema)?;
+
+        Self::from_raw_schema(reader.schema())
+    }
+}
+
+/// A local copy of a file, used for reading the schema.
+enum LocalFile {
+    /// The file is already local.
+    Local(std::fs::File),
+    /// The file was downloaded to a temporary file, which is deleted when dropped.
+    Downloaded(NamedTempFile),
+}
+
+impl std::io::Read for LocalFile {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Local(file) => file.read(buf),
+            Self::Downloaded(file) => file.read(buf),
+        }
+    }
+}
+
+impl std::io::Seek for LocalFile {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        match self {
+            Self::Local(file) => file.seek(pos),
+            Self::Downloaded(file) => file.seek(pos),
+        }
+    }
+}
+
+/// Open the file at `path`, downloading it if it isn't local.
+async fn local_file(
+    path: &str,
+    object_stores: &ObjectStoreRegistry,
+) -> error_stack::Result<LocalFile, Error> {
+    let object_store_url = ObjectStoreUrl::from_str(path)
+        .change_context_lazy(|| Error::ObjectStore(path.to_owned()))?;
+
+    if let Some(local_path) = object_store_url.local_path() {
+        let file = file_from_path(local_path)
+            .into_report()
+            .change_context_lazy(|| Error::LocalFile)?;
+        Ok(LocalFile::Local(file))
+    } else {
+        let download_file = NamedTempFile::new()
+            .into_report()
+            .change_context_lazy(|| Error::Download)?;
+        object_stores
+            .download(object_store_url, download_file.path())
+            .await
+            .change_context_lazy(|| Error::Download)?;
+        Ok(LocalFile::Downloaded(download_file))
+    }
 }
 
 /// Converts the schema to a table schema
@@ -224,37 +344,30 @@ fn convert_schema(schema: &Schema) -> error_stack::Result<SchemaRef, Error> {
     Ok(Arc::new(Schema::new(fields)))
 }
 
-/// Arrow doesn't support time zones very well; it assumes all have a time zone
-/// of `None`, which will use system time. Sparrow only operates on
-/// [arrow::datatypes::TimestampNanosecondType], and currently cannot pass
-/// through a time zone. In order to load and operate on time-zoned data, this
-/// is a hack that forcibly casts all timestamp types to a time zone of `None`.
-/// This can cause incorrect errors and possible ordering problems when multiple
-/// input files have different time zones.
+/// Timestamps with a time zone are supported as-is. The values are relative
+/// to the UTC epoch, and the time zone is preserved so that results may be
+/// rendered (and calendar functions applied) in the original zone.
 ///
-/// Arrow also does not support Decimal types. As of now, we are currently
-/// dropping the columns that are Decimal types since we do not support at query
-/// time either.
+/// 128-bit decimals are supported as-is. 256-bit decimals are narrowed to
+/// 128-bit decimals if their precision fits (at most 38 digits), and rejected
+/// otherwise, since they are not supported at query time.
 fn convert_field(field: &FieldRef) -> error_stack::Result<FieldRef, Error> {
     match field.data_type() {
-        DataType::Timestamp(time_unit, Some(tz)) => {
-            // TODO: We discard this because the conversion from an Arrow
-            // schema to the Schema protobuf currently fails on such timestamp columns.
-            tracing::warn!(
-                "Time zones are unsupported. Interpreting column '{}' with time zone '{}' as UTC",
-                tz,
-                field.name()
-            );
+        DataType::Decimal256(precision, scale) if *precision <= DECIMAL128_MAX_PRECISION => {
             Ok(Arc::new(Field::new(
                 field.name(),
-                DataType::Timestamp(time_unit.clone(), None),
+                DataType::Decimal128(*precision, *scale),
                 field.is_nullable(),
             )))
         }
-        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
-            tracing::warn!("Decimal columns are unsupported: '{}'", field.name());
+        DataType::Decimal256(precision, _) => {
+            tracing::warn!(
+                "Decimal256 columns with precision {precision} are unsupported: '{}'",
+                field.name()
+            );
             error_stack::bail!(Error::UnsupportedColumn(format!(
-                "Decimal columns are unsupported: {}",
+                "Decimal256 columns with precision greater than {DECIMAL128_MAX_PRECISION} are \
+                 unsupported: {}",
                 field.name()
             )))
         }
@@ -294,7 +407,7 @@ mod tests {
     fn test_raw_metadata_conversion() {
         let raw_schema = Arc::new(Schema::new(vec![
             Field::new("time", DataType::Utf8, false),
-            // Time zone should be removed.
+            // Time zone should be preserved.
             Field::new(
                 "time_zone",
                 DataType::Timestamp(TimeUnit::Nanosecond, Some(Arc::from("UTC"))),
@@ -307,30 +420,15 @@ mod tests {
             Field::new("c", DataType::Int64, true),
         ]));
 
-        let converted_schema = Arc::new(Schema::new(vec![
-            Field::new("time", DataType::Utf8, false),
-            Field::new(
-                "time_zone",
-                DataType::Timestamp(TimeUnit::Nanosecond, None),
-                false,
-            ),
-            Field::new("subsort", DataType::UInt64, false),
-            Field::new("key", DataType::UInt64, false),
-            Field::new("a", DataType::Int64, true),
-            Field::new("b", DataType::Int64, true),
-            Field::new("c", DataType::Int64, true),
-        ]));
-
         let metadata = RawMetadata::from_raw_schema(raw_schema.clone()).unwrap();
         assert_eq!(metadata.raw_schema, raw_schema);
-        assert_eq!(metadata.table_schema, converted_schema);
+        assert_eq!(metadata.table_schema, raw_schema);
     }
 
     #[test]
-    fn test_raw_metadata_timestamp_drop_timezones() {
+    fn test_raw_metadata_timestamp_preserve_timezones() {
         let raw_schema = Arc::new(Schema::new(vec![
             Field::new("time", DataType::Utf8, false),
-            // Time zone should be removed.
             Field::new(
                 "time_zone_micro",
                 DataType::Timestamp(TimeUnit::Microsecond, Some(Arc::from("UTC"))),
@@ -338,44 +436,51 @@ mod tests {
             ),
             Field::new(
                 "time_zone_nano",
-                DataType::Timestamp(TimeUnit::Nanosecond, Some(Arc::from("UTC"))),
+                DataType::Timestamp(TimeUnit::Nanosecond, Some(Arc::from("America/New_York"))),
                 false,
             ),
             Field::new(
                 "time_zone_second",
-                DataType::Timestamp(TimeUnit::Second, Some(Arc::from("UTC"))),
+                DataType::Timestamp(TimeUnit::Second, Some(Arc::from("+07:00"))),
                 false,
             ),
             Field::new(
                 "time_zone_milli",
-                DataType::Timestamp(TimeUnit::Millisecond, Some(Arc::from("UTC"))),
+                DataType::Timestamp(TimeUnit::Millisecond, Some(Arc::from("Asia/Tokyo"))),
                 false,
             ),
         ]));
 
-        let converted_schema = Arc::new(Schema::new(vec![
-            Field::new("time", DataType::Utf8, false),
-            Field::new(
-                "time_zone_micro",
-                DataType::Timestamp(TimeUnit::Microsecond, None),
-                false,
-            ),
-            Field::new(
-                "time_zone_nano",
-                DataType::Timestamp(TimeUnit::Nanosecond, None),
-                false,
-            ),
-            Field::new(
-                "time_zone_second",
-                DataType::Timestamp(TimeUnit::Second, None),
-                false,
-            ),
-            Field::new(
-                "time_zone_milli",
-                DataType::Timestamp(TimeUnit::Millisecond, None),
-                false,
-            ),
-        ]));
+        let metadata = RawMetadata::from_raw_schema(raw_schema.clone()).unwrap();
+        assert_eq!(metadata.raw_schema, raw_schema);
+        assert_eq!(metadata.table_schema, raw_schema);
+    }
+
+    #[test]
+    fn test_raw_metadata_decimal128() {
+        let raw_schema = Arc::new(Schema::new(vec![Field::new(
+            "decimal_col",
+            DataType::Decimal128(10, 2),
+            true,
+        )]));
+
+        let metadata = RawMetadata::from_raw_schema(raw_schema.clone()).unwrap();
+        assert_eq!(metadata.raw_schema, raw_schema);
+        assert_eq!(metadata.table_schema, raw_schema);
+    }
+
+    #[test]
+    fn test_raw_metadata_decimal256_narrowed() {
+        let raw_schema = Arc::new(Schema::new(vec![Field::new(
+            "decimal_col",
+            DataType::Decimal256(38, 2),
+            true,
+        )]));
+        let converted_schema = Arc::new(Schema::new(vec![Field::new(
+            "decimal_col",
+            DataType::Decimal128(38, 2),
+            true,
+        )]));
 
         let metadata = RawMetadata::from_raw_schema(raw_schema.clone()).unwrap();
         assert_eq!(metadata.raw_schema, raw_schema);
@@ -383,10 +488,10 @@ mod tests {
     }
 
     #[test]
-    fn test_raw_metadata_decimal_errors() {
+    fn test_raw_metadata_decimal256_errors() {
         let raw_schema = Arc::new(Schema::new(vec![Field::new(
             "decimal_col",
-            DataType::Decimal128(0, 0),
+            DataType::Decimal256(50, 2),
             false,
         )]));
 
@@ -396,7 +501,8 @@ mod tests {
             Err(e) => {
                 assert_eq!(
                     e.as_error().to_string(),
-                    "unsupport column detected: 'Decimal columns are unsupported: decimal_col"
+                    "unsupport column detected: 'Decimal256 columns with precision greater than \
+                     38 are unsupported: decimal_col"
                 )
             }
         }
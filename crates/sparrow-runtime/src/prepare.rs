@@ -10,6 +10,7 @@ use object_store::ObjectStore;
 use sparrow_api::kaskada::v1alpha::{
     slice_plan, source_data, PreparedFile, SourceData, TableConfig,
 };
+use tempfile::NamedTempFile;
 
 mod column_behavior;
 mod error;
@@ -78,34 +79,8 @@ pub async fn prepared_batches<'a>(
                 .change_context(Error::CreateReader)?
             }
             source_data::Source::CsvPath(source) => {
-                let url = ObjectStoreUrl::from_str(source)
-                    .change_context(Error::InvalidUrl(source.to_owned()))?;
-                let local_file = tempfile::Builder::new()
-                    .suffix(".csv")
-                    .tempfile()
-                    .into_report()
-                    .change_context(Error::CreateReader)?;
-
-                // Get the prepare hash. This could be cleaned up if we had a better wrapper
-                // around the object stores.
-                let object_store = object_stores
-                    .object_store(&url)
-                    .change_context(Error::CreateReader)?;
-                let location = url.path().change_context(Error::CreateReader)?;
-                let object_meta = object_store
-                    .head(&location)
-                    .await
-                    .into_report()
-                    .change_context(Error::CreateReader)?;
-                let prepare_hash = object_meta.etag_hash();
-
-                // For CSV we need to download the file (for now) to perform inference.
-                // We could improve this by looking at the size and creating an in-memory
-                // buffer and/or looking at a prefix of the file...
-                object_stores
-                    .download(url, local_file.path())
-                    .await
-                    .change_context(Error::DownloadingObject)?;
+                let (local_file, prepare_hash) =
+                    download_to_local(object_stores, source, ".csv").await?;
 
                 // Transfer the local file to the reader. When the CSV reader
                 // completes the reader will be dropped, and the file deleted.
@@ -119,6 +94,20 @@ pub async fn prepared_batches<'a>(
                 let reader = BufReader::new(content);
                 reader_from_csv(config, reader, prepare_hash, slice).await?
             }
+            source_data::Source::JsonlPath(source) => {
+                let (local_file, prepare_hash) =
+                    download_to_local(object_stores, source, ".jsonl").await?;
+
+                let reader = BufReader::new(local_file);
+                reader_from_jsonl(config, reader, prepare_hash, slice).await?
+            }
+            source_data::Source::ArrowIpcPath(source) => {
+                let (local_file, prepare_hash) =
+                    download_to_local(object_stores, source, ".arrow").await?;
+
+                let reader = BufReader::new(local_file);
+                reader_from_arrow_ipc(config, reader, prepare_hash, slice).await?
+            }
         },
     };
 
@@ -231,6 +220,47 @@ fn get_batch_size(num_rows: usize, num_files: usize) -> usize {
 
 const BATCH_SIZE: usize = 1_000_000;
 
+/// Download the object at `source` to a local temporary file.
+///
+/// Returns the file along with the prepare hash of the object. The file is
+/// deleted when it is dropped.
+async fn download_to_local(
+    object_stores: &ObjectStoreRegistry,
+    source: &str,
+    suffix: &str,
+) -> error_stack::Result<(NamedTempFile, u64), Error> {
+    let url =
+        ObjectStoreUrl::from_str(source).change_context(Error::InvalidUrl(source.to_owned()))?;
+    let local_file = tempfile::Builder::new()
+        .suffix(suffix)
+        .tempfile()
+        .into_report()
+        .change_context(Error::CreateReader)?;
+
+    // Get the prepare hash. This could be cleaned up if we had a better wrapper
+    // around the object stores.
+    let object_store = object_stores
+        .object_store(&url)
+        .change_context(Error::CreateReader)?;
+    let location = url.path().change_context(Error::CreateReader)?;
+    let object_meta = object_store
+        .head(&location)
+        .await
+        .into_report()
+        .change_context(Error::CreateReader)?;
+    let prepare_hash = object_meta.etag_hash();
+
+    // For CSV and JSON we need to download the file (for now) to perform inference.
+    // We could improve this by looking at the size and creating an in-memory
+    // buffer and/or looking at a prefix of the file...
+    object_stores
+        .download(url, local_file.path())
+        .await
+        .change_context(Error::DownloadingObject)?;
+
+    Ok((local_file, prepare_hash))
+}
+
 async fn reader_from_csv<'a, R: std::io::Read + std::io::Seek + Send + 'static>(
     config: &'a TableConfig,
     mut reader: R,
@@ -276,11 +306,65 @@ async fn reader_from_csv<'a, R: std::io::Read + std::io::Seek + Send + 'static>(
         .change_context(Error::CreateReader)
 }
 
+async fn reader_from_jsonl<'a, R: std::io::BufRead + std::io::Seek + Send + 'static>(
+    config: &'a TableConfig,
+    mut reader: R,
+    prepare_hash: u64,
+    slice: &'a Option<slice_plan::Slice>,
+) -> error_stack::Result<BoxStream<'a, error_stack::Result<(RecordBatch, RecordBatch), Error>>, Error>
+{
+    let raw_schema = arrow::json::reader::infer_json_schema_from_seekable(&mut reader, None)
+        .into_report()
+        .change_context(Error::ReadSchema)?;
+    let raw_schema = Arc::new(raw_schema);
+
+    let reader = arrow::json::ReaderBuilder::new(raw_schema.clone())
+        .with_batch_size(BATCH_SIZE)
+        .build(reader)
+        .into_report()
+        .change_context(Error::CreateReader)?;
+    let raw_metadata =
+        RawMetadata::from_raw_schema(raw_schema).change_context(Error::ReadSchema)?;
+    let reader = futures::stream::iter(reader)
+        .map(|batch| batch.into_report().change_context(Error::ReadingBatch))
+        .boxed();
+
+    prepare_input_stream::prepare_input(reader, config, raw_metadata, prepare_hash, slice)
+        .await
+        .into_report()
+        .change_context(Error::CreateReader)
+}
+
+async fn reader_from_arrow_ipc<'a, R: std::io::Read + std::io::Seek + Send + 'static>(
+    config: &'a TableConfig,
+    reader: R,
+    prepare_hash: u64,
+    slice: &'a Option<slice_plan::Slice>,
+) -> error_stack::Result<BoxStream<'a, error_stack::Result<(RecordBatch, RecordBatch), Error>>, Error>
+{
+    // The schema is stored in the IPC file, so no inference is needed.
+    let reader = arrow::ipc::reader::FileReader::try_new(reader, None)
+        .into_report()
+        .change_context(Error::CreateReader)?;
+    let raw_metadata =
+        RawMetadata::from_raw_schema(reader.schema()).change_context(Error::ReadSchema)?;
+    let reader = futures::stream::iter(reader)
+        .map(|batch| batch.into_report().change_context(Error::ReadingBatch))
+        .boxed();
+
+    prepare_input_stream::prepare_input(reader, config, raw_metadata, prepare_hash, slice)
+        .await
+        .into_report()
+        .change_context(Error::CreateReader)
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
-    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::array::{Int64Array, StringArray, TimestampNanosecondArray, UInt64Array};
+    use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+    use arrow::record_batch::RecordBatch;
     use futures::StreamExt;
     use sparrow_api::kaskada::v1alpha::slice_plan::{EntityKeysSlice, Slice};
     use sparrow_api::kaskada::v1alpha::{source_data, SourceData, TableConfig};
@@ -414,6 +498,118 @@ mod tests {
         let _metadata_schema = metadata.schema();
     }
 
+    #[tokio::test]
+    async fn test_prepare_jsonl() {
+        let mut input_file = tempfile::Builder::new()
+            .suffix(".jsonl")
+            .tempfile()
+            .unwrap();
+        std::io::Write::write_all(
+            &mut input_file,
+            concat!(
+                r#"{"time": "1996-12-19T16:39:57-08:00", "subsort": 0, "key": "A", "m": 5}"#,
+                "\n",
+                r#"{"time": "1996-12-19T16:39:58-08:00", "subsort": 0, "key": "B", "m": 24}"#,
+                "\n",
+                r#"{"time": "1996-12-19T16:39:59-08:00", "subsort": 0, "key": "A"}"#,
+                "\n",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        let source_data = SourceData {
+            source: Some(source_data::Source::JsonlPath(format!(
+                "file:///{}",
+                input_file.path().display()
+            ))),
+        };
+        let table_config = TableConfig::new_with_table_source(
+            "Numbers",
+            &Uuid::new_v4(),
+            "time",
+            Some("subsort"),
+            "key",
+            "",
+        );
+
+        let prepared_batches = super::prepared_batches(
+            &ObjectStoreRegistry::default(),
+            &source_data,
+            &table_config,
+            &None,
+        )
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(prepared_batches.len(), 1);
+        let (prepared_batch, _metadata) = prepared_batches[0].as_ref().unwrap();
+        assert_eq!(prepared_batch.num_rows(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_prepare_arrow_ipc() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new(
+                "time",
+                DataType::Timestamp(TimeUnit::Nanosecond, None),
+                false,
+            ),
+            Field::new("subsort", DataType::UInt64, false),
+            Field::new("key", DataType::Utf8, false),
+            Field::new("m", DataType::Int64, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(TimestampNanosecondArray::from(vec![1, 2, 3])),
+                Arc::new(UInt64Array::from(vec![0, 0, 0])),
+                Arc::new(StringArray::from(vec!["A", "B", "A"])),
+                Arc::new(Int64Array::from(vec![Some(5), Some(24), None])),
+            ],
+        )
+        .unwrap();
+
+        let input_file = tempfile::Builder::new()
+            .suffix(".arrow")
+            .tempfile()
+            .unwrap();
+        let mut writer =
+            arrow::ipc::writer::FileWriter::try_new(input_file.reopen().unwrap(), &schema).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+
+        let source_data = SourceData {
+            source: Some(source_data::Source::ArrowIpcPath(format!(
+                "file:///{}",
+                input_file.path().display()
+            ))),
+        };
+        let table_config = TableConfig::new_with_table_source(
+            "Numbers",
+            &Uuid::new_v4(),
+            "time",
+            Some("subsort"),
+            "key",
+            "",
+        );
+
+        let prepared_batches = super::prepared_batches(
+            &ObjectStoreRegistry::default(),
+            &source_data,
+            &table_config,
+            &None,
+        )
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(prepared_batches.len(), 1);
+        let (prepared_batch, _metadata) = prepared_batches[0].as_ref().unwrap();
+        assert_eq!(prepared_batch.num_rows(), 3);
+    }
+
     #[tokio::test]
     async fn test_preparation_single_entity_key_slicing() {
         let entity_keys = vec!["0b00083c-5c1e-47f5-abba-f89b12ae3cf4".to_owned()];
@@ -12,7 +12,7 @@ use anyhow::anyhow;
 use arrow::array::{Array, UInt64Array};
 
 use error_stack::{IntoReport, IntoReportCompat, ResultExt};
-use sparrow_arrow::utils::make_null_array;
+use sparrow_arrow::utils::{cast_timestamp, make_null_array};
 use sparrow_kernels::order_preserving_cast_to_u64;
 
 use crate::prepare::Error;
@@ -182,8 +182,9 @@ impl ColumnBehavior {
     /// If the `result_field` doesn't exist in the `source_schema` the result
     /// is a column of nulls.
     ///
-    /// In the special case of a `Timestamp` with a time zone, this will cast to
-    /// a `Timestamp` with no time zone.
+    /// Timestamps are cast to the time zone of the result field, since
+    /// different files may contain the same column in different time zones.
+    /// 256-bit decimals are narrowed to the 128-bit decimal result field.
     ///
     /// # Errors
     /// Internal error if the type of the column in the source schema is
@@ -194,19 +195,18 @@ impl ColumnBehavior {
     ) -> anyhow::Result<Self> {
         if let Some((column, source_field)) = source_schema.column_with_name(result_field.name()) {
             match (source_field.data_type(), result_field.data_type()) {
-                (DataType::Timestamp(_, Some(_)), DataType::Timestamp(to_unit, None)) => {
-                    Ok(Self::Cast {
-                        index: column,
-                        data_type: DataType::Timestamp(to_unit.clone(), None),
-                        nullable: true,
-                    })
-                }
                 (source_type, expected_type) if source_type == expected_type => {
                     Ok(Self::Reference {
                         index: column,
                         nullable: true,
                     })
                 }
+                (DataType::Timestamp(_, _), DataType::Timestamp(_, _))
+                | (DataType::Decimal256(_, _), DataType::Decimal128(_, _)) => Ok(Self::Cast {
+                    index: column,
+                    data_type: result_field.data_type().clone(),
+                    nullable: true,
+                }),
                 (source_type, expected_type) => Err(anyhow!(
                     "Unable to get field '{}' as type {:?} from file containing {:?}",
                     result_field.name(),
@@ -243,7 +243,13 @@ impl ColumnBehavior {
                         null_count: column.null_count()
                     }
                 );
-                arrow::compute::cast(column, data_type)
+                let result = if matches!(data_type, DataType::Timestamp(_, _)) {
+                    // Casting timestamps should preserve the instants.
+                    cast_timestamp(column, data_type)
+                } else {
+                    arrow::compute::cast(column, data_type)
+                };
+                result
                     .into_report()
                     .change_context(Error::PreparingColumn)?
             }
@@ -317,8 +323,11 @@ mod tests {
     use std::sync::Arc;
 
     use super::ColumnBehavior;
-    use arrow::array::{Int64Array, TimestampNanosecondArray, UInt64Array};
-    use arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
+    use arrow::array::{
+        Decimal128Array, Decimal256Array, Int64Array, TimestampMillisecondArray,
+        TimestampNanosecondArray, UInt64Array,
+    };
+    use arrow::datatypes::{i256, DataType, Field, Schema, SchemaRef, TimeUnit};
     use arrow::record_batch::RecordBatch;
     use static_init::dynamic;
 
@@ -419,4 +428,77 @@ mod tests {
             &UInt64Array::from(vec![105, 106, 107])
         );
     }
+
+    #[tokio::test]
+    async fn test_cast_timestamp_preserves_instants() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new(
+                "utc",
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+                true,
+            ),
+            Field::new(
+                "tokyo",
+                DataType::Timestamp(TimeUnit::Nanosecond, Some(Arc::from("Asia/Tokyo"))),
+                true,
+            ),
+        ]));
+        let utc = TimestampMillisecondArray::from(vec![1_000, 2_000]);
+        let tokyo = TimestampNanosecondArray::from(vec![3_000_000_000, 4_000_000_000])
+            .with_timezone("Asia/Tokyo");
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(utc), Arc::new(tokyo)]).unwrap();
+
+        // Times without a zone are UTC, so adding a zone doesn't change them.
+        let new_york =
+            DataType::Timestamp(TimeUnit::Nanosecond, Some(Arc::from("America/New_York")));
+        let mut behavior = ColumnBehavior::try_cast_or_reference_or_null(
+            &schema,
+            &Field::new("utc", new_york.clone(), true),
+        )
+        .unwrap();
+        assert_eq!(
+            behavior.get_result(&batch).await.unwrap().as_ref(),
+            &TimestampNanosecondArray::from(vec![1_000_000_000, 2_000_000_000])
+                .with_timezone("America/New_York")
+        );
+
+        // Changing the zone doesn't change the instants.
+        let mut behavior = ColumnBehavior::try_cast_or_reference_or_null(
+            &schema,
+            &Field::new("tokyo", new_york, true),
+        )
+        .unwrap();
+        assert_eq!(
+            behavior.get_result(&batch).await.unwrap().as_ref(),
+            &TimestampNanosecondArray::from(vec![3_000_000_000, 4_000_000_000])
+                .with_timezone("America/New_York")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cast_decimal256_to_decimal128() {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "amount",
+            DataType::Decimal256(10, 2),
+            true,
+        )]));
+        let amount =
+            Decimal256Array::from(vec![Some(i256::from(1_25)), None, Some(i256::from(-3_00))])
+                .with_precision_and_scale(10, 2)
+                .unwrap();
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(amount)]).unwrap();
+
+        let mut behavior = ColumnBehavior::try_cast_or_reference_or_null(
+            &schema,
+            &Field::new("amount", DataType::Decimal128(10, 2), true),
+        )
+        .unwrap();
+        assert_eq!(
+            behavior.get_result(&batch).await.unwrap().as_ref(),
+            &Decimal128Array::from(vec![Some(1_25), None, Some(-3_00)])
+                .with_precision_and_scale(10, 2)
+                .unwrap()
+        );
+    }
 }
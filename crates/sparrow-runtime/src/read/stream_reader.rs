@@ -1,12 +1,17 @@
 use std::sync::Arc;
 
 use arrow::datatypes::{Schema, SchemaRef};
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
 use error_stack::{IntoReportCompat, ResultExt};
 
+use futures::stream::BoxStream;
 use futures::{Stream, StreamExt};
 use hashbrown::HashSet;
 use sparrow_api::kaskada::v1alpha::slice_plan::Slice;
-use sparrow_api::kaskada::v1alpha::{PulsarSource, PulsarSubscription};
+use sparrow_api::kaskada::v1alpha::{
+    KafkaSource, KafkaSubscription, PulsarSource, PulsarSubscription,
+};
 use sparrow_compiler::TableInfo;
 use sparrow_qfr::{
     activity, gauge, Activity, FlightRecorder, Gauge, PushRegistration, Registration, Registrations,
@@ -46,7 +51,7 @@ inventory::submit!(&REGISTRATION);
 /// by statistically modeling event behavior and adapting the watermark accordingly.
 const BOUNDED_LATENESS_NS: i64 = 1_000_000_000;
 
-/// Create a stream that continually reads messages from a stream.
+/// Create a stream that continually reads messages from a Pulsar topic.
 pub(crate) async fn stream_reader(
     context: &OperationContext,
     table_info: &TableInfo,
@@ -97,6 +102,84 @@ pub(crate) async fn stream_reader(
         pulsar_subscription.last_publish_time,
     );
 
+    prepared_stream(
+        context,
+        table_info,
+        requested_slice,
+        pulsar_metadata.user_schema.clone(),
+        projected_schema,
+        stream.boxed(),
+    )
+    .await
+}
+
+/// Create a stream that continually reads messages from a Kafka topic.
+pub(crate) async fn kafka_stream_reader(
+    context: &OperationContext,
+    table_info: &TableInfo,
+    requested_slice: Option<&Slice>,
+    projected_columns: Option<Vec<String>>,
+    _flight_recorder: FlightRecorder,
+    kafka_source: &KafkaSource,
+) -> error_stack::Result<impl Stream<Item = error_stack::Result<Batch, Error>> + 'static, Error> {
+    // Default to identifying the consumer by the table name. This is only a
+    // label for the client; offsets aren't committed to a consumer group.
+    let kafka_group = if kafka_source.group.is_empty() {
+        table_info.name().to_owned()
+    } else {
+        kafka_source.group.clone()
+    };
+    let kafka_config = kafka_source.config.as_ref().ok_or(Error::Internal)?;
+    let kafka_subscription = KafkaSubscription {
+        config: Some(kafka_config.clone()),
+        group: kafka_group,
+        start_offset: kafka_source.start_offset.clone(),
+    };
+    let kafka_metadata = RawMetadata::try_from_kafka(kafka_config)
+        .await
+        .change_context(Error::CreateStream)?;
+    let raw_schema = kafka_metadata.sparrow_metadata.raw_schema.clone();
+    // Verify the provided table schema matches the topic schema
+    verify_schema_match(raw_schema.clone(), table_info.schema().clone())?;
+
+    let projected_schema = if let Some(columns) = &projected_columns {
+        projected_schema(kafka_metadata.sparrow_metadata.table_schema, columns)
+            .change_context(Error::CreateStream)?
+    } else {
+        kafka_metadata.sparrow_metadata.table_schema
+    };
+
+    let consumer = streams::kafka::stream::consumer(&kafka_subscription)
+        .await
+        .change_context(Error::CreateStream)?;
+    let stream = streams::kafka::stream::execution_stream(
+        kafka_metadata.kafka_schema,
+        raw_schema.clone(),
+        projected_schema.clone(),
+        consumer,
+    );
+
+    prepared_stream(
+        context,
+        table_info,
+        requested_slice,
+        raw_schema,
+        projected_schema,
+        stream.boxed(),
+    )
+    .await
+}
+
+/// Prepare the batches read from a stream, handling late data using the
+/// bounded lateness.
+async fn prepared_stream(
+    context: &OperationContext,
+    table_info: &TableInfo,
+    requested_slice: Option<&Slice>,
+    raw_schema: SchemaRef,
+    projected_schema: SchemaRef,
+    stream: BoxStream<'static, Result<RecordBatch, ArrowError>>,
+) -> error_stack::Result<impl Stream<Item = error_stack::Result<Batch, Error>> + 'static, Error> {
     let table_config = table_info.config().clone();
     let bounded_lateness = if let Some(bounded_lateness) = context.bounded_lateness_ns {
         bounded_lateness
@@ -105,9 +188,9 @@ pub(crate) async fn stream_reader(
     };
 
     let mut input_stream = prepare::execute_input_stream::prepare_input(
-        stream.boxed(),
+        stream,
         table_config,
-        pulsar_metadata.user_schema.clone(),
+        raw_schema,
         projected_schema,
         0,
         requested_slice,
@@ -0,0 +1,2 @@
+pub(crate) mod schema;
+pub(crate) mod stream;
@@ -0,0 +1,145 @@
+use arrow::datatypes::Schema;
+use error_stack::{IntoReport, Result, ResultExt};
+use sparrow_api::kaskada::v1alpha::{kafka_config, KafkaConfig};
+
+#[derive(Debug, derive_more::Display)]
+pub enum Error {
+    #[display(fmt = "missing schema in kafka config")]
+    MissingSchema,
+    #[display(fmt = "failed to convert avro schema")]
+    AvroSchemaConversion,
+    #[display(fmt = "failed to request schema from registry")]
+    SchemaRequest,
+    #[display(fmt = "unsupported schema type '{_0}'")]
+    UnsupportedSchema(String),
+}
+
+impl error_stack::Context for Error {}
+
+/// The schema of the messages in a Kafka topic.
+#[derive(Debug, Clone)]
+pub struct KafkaSchema {
+    /// The Arrow schema corresponding to the Avro schema of the messages.
+    pub arrow_schema: Schema,
+    /// The Avro schema used to decode the messages.
+    pub avro_schema: avro_rs::Schema,
+    /// Whether messages are encoded using the schema registry wire format.
+    ///
+    /// In this format, each message is prefixed by a magic byte and a 4-byte
+    /// schema ID, followed by the Avro datum.
+    pub registry_wire_format: bool,
+}
+
+impl KafkaSchema {
+    fn try_from_formatted(
+        formatted_schema: &str,
+        registry_wire_format: bool,
+    ) -> Result<Self, Error> {
+        let arrow_schema = crate::streams::pulsar::schema::schema_from_formatted(formatted_schema)
+            .change_context(Error::AvroSchemaConversion)?;
+        let avro_schema = avro_rs::Schema::parse_str(formatted_schema)
+            .into_report()
+            .change_context(Error::AvroSchemaConversion)
+            .attach_printable_lazy(|| {
+                format!("failed to parse avro schema from json string: {formatted_schema}")
+            })?;
+        Ok(Self {
+            arrow_schema,
+            avro_schema,
+            registry_wire_format,
+        })
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct SchemaResponse {
+    #[serde(rename = "schemaType")]
+    schema_type: Option<String>,
+    schema: String,
+}
+
+/// Determine the schema of the messages in the configured topic.
+///
+/// The schema is either provided directly as an Avro schema, or retrieved
+/// from the latest value schema registered for the topic in the schema
+/// registry.
+pub async fn get_kafka_schema(config: &KafkaConfig) -> Result<KafkaSchema, Error> {
+    match config.schema.as_ref().ok_or(Error::MissingSchema)? {
+        kafka_config::Schema::AvroSchema(avro_schema) => {
+            KafkaSchema::try_from_formatted(avro_schema, false)
+        }
+        kafka_config::Schema::SchemaRegistryUrl(registry_url) => {
+            let schema = get_registry_schema(registry_url, &config.topic).await?;
+            KafkaSchema::try_from_formatted(&schema, true)
+        }
+    }
+}
+
+// Retrieve the latest value schema for the topic from the schema registry.
+//
+// This uses the default `TopicNameStrategy`, in which the subject for the
+// values in a topic is `<topic>-value`.
+async fn get_registry_schema(registry_url: &str, topic: &str) -> Result<String, Error> {
+    let url = format!(
+        "{}/subjects/{topic}-value/versions/latest",
+        registry_url.trim_end_matches('/')
+    );
+    tracing::debug!("requesting schema from {}", url);
+    let text = reqwest::get(&url)
+        .await
+        .into_report()
+        .change_context(Error::SchemaRequest)?
+        .error_for_status()
+        .into_report()
+        .change_context(Error::SchemaRequest)?
+        .text()
+        .await
+        .into_report()
+        .change_context(Error::SchemaRequest)?;
+
+    let schema_response: SchemaResponse = serde_json::from_str(&text)
+        .into_report()
+        .change_context(Error::SchemaRequest)
+        .attach_printable_lazy(|| format!("from_str({:?}) failed", &text))?;
+
+    // The registry omits the schema type for Avro schemas.
+    match schema_response.schema_type.as_deref() {
+        None | Some("AVRO") => Ok(schema_response.schema),
+        Some(other) => error_stack::bail!(Error::UnsupportedSchema(other.to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_avro_schema_from_config() {
+        let config = KafkaConfig {
+            hosts: vec!["localhost:9092".to_owned()],
+            topic: "purchases".to_owned(),
+            schema: Some(kafka_config::Schema::AvroSchema(
+                r#"{
+                    "type": "record",
+                    "name": "Purchase",
+                    "fields": [
+                        {"name": "time", "type": "long"},
+                        {"name": "user", "type": "string"},
+                        {"name": "amount", "type": "double"}
+                    ]
+                }"#
+                .to_owned(),
+            )),
+        };
+
+        let schema = get_kafka_schema(&config).await.unwrap();
+        assert!(!schema.registry_wire_format);
+        let names: Vec<_> = schema
+            .arrow_schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect();
+        assert_eq!(names, vec!["time", "user", "amount"]);
+    }
+}
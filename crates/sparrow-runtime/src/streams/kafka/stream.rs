@@ -0,0 +1,390 @@
+use std::collections::BTreeMap;
+use std::io::Cursor;
+use std::sync::Arc;
+use std::time::Duration;
+
+use arrow::datatypes::SchemaRef;
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use avro_rs::types::Value;
+use error_stack::{IntoReport, ResultExt};
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt};
+use rskafka::client::consumer::{StartOffset, StreamConsumerBuilder};
+use rskafka::client::partition::UnknownTopicHandling;
+use rskafka::client::ClientBuilder;
+use rskafka::record::RecordAndOffset;
+use sparrow_api::kaskada::v1alpha::kafka_start_offset::Position;
+use sparrow_api::kaskada::v1alpha::{KafkaStartOffset, KafkaSubscription};
+use tokio::time::timeout;
+
+use super::schema::KafkaSchema;
+use crate::prepare::Error;
+
+/// Magic byte prefixing messages written using the schema registry wire format.
+const REGISTRY_MAGIC_BYTE: u8 = 0;
+
+/// Length of the schema registry wire format header (magic byte and schema ID).
+const REGISTRY_HEADER_LEN: usize = 5;
+
+/// Maximum time to wait for the broker to return records for a partition.
+const MAX_WAIT_MS: i32 = 500;
+
+/// A stream of records from all partitions of a topic, with the partition
+/// each record was read from.
+pub type KafkaConsumer = BoxStream<'static, rskafka::client::error::Result<(i32, RecordAndOffset)>>;
+
+#[derive(derive_more::Display, Debug)]
+pub enum DeserializeError {
+    #[display(fmt = "message has no value")]
+    MissingValue,
+    #[display(fmt = "message is missing the schema registry header")]
+    MissingRegistryHeader,
+    #[display(fmt = "error reading Avro record")]
+    Avro,
+    #[display(fmt = "unsupported Avro value")]
+    UnsupportedType,
+}
+
+impl error_stack::Context for DeserializeError {}
+
+#[derive(Debug)]
+struct DeserializeErrorWrapper(error_stack::Report<DeserializeError>);
+
+impl std::fmt::Display for DeserializeErrorWrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl std::error::Error for DeserializeErrorWrapper {}
+
+/// Creates a Kafka stream to be used during execution in a long-lived process.
+///
+/// This stream should not close naturally. It continually reads messages from
+/// every partition of the topic, batches them, and passes them to the runtime layer.
+///
+/// Note that this stream does not do any filtering or ordering of events.
+pub fn execution_stream(
+    kafka_schema: KafkaSchema,
+    raw_schema: SchemaRef,
+    projected_schema: SchemaRef,
+    consumer: KafkaConsumer,
+) -> impl Stream<Item = Result<RecordBatch, ArrowError>> {
+    async_stream::try_stream! {
+        let mut reader = KafkaReader {
+            kafka_schema,
+            raw_schema,
+            projected_schema,
+            consumer,
+            next_offsets: BTreeMap::new(),
+        };
+        loop {
+            // Indefinitely reads messages from the stream
+            if let Some(next) = reader.next_result_async().await? {
+                yield next
+            } else {
+                // Keep looping - this may happen if we timed out trying to read from the stream
+            }
+        }
+    }
+}
+
+struct KafkaReader {
+    kafka_schema: KafkaSchema,
+    /// The raw schema; includes all columns in the stream.
+    raw_schema: SchemaRef,
+    /// The projected schema; includes only columns that are needed by the query.
+    projected_schema: SchemaRef,
+    consumer: KafkaConsumer,
+    /// The offset of the next message to read, keyed by partition.
+    ///
+    /// These aren't committed to the brokers. They are logged after each batch
+    /// so that a restarted consumer may resume from them using
+    /// `KafkaStartOffset::partition_offsets`.
+    next_offsets: BTreeMap<i32, i64>,
+}
+
+impl KafkaReader {
+    // Using ArrowError is not a great fit but that is what PrepareIter requires
+    async fn next_result_async(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        tracing::debug!("reading kafka messages");
+        let max_batch_size = 100000; // TODO make this adaptive based on the size of the messages
+        let mut avro_values = Vec::with_capacity(max_batch_size);
+        while avro_values.len() < max_batch_size {
+            // The consumer waits up to `MAX_WAIT_MS` for each fetch, so use a longer
+            // timeout to detect that no messages are currently available.
+            let next_result = timeout(Duration::from_millis(1000), self.consumer.next()).await;
+            let Ok(next) = next_result else {
+                tracing::trace!("timed out reading next message");
+                break;
+            };
+
+            match next {
+                Some(next) => {
+                    let (partition, record) =
+                        next.map_err(|e| ArrowError::from_external_error(Box::new(e)))?;
+                    let fields = decode_record(&self.kafka_schema, record.record.value.as_deref())
+                        .map_err(|e| {
+                            let wrapped_error = DeserializeErrorWrapper(e);
+                            tracing::debug!("error deserializing message: {:#?}", wrapped_error);
+                            ArrowError::from_external_error(Box::new(wrapped_error))
+                        })?;
+                    avro_values.push(fields);
+                    self.next_offsets.insert(partition, record.offset + 1);
+                }
+                None => {
+                    // The partition consumers never end, so this only happens if
+                    // the topic has no partitions.
+                    tracing::debug!("read None from consumer");
+                    break;
+                }
+            }
+        }
+
+        match avro_values.len() {
+            0 => {
+                tracing::debug!("read no messages");
+                Ok(None)
+            }
+            num_messages => {
+                tracing::info!(
+                    "read {num_messages} messages; next offsets by partition: {:?}",
+                    self.next_offsets
+                );
+                let arrow_data = sparrow_arrow::avro::avro_to_arrow(avro_values).map_err(|e| {
+                    tracing::error!("avro_to_arrow error: {}", e);
+                    ArrowError::from_external_error(Box::new(e))
+                })?;
+                let batch = RecordBatch::try_new(self.raw_schema.clone(), arrow_data)?;
+                let columns = self
+                    .projected_schema
+                    .fields()
+                    .iter()
+                    .map(|field| {
+                        self.raw_schema
+                            .index_of(field.name())
+                            .map(|index| batch.column(index).clone())
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(RecordBatch::try_new(self.projected_schema.clone(), columns).map(Some)?)
+            }
+        }
+    }
+}
+
+/// Decode the Avro record contained in the value of a Kafka message.
+fn decode_record(
+    kafka_schema: &KafkaSchema,
+    value: Option<&[u8]>,
+) -> error_stack::Result<Vec<(String, Value)>, DeserializeError> {
+    let mut value = value.ok_or(DeserializeError::MissingValue)?;
+    if kafka_schema.registry_wire_format {
+        error_stack::ensure!(
+            value.len() >= REGISTRY_HEADER_LEN && value[0] == REGISTRY_MAGIC_BYTE,
+            DeserializeError::MissingRegistryHeader
+        );
+        value = &value[REGISTRY_HEADER_LEN..];
+    }
+
+    let value = avro_rs::from_avro_datum(&kafka_schema.avro_schema, &mut Cursor::new(value), None)
+        .into_report()
+        .change_context(DeserializeError::Avro)?;
+    match value {
+        Value::Record(fields) => Ok(fields),
+        other => Err(error_stack::report!(DeserializeError::UnsupportedType))
+            .attach_printable_lazy(|| format!("expected a record but got {other:?}")),
+    }
+}
+
+/// Create a consumer reading from every partition in the topic.
+///
+/// Each partition starts from the offset configured in the subscription. The
+/// subscription's `group` only identifies the client; rskafka doesn't support
+/// consumer groups, so offsets aren't committed.
+pub async fn consumer(
+    subscription: &KafkaSubscription,
+) -> error_stack::Result<KafkaConsumer, Error> {
+    let config = subscription.config.as_ref().ok_or(Error::Internal)?;
+    let client = ClientBuilder::new(config.hosts.clone())
+        .client_id(format!("sparrow consumer for {}", subscription.group))
+        .build()
+        .await
+        .into_report()
+        .change_context(Error::CreateReader)?;
+
+    let topic = client
+        .list_topics()
+        .await
+        .into_report()
+        .change_context(Error::CreateReader)?
+        .into_iter()
+        .find(|topic| topic.name == config.topic)
+        .ok_or(Error::CreateReader)
+        .into_report()
+        .attach_printable_lazy(|| format!("topic '{}' does not exist", config.topic))?;
+
+    let mut partitions = Vec::with_capacity(topic.partitions.len());
+    for partition in topic.partitions {
+        let partition_client = client
+            .partition_client(config.topic.clone(), partition, UnknownTopicHandling::Retry)
+            .await
+            .into_report()
+            .change_context(Error::CreateReader)?;
+        let start_offset = start_offset(subscription.start_offset.as_ref(), partition);
+        let partition_consumer =
+            StreamConsumerBuilder::new(Arc::new(partition_client), start_offset)
+                .with_max_wait_ms(MAX_WAIT_MS)
+                .build()
+                .map(move |next| next.map(|(record, _high_watermark)| (partition, record)));
+        partitions.push(partition_consumer);
+    }
+
+    Ok(futures::stream::select_all(partitions).boxed())
+}
+
+/// Return the offset to start reading `partition` from.
+///
+/// Partitions with a recorded offset resume from it. Others start from the
+/// configured position, defaulting to the earliest offset.
+fn start_offset(start_offset: Option<&KafkaStartOffset>, partition: i32) -> StartOffset {
+    let Some(start_offset) = start_offset else {
+        return StartOffset::Earliest;
+    };
+
+    if let Some(offset) = start_offset.partition_offsets.get(&partition) {
+        StartOffset::At(*offset)
+    } else if start_offset.position() == Position::Latest {
+        StartOffset::Latest
+    } else {
+        StartOffset::Earliest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::datatypes::{DataType, Field, Schema};
+    use rskafka::record::Record;
+
+    use super::*;
+
+    fn test_schema(registry_wire_format: bool) -> KafkaSchema {
+        let avro_schema = avro_rs::Schema::parse_str(
+            r#"{
+                "type": "record",
+                "name": "Purchase",
+                "fields": [
+                    {"name": "user", "type": "string"},
+                    {"name": "amount", "type": "long"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        KafkaSchema {
+            arrow_schema: Schema::empty(),
+            avro_schema,
+            registry_wire_format,
+        }
+    }
+
+    fn encode(schema: &KafkaSchema) -> Vec<u8> {
+        let record = Value::Record(vec![
+            ("user".to_owned(), Value::String("alice".to_owned())),
+            ("amount".to_owned(), Value::Long(42)),
+        ]);
+        avro_rs::to_avro_datum(&schema.avro_schema, record).unwrap()
+    }
+
+    #[test]
+    fn test_decode_record() {
+        let schema = test_schema(false);
+        let fields = decode_record(&schema, Some(&encode(&schema))).unwrap();
+        assert_eq!(
+            fields,
+            vec![
+                ("user".to_owned(), Value::String("alice".to_owned())),
+                ("amount".to_owned(), Value::Long(42)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_record_registry_wire_format() {
+        let schema = test_schema(true);
+        let mut payload = vec![REGISTRY_MAGIC_BYTE, 0, 0, 0, 7];
+        payload.extend(encode(&schema));
+        let fields = decode_record(&schema, Some(&payload)).unwrap();
+        assert_eq!(fields.len(), 2);
+
+        // Without the header, the message is rejected.
+        assert!(decode_record(&schema, Some(&encode(&schema))).is_err());
+        assert!(decode_record(&schema, None).is_err());
+    }
+
+    #[test]
+    fn test_start_offset() {
+        assert!(matches!(start_offset(None, 0), StartOffset::Earliest));
+
+        let mut latest = KafkaStartOffset::default();
+        latest.set_position(Position::Latest);
+        assert!(matches!(
+            start_offset(Some(&latest), 0),
+            StartOffset::Latest
+        ));
+
+        // Partitions with a recorded offset resume from it.
+        latest.partition_offsets.insert(1, 17);
+        assert!(matches!(
+            start_offset(Some(&latest), 0),
+            StartOffset::Latest
+        ));
+        assert!(matches!(
+            start_offset(Some(&latest), 1),
+            StartOffset::At(17)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resume_from_next_offsets() {
+        let schema = test_schema(false);
+        let message = |partition: i32, offset: i64| {
+            let record = Record {
+                key: None,
+                value: Some(encode(&schema)),
+                headers: BTreeMap::new(),
+                timestamp: chrono::Utc::now(),
+            };
+            Ok::<_, rskafka::client::error::Error>((partition, RecordAndOffset { record, offset }))
+        };
+        let consumer = futures::stream::iter(vec![message(0, 3), message(1, 7), message(0, 4)]);
+
+        let raw_schema = Arc::new(Schema::new(vec![
+            Field::new("user", DataType::Utf8, true),
+            Field::new("amount", DataType::Int64, true),
+        ]));
+        let mut reader = KafkaReader {
+            kafka_schema: schema.clone(),
+            raw_schema: raw_schema.clone(),
+            projected_schema: raw_schema,
+            consumer: consumer.boxed(),
+            next_offsets: BTreeMap::new(),
+        };
+        let batch = reader.next_result_async().await.unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(reader.next_offsets, BTreeMap::from([(0, 5), (1, 8)]));
+
+        // A consumer restarted with the reported offsets continues after the
+        // messages that were read.
+        let resume = KafkaStartOffset {
+            partition_offsets: reader.next_offsets.into_iter().collect(),
+            ..KafkaStartOffset::default()
+        };
+        assert!(matches!(start_offset(Some(&resume), 0), StartOffset::At(5)));
+        assert!(matches!(start_offset(Some(&resume), 1), StartOffset::At(8)));
+        assert!(matches!(
+            start_offset(Some(&resume), 2),
+            StartOffset::Earliest
+        ));
+    }
+}
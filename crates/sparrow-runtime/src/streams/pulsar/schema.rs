@@ -56,7 +56,7 @@ pub fn format_schema(schema: SchemaRef) -> Result<String, Error> {
         })
 }
 
-fn schema_from_formatted(formatted_schema: &str) -> Result<Schema, Error> {
+pub(crate) fn schema_from_formatted(formatted_schema: &str) -> Result<Schema, Error> {
     // construct an avro_schema::schema::Schema from the json formatted schema
     let avro_schema: avro_schema::schema::Schema = serde_json::from_str(formatted_schema)
         .into_report()
@@ -0,0 +1,819 @@
+//! Formatter for Fenl expressions.
+//!
+//! The formatter prints the parsed expression using consistent spacing and
+//! layout. Each node is printed on a single line if it fits within
+//! [MAX_WIDTH] and contains no comments. Otherwise, it is broken across lines:
+//!
+//!  - Each stage of a pipe (`|`) chain is placed on its own line.
+//!  - Each field of a record and argument of a call is placed on its own line,
+//!    with a trailing comma.
+//!  - Each `let` and `def` binding is placed on its own line, followed by the
+//!    `in` and the body.
+//!
+//! The AST doesn't contain comments, so they are collected from the source and
+//! re-inserted at the nearest line break. Comments at the end of a line remain
+//! at the end of the corresponding line.
+
+use std::ops::Range;
+
+use logos::Logos;
+
+use crate::parser::{try_parse_expr, Token};
+use crate::{Argument, Expr, ExprOp, FeatureSetPart, FenlType, Located, ParseErrors};
+
+/// The maximum width of a line before breaking.
+const MAX_WIDTH: usize = 80;
+
+/// The number of spaces for each level of indentation.
+const INDENT: usize = 2;
+
+/// Format the Fenl expression in `input`.
+///
+/// The result parses to the same expression as the input, and formatting it
+/// again produces the same output.
+///
+/// # Errors
+/// Returns the parse errors if the input isn't a valid expression.
+pub fn format_expr(input: &str) -> Result<String, ParseErrors<'_>> {
+    let expr = try_parse_expr(FeatureSetPart::Query, input)?;
+
+    let (comments, code) = scan_source(input);
+    let mut formatter = Formatter {
+        source: input,
+        comments,
+        next_comment: 0,
+        out: String::with_capacity(input.len()),
+        indent: 0,
+    };
+
+    if let Some(code) = code {
+        formatter.leading_comments(code.start);
+        formatter.format(&expr, code.clone(), 0);
+        formatter.trailing_comment(code.end);
+    }
+    formatter.remaining_comments();
+
+    let mut out = formatter.out;
+    out.truncate(out.trim_end().len());
+    out.push('\n');
+    Ok(out)
+}
+
+/// A comment in the source.
+#[derive(Debug)]
+struct Comment {
+    /// The byte offset of the `#` starting the comment.
+    start: usize,
+    /// The text of the comment, including the `#`.
+    text: String,
+}
+
+/// Return the comments in the source and the range of the code.
+///
+/// The comments are found in the gaps between tokens, so that `#` within a
+/// string literal isn't treated as a comment.
+fn scan_source(input: &str) -> (Vec<Comment>, Option<Range<usize>>) {
+    let mut comments = Vec::new();
+    let mut code: Option<Range<usize>> = None;
+
+    let mut lexer = Token::lexer(input);
+    let mut gap_start = 0;
+    loop {
+        let token = lexer.next();
+        let gap_end = if token.is_some() {
+            lexer.span().start
+        } else {
+            input.len()
+        };
+
+        let mut offset = gap_start;
+        while let Some(index) = input[offset..gap_end].find('#') {
+            let start = offset + index;
+            let end = input[start..gap_end]
+                .find('\n')
+                .map_or(gap_end, |end| start + end);
+            comments.push(Comment {
+                start,
+                text: input[start..end].trim_end().to_owned(),
+            });
+            offset = end;
+        }
+
+        if token.is_none() {
+            break;
+        }
+        let span = lexer.span();
+        code = Some(code.map_or(span.clone(), |code| code.start..span.end));
+        gap_start = span.end;
+    }
+
+    (comments, code)
+}
+
+/// The precedence of expressions, from loosest to tightest binding.
+///
+/// These correspond to the levels of the grammar. An expression must be
+/// wrapped in parentheses when it appears where a tighter binding expression
+/// is expected.
+mod precedence {
+    pub(super) const CAST: u8 = 0;
+    pub(super) const PIPE: u8 = 1;
+    pub(super) const OR: u8 = 2;
+    pub(super) const AND: u8 = 3;
+    pub(super) const EQUALITY: u8 = 4;
+    pub(super) const RELATIONAL: u8 = 5;
+    pub(super) const ADDITIVE: u8 = 6;
+    pub(super) const MULTIPLICATIVE: u8 = 7;
+    pub(super) const UNARY: u8 = 8;
+    pub(super) const POSTFIX: u8 = 9;
+    pub(super) const PRIMARY: u8 = 10;
+}
+
+/// How a call is written in the source.
+enum CallSyntax<'a> {
+    /// A function call, such as `sum(x)`.
+    Function(&'a str),
+    /// A binary operator, such as `x + y`.
+    Binary(&'a str, u8),
+    /// A unary operator, such as `-x`.
+    Unary(&'a str),
+    /// An index, such as `x[i]`.
+    Index,
+}
+
+struct Formatter<'a> {
+    source: &'a str,
+    comments: Vec<Comment>,
+    /// The index of the next comment to be written.
+    next_comment: usize,
+    out: String,
+    indent: usize,
+}
+
+impl<'a> Formatter<'a> {
+    /// Return the source text at the given location.
+    fn text(&self, location: &crate::Location) -> &'a str {
+        &self.source[location.start()..location.end()]
+    }
+
+    fn syntax<'e>(&self, expr: &'e Expr) -> Option<CallSyntax<'e>>
+    where
+        'a: 'e,
+    {
+        let name = match expr.op() {
+            ExprOp::Call(name) => name,
+            ExprOp::ExtendRecord(location)
+            | ExprOp::RemoveFields(location)
+            | ExprOp::SelectFields(location) => {
+                return Some(CallSyntax::Function(self.text(location)))
+            }
+            _ => return None,
+        };
+
+        let text = self.text(name.location());
+        let syntax = if text == name.inner() {
+            CallSyntax::Function(text)
+        } else if name.inner() == "index" && text.starts_with('[') {
+            CallSyntax::Index
+        } else if expr.args().len() == 1 {
+            CallSyntax::Unary(text)
+        } else {
+            let precedence = match text {
+                "or" => precedence::OR,
+                "and" => precedence::AND,
+                "==" | "<>" | "!=" => precedence::EQUALITY,
+                "<" | ">" | "<=" | ">=" => precedence::RELATIONAL,
+                "+" | "-" => precedence::ADDITIVE,
+                "*" | "/" => precedence::MULTIPLICATIVE,
+                _ => return Some(CallSyntax::Function(name.inner())),
+            };
+            CallSyntax::Binary(text, precedence)
+        };
+        Some(syntax)
+    }
+
+    fn precedence(&self, expr: &Expr) -> u8 {
+        match expr.op() {
+            ExprOp::Cast(..) => precedence::CAST,
+            ExprOp::Pipe(_) | ExprOp::Let(..) | ExprOp::Def(..) => precedence::PIPE,
+            ExprOp::FieldRef(..) => precedence::POSTFIX,
+            ExprOp::Literal(_) | ExprOp::Reference(_) | ExprOp::Record(..) | ExprOp::Error => {
+                precedence::PRIMARY
+            }
+            _ => match self.syntax(expr) {
+                Some(CallSyntax::Binary(_, precedence)) => precedence,
+                Some(CallSyntax::Unary(_)) => precedence::UNARY,
+                _ => precedence::POSTFIX,
+            },
+        }
+    }
+
+    /// Return the expression printed on a single line.
+    ///
+    /// Returns `None` if the expression can't be printed on a single line,
+    /// because it contains `let` or `def` bindings.
+    fn flat(&self, expr: &Expr, min_precedence: u8) -> Option<String> {
+        let flat = self.flat_unparenthesized(expr)?;
+        if self.precedence(expr) < min_precedence {
+            Some(format!("({flat})"))
+        } else {
+            Some(flat)
+        }
+    }
+
+    fn flat_unparenthesized(&self, expr: &Expr) -> Option<String> {
+        let args = expr.args();
+        let arg = |index: usize, min_precedence: u8| -> Option<String> {
+            self.flat(args[index].value().inner(), min_precedence)
+        };
+
+        let flat = match expr.op() {
+            ExprOp::Literal(literal) => self.text(literal.location()).to_owned(),
+            ExprOp::Reference(name) => name.inner().clone(),
+            ExprOp::FieldRef(field, _) => format!("{}.{field}", arg(0, precedence::POSTFIX)?),
+            ExprOp::Pipe(_) => format!(
+                "{} | {}",
+                arg(0, precedence::OR)?,
+                arg(1, precedence::PIPE)?
+            ),
+            ExprOp::Cast(fenl_type, _) => format!(
+                "{} as {}",
+                arg(0, precedence::PIPE)?,
+                self.text(fenl_type.location())
+            ),
+            ExprOp::Record(names, _) => {
+                if names.is_empty() {
+                    "{}".to_owned()
+                } else {
+                    let mut fields = Vec::with_capacity(names.len());
+                    for (name, value) in names.iter().zip(args.iter()) {
+                        let value = value.value();
+                        fields.push(if is_shorthand(name, value) {
+                            name.inner().clone()
+                        } else {
+                            format!("{name}: {}", self.flat(value.inner(), precedence::CAST)?)
+                        });
+                    }
+                    format!("{{ {} }}", fields.join(", "))
+                }
+            }
+            ExprOp::Let(..) | ExprOp::Def(..) | ExprOp::Error => return None,
+            _ => match self.syntax(expr)? {
+                CallSyntax::Function(name) => {
+                    let mut flat_args = Vec::with_capacity(args.len());
+                    for arg in args.iter() {
+                        let value = self.flat(arg.value().inner(), precedence::CAST)?;
+                        flat_args.push(match arg {
+                            Argument::Positional(_) => value,
+                            Argument::Keyword(keyword, _) => format!("{keyword} = {value}"),
+                        });
+                    }
+                    format!("{name}({})", flat_args.join(", "))
+                }
+                CallSyntax::Binary(op, precedence) => {
+                    format!("{} {op} {}", arg(0, precedence)?, arg(1, precedence + 1)?)
+                }
+                CallSyntax::Unary(op) => format!("{op}{}", arg(0, precedence::UNARY)?),
+                CallSyntax::Index => {
+                    format!(
+                        "{}[{}]",
+                        arg(1, precedence::POSTFIX)?,
+                        arg(0, precedence::CAST)?
+                    )
+                }
+            },
+        };
+        Some(flat)
+    }
+
+    /// Write the expression spanning the `span` of the source.
+    ///
+    /// The expression is written on a single line if possible, and otherwise
+    /// broken across lines.
+    fn format(&mut self, expr: &Expr, span: Range<usize>, min_precedence: u8) {
+        if !self.has_comments_before(span.end) {
+            if let Some(flat) = self.flat(expr, min_precedence) {
+                if self.column() + flat.chars().count() <= MAX_WIDTH {
+                    self.write(&flat);
+                    return;
+                }
+            }
+        }
+
+        if self.precedence(expr) >= min_precedence {
+            self.format_broken(expr, span);
+        } else if is_block(expr) {
+            self.write("(");
+            self.indent += INDENT;
+            self.newline();
+            self.format_broken(expr, span);
+            self.indent -= INDENT;
+            self.newline();
+            self.write(")");
+        } else {
+            self.write("(");
+            self.format_broken(expr, span);
+            self.write(")");
+        }
+    }
+
+    fn format_located(&mut self, expr: &Located<crate::ExprRef>, min_precedence: u8) {
+        self.format(expr.inner(), span(expr), min_precedence)
+    }
+
+    /// Write the value of a binding or field.
+    ///
+    /// Blocks of bindings are started on a new, indented line.
+    fn format_value(&mut self, value: &Located<crate::ExprRef>) {
+        if is_block(value.inner()) {
+            self.indent += INDENT;
+            self.newline();
+            self.format_located(value, precedence::CAST);
+            self.indent -= INDENT;
+        } else {
+            self.format_located(value, precedence::CAST);
+        }
+    }
+
+    fn format_broken(&mut self, expr: &Expr, span: Range<usize>) {
+        let args = expr.args();
+        match expr.op() {
+            ExprOp::Literal(literal) => self.write(self.text(literal.location())),
+            ExprOp::Reference(name) => self.write(name.inner()),
+            ExprOp::FieldRef(field, _) => {
+                self.format_located(args[0].value(), precedence::POSTFIX);
+                self.write(".");
+                self.write(field.inner());
+            }
+            ExprOp::Pipe(_) => self.format_pipe(expr),
+            ExprOp::Let(..) | ExprOp::Def(..) => self.format_block(expr),
+            ExprOp::Cast(fenl_type, _) => {
+                self.format_located(args[0].value(), precedence::PIPE);
+                self.write(" as ");
+                self.write(self.text(fenl_type.location()));
+            }
+            ExprOp::Record(names, _) => {
+                self.write("{");
+                self.indent += INDENT;
+                for (name, value) in names.iter().zip(args.iter()) {
+                    let value = value.value();
+                    self.newline();
+                    // Comments between the name and the value are moved before the field.
+                    self.leading_comments(value.location().start());
+                    if is_shorthand(name, value) {
+                        self.write(name.inner());
+                    } else {
+                        self.write(name.inner());
+                        self.write(": ");
+                        self.format_value(value);
+                    }
+                    self.write(",");
+                    self.trailing_comment(value.location().end());
+                }
+                let has_comments = self.has_comments_before(span.end);
+                if has_comments {
+                    self.newline();
+                    self.leading_comments(span.end);
+                }
+                self.indent -= INDENT;
+                if has_comments {
+                    // The comments end with a new line, so only dedent it.
+                    self.out.truncate(self.out.trim_end_matches(' ').len());
+                    self.out.push_str(&" ".repeat(self.indent));
+                } else {
+                    self.newline();
+                }
+                self.write("}");
+            }
+            ExprOp::Error => (),
+            _ => match self.syntax(expr) {
+                Some(CallSyntax::Function(name)) => {
+                    self.write(name);
+                    self.write("(");
+                    if !args.is_empty() {
+                        self.indent += INDENT;
+                        for arg in args.iter() {
+                            self.newline();
+                            match arg {
+                                Argument::Positional(value) => {
+                                    self.leading_comments(value.location().start());
+                                    self.format_located(value, precedence::CAST);
+                                }
+                                Argument::Keyword(keyword, value) => {
+                                    // Comments between the keyword and the value are moved
+                                    // before the argument.
+                                    self.leading_comments(value.location().start());
+                                    self.write(keyword.inner());
+                                    self.write(" = ");
+                                    self.format_value(value);
+                                }
+                            }
+                            self.write(",");
+                            self.trailing_comment(arg.value().location().end());
+                        }
+                        self.indent -= INDENT;
+                        self.newline();
+                    }
+                    self.write(")");
+                }
+                Some(CallSyntax::Binary(op, precedence)) => {
+                    self.format_located(args[0].value(), precedence);
+                    self.write(" ");
+                    self.write(op);
+                    self.write(" ");
+                    self.format_located(args[1].value(), precedence + 1);
+                }
+                Some(CallSyntax::Unary(op)) => {
+                    self.write(op);
+                    self.format_located(args[0].value(), precedence::UNARY);
+                }
+                Some(CallSyntax::Index) => {
+                    self.format_located(args[1].value(), precedence::POSTFIX);
+                    self.write("[");
+                    self.format_located(args[0].value(), precedence::CAST);
+                    self.write("]");
+                }
+                None => (),
+            },
+        }
+    }
+
+    /// Write a pipe chain with each stage on its own line.
+    fn format_pipe(&mut self, expr: &Expr) {
+        // Pipes are right-associative, so the chain `a | b | c` is parsed as
+        // `a | (b | c)`.
+        let mut stages = vec![expr.args()[0].value()];
+        let mut last = expr.args()[1].value();
+        while let ExprOp::Pipe(_) = last.inner().op() {
+            stages.push(last.inner().args()[0].value());
+            last = last.inner().args()[1].value();
+        }
+
+        for (index, stage) in stages.iter().enumerate() {
+            if index > 0 {
+                self.newline();
+                self.leading_comments(stage.location().start());
+                self.write("| ");
+            }
+            self.format_located(stage, precedence::OR);
+            self.trailing_comment(stage.location().end());
+        }
+        self.newline();
+        self.leading_comments(last.location().start());
+        self.write("| ");
+        self.format_located(last, precedence::PIPE);
+    }
+
+    /// Write a block of `let` and `def` bindings, followed by the body.
+    fn format_block(&mut self, expr: &Expr) {
+        let location = block_location(expr);
+        let mut current = expr;
+        let body = loop {
+            let args = current.args();
+            let body = match current.op() {
+                ExprOp::Let(names, _) => {
+                    // The last name corresponds to the `let_body`.
+                    let bindings = names.len() - 1;
+                    for (name, value) in names.iter().zip(args.iter()).take(bindings) {
+                        let value = value.value();
+                        self.leading_comments(name.location().start());
+                        self.write("let ");
+                        self.write(name.inner());
+                        self.write(" = ");
+                        self.format_value(value);
+                        self.trailing_comment(value.location().end());
+                        self.newline();
+                    }
+                    args[bindings].value()
+                }
+                ExprOp::Def(signature, _) => {
+                    let definition = args[0].value();
+                    self.leading_comments(definition.location().start());
+                    self.write("def ");
+                    self.write(signature.name());
+
+                    let type_parameters: Vec<_> = signature
+                        .type_parameters
+                        .iter()
+                        .filter(|parameter| !is_implicit_type(&parameter.name.0))
+                        .map(|parameter| {
+                            let classes: Vec<_> = parameter
+                                .type_classes
+                                .iter()
+                                .map(ToString::to_string)
+                                .collect();
+                            format!("{}: {}", parameter.name, classes.join(" + "))
+                        })
+                        .collect();
+                    if !type_parameters.is_empty() {
+                        self.write(&format!("<{}>", type_parameters.join(", ")));
+                    }
+
+                    let parameters = signature.parameters();
+                    let parameters: Vec<_> = parameters
+                        .names()
+                        .iter()
+                        .zip(parameters.types())
+                        .map(|(name, fenl_type)| match fenl_type.inner() {
+                            FenlType::TypeRef(type_var) if is_implicit_type(&type_var.0) => {
+                                name.inner().clone()
+                            }
+                            _ => format!("{name}: {}", self.text(fenl_type.location())),
+                        })
+                        .collect();
+                    self.write(&format!("({}) = ", parameters.join(", ")));
+                    self.format_value(definition);
+                    self.trailing_comment(definition.location().end());
+                    self.newline();
+                    args[1].value()
+                }
+                _ => unreachable!("blocks start with a binding"),
+            };
+
+            // The bindings from a single block share the location of the
+            // block. A body with a different location is a nested block.
+            if is_block(body.inner()) && block_location(body.inner()) == location {
+                current = body.inner();
+            } else {
+                break body;
+            }
+        };
+
+        self.leading_comments(body.location().start());
+        self.write("in ");
+        self.format_located(body, precedence::PIPE);
+    }
+
+    fn column(&self) -> usize {
+        let line_start = self.out.rfind('\n').map_or(0, |index| index + 1);
+        self.out[line_start..].chars().count()
+    }
+
+    fn write(&mut self, text: &str) {
+        self.out.push_str(text);
+    }
+
+    /// Start a new line at the current indentation.
+    fn newline(&mut self) {
+        self.out.truncate(self.out.trim_end_matches(' ').len());
+        self.out.push('\n');
+        self.out.push_str(&" ".repeat(self.indent));
+    }
+
+    fn has_comments_before(&self, offset: usize) -> bool {
+        self.comments
+            .get(self.next_comment)
+            .is_some_and(|comment| comment.start < offset)
+    }
+
+    /// Write the comments before the given offset, each on its own line.
+    ///
+    /// This should be called at the start of a line.
+    fn leading_comments(&mut self, offset: usize) {
+        while self.has_comments_before(offset) {
+            let comment = &self.comments[self.next_comment];
+            self.out.push_str(&comment.text);
+            self.next_comment += 1;
+            self.newline();
+        }
+    }
+
+    /// Write the comment on the same line as the given offset, if any.
+    ///
+    /// Only a comment separated from the offset by whitespace and closing
+    /// punctuation is trailing. A comment after more code on the same line
+    /// belongs to that code instead.
+    ///
+    /// This should be called at the end of a line.
+    fn trailing_comment(&mut self, offset: usize) {
+        let Some(comment) = self.comments.get(self.next_comment) else {
+            return;
+        };
+        let is_trailing =
+            |c: char| c != '\n' && (c.is_whitespace() || matches!(c, ',' | ')' | '}'));
+        if comment.start >= offset && self.source[offset..comment.start].chars().all(is_trailing) {
+            self.out.push(' ');
+            self.out.push_str(&comment.text);
+            self.next_comment += 1;
+        }
+    }
+
+    /// Write all remaining comments, each on its own line.
+    fn remaining_comments(&mut self) {
+        while self.next_comment < self.comments.len() {
+            self.newline();
+            self.out.push_str(&self.comments[self.next_comment].text);
+            self.next_comment += 1;
+        }
+    }
+}
+
+fn span<T>(located: &Located<T>) -> Range<usize> {
+    located.location().start()..located.location().end()
+}
+
+fn is_block(expr: &Expr) -> bool {
+    matches!(expr.op(), ExprOp::Let(..) | ExprOp::Def(..))
+}
+
+fn block_location(expr: &Expr) -> Option<&crate::Location> {
+    match expr.op() {
+        ExprOp::Let(_, location) | ExprOp::Def(_, location) => Some(location),
+        _ => None,
+    }
+}
+
+/// Return true if the record field was written using the shorthand `{ name }`.
+fn is_shorthand(name: &Located<String>, value: &Located<crate::ExprRef>) -> bool {
+    matches!(value.inner().op(), ExprOp::Reference(reference) if reference.inner() == name.inner())
+        && value.location() == name.location()
+}
+
+/// Return true if the type variable was added for an untyped `def` parameter.
+fn is_implicit_type(type_var: &str) -> bool {
+    type_var.starts_with("typeof(")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Return the expression without locations, to compare expressions
+    /// parsed from different text.
+    fn without_locations(input: &str) -> serde_json::Value {
+        fn strip(value: &mut serde_json::Value) {
+            match value {
+                serde_json::Value::Object(object) => {
+                    object.remove("start");
+                    object.remove("end");
+                    object.values_mut().for_each(strip);
+                }
+                serde_json::Value::Array(array) => array.iter_mut().for_each(strip),
+                _ => (),
+            }
+        }
+
+        let expr = try_parse_expr(FeatureSetPart::Query, input).unwrap();
+        let mut value = serde_json::to_value(&*expr).unwrap();
+        strip(&mut value);
+        value
+    }
+
+    /// Format the input, checking that the result is idempotent and parses
+    /// to the same expression.
+    fn format(input: &str) -> String {
+        let formatted = format_expr(input).unwrap();
+        assert_eq!(
+            without_locations(input),
+            without_locations(&formatted),
+            "Formatted expression differs:\n{formatted}"
+        );
+        assert_eq!(
+            format_expr(&formatted).unwrap(),
+            formatted,
+            "Formatting is not idempotent"
+        );
+        formatted
+    }
+
+    #[test]
+    fn test_format_operators() {
+        assert_eq!(format("a+b*c"), "a + b * c\n");
+        assert_eq!(format("(a+b)*c"), "(a + b) * c\n");
+        assert_eq!(format("a - (b - c)"), "a - (b - c)\n");
+        assert_eq!(format("((a - b)) - c"), "a - b - c\n");
+        assert_eq!(format("-(-x)"), "--x\n");
+        assert_eq!(format("!(a and b) or c"), "!(a and b) or c\n");
+        assert_eq!(format("a==b  <>  c"), "a == b <> c\n");
+        assert_eq!(format("(a | b) as i64"), "a | b as i64\n");
+        assert_eq!(format("(a as i64) + 1"), "(a as i64) + 1\n");
+    }
+
+    #[test]
+    fn test_format_postfix() {
+        assert_eq!(format("Foo . x"), "Foo.x\n");
+        assert_eq!(format("(a + b).x"), "(a + b).x\n");
+        assert_eq!(format("x [ 1 + 2 ]"), "x[1 + 2]\n");
+        assert_eq!(format("(-x)[0]"), "(-x)[0]\n");
+    }
+
+    #[test]
+    fn test_format_calls() {
+        assert_eq!(
+            format("sum( Foo.x ,window=since(daily()) )"),
+            "sum(Foo.x, window = since(daily()))\n"
+        );
+        assert_eq!(format("add(a, b)"), "add(a, b)\n");
+        assert_eq!(format("extend(Foo,{x:5})"), "extend(Foo, { x: 5 })\n");
+    }
+
+    #[test]
+    fn test_format_literals() {
+        assert_eq!(
+            format("f('single', \"double\", 1.5, 10u8, null, true)"),
+            "f('single', \"double\", 1.5, 10u8, null, true)\n"
+        );
+        assert_eq!(format("\"# not a comment\""), "\"# not a comment\"\n");
+    }
+
+    #[test]
+    fn test_format_records() {
+        assert_eq!(format("{a:1,b}"), "{ a: 1, b }\n");
+        assert_eq!(format("{  }"), "{}\n");
+        assert_eq!(
+            format(
+                "{ purchase_total: sum(Purchases.amount), purchase_count: count(Purchases), \
+                 last_purchase: last(Purchases.time) }"
+            ),
+            "{\n  purchase_total: sum(Purchases.amount),\n  purchase_count: count(Purchases),\n  \
+             last_purchase: last(Purchases.time),\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_format_pipes() {
+        assert_eq!(format("a|b|c"), "a | b | c\n");
+        assert_eq!(format("(a | b) | c"), "(a | b) | c\n");
+        assert_eq!(
+            format(
+                "Purchases | when($input.amount > 100) | sum($input.amount, window = \
+                 since(daily())) | last()"
+            ),
+            "Purchases\n| when($input.amount > 100)\n| sum($input.amount, window = \
+             since(daily()))\n| last()\n"
+        );
+    }
+
+    #[test]
+    fn test_format_bindings() {
+        assert_eq!(
+            format("let a = 1 let b = a + 1 in { a, b }"),
+            "let a = 1\nlet b = a + 1\nin { a, b }\n"
+        );
+        assert_eq!(
+            format("let a = 1 def f(x, y: i64) = x + y in f(a, 2)"),
+            "let a = 1\ndef f(x, y: i64) = x + y\nin f(a, 2)\n"
+        );
+        assert_eq!(
+            format("def f<N: number>(x: N) = x * 2 in f(1)"),
+            "def f<N: number>(x: N) = x * 2\nin f(1)\n"
+        );
+        assert_eq!(
+            format("let a = let b = 1 in b in a"),
+            "let a =\n  let b = 1\n  in b\nin a\n"
+        );
+        assert_eq!(
+            format("(let a = 1 in a) + 1"),
+            "(\n  let a = 1\n  in a\n) + 1\n"
+        );
+        assert_eq!(
+            format("x | (let y = $input in y) | z"),
+            "x\n| (\n  let y = $input\n  in y\n)\n| z\n"
+        );
+        assert_eq!(format("f(let a = 1 in a)"), "f(\n  let a = 1\n  in a,\n)\n");
+    }
+
+    #[test]
+    fn test_format_comments() {
+        assert_eq!(
+            format("# Leading comment\nlet a = 1 # The first\n# About b\nlet b = 2\nin a + b # The result\n# Final"),
+            "# Leading comment\nlet a = 1 # The first\n# About b\nlet b = 2\nin a + b # The result\n# Final\n"
+        );
+        assert_eq!(
+            format("{ a: 1, # One\n b: 2 }"),
+            "{\n  a: 1, # One\n  b: 2,\n}\n"
+        );
+        assert_eq!(
+            format("{ a: 1,\n # Before b\n b: 2\n # At the end\n }"),
+            "{\n  a: 1,\n  # Before b\n  b: 2,\n  # At the end\n}\n"
+        );
+        assert_eq!(
+            format("Foo # The table\n| when($input.x) # The condition\n| last()"),
+            "Foo # The table\n| when($input.x) # The condition\n| last()\n"
+        );
+        assert_eq!(
+            format("f(a, # The first\n b)"),
+            "f(\n  a, # The first\n  b,\n)\n"
+        );
+
+        // Comments after later code on the same line aren't attached to
+        // earlier arguments or bindings.
+        assert_eq!(
+            format("f(a, b, c = # k\n 1)"),
+            "f(\n  a,\n  b,\n  # k\n  c = 1,\n)\n"
+        );
+        assert_eq!(
+            format("let f = 1 def g(x: i64) = # c\n x + f in g(1)"),
+            "let f = 1\n# c\ndef g(x: i64) = x + f\nin g(1)\n"
+        );
+        assert_eq!(
+            format("{ a: 1, b: # Two\n 2 }"),
+            "{\n  a: 1,\n  # Two\n  b: 2,\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_format_parse_error() {
+        assert!(format_expr("let a = in a").is_err());
+    }
+}
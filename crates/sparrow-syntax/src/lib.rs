@@ -10,8 +10,10 @@
     clippy::print_stderr
 )]
 
+mod format;
 mod parser;
 mod syntax;
 
+pub use format::*;
 pub use parser::*;
 pub use syntax::*;
@@ -598,38 +598,38 @@ fn test_indexing_one_literal() {
       args: Arguments([
         Positional(Located(
           value: Expr(
-            op: Reference(Located(
-              value: "a",
+            op: Literal(Located(
+              value: Number("0"),
               location: Location(
                 part: Internal("a[0]"),
-                start: 0,
-                end: 1,
+                start: 2,
+                end: 3,
               ),
             )),
             args: Arguments([]),
           ),
           location: Location(
             part: Internal("a[0]"),
-            start: 0,
-            end: 1,
+            start: 2,
+            end: 3,
           ),
         )),
         Positional(Located(
           value: Expr(
-            op: Literal(Located(
-              value: Number("0"),
+            op: Reference(Located(
+              value: "a",
               location: Location(
                 part: Internal("a[0]"),
-                start: 2,
-                end: 3,
+                start: 0,
+                end: 1,
               ),
             )),
             args: Arguments([]),
           ),
           location: Location(
             part: Internal("a[0]"),
-            start: 2,
-            end: 3,
+            start: 0,
+            end: 1,
           ),
         )),
       ]),
@@ -653,111 +653,111 @@ fn test_indexing_with_expressions() {
         Positional(Located(
           value: Expr(
             op: Call(Located(
-              value: "index",
+              value: "add",
               location: Location(
                 part: Internal("a[0][1 + 1]"),
-                start: 1,
-                end: 4,
+                start: 7,
+                end: 8,
               ),
             )),
             args: Arguments([
               Positional(Located(
                 value: Expr(
-                  op: Reference(Located(
-                    value: "a",
+                  op: Literal(Located(
+                    value: Number("1"),
                     location: Location(
                       part: Internal("a[0][1 + 1]"),
-                      start: 0,
-                      end: 1,
+                      start: 5,
+                      end: 6,
                     ),
                   )),
                   args: Arguments([]),
                 ),
                 location: Location(
                   part: Internal("a[0][1 + 1]"),
-                  start: 0,
-                  end: 1,
+                  start: 5,
+                  end: 6,
                 ),
               )),
               Positional(Located(
                 value: Expr(
                   op: Literal(Located(
-                    value: Number("0"),
+                    value: Number("1"),
                     location: Location(
                       part: Internal("a[0][1 + 1]"),
-                      start: 2,
-                      end: 3,
+                      start: 9,
+                      end: 10,
                     ),
                   )),
                   args: Arguments([]),
                 ),
                 location: Location(
                   part: Internal("a[0][1 + 1]"),
-                  start: 2,
-                  end: 3,
+                  start: 9,
+                  end: 10,
                 ),
               )),
             ]),
           ),
           location: Location(
             part: Internal("a[0][1 + 1]"),
-            start: 0,
-            end: 4,
+            start: 5,
+            end: 10,
           ),
         )),
         Positional(Located(
           value: Expr(
             op: Call(Located(
-              value: "add",
+              value: "index",
               location: Location(
                 part: Internal("a[0][1 + 1]"),
-                start: 7,
-                end: 8,
+                start: 1,
+                end: 4,
               ),
             )),
             args: Arguments([
               Positional(Located(
                 value: Expr(
                   op: Literal(Located(
-                    value: Number("1"),
+                    value: Number("0"),
                     location: Location(
                       part: Internal("a[0][1 + 1]"),
-                      start: 5,
-                      end: 6,
+                      start: 2,
+                      end: 3,
                     ),
                   )),
                   args: Arguments([]),
                 ),
                 location: Location(
                   part: Internal("a[0][1 + 1]"),
-                  start: 5,
-                  end: 6,
+                  start: 2,
+                  end: 3,
                 ),
               )),
               Positional(Located(
                 value: Expr(
-                  op: Literal(Located(
-                    value: Number("1"),
+                  op: Reference(Located(
+                    value: "a",
                     location: Location(
                       part: Internal("a[0][1 + 1]"),
-                      start: 9,
-                      end: 10,
+                      start: 0,
+                      end: 1,
                     ),
                   )),
                   args: Arguments([]),
                 ),
                 location: Location(
                   part: Internal("a[0][1 + 1]"),
-                  start: 9,
-                  end: 10,
+                  start: 0,
+                  end: 1,
                 ),
               )),
             ]),
           ),
           location: Location(
             part: Internal("a[0][1 + 1]"),
-            start: 5,
-            end: 10,
+            start: 0,
+            end: 4,
           ),
         )),
       ]),
@@ -778,24 +778,6 @@ fn test_index_arithmetic() {
         ),
       )),
       args: Arguments([
-        Positional(Located(
-          value: Expr(
-            op: Reference(Located(
-              value: "a",
-              location: Location(
-                part: Internal("a[0 + 1]"),
-                start: 0,
-                end: 1,
-              ),
-            )),
-            args: Arguments([]),
-          ),
-          location: Location(
-            part: Internal("a[0 + 1]"),
-            start: 0,
-            end: 1,
-          ),
-        )),
         Positional(Located(
           value: Expr(
             op: Call(Located(
@@ -851,6 +833,24 @@ fn test_index_arithmetic() {
             end: 7,
           ),
         )),
+        Positional(Located(
+          value: Expr(
+            op: Reference(Located(
+              value: "a",
+              location: Location(
+                part: Internal("a[0 + 1]"),
+                start: 0,
+                end: 1,
+              ),
+            )),
+            args: Arguments([]),
+          ),
+          location: Location(
+            part: Internal("a[0 + 1]"),
+            start: 0,
+            end: 1,
+          ),
+        )),
       ]),
     )
     "###);
@@ -909,6 +909,24 @@ fn test_field_ref_with_indexing() {
         ),
       )),
       args: Arguments([
+        Positional(Located(
+          value: Expr(
+            op: Literal(Located(
+              value: Number("1"),
+              location: Location(
+                part: Internal("a[0].foo[1]"),
+                start: 9,
+                end: 10,
+              ),
+            )),
+            args: Arguments([]),
+          ),
+          location: Location(
+            part: Internal("a[0].foo[1]"),
+            start: 9,
+            end: 10,
+          ),
+        )),
         Positional(Located(
           value: Expr(
             op: FieldRef(Located(
@@ -937,38 +955,38 @@ fn test_field_ref_with_indexing() {
                   args: Arguments([
                     Positional(Located(
                       value: Expr(
-                        op: Reference(Located(
-                          value: "a",
+                        op: Literal(Located(
+                          value: Number("0"),
                           location: Location(
                             part: Internal("a[0].foo[1]"),
-                            start: 0,
-                            end: 1,
+                            start: 2,
+                            end: 3,
                           ),
                         )),
                         args: Arguments([]),
                       ),
                       location: Location(
                         part: Internal("a[0].foo[1]"),
-                        start: 0,
-                        end: 1,
+                        start: 2,
+                        end: 3,
                       ),
                     )),
                     Positional(Located(
                       value: Expr(
-                        op: Literal(Located(
-                          value: Number("0"),
+                        op: Reference(Located(
+                          value: "a",
                           location: Location(
                             part: Internal("a[0].foo[1]"),
-                            start: 2,
-                            end: 3,
+                            start: 0,
+                            end: 1,
                           ),
                         )),
                         args: Arguments([]),
                       ),
                       location: Location(
                         part: Internal("a[0].foo[1]"),
-                        start: 2,
-                        end: 3,
+                        start: 0,
+                        end: 1,
                       ),
                     )),
                   ]),
@@ -987,24 +1005,6 @@ fn test_field_ref_with_indexing() {
             end: 8,
           ),
         )),
-        Positional(Located(
-          value: Expr(
-            op: Literal(Located(
-              value: Number("1"),
-              location: Location(
-                part: Internal("a[0].foo[1]"),
-                start: 9,
-                end: 10,
-              ),
-            )),
-            args: Arguments([]),
-          ),
-          location: Location(
-            part: Internal("a[0].foo[1]"),
-            start: 9,
-            end: 10,
-          ),
-        )),
       ]),
     )
     "###);
@@ -2005,3 +2005,36 @@ fn test_parse_cast_with_pipe() {
     )
     "###);
 }
+
+#[test]
+fn test_parse_def() {
+    let expr = test_expr("def scale(x, factor: f64) = x * factor in scale(a, 2)");
+    let ExprOp::Def(signature, _) = expr.op() else {
+        panic!("Expected def, but was {:?}", expr.op())
+    };
+    assert_eq!(signature.name(), "scale");
+    assert_eq!(signature.arg_names()[0].inner(), "x");
+    assert_eq!(signature.arg_names()[1].inner(), "factor");
+    assert_eq!(
+        signature.parameters().types()[1].inner(),
+        &FenlType::Concrete(arrow::datatypes::DataType::Float64)
+    );
+
+    // The definition is the first argument, and the body is the second.
+    assert!(matches!(expr.arg(0).unwrap().op(), ExprOp::Call(name) if name.inner() == "mul"));
+    assert!(matches!(expr.arg(1).unwrap().op(), ExprOp::Call(name) if name.inner() == "scale"));
+}
+
+#[test]
+fn test_parse_def_with_lets() {
+    // Consecutive lets are combined, and each def wraps the later bindings.
+    let expr = test_expr("let a = 1 let b = 2 def f(x) = x + a let c = f(b) in c");
+    let ExprOp::Let(names, _) = expr.op() else {
+        panic!("Expected let, but was {:?}", expr.op())
+    };
+    assert_eq!(names.len(), 3);
+
+    let def = expr.arg(2).unwrap();
+    assert!(matches!(def.op(), ExprOp::Def(signature, _) if signature.name() == "f"));
+    assert!(matches!(def.arg(1).unwrap().op(), ExprOp::Let(names, _) if names.len() == 2));
+}
@@ -39,6 +39,8 @@ fn lex_string_literal<'input>(lex: &mut Lexer<'input, Token<'input>>) -> Option<
 pub enum Token<'input> {
     #[token("let")]
     KwLet,
+    #[token("def")]
+    KwDef,
     #[token("and")]
     KwAnd,
     #[token("or")]
@@ -144,6 +146,7 @@ impl<'a> Display for Token<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Token::KwLet => write!(f, "let"),
+            Token::KwDef => write!(f, "def"),
             Token::KwAnd => write!(f, "and"),
             Token::KwOr => write!(f, "or"),
             Token::KwIn => write!(f, "in"),
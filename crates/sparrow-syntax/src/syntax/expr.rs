@@ -5,7 +5,7 @@ use codespan_reporting::diagnostic::Label;
 use static_init::dynamic;
 
 use crate::parser::try_parse_expr;
-use crate::{ArgVec, Arguments, FenlType, LiteralValue, ParseErrors, Resolved};
+use crate::{ArgVec, Arguments, FenlType, LiteralValue, ParseErrors, Resolved, Signature};
 
 /// Identifies a specific part of a feature set query.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -215,7 +215,7 @@ pub struct Expr {
 ///
 /// A resolved expression has verified its signature against
 /// the arguments given.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ResolvedExpr {
     pub op: ExprOp,
     // The resolved arguments to the expression.
@@ -257,6 +257,14 @@ pub enum ExprOp {
     /// and then the value is evaluated and returned. Earlier bindings are
     /// available to the expressions of later bindings.
     Let(ArgVec<Located<String>>, Location),
+    /// A function definition. Specifies the signature of the user-defined
+    /// function. Should be two arguments -- the definition of the function
+    /// and the body in which the function may be called.
+    ///
+    /// Parameters without a declared type accept any type. The result type
+    /// is determined from the definition at each call, so the result of the
+    /// signature is not used.
+    Def(Arc<Signature>, Location),
     /// A record expression. Creates a record with the given field names.
     /// Each argument corresponds to a record.
     Record(ArgVec<Located<String>>, Location),
@@ -282,6 +290,15 @@ pub enum ExprOp {
     Error,
 }
 
+/// A binding in a `let ... in` expression.
+#[derive(Debug)]
+pub enum Binding<'a> {
+    /// A value bound to a name, such as `let name = value`.
+    Let(Located<&'a str>, Located<ExprRef>),
+    /// A user-defined function, such as `def name(a, b) = definition`.
+    Def(Box<Signature>, Located<ExprRef>),
+}
+
 #[dynamic]
 static IMPLICIT_INPUT: ExprRef = {
     let reference = Expr::reference(Located::new(
@@ -373,6 +390,44 @@ impl Expr {
         }
     }
 
+    /// Create an expression for a sequence of `let` and `def` bindings.
+    ///
+    /// Consecutive `let` bindings are combined into a single `Let`. Each
+    /// `def` wraps the expressions following it, so that the function is
+    /// available to later bindings and the body.
+    pub fn new_bindings(
+        bindings: ArgVec<Binding<'_>>,
+        body: Located<ExprRef>,
+        location: Location,
+    ) -> Expr {
+        let mut lets = ArgVec::new();
+        let mut body = body;
+        for binding in bindings.into_iter().rev() {
+            match binding {
+                Binding::Let(name, value) => lets.push((name, value)),
+                Binding::Def(signature, definition) => {
+                    if !lets.is_empty() {
+                        lets.reverse();
+                        let expr = Self::new_let(std::mem::take(&mut lets), body, location.clone());
+                        body = Located::new(Arc::new(expr), location.clone());
+                    }
+                    let expr = Expr {
+                        op: ExprOp::Def(Arc::from(signature), location.clone()),
+                        args: [definition, body].into_iter().collect(),
+                    };
+                    body = Located::new(Arc::new(expr), location.clone());
+                }
+            }
+        }
+
+        if lets.is_empty() {
+            Arc::try_unwrap(body.into_inner()).unwrap_or_else(|expr| (*expr).clone())
+        } else {
+            lets.reverse();
+            Self::new_let(lets, body, location)
+        }
+    }
+
     pub fn new_record(
         fields: ArgVec<(Located<&str>, Located<ExprRef>)>,
         location: Location,
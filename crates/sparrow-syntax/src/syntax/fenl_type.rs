@@ -70,6 +70,7 @@ impl<'a> std::fmt::Display for FormatDataType<'a> {
             DataType::UInt64 => fmt.write_str("u64"),
             DataType::Float32 => fmt.write_str("f32"),
             DataType::Float64 => fmt.write_str("f64"),
+            DataType::Decimal128(precision, scale) => write!(fmt, "decimal({precision}, {scale})"),
             DataType::Interval(IntervalUnit::DayTime) => fmt.write_str("interval_days"),
             DataType::Interval(IntervalUnit::YearMonth) => fmt.write_str("interval_months"),
             DataType::Duration(timeunit) => {
@@ -78,10 +79,18 @@ impl<'a> std::fmt::Display for FormatDataType<'a> {
             DataType::Timestamp(timeunit, None) => {
                 write!(fmt, "timestamp_{}", timeunit_suffix(timeunit))
             }
+            DataType::Timestamp(timeunit, Some(time_zone)) => {
+                write!(
+                    fmt,
+                    "timestamp_{}(\"{time_zone}\")",
+                    timeunit_suffix(timeunit)
+                )
+            }
             DataType::Struct(fields) => {
                 write!(fmt, "{}", FormatStruct(fields))
             }
             DataType::Date32 => fmt.write_str("date32"),
+            DataType::List(f) => write!(fmt, "list<{}>", FormatDataType(f.data_type())),
             DataType::Map(f, _) => match f.data_type() {
                 DataType::Struct(fields) => {
                     write!(
@@ -139,10 +148,16 @@ pub enum TypeClass {
     Signed,
     /// Any floating point numeric type.
     Float,
+    /// Any numeric type able to represent fractional values.
+    ///
+    /// Decimals satisfy this as-is. Other numbers are widened to `f64`.
+    Fractional,
     /// Any time delta.
     TimeDelta,
     /// Any ordered type. This includes numbers and timestamps.
     Ordered,
+    /// Any list type.
+    List,
     /// Error variant.
     ///
     /// This indicates the error has already been reported, so no more error
@@ -158,8 +173,10 @@ impl Display for TypeClass {
             TypeClass::Any => fmt.write_str("any"),
             TypeClass::Signed => fmt.write_str("signed"),
             TypeClass::Float => fmt.write_str("float"),
+            TypeClass::Fractional => fmt.write_str("fractional"),
             TypeClass::TimeDelta => fmt.write_str("timedelta"),
             TypeClass::Ordered => fmt.write_str("ordered"),
+            TypeClass::List => fmt.write_str("list"),
             TypeClass::Error => fmt.write_str("error"),
         }
     }
@@ -175,8 +192,10 @@ impl FromStr for TypeClass {
             "any" => Ok(TypeClass::Any),
             "signed" => Ok(TypeClass::Signed),
             "float" => Ok(TypeClass::Float),
+            "fractional" => Ok(TypeClass::Fractional),
             "timedelta" => Ok(TypeClass::TimeDelta),
             "ordered" => Ok(TypeClass::Ordered),
+            "list" => Ok(TypeClass::List),
             _ => Err(TypeClass::Error),
         }
     }
@@ -188,6 +207,7 @@ impl FromStr for TypeClass {
 pub enum WindowBehavior {
     Since,
     Sliding,
+    Trailing,
 }
 
 impl WindowBehavior {
@@ -195,6 +215,7 @@ impl WindowBehavior {
         match self {
             Self::Since => "since",
             Self::Sliding => "sliding",
+            Self::Trailing => "trailing",
         }
     }
 }
@@ -241,6 +262,47 @@ impl FromStr for FenlType {
             "duration_ms" => Ok(DataType::Duration(TimeUnit::Millisecond).into()),
             "duration_us" => Ok(DataType::Duration(TimeUnit::Microsecond).into()),
             "duration_ns" => Ok(DataType::Duration(TimeUnit::Nanosecond).into()),
+            s if s.starts_with("decimal(") && s.ends_with(')') => {
+                let args = s[8..s.len() - 1]
+                    .split(',')
+                    .map(|s| s.trim())
+                    .collect::<Vec<_>>();
+
+                // Precision and scale for a decimal
+                if args.len() != 2 {
+                    return Err(FenlType::Error);
+                }
+                let precision = u8::from_str(args[0]).map_err(|_| FenlType::Error)?;
+                let scale = i8::from_str(args[1]).map_err(|_| FenlType::Error)?;
+                if precision == 0
+                    || precision > arrow_schema::DECIMAL128_MAX_PRECISION
+                    || scale > precision as i8
+                {
+                    return Err(FenlType::Error);
+                }
+                Ok(DataType::Decimal128(precision, scale).into())
+            }
+            s if s.starts_with("timestamp_") && s.ends_with(')') => {
+                let (unit, time_zone) = s[..s.len() - 1].split_once('(').ok_or(FenlType::Error)?;
+                let unit = match unit {
+                    "timestamp_s" => TimeUnit::Second,
+                    "timestamp_ms" => TimeUnit::Millisecond,
+                    "timestamp_us" => TimeUnit::Microsecond,
+                    "timestamp_ns" => TimeUnit::Nanosecond,
+                    _ => return Err(FenlType::Error),
+                };
+
+                // The time zone may be quoted, as in the string representation.
+                let time_zone = time_zone.trim();
+                let time_zone = time_zone
+                    .strip_prefix('"')
+                    .and_then(|tz| tz.strip_suffix('"'))
+                    .unwrap_or(time_zone);
+                if time_zone.is_empty() {
+                    return Err(FenlType::Error);
+                }
+                Ok(DataType::Timestamp(unit, Some(time_zone.into())).into())
+            }
             "window" => Ok(FenlType::Window),
             "json" => Ok(FenlType::Json),
             // TODO(https://github.com/kaskada-ai/kaskada/issues/494): Support fenl types
@@ -89,9 +89,18 @@ impl Signature {
                 _ => (),
             }
         }
-        if let FenlType::TypeRef(type_var) = &result {
-            verify_is_defined(type_var, &type_parameters, &name)?;
-            type_vars.push(type_var);
+        match &result {
+            FenlType::TypeRef(type_var) => {
+                verify_is_defined(type_var, &type_parameters, &name)?;
+                type_vars.push(type_var);
+            }
+            FenlType::Collection(_, coll_types) => {
+                for type_var in coll_types {
+                    verify_is_defined(type_var, &type_parameters, &name)?;
+                    type_vars.push(type_var);
+                }
+            }
+            _ => (),
         }
 
         // check that all type parameters defined are used in the parameters or result
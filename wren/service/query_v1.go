@@ -421,7 +421,8 @@ func (q *queryV1Service) validateOutputTo(ctx context.Context, query *v1alpha.Qu
 		switch kind := query.Destination.Destination.(type) {
 		case *v1alpha.Destination_ObjectStore:
 			switch kind.ObjectStore.FileType {
-			case v1alpha.FileType_FILE_TYPE_PARQUET, v1alpha.FileType_FILE_TYPE_CSV:
+			case v1alpha.FileType_FILE_TYPE_PARQUET, v1alpha.FileType_FILE_TYPE_CSV,
+				v1alpha.FileType_FILE_TYPE_JSONL, v1alpha.FileType_FILE_TYPE_ARROW_IPC:
 				return nil
 			default:
 				subLogger.Warn().Interface("kind", kind).Interface("type", kind.ObjectStore.FileType).Msg("unknown output_to file_type, defaulting to 'ObjectStore->Parquet'")